@@ -84,6 +84,17 @@ impl KeyDeserialize for SlashingRequestId {
     }
 }
 
+impl KeyDeserialize for &SlashingRequestId {
+    type Output = SlashingRequestId;
+
+    const KEY_ELEMS: u16 = 1;
+
+    #[inline(always)]
+    fn from_vec(value: Vec<u8>) -> StdResult<Self::Output> {
+        Self::Output::from_vec(value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,40 +1,54 @@
 #![cfg(not(target_arch = "wasm32"))]
 // Only exposed on unit and integration testing, not compiled to Wasm.
 
+mod price_feed;
+
 use bvs_guardrail::testing::GuardrailContract;
 pub use bvs_library::testing::{Cw20TokenContract, TestingContract};
+pub use bvs_oracle::testing::OracleContract;
 pub use bvs_pauser::testing::PauserContract;
 pub use bvs_registry::testing::RegistryContract;
 pub use bvs_vault_bank::testing::VaultBankContract;
 pub use bvs_vault_cw20::testing::VaultCw20Contract;
 pub use bvs_vault_router::testing::VaultRouterContract;
-use cosmwasm_std::{Addr, Env};
+use cosmwasm_std::{Addr, Env, HexBinary};
 use cw20::MinterResponse;
 use cw_multi_test::App;
+pub use price_feed::MockPriceFeedContract;
 
 pub struct BvsMultiTest {
     pub pauser: PauserContract,
     pub registry: RegistryContract,
     pub guardrail: GuardrailContract,
     pub vault_router: VaultRouterContract,
+    pub oracle: OracleContract,
 }
 
 /// [BvsMultiTest] provides a convenient way to bootstrap all the necessary contracts
 /// for testing a Service in the BVS ecosystem.
 impl BvsMultiTest {
     /// Creates a new instance of [BvsMultiTest] with the given [App] and [Env].
-    /// It initializes the [PauserContract], [RegistryContract], and [VaultRouterContract].
+    /// It initializes the [PauserContract], [RegistryContract], [VaultRouterContract],
+    /// and [OracleContract] (set as the oracle of the [VaultRouterContract]).
     pub fn new(app: &mut App, env: &Env) -> Self {
         let pauser = PauserContract::new(app, env, None);
         let registry = RegistryContract::new(app, env, None);
         let guardrail = GuardrailContract::new(app, env, None);
         let vault_router = VaultRouterContract::new(app, env, None);
+        let oracle = OracleContract::new(app, env, None);
+
+        let set_oracle = &bvs_vault_router::msg::ExecuteMsg::SetOracle {
+            oracle: Some(oracle.addr.to_string()),
+        };
+        let owner = app.api().addr_make("owner");
+        vault_router.execute(app, &owner, set_oracle).unwrap();
 
         Self {
             pauser,
             registry,
             guardrail,
             vault_router,
+            oracle,
         }
     }
 
@@ -111,12 +125,39 @@ impl BvsMultiTest {
 
         Cw20TokenContract::new(app, env, Some(init_msg))
     }
+
+    /// Deploys a new [MockPriceFeedContract] (Pyth-style) and sets it as the price source
+    /// of the asset in the [OracleContract], with the given price feed id and asset decimals.
+    pub fn deploy_mock_price_feed(
+        &self,
+        app: &mut App,
+        env: &Env,
+        asset_id: impl Into<String>,
+        decimals: u8,
+        price_id: HexBinary,
+    ) -> MockPriceFeedContract {
+        let price_feed = MockPriceFeedContract::new(app, env, None);
+        let set_price_source = &bvs_oracle::msg::ExecuteMsg::SetPriceSource {
+            asset_id: asset_id.into(),
+            source: bvs_oracle::msg::PriceSource {
+                decimals,
+                feed: bvs_oracle::msg::PriceFeed::Pyth {
+                    contract: price_feed.addr.clone(),
+                    price_id,
+                },
+            },
+        };
+        let owner = app.api().addr_make("owner");
+        self.oracle.execute(app, &owner, set_price_source).unwrap();
+        price_feed
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{Decimal, Uint128};
     use cw_multi_test::App;
 
     #[test]
@@ -155,4 +196,25 @@ mod tests {
         assert_eq!(cw20_vault.init.operator, operator.to_string());
         assert_eq!(cw20_vault.init.cw20_contract, token.addr.to_string());
     }
+
+    #[test]
+    fn test_deploy_mock_price_feed() {
+        let mut app = App::default();
+        let env = mock_env();
+        let bvs = BvsMultiTest::new(&mut app, &env);
+
+        let asset_id = "cosmos:cosmos-testnet/bank:denom".to_string();
+        let price_id = HexBinary::from(vec![1; 32]);
+        let price_feed =
+            bvs.deploy_mock_price_feed(&mut app, &env, asset_id.clone(), 6, price_id.clone());
+
+        // 2.5 USD, 2_000_000 denom = 2 units
+        price_feed.set_price(&mut app, &price_id, 250, -2);
+        let msg = bvs_oracle::msg::QueryMsg::UsdValue {
+            asset_id,
+            amount: Uint128::new(2_000_000),
+        };
+        let res: bvs_oracle::msg::UsdValueResponse = bvs.oracle.query(&app, &msg).unwrap();
+        assert_eq!(res.0, Some(Decimal::percent(500)));
+    }
 }
@@ -0,0 +1,108 @@
+//! A mock Pyth-style price feed contract, for testing the BVS Oracle offline.
+//! It implements the [`bvs_oracle::pyth::QueryMsg`] interface with prices set by anyone.
+
+use bvs_library::testing::TestingContract;
+use bvs_oracle::pyth::{Price, PriceFeed, PriceFeedResponse, QueryMsg};
+use cosmwasm_std::{
+    from_json, to_json_binary, to_json_vec, Addr, Binary, Deps, DepsMut, Empty, Env, HexBinary,
+    Int64, MessageInfo, Response, StdError, StdResult, Uint64,
+};
+use cw_multi_test::{App, Contract, ContractWrapper};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Set the price (`price * 10^expo`) of the feed `id`, published at the current block time.
+    SetPrice {
+        id: HexBinary,
+        price: Int64,
+        expo: i32,
+    },
+}
+
+fn instantiate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+fn execute(deps: DepsMut, env: Env, _info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
+    match msg {
+        ExecuteMsg::SetPrice { id, price, expo } => {
+            let price = Price {
+                price,
+                conf: Uint64::zero(),
+                expo,
+                publish_time: env.block.time.seconds() as i64,
+            };
+            let price_feed = PriceFeed {
+                id: id.clone(),
+                price: price.clone(),
+                ema_price: price,
+            };
+            deps.storage.set(id.as_slice(), &to_json_vec(&price_feed)?);
+            Ok(Response::new())
+        }
+    }
+}
+
+fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::PriceFeed { id } => {
+            let price_feed: PriceFeed = deps
+                .storage
+                .get(id.as_slice())
+                .ok_or(StdError::not_found("price feed"))
+                .and_then(from_json)?;
+            to_json_binary(&PriceFeedResponse { price_feed })
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MockPriceFeedContract {
+    pub addr: Addr,
+    pub init: InstantiateMsg,
+}
+
+impl TestingContract<InstantiateMsg, ExecuteMsg, QueryMsg> for MockPriceFeedContract {
+    fn wrapper() -> Box<dyn Contract<Empty>> {
+        Box::new(ContractWrapper::new(execute, instantiate, query))
+    }
+
+    fn default_init(_app: &mut App, _env: &Env) -> InstantiateMsg {
+        InstantiateMsg {}
+    }
+
+    fn new(app: &mut App, env: &Env, msg: Option<InstantiateMsg>) -> Self {
+        let init = msg.unwrap_or(Self::default_init(app, env));
+        let code_id = Self::store_code(app);
+        let addr = Self::instantiate(app, code_id, "mock_price_feed", &init);
+        Self { addr, init }
+    }
+
+    fn addr(&self) -> &Addr {
+        &self.addr
+    }
+}
+
+impl MockPriceFeedContract {
+    /// Set the price (`price * 10^expo`) of the feed `id`, published at the current block time.
+    pub fn set_price(&self, app: &mut App, id: &HexBinary, price: i64, expo: i32) {
+        let sender = app.api().addr_make("sender");
+        let msg = &ExecuteMsg::SetPrice {
+            id: id.clone(),
+            price: Int64::new(price),
+            expo,
+        };
+        self.execute(app, &sender, msg).unwrap();
+    }
+}
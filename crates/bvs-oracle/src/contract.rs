@@ -0,0 +1,532 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use bvs_library::ownership;
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cw2::set_contract_version;
+
+const CONTRACT_NAME: &str = concat!("crates.io:", env!("CARGO_PKG_NAME"));
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let owner = deps.api.addr_validate(&msg.owner)?;
+    ownership::set_owner(deps.storage, &owner)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("owner", msg.owner))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::SetPriceSource { asset_id, source } => {
+            execute::set_price_source(deps, info, asset_id, source)
+        }
+        ExecuteMsg::RemovePriceSource { asset_id } => {
+            execute::remove_price_source(deps, info, asset_id)
+        }
+        ExecuteMsg::PushPrice { asset_id, price } => {
+            execute::push_price(deps, env, info, asset_id, price)
+        }
+        ExecuteMsg::TransferOwnership { new_owner } => {
+            let new_owner = deps.api.addr_validate(&new_owner)?;
+            ownership::transfer_ownership(deps.storage, info, new_owner)
+                .map_err(ContractError::Ownership)
+        }
+    }
+}
+
+mod execute {
+    use super::*;
+    use crate::msg::{Price, PriceFeed, PriceSource};
+    use crate::state::{MAX_DECIMALS, PRICE_SOURCES, PUSHED_PRICES};
+    use cosmwasm_std::{Decimal, Event};
+
+    /// Set (or replace) the price source of the asset.
+    /// The pushed price (if any) is removed,
+    /// so a price pushed for a previous source is never read for the new source.
+    pub fn set_price_source(
+        deps: DepsMut,
+        info: MessageInfo,
+        asset_id: String,
+        source: PriceSource,
+    ) -> Result<Response, ContractError> {
+        ownership::assert_owner(deps.storage, &info)?;
+
+        if asset_id.is_empty() {
+            return Err(ContractError::InvalidPriceSource {
+                msg: "Asset id must not be empty".to_string(),
+            });
+        }
+
+        if source.decimals > MAX_DECIMALS {
+            return Err(ContractError::InvalidPriceSource {
+                msg: "Decimals must not exceed 18".to_string(),
+            });
+        }
+
+        let mut event = Event::new("PriceSourceSet")
+            .add_attribute("asset_id", &asset_id)
+            .add_attribute("decimals", source.decimals.to_string());
+
+        match &source.feed {
+            PriceFeed::Push => {
+                event = event.add_attribute("feed", "push");
+            }
+            PriceFeed::Pyth { contract, price_id } => {
+                deps.api.addr_validate(contract.as_str())?;
+                if price_id.len() != 32 {
+                    return Err(ContractError::InvalidPriceSource {
+                        msg: "Price id must be 32 bytes".to_string(),
+                    });
+                }
+                event = event
+                    .add_attribute("feed", "pyth")
+                    .add_attribute("contract", contract)
+                    .add_attribute("price_id", price_id.to_hex());
+            }
+        }
+
+        PRICE_SOURCES.save(deps.storage, &asset_id, &source)?;
+        PUSHED_PRICES.remove(deps.storage, &asset_id);
+
+        Ok(Response::new().add_event(event))
+    }
+
+    /// Remove the price source (and pushed price) of the asset.
+    pub fn remove_price_source(
+        deps: DepsMut,
+        info: MessageInfo,
+        asset_id: String,
+    ) -> Result<Response, ContractError> {
+        ownership::assert_owner(deps.storage, &info)?;
+
+        PRICE_SOURCES.remove(deps.storage, &asset_id);
+        PUSHED_PRICES.remove(deps.storage, &asset_id);
+
+        Ok(Response::new()
+            .add_event(Event::new("PriceSourceRemoved").add_attribute("asset_id", asset_id)))
+    }
+
+    /// Push the USD price of one whole unit of the asset, published at the current block time.
+    pub fn push_price(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        asset_id: String,
+        price: Decimal,
+    ) -> Result<Response, ContractError> {
+        ownership::assert_owner(deps.storage, &info)?;
+
+        let source = PRICE_SOURCES.may_load(deps.storage, &asset_id)?.ok_or(
+            ContractError::InvalidPrice {
+                msg: "Asset has no price source".to_string(),
+            },
+        )?;
+
+        if source.feed != PriceFeed::Push {
+            return Err(ContractError::InvalidPrice {
+                msg: "Asset price source is not push".to_string(),
+            });
+        }
+
+        if price.is_zero() {
+            return Err(ContractError::InvalidPrice {
+                msg: "Price must be greater than zero".to_string(),
+            });
+        }
+
+        PUSHED_PRICES.save(
+            deps.storage,
+            &asset_id,
+            &Price {
+                price,
+                publish_time: env.block.time,
+            },
+        )?;
+
+        Ok(Response::new().add_event(
+            Event::new("PricePushed")
+                .add_attribute("asset_id", asset_id)
+                .add_attribute("price", price.to_string()),
+        ))
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::PriceSource(asset_id) => to_json_binary(&query::price_source(deps, asset_id)?),
+        QueryMsg::Price(asset_id) => to_json_binary(&query::price(deps, env, asset_id)?),
+        QueryMsg::UsdValue { asset_id, amount } => {
+            to_json_binary(&query::usd_value(deps, env, asset_id, amount)?)
+        }
+    }
+}
+
+mod query {
+    use super::*;
+    use crate::msg::{
+        Price, PriceFeed, PriceResponse, PriceSource, PriceSourceResponse, UsdValueResponse,
+    };
+    use crate::pyth;
+    use crate::state::{MAX_PRICE_AGE, PRICE_SOURCES, PUSHED_PRICES};
+    use cosmwasm_std::{Decimal, StdError, Timestamp, Uint128};
+
+    pub fn price_source(deps: Deps, asset_id: String) -> StdResult<PriceSourceResponse> {
+        let source = PRICE_SOURCES.may_load(deps.storage, &asset_id)?;
+        Ok(PriceSourceResponse(source))
+    }
+
+    /// Returns the USD price of one whole unit of the asset,
+    /// errors if the asset has no price source or the price is stale.
+    pub fn price(deps: Deps, env: Env, asset_id: String) -> StdResult<PriceResponse> {
+        let source = PRICE_SOURCES
+            .may_load(deps.storage, &asset_id)?
+            .ok_or(StdError::not_found("price source"))?;
+        let price = get_price(deps, &env, &asset_id, &source)?;
+        Ok(PriceResponse(price))
+    }
+
+    /// Returns the USD value of the `amount` (in the smallest unit) of the asset,
+    /// `None` if the asset has no price source.
+    pub fn usd_value(
+        deps: Deps,
+        env: Env,
+        asset_id: String,
+        amount: Uint128,
+    ) -> StdResult<UsdValueResponse> {
+        let Some(source) = PRICE_SOURCES.may_load(deps.storage, &asset_id)? else {
+            return Ok(UsdValueResponse(None));
+        };
+
+        if amount.is_zero() {
+            return Ok(UsdValueResponse(Some(Decimal::zero())));
+        }
+
+        let price = get_price(deps, &env, &asset_id, &source)?;
+        let units = Decimal::from_atomics(amount, source.decimals.into())
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+        let value = units.checked_mul(price.price)?;
+        Ok(UsdValueResponse(Some(value)))
+    }
+
+    /// Get the price of the asset from its price source, errors if the price is stale.
+    fn get_price(deps: Deps, env: &Env, asset_id: &str, source: &PriceSource) -> StdResult<Price> {
+        let price = match &source.feed {
+            PriceFeed::Push => PUSHED_PRICES
+                .may_load(deps.storage, asset_id)?
+                .ok_or(StdError::not_found("pushed price"))?,
+            PriceFeed::Pyth { contract, price_id } => {
+                let pyth::PriceFeedResponse { price_feed } = deps.querier.query_wasm_smart(
+                    contract,
+                    &pyth::QueryMsg::PriceFeed {
+                        id: price_id.clone(),
+                    },
+                )?;
+                from_pyth_price(&price_feed.price)?
+            }
+        };
+
+        if price.publish_time.plus_seconds(MAX_PRICE_AGE) < env.block.time {
+            return Err(StdError::generic_err(
+                "Price is older than the max price age",
+            ));
+        }
+
+        Ok(price)
+    }
+
+    /// Convert a Pyth price (`price * 10^expo`) to a [`Decimal`] price.
+    pub(crate) fn from_pyth_price(price: &pyth::Price) -> StdResult<Price> {
+        let value = price.price.i64();
+        if value < 0 {
+            return Err(StdError::generic_err("Price must not be negative"));
+        }
+        let value = Uint128::from(value as u64);
+
+        let decimal = if price.expo >= 0 {
+            let factor = Uint128::new(10).checked_pow(price.expo.unsigned_abs())?;
+            Decimal::from_atomics(value.checked_mul(factor)?, 0)
+        } else {
+            Decimal::from_atomics(value, price.expo.unsigned_abs())
+        }
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+        Ok(Price {
+            price: decimal,
+            publish_time: Timestamp::from_seconds(price.publish_time.max(0) as u64),
+        })
+    }
+}
+
+/// This can only be called by the contract ADMIN, enforced by `wasmd` separate from cosmwasm.
+/// See https://github.com/CosmWasm/cosmwasm/issues/926#issuecomment-851259818
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
+    cw2::ensure_from_older_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::msg::{Price, PriceFeed, PriceSource};
+    use crate::pyth;
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::{Decimal, Event, HexBinary, Int64, StdError, Timestamp, Uint128, Uint64};
+
+    fn pyth_price(price: i64, expo: i32) -> pyth::Price {
+        pyth::Price {
+            price: Int64::new(price),
+            conf: Uint64::zero(),
+            expo,
+            publish_time: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn test_from_pyth_price() {
+        let price = query::from_pyth_price(&pyth_price(6_400_012_345_678, -8)).unwrap();
+        assert_eq!(
+            price,
+            Price {
+                price: "64000.12345678".parse().unwrap(),
+                publish_time: Timestamp::from_seconds(1_700_000_000),
+            }
+        );
+
+        let price = query::from_pyth_price(&pyth_price(12, 3)).unwrap();
+        assert_eq!(price.price, Decimal::from_ratio(12_000u128, 1u128));
+
+        // more decimals than Decimal supports are truncated
+        let price = query::from_pyth_price(&pyth_price(123, -20)).unwrap();
+        assert_eq!(price.price, Decimal::from_atomics(1u128, 18).unwrap());
+
+        let err = query::from_pyth_price(&pyth_price(-1, -8)).unwrap_err();
+        assert_eq!(err, StdError::generic_err("Price must not be negative"));
+    }
+
+    #[test]
+    fn test_push_price() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let owner = deps.api.addr_make("owner");
+        let owner_info = message_info(&owner, &[]);
+        let asset_id = "cosmos:bbn-1/bank:ubbn".to_string();
+
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            owner_info.clone(),
+            InstantiateMsg {
+                owner: owner.to_string(),
+            },
+        )
+        .unwrap();
+
+        // cannot push price without a push price source
+        let err = execute::push_price(
+            deps.as_mut(),
+            env.clone(),
+            owner_info.clone(),
+            asset_id.clone(),
+            Decimal::percent(5),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            ContractError::InvalidPrice {
+                msg: "Asset has no price source".to_string()
+            }
+            .to_string()
+        );
+
+        let res = execute::set_price_source(
+            deps.as_mut(),
+            owner_info.clone(),
+            asset_id.clone(),
+            PriceSource {
+                decimals: 6,
+                feed: PriceFeed::Push,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::new("PriceSourceSet")
+                .add_attribute("asset_id", &asset_id)
+                .add_attribute("decimals", "6")
+                .add_attribute("feed", "push")]
+        );
+
+        // only owner can push price
+        let not_owner = message_info(&deps.api.addr_make("not_owner"), &[]);
+        execute::push_price(
+            deps.as_mut(),
+            env.clone(),
+            not_owner,
+            asset_id.clone(),
+            Decimal::percent(5),
+        )
+        .unwrap_err();
+
+        let res = execute::push_price(
+            deps.as_mut(),
+            env.clone(),
+            owner_info.clone(),
+            asset_id.clone(),
+            Decimal::percent(5),
+        )
+        .unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::new("PricePushed")
+                .add_attribute("asset_id", &asset_id)
+                .add_attribute("price", "0.05")]
+        );
+
+        // 1_000_000 ubbn = 1 bbn = 0.05 USD
+        let res = query::usd_value(
+            deps.as_ref(),
+            env.clone(),
+            asset_id.clone(),
+            Uint128::new(3_000_000),
+        )
+        .unwrap();
+        assert_eq!(res.0, Some(Decimal::percent(15)));
+
+        // asset without price source has no usd value
+        let res = query::usd_value(
+            deps.as_ref(),
+            env.clone(),
+            "cosmos:bbn-1/bank:unknown".to_string(),
+            Uint128::new(3_000_000),
+        )
+        .unwrap();
+        assert_eq!(res.0, None);
+
+        // stale price
+        env.block.time = env.block.time.plus_seconds(15 * 60 + 1);
+        let err = query::price(deps.as_ref(), env.clone(), asset_id.clone()).unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("Price is older than the max price age")
+        );
+
+        // replacing the price source removes the pushed price
+        execute::set_price_source(
+            deps.as_mut(),
+            owner_info.clone(),
+            asset_id.clone(),
+            PriceSource {
+                decimals: 6,
+                feed: PriceFeed::Push,
+            },
+        )
+        .unwrap();
+        let err = query::price(deps.as_ref(), env, asset_id).unwrap_err();
+        assert_eq!(err, StdError::not_found("pushed price"));
+    }
+
+    #[test]
+    fn test_set_price_source_invalid() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let owner = deps.api.addr_make("owner");
+        let owner_info = message_info(&owner, &[]);
+        let pyth = deps.api.addr_make("pyth");
+
+        instantiate(
+            deps.as_mut(),
+            env,
+            owner_info.clone(),
+            InstantiateMsg {
+                owner: owner.to_string(),
+            },
+        )
+        .unwrap();
+
+        let err = execute::set_price_source(
+            deps.as_mut(),
+            owner_info.clone(),
+            "cosmos:bbn-1/bank:ubbn".to_string(),
+            PriceSource {
+                decimals: 19,
+                feed: PriceFeed::Push,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            ContractError::InvalidPriceSource {
+                msg: "Decimals must not exceed 18".to_string()
+            }
+            .to_string()
+        );
+
+        let err = execute::set_price_source(
+            deps.as_mut(),
+            owner_info,
+            "cosmos:bbn-1/bank:ubbn".to_string(),
+            PriceSource {
+                decimals: 6,
+                feed: PriceFeed::Pyth {
+                    contract: pyth,
+                    price_id: HexBinary::from(vec![1; 31]),
+                },
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            ContractError::InvalidPriceSource {
+                msg: "Price id must be 32 bytes".to_string()
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_migrate() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let owner = deps.api.addr_make("owner");
+
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            InstantiateMsg {
+                owner: owner.to_string(),
+            },
+        )
+        .unwrap();
+
+        migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap();
+
+        let version = cw2::get_contract_version(&deps.storage).unwrap();
+        assert_eq!(version.contract, CONTRACT_NAME);
+        assert_eq!(version.version, CONTRACT_VERSION);
+
+        // cannot migrate from a different contract
+        cw2::set_contract_version(&mut deps.storage, "crates.io:other", CONTRACT_VERSION).unwrap();
+        migrate(deps.as_mut(), env, MigrateMsg {}).unwrap_err();
+    }
+}
@@ -0,0 +1,17 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Ownership(#[from] bvs_library::ownership::OwnershipError),
+
+    #[error("Invalid price source: {msg}")]
+    InvalidPriceSource { msg: String },
+
+    #[error("Invalid price: {msg}")]
+    InvalidPrice { msg: String },
+}
@@ -0,0 +1,9 @@
+pub mod contract;
+pub mod msg;
+pub mod pyth;
+pub mod testing;
+
+mod error;
+mod state;
+
+pub use crate::error::ContractError;
@@ -0,0 +1,96 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, HexBinary, Timestamp, Uint128};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub owner: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// ExecuteMsg SetPriceSource sets (or replaces) the price source of the asset.
+    /// Any previously pushed price of the asset is removed.
+    /// Only the `owner` can call this message.
+    SetPriceSource {
+        /// Asset identifier, using the CAIP-19 format.
+        asset_id: String,
+        source: PriceSource,
+    },
+
+    /// ExecuteMsg RemovePriceSource removes the price source (and pushed price) of the asset.
+    /// Only the `owner` can call this message.
+    RemovePriceSource { asset_id: String },
+
+    /// ExecuteMsg PushPrice pushes the USD price of one whole unit of the asset,
+    /// published at the current block time.
+    /// The asset must have a [`PriceFeed::Push`] price source.
+    /// Only the `owner` can call this message.
+    PushPrice { asset_id: String, price: Decimal },
+
+    /// ExecuteMsg TransferOwnership
+    /// See [`bvs_library::ownership::transfer_ownership`] for more information on this field
+    TransferOwnership { new_owner: String },
+}
+
+/// The price source of an asset.
+#[cw_serde]
+pub struct PriceSource {
+    /// The number of decimals of the asset,
+    /// used to convert an amount (in the smallest unit) to whole units.
+    /// Max 18.
+    pub decimals: u8,
+    /// Where the price is read from.
+    pub feed: PriceFeed,
+}
+
+#[cw_serde]
+pub enum PriceFeed {
+    /// The price is pushed by the `owner` with [`ExecuteMsg::PushPrice`].
+    Push,
+    /// The price is queried from a Pyth-style price feed contract,
+    /// see [`crate::pyth`] for the interface.
+    Pyth {
+        contract: Addr,
+        /// The price feed id (32 bytes).
+        price_id: HexBinary,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// QueryMsg PriceSource: returns the price source of the asset.
+    #[returns(PriceSourceResponse)]
+    PriceSource(String),
+
+    /// QueryMsg Price: returns the USD price of one whole unit of the asset.
+    /// Errors if the asset has no price source, or the price is older than the max price age.
+    #[returns(PriceResponse)]
+    Price(String),
+
+    /// QueryMsg UsdValue: returns the USD value of the `amount` (in the smallest unit) of the asset.
+    /// Returns `None` if the asset has no price source,
+    /// errors if the price is older than the max price age.
+    #[returns(UsdValueResponse)]
+    UsdValue { asset_id: String, amount: Uint128 },
+}
+
+#[cw_serde]
+pub struct PriceSourceResponse(pub Option<PriceSource>);
+
+#[cw_serde]
+pub struct PriceResponse(pub Price);
+
+#[cw_serde]
+pub struct Price {
+    /// The USD price of one whole unit of the asset.
+    pub price: Decimal,
+    /// The time the price was published at.
+    pub publish_time: Timestamp,
+}
+
+#[cw_serde]
+pub struct UsdValueResponse(pub Option<Decimal>);
+
+#[cw_serde]
+pub struct MigrateMsg {}
@@ -0,0 +1,42 @@
+//! The Pyth-style price feed interface queried by the oracle for [`crate::msg::PriceFeed::Pyth`].
+//!
+//! This mirrors the `price_feed` query of the Pyth CosmWasm contract,
+//! so the oracle can read from the Pyth contract directly,
+//! or from any adapter contract implementing the same query (e.g., to wrap another price provider).
+
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{HexBinary, Int64, Uint64};
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// QueryMsg PriceFeed: returns the latest price of the feed `id` (32 bytes).
+    #[returns(PriceFeedResponse)]
+    PriceFeed { id: HexBinary },
+}
+
+#[cw_serde]
+pub struct PriceFeedResponse {
+    pub price_feed: PriceFeed,
+}
+
+#[cw_serde]
+pub struct PriceFeed {
+    /// The price feed id (32 bytes).
+    pub id: HexBinary,
+    /// The latest price.
+    pub price: Price,
+    /// The exponentially-weighted moving average price.
+    pub ema_price: Price,
+}
+
+/// A price with a degree of uncertainty at a certain time,
+/// represented as `price * 10^expo` with a confidence interval of `conf * 10^expo`.
+#[cw_serde]
+pub struct Price {
+    pub price: Int64,
+    pub conf: Uint64,
+    pub expo: i32,
+    /// The unix timestamp (in seconds) the price was published at.
+    pub publish_time: i64,
+}
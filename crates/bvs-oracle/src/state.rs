@@ -0,0 +1,16 @@
+use crate::msg::{Price, PriceSource};
+use bvs_library::time::MINUTES;
+use cw_storage_plus::Map;
+
+/// Mapping of asset id (CAIP-19) to its price source.
+pub(crate) const PRICE_SOURCES: Map<&str, PriceSource> = Map::new("price_sources");
+
+/// Mapping of asset id (CAIP-19) to its latest price pushed by the `owner`.
+/// Only for assets with a [`crate::msg::PriceFeed::Push`] price source.
+pub(crate) const PUSHED_PRICES: Map<&str, Price> = Map::new("pushed_prices");
+
+/// The max age of a price in seconds, older prices are rejected as stale.
+pub const MAX_PRICE_AGE: u64 = 15 * MINUTES;
+
+/// The max number of decimals of an asset.
+pub const MAX_DECIMALS: u8 = 18;
@@ -0,0 +1,147 @@
+use bvs_library::{ownership::OwnershipError, testing::TestingContract};
+use bvs_oracle::msg::{
+    ExecuteMsg, InstantiateMsg, Price, PriceFeed, PriceResponse, PriceSource, PriceSourceResponse,
+    QueryMsg, UsdValueResponse,
+};
+use bvs_oracle::testing::OracleContract;
+use bvs_oracle::ContractError;
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{Decimal, Event, Uint128};
+use cw_multi_test::App;
+
+fn instantiate(msg: Option<InstantiateMsg>) -> (App, OracleContract) {
+    let mut app = App::default();
+    let env = mock_env();
+    let contract = OracleContract::new(&mut app, &env, msg);
+    (app, contract)
+}
+
+#[test]
+fn push_price_and_usd_value() {
+    let (mut app, contract) = instantiate(None);
+    let owner = app.api().addr_make("owner");
+    let asset_id = "cosmos:bbn-1/bank:ubbn".to_string();
+
+    let source = PriceSource {
+        decimals: 6,
+        feed: PriceFeed::Push,
+    };
+    let msg = &ExecuteMsg::SetPriceSource {
+        asset_id: asset_id.clone(),
+        source: source.clone(),
+    };
+    let res = contract.execute(&mut app, &owner, msg).unwrap();
+    assert_eq!(
+        res.events[1],
+        Event::new("wasm-PriceSourceSet")
+            .add_attribute("_contract_address", contract.addr.to_string())
+            .add_attribute("asset_id", &asset_id)
+            .add_attribute("decimals", "6")
+            .add_attribute("feed", "push")
+    );
+
+    let res: PriceSourceResponse = contract
+        .query(&app, &QueryMsg::PriceSource(asset_id.clone()))
+        .unwrap();
+    assert_eq!(res, PriceSourceResponse(Some(source)));
+
+    let msg = &ExecuteMsg::PushPrice {
+        asset_id: asset_id.clone(),
+        price: Decimal::percent(250),
+    };
+    contract.execute(&mut app, &owner, msg).unwrap();
+
+    let res: PriceResponse = contract
+        .query(&app, &QueryMsg::Price(asset_id.clone()))
+        .unwrap();
+    assert_eq!(
+        res,
+        PriceResponse(Price {
+            price: Decimal::percent(250),
+            publish_time: app.block_info().time,
+        })
+    );
+
+    // 1_500_000 ubbn = 1.5 bbn at 2.5 USD
+    let res: UsdValueResponse = contract
+        .query(
+            &app,
+            &QueryMsg::UsdValue {
+                asset_id: asset_id.clone(),
+                amount: Uint128::new(1_500_000),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, UsdValueResponse(Some(Decimal::permille(3750))));
+
+    // price is stale after the max price age
+    app.update_block(|block| {
+        block.height += 100;
+        block.time = block.time.plus_minutes(16);
+    });
+    let err = contract
+        .query::<UsdValueResponse>(
+            &app,
+            &QueryMsg::UsdValue {
+                asset_id: asset_id.clone(),
+                amount: Uint128::new(1_500_000),
+            },
+        )
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Price is older than the max price age"));
+
+    // removed price source has no usd value
+    let msg = &ExecuteMsg::RemovePriceSource {
+        asset_id: asset_id.clone(),
+    };
+    contract.execute(&mut app, &owner, msg).unwrap();
+    let res: UsdValueResponse = contract
+        .query(
+            &app,
+            &QueryMsg::UsdValue {
+                asset_id,
+                amount: Uint128::new(1_500_000),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, UsdValueResponse(None));
+}
+
+#[test]
+fn push_price_not_owner() {
+    let (mut app, contract) = instantiate(None);
+    let not_owner = app.api().addr_make("not_owner");
+
+    let msg = &ExecuteMsg::SetPriceSource {
+        asset_id: "cosmos:bbn-1/bank:ubbn".to_string(),
+        source: PriceSource {
+            decimals: 6,
+            feed: PriceFeed::Push,
+        },
+    };
+    let err = contract.execute(&mut app, &not_owner, msg).unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::Ownership(OwnershipError::Unauthorized).to_string()
+    );
+}
+
+#[test]
+fn transfer_ownership() {
+    let (mut app, contract) = instantiate(None);
+    let owner = app.api().addr_make("owner");
+    let new_owner = app.api().addr_make("new_owner");
+
+    let msg = &ExecuteMsg::TransferOwnership {
+        new_owner: new_owner.to_string(),
+    };
+    contract.execute(&mut app, &owner, msg).unwrap();
+
+    let msg = &ExecuteMsg::RemovePriceSource {
+        asset_id: "cosmos:bbn-1/bank:ubbn".to_string(),
+    };
+    contract.execute(&mut app, &new_owner, msg).unwrap();
+    contract.execute(&mut app, &owner, msg).unwrap_err();
+}
@@ -4,12 +4,22 @@ use cosmwasm_std::entry_point;
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 use bvs_library::ownership;
-use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Env, Event, MessageInfo, Reply, Response, StdError,
+    StdResult, SubMsgResult,
+};
 use cw2::set_contract_version;
 
 const CONTRACT_NAME: &str = concat!("crates.io:", env!("CARGO_PKG_NAME"));
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Reply id of the service hook sub-messages, see [`reply`].
+pub const SERVICE_HOOK_REPLY_ID: u64 = 1;
+
+/// Gas limit of a service hook sub-message,
+/// so a service hook cannot consume the gas of the registry action.
+pub const SERVICE_HOOK_GAS_LIMIT: u64 = 500_000;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -42,16 +52,20 @@ pub fn execute(
 
     match msg {
         ExecuteMsg::RegisterAsService { metadata } => {
-            execute::register_as_service(deps, info, metadata)
+            execute::register_as_service(deps, env, info, metadata)
         }
         ExecuteMsg::UpdateServiceMetadata(metadata) => {
-            execute::service_update_metadata(deps, info, metadata)
+            execute::service_update_metadata(deps, env, info, metadata)
         }
         ExecuteMsg::RegisterAsOperator { metadata } => {
-            execute::register_as_operator(deps, info, metadata)
+            execute::register_as_operator(deps, env, info, metadata)
         }
         ExecuteMsg::UpdateOperatorMetadata(metadata) => {
-            execute::update_operator_metadata(deps, info, metadata)
+            execute::update_operator_metadata(deps, env, info, metadata, None)
+        }
+        ExecuteMsg::UpdateOperatorMetadataAsManager { operator, metadata } => {
+            let operator = deps.api.addr_validate(&operator)?;
+            execute::update_operator_metadata(deps, env, info, metadata, Some(operator))
         }
         ExecuteMsg::RegisterOperatorToService { operator } => {
             let operator = deps.api.addr_validate(&operator)?;
@@ -61,40 +75,336 @@ pub fn execute(
             let operator = deps.api.addr_validate(&operator)?;
             execute::deregister_operator_from_service(deps, info, env, operator)
         }
-        ExecuteMsg::RegisterServiceToOperator { service } => {
+        ExecuteMsg::RegisterServiceToOperator { service, operator } => {
             let service = deps.api.addr_validate(&service)?;
-            execute::register_service_to_operator(deps, info, env, service)
+            let operator = operator
+                .map(|operator| deps.api.addr_validate(&operator))
+                .transpose()?;
+            execute::register_service_to_operator(deps, info, env, service, operator)
+        }
+        ExecuteMsg::DeregisterServiceFromOperator { service, operator } => {
+            let service = deps.api.addr_validate(&service)?;
+            let operator = operator
+                .map(|operator| deps.api.addr_validate(&operator))
+                .transpose()?;
+            execute::deregister_service_from_operator(deps, info, env, service, operator)
+        }
+        ExecuteMsg::RegisterOperatorsToService { operators } => {
+            let operators = operators
+                .iter()
+                .map(|operator| deps.api.addr_validate(operator))
+                .collect::<StdResult<Vec<_>>>()?;
+            execute::register_operators_to_service(deps, info, env, operators)
+        }
+        ExecuteMsg::DeregisterOperatorsFromService { operators } => {
+            let operators = operators
+                .iter()
+                .map(|operator| deps.api.addr_validate(operator))
+                .collect::<StdResult<Vec<_>>>()?;
+            execute::deregister_operators_from_service(deps, info, env, operators)
         }
-        ExecuteMsg::DeregisterServiceFromOperator { service } => {
+        ExecuteMsg::RegisterServicesToOperator { services, operator } => {
+            let services = services
+                .iter()
+                .map(|service| deps.api.addr_validate(service))
+                .collect::<StdResult<Vec<_>>>()?;
+            let operator = operator
+                .map(|operator| deps.api.addr_validate(&operator))
+                .transpose()?;
+            execute::register_services_to_operator(deps, info, env, services, operator)
+        }
+        ExecuteMsg::DeregisterServicesFromOperator { services, operator } => {
+            let services = services
+                .iter()
+                .map(|service| deps.api.addr_validate(service))
+                .collect::<StdResult<Vec<_>>>()?;
+            let operator = operator
+                .map(|operator| deps.api.addr_validate(&operator))
+                .transpose()?;
+            execute::deregister_services_from_operator(deps, info, env, services, operator)
+        }
+        ExecuteMsg::FinalizeDeregistration { service, operator } => {
             let service = deps.api.addr_validate(&service)?;
-            execute::deregister_service_from_operator(deps, info, env, service)
+            let operator = deps.api.addr_validate(&operator)?;
+            execute::finalize_deregistration(deps, info, env, operator, service)
+        }
+        ExecuteMsg::DeregisterAsService { limit } => {
+            execute::deregister_as_service(deps, env, info, limit)
+        }
+        ExecuteMsg::DeregisterAsOperator { limit } => {
+            execute::deregister_as_operator(deps, env, info, limit)
+        }
+        ExecuteMsg::SetDeregistrationDelay { delay } => {
+            execute::set_deregistration_delay(deps, info, delay)
+        }
+        ExecuteMsg::SetSlashingParametersNoticePeriod { notice_period } => {
+            execute::set_slashing_parameters_notice_period(deps, info, notice_period)
         }
         ExecuteMsg::EnableSlashing {
             slashing_parameters,
         } => execute::enable_slashing(deps, env, info, slashing_parameters),
         ExecuteMsg::DisableSlashing {} => execute::disable_slashing(deps, env, info),
-        ExecuteMsg::OperatorOptInToSlashing { service } => {
+        ExecuteMsg::ProposeSlashingParameters {
+            slashing_parameters,
+        } => execute::propose_slashing_parameters(deps, env, info, slashing_parameters),
+        ExecuteMsg::ActivateSlashingParameters { service } => {
+            let service = deps.api.addr_validate(&service)?;
+            execute::activate_slashing_parameters(deps, env, service)
+        }
+        ExecuteMsg::SetSlashingCategory {
+            category,
+            slashing_parameters,
+        } => execute::set_slashing_category(deps, env, info, category, slashing_parameters),
+        ExecuteMsg::ProposeSlashingCategory {
+            category,
+            slashing_parameters,
+        } => execute::propose_slashing_category(deps, env, info, category, slashing_parameters),
+        ExecuteMsg::ActivateSlashingCategory { service, category } => {
+            let service = deps.api.addr_validate(&service)?;
+            execute::activate_slashing_category(deps, env, service, category)
+        }
+        ExecuteMsg::RemoveSlashingCategory { category } => {
+            execute::remove_slashing_category(deps, env, info, category)
+        }
+        ExecuteMsg::OperatorOptInToSlashingCategory {
+            service,
+            category,
+            operator,
+        } => {
+            let service = deps.api.addr_validate(&service)?;
+            let operator = operator
+                .map(|operator| deps.api.addr_validate(&operator))
+                .transpose()?;
+            execute::operator_opt_in_to_slashing_category(
+                deps, env, info, service, category, operator,
+            )
+        }
+        ExecuteMsg::ProposeCommission {
+            service,
+            commission_bips,
+        } => {
+            let service = deps.api.addr_validate(&service)?;
+            execute::propose_commission(deps, env, info, service, commission_bips)
+        }
+        ExecuteMsg::ActivateCommission { service, operator } => {
+            let service = deps.api.addr_validate(&service)?;
+            let operator = deps.api.addr_validate(&operator)?;
+            execute::activate_commission(deps, env, operator, service)
+        }
+        ExecuteMsg::SetCommissionChangeDelay { delay } => {
+            execute::set_commission_change_delay(deps, info, delay)
+        }
+        ExecuteMsg::SetRouter { router } => {
+            let router = deps.api.addr_validate(&router)?;
+            execute::set_router(deps, info, router)
+        }
+        ExecuteMsg::SetAdmissionPolicy { policy } => {
+            execute::set_admission_policy(deps, info, policy)
+        }
+        ExecuteMsg::SetAdmissionAllowlist { operators, allowed } => {
+            let operators = operators
+                .iter()
+                .map(|operator| deps.api.addr_validate(operator))
+                .collect::<StdResult<Vec<_>>>()?;
+            execute::set_admission_allowlist(deps, info, operators, allowed)
+        }
+        ExecuteMsg::SetOperatorKey {
+            service,
+            key_type,
+            public_key,
+            signature,
+        } => {
+            let service = deps.api.addr_validate(&service)?;
+            execute::set_operator_key(deps, env, info, service, key_type, public_key, signature)
+        }
+        ExecuteMsg::RemoveOperatorKey { service, key_type } => {
             let service = deps.api.addr_validate(&service)?;
-            execute::operator_opt_in_to_slashing(deps, env, info, service)
+            execute::remove_operator_key(deps, env, info, service, key_type)
+        }
+        ExecuteMsg::OperatorOptInToSlashing { service, operator } => {
+            let service = deps.api.addr_validate(&service)?;
+            let operator = operator
+                .map(|operator| deps.api.addr_validate(&operator))
+                .transpose()?;
+            execute::operator_opt_in_to_slashing(deps, env, info, service, operator)
+        }
+        ExecuteMsg::EnableServiceHooks {} => execute::enable_service_hooks(deps, info),
+        ExecuteMsg::DisableServiceHooks {} => execute::disable_service_hooks(deps, info),
+        ExecuteMsg::SetOperatorManager {
+            manager,
+            permissions,
+        } => {
+            let manager = deps.api.addr_validate(&manager)?;
+            execute::set_operator_manager(deps, info, manager, permissions)
+        }
+        ExecuteMsg::RevokeOperatorManager { manager } => {
+            let manager = deps.api.addr_validate(&manager)?;
+            execute::revoke_operator_manager(deps, info, manager)
         }
         ExecuteMsg::TransferOwnership { new_owner } => {
             let new_owner = deps.api.addr_validate(&new_owner)?;
             ownership::transfer_ownership(deps.storage, info, new_owner)
                 .map_err(ContractError::Ownership)
         }
+        ExecuteMsg::SetMaxActiveRelationships {
+            per_operator,
+            per_service,
+        } => execute::set_max_active_relationships(deps, info, per_operator, per_service),
+        ExecuteMsg::SetMaxActiveRelationshipsForOperator { operator, max } => {
+            let operator = deps.api.addr_validate(&operator)?;
+            execute::set_max_active_relationships_for_operator(deps, info, operator, max)
+        }
+        ExecuteMsg::SetMaxActiveRelationshipsForService { service, max } => {
+            let service = deps.api.addr_validate(&service)?;
+            execute::set_max_active_relationships_for_service(deps, info, service, max)
+        }
     }
 }
 
 mod execute {
+    use crate::contract::{SERVICE_HOOK_GAS_LIMIT, SERVICE_HOOK_REPLY_ID};
     use crate::error::ContractError;
-    use crate::msg::Metadata;
+    use crate::msg::{Metadata, RegistryHookMsg};
     use crate::state;
     use crate::state::{
         get_registration_status, is_slashing_enabled, require_active_registration_status,
-        require_operator_registered, require_service_registered, set_registration_status,
-        RegistrationStatus, SlashingParameters, OPERATORS, SERVICES,
+        require_operator_or_manager, require_operator_registered, require_service_registered,
+        set_registration_status, AdmissionPolicy, KeyType, ManagerPermission, RegistrationStatus,
+        SlashingParameters, Sunset, ADMISSION_ALLOWLIST, ADMISSION_POLICIES,
+        COMMISSION_CHANGE_DELAY, DEREGISTRATION_DELAY, MAX_ACTIVE_RELATIONSHIPS_PER_OPERATOR,
+        MAX_ACTIVE_RELATIONSHIPS_PER_SERVICE, OPERATORS, OPERATOR_MANAGERS,
+        OPERATOR_MAX_ACTIVE_RELATIONSHIPS, OPERATOR_SUNSETS, PENDING_EXITS, REGISTRATION_STATUS,
+        ROUTER, SERVICES, SERVICE_HOOKS, SERVICE_MAX_ACTIVE_RELATIONSHIPS, SERVICE_OPERATORS,
+        SERVICE_SUNSETS, SLASHING_PARAMETERS_NOTICE_PERIOD,
+    };
+    use bvs_library::ownership;
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::{
+        Addr, Deps, DepsMut, Env, Event, HexBinary, MessageInfo, Order, Response, StdError,
+        StdResult, Storage, SubMsg, Uint128, Uint64,
     };
-    use cosmwasm_std::{Addr, DepsMut, Env, Event, MessageInfo, Response};
+    use cw_storage_plus::Bound;
+    use serde::Deserialize;
+
+    /// Hook sub-message to the service contract if the service has enabled hooks.
+    /// The hook is sent with [`SERVICE_HOOK_GAS_LIMIT`] and only replies on error,
+    /// the error is caught in [`super::reply`] so a failing hook cannot revert the registry action.
+    fn service_hook(
+        storage: &dyn Storage,
+        service: &Addr,
+        hook: RegistryHookMsg,
+    ) -> Result<Vec<SubMsg>, ContractError> {
+        if !state::is_service_hooks_enabled(storage, service)? {
+            return Ok(vec![]);
+        }
+
+        let msg = hook.into_cosmos_msg(service)?;
+        Ok(vec![SubMsg::reply_on_error(msg, SERVICE_HOOK_REPLY_ID)
+            .with_gas_limit(SERVICE_HOOK_GAS_LIMIT)
+            .with_payload(service.as_bytes())])
+    }
+
+    /// Subset of the `vault-router` QueryMsg,
+    /// the registry can't depend on the `vault-router` crate as it depends on the registry.
+    #[cw_serde]
+    enum RouterQueryMsg {
+        ListVaultsByOperator {
+            operator: String,
+            limit: Option<u32>,
+            start_after: Option<String>,
+        },
+        OperatorWithdrawalLockPeriod {
+            operator: String,
+        },
+    }
+
+    /// Item of the `vault-router` `VaultListResponse`
+    #[derive(Deserialize)]
+    struct RouterVault {
+        vault: Addr,
+        whitelisted: bool,
+    }
+
+    /// Subset of the vault QueryMsg
+    #[cw_serde]
+    enum VaultQueryMsg {
+        VaultInfo {},
+    }
+
+    /// Subset of the vault `VaultInfoResponse`
+    #[derive(Deserialize)]
+    struct VaultInfo {
+        total_assets: Uint128,
+        asset_id: String,
+    }
+
+    /// Whether the operator is admitted by the service [`AdmissionPolicy`].
+    /// With [`AdmissionPolicy::Manual`] no operator is admitted.
+    /// An operator that isn't admitted falls back to [`RegistrationStatus::OperatorRegistered`],
+    /// pending the manual approval of the service.
+    fn is_admitted(deps: Deps, service: &Addr, operator: &Addr) -> Result<bool, ContractError> {
+        match state::get_admission_policy(deps.storage, service)? {
+            AdmissionPolicy::Manual => Ok(false),
+            AdmissionPolicy::Open => Ok(true),
+            AdmissionPolicy::Allowlist => Ok(state::is_operator_allowlisted(
+                deps.storage,
+                service,
+                operator,
+            )),
+            AdmissionPolicy::MinStake { asset_id, amount } => {
+                Ok(operator_stake(deps, operator, &asset_id)? >= amount)
+            }
+        }
+    }
+
+    /// Total assets of the `asset_id` in the whitelisted vaults of the operator,
+    /// queried through the `vault-router`.
+    ///
+    /// The query messages and responses are the JSON subsets of `bvs_vault_router::msg::QueryMsg::ListVaultsByOperator`
+    /// (`VaultListResponse`) and the vault `QueryMsg::VaultInfo` (`bvs_vault_base::msg::VaultInfoResponse`).
+    /// A change to these router or vault messages must be mirrored here,
+    /// the `registry_admission_min_stake` integration test of the `vault-router` checks them against the real contracts.
+    fn operator_stake(
+        deps: Deps,
+        operator: &Addr,
+        asset_id: &str,
+    ) -> Result<Uint128, ContractError> {
+        let router =
+            ROUTER
+                .may_load(deps.storage)?
+                .ok_or(ContractError::InvalidAdmissionPolicy {
+                    msg: "Router is not set".to_string(),
+                })?;
+
+        let mut stake = Uint128::zero();
+        let mut start_after = None;
+        loop {
+            let vaults: Vec<RouterVault> = deps.querier.query_wasm_smart(
+                &router,
+                &RouterQueryMsg::ListVaultsByOperator {
+                    operator: operator.to_string(),
+                    limit: Some(100),
+                    start_after,
+                },
+            )?;
+
+            for vault in vaults.iter().filter(|vault| vault.whitelisted) {
+                let info: VaultInfo = deps
+                    .querier
+                    .query_wasm_smart(&vault.vault, &VaultQueryMsg::VaultInfo {})?;
+                if info.asset_id == asset_id {
+                    stake = stake
+                        .checked_add(info.total_assets)
+                        .map_err(StdError::from)?;
+                }
+            }
+
+            if vaults.len() < 100 {
+                return Ok(stake);
+            }
+            start_after = vaults.last().map(|vault| vault.vault.to_string());
+        }
+    }
 
     /// Event for MetadataUpdated
     fn create_metadata_event(metadata: Metadata) -> Event {
@@ -114,17 +424,21 @@ mod execute {
     /// Register `info.sender` as a service.
     pub fn register_as_service(
         deps: DepsMut,
+        env: Env,
         info: MessageInfo,
         metadata: Metadata,
     ) -> Result<Response, ContractError> {
-        let registered = SERVICES
-            .may_load(deps.storage, &info.sender)?
-            .unwrap_or(false);
-
-        if registered {
-            return Err(ContractError::ServiceRegistered {});
+        match SERVICES.may_load(deps.storage, &info.sender)? {
+            Some(true) => return Err(ContractError::ServiceRegistered {}),
+            Some(false) => {
+                return Err(ContractError::Retired {
+                    msg: "Service has been retired".to_string(),
+                })
+            }
+            None => {}
         }
 
+        state::set_service_metadata(deps.storage, &env, &info.sender, &metadata)?;
         SERVICES.save(deps.storage, &info.sender, &true)?;
 
         let metadata_event =
@@ -140,10 +454,12 @@ mod execute {
     /// Update service metadata (info.sender is the service)
     pub fn service_update_metadata(
         deps: DepsMut,
+        env: Env,
         info: MessageInfo,
         metadata: Metadata,
     ) -> Result<Response, ContractError> {
         require_service_registered(deps.storage, &info.sender)?;
+        state::set_service_metadata(deps.storage, &env, &info.sender, &metadata)?;
 
         let metadata_event =
             create_metadata_event(metadata).add_attribute("service", info.sender.clone());
@@ -153,23 +469,28 @@ mod execute {
 
     /// Registers the `info.sender` as an operator.
     ///
-    /// `Metadata` is never stored and is only emitted in the `MetadataUpdated` event.
+    /// `Metadata` is stored and emitted in the `MetadataUpdated` event.
     pub fn register_as_operator(
         deps: DepsMut,
+        env: Env,
         info: MessageInfo,
         metadata: Metadata,
     ) -> Result<Response, ContractError> {
         let operator = info.sender.clone();
 
-        // error if the operator is already registered
-        let operator_registered = OPERATORS
-            .may_load(deps.storage, &operator)?
-            .unwrap_or(false);
-        if operator_registered {
-            return Err(ContractError::OperatorRegistered {});
+        // error if the operator is already registered or retired
+        match OPERATORS.may_load(deps.storage, &operator)? {
+            Some(true) => return Err(ContractError::OperatorRegistered {}),
+            Some(false) => {
+                return Err(ContractError::Retired {
+                    msg: "Operator has been retired".to_string(),
+                })
+            }
+            None => {}
         }
 
         // add operator into the state
+        state::set_operator_metadata(deps.storage, &env, &operator, &metadata)?;
         OPERATORS.save(deps.storage, &operator, &true)?;
 
         let mut response = Response::new();
@@ -185,14 +506,23 @@ mod execute {
         Ok(response)
     }
 
-    /// Called by an operator to emit a `MetadataUpdated` event indicating the information has updated.
+    /// Called by an operator (or its manager) to update its stored metadata
+    /// and emit a `MetadataUpdated` event indicating the information has updated.
     pub fn update_operator_metadata(
         deps: DepsMut,
+        env: Env,
         info: MessageInfo,
         metadata: Metadata,
+        operator: Option<Addr>,
     ) -> Result<Response, ContractError> {
-        let operator = info.sender.clone();
+        let operator = require_operator_or_manager(
+            deps.storage,
+            &info.sender,
+            operator,
+            ManagerPermission::Metadata,
+        )?;
         require_operator_registered(deps.storage, &operator)?;
+        state::set_operator_metadata(deps.storage, &env, &operator, &metadata)?;
 
         let mut response = Response::new();
         let metadata_event =
@@ -223,6 +553,9 @@ mod execute {
             RegistrationStatus::Active => Err(ContractError::InvalidRegistrationStatus {
                 msg: "Registration between operator and service is already active".to_string(),
             }),
+            RegistrationStatus::Exiting => Err(ContractError::InvalidRegistrationStatus {
+                msg: "Registration between operator and service is exiting".to_string(),
+            }),
             RegistrationStatus::ServiceRegistered => {
                 Err(ContractError::InvalidRegistrationStatus {
                     msg: "Service has already registered this operator".to_string(),
@@ -235,30 +568,50 @@ mod execute {
                     key,
                     RegistrationStatus::ServiceRegistered,
                 )?;
-                Ok(Response::new().add_event(
-                    Event::new("RegistrationStatusUpdated")
-                        .add_attribute("method", "register_operator_to_service")
-                        .add_attribute("operator", operator)
-                        .add_attribute("service", service)
-                        .add_attribute("status", "ServiceRegistered"),
-                ))
+
+                let hook = RegistryHookMsg::RegistrationStatusUpdated {
+                    operator: operator.to_string(),
+                    status: RegistrationStatus::ServiceRegistered,
+                };
+                let hook = service_hook(deps.storage, &service, hook)?;
+
+                Ok(Response::new()
+                    .add_event(
+                        Event::new("RegistrationStatusUpdated")
+                            .add_attribute("method", "register_operator_to_service")
+                            .add_attribute("operator", operator)
+                            .add_attribute("service", service)
+                            .add_attribute("status", "ServiceRegistered"),
+                    )
+                    .add_submessages(hook))
             }
             RegistrationStatus::OperatorRegistered => {
                 set_registration_status(deps.storage, &env, key, RegistrationStatus::Active)?;
 
-                Ok(Response::new().add_event(
-                    Event::new("RegistrationStatusUpdated")
-                        .add_attribute("method", "register_operator_to_service")
-                        .add_attribute("operator", operator)
-                        .add_attribute("service", service)
-                        .add_attribute("status", "Active"),
-                ))
+                let hook = RegistryHookMsg::RegistrationStatusUpdated {
+                    operator: operator.to_string(),
+                    status: RegistrationStatus::Active,
+                };
+                let hook = service_hook(deps.storage, &service, hook)?;
+
+                Ok(Response::new()
+                    .add_event(
+                        Event::new("RegistrationStatusUpdated")
+                            .add_attribute("method", "register_operator_to_service")
+                            .add_attribute("operator", operator)
+                            .add_attribute("service", service)
+                            .add_attribute("status", "Active"),
+                    )
+                    .add_submessages(hook))
             }
         }
     }
 
     /// Deregister an operator from a service (info.sender is the service)
-    /// Set the registration status to [`RegistrationStatus::Inactive`] (0)
+    /// If the registration is [`RegistrationStatus::Active`] (1),
+    /// set the registration status to [`RegistrationStatus::Exiting`] (4) until finalized with
+    /// [`ExecuteMsg::FinalizeDeregistration`](crate::msg::ExecuteMsg::FinalizeDeregistration).
+    /// Else set the registration status to [`RegistrationStatus::Inactive`] (0)
     pub fn deregister_operator_from_service(
         deps: DepsMut,
         info: MessageInfo,
@@ -276,19 +629,27 @@ mod execute {
                 msg: "Operator is not registered with this service".to_string(),
             })
         } else {
-            set_registration_status(deps.storage, &env, key, RegistrationStatus::Inactive)?;
+            deregister(deps, env, key, status, "deregister_operator_from_service")
+        }
+    }
 
-            Ok(Response::new().add_event(
-                Event::new("RegistrationStatusUpdated")
-                    .add_attribute("method", "deregister_operator_from_service")
-                    .add_attribute("operator", operator)
-                    .add_attribute("service", service)
-                    .add_attribute("status", "Inactive"),
-            ))
+    /// Set the registration status changed by the operator (or its manager),
+    /// opting in the operator to slashing when the registration becomes active only if `can_opt_in`.
+    fn set_operator_registration_status(
+        storage: &mut dyn Storage,
+        env: &Env,
+        key: (&Addr, &Addr),
+        status: RegistrationStatus,
+        can_opt_in: bool,
+    ) -> Result<(), ContractError> {
+        if can_opt_in {
+            set_registration_status(storage, env, key, status)
+        } else {
+            state::set_registration_status_without_opt_in(storage, env, key, status)
         }
     }
 
-    /// Register a service to an operator (info.sender is the operator)
+    /// Register a service to an operator (info.sender is the operator or its manager)
     /// Operator must be registered with [`ExecuteMsg::RegisterAsOperator`]
     /// If the service has registered this operator, the registration status will be set to [`RegistrationStatus::Active`] (1)
     /// Else the registration status will be set to [`RegistrationStatus::OperatorRegistered`] (2)
@@ -297,11 +658,26 @@ mod execute {
         info: MessageInfo,
         env: Env,
         service: Addr,
+        operator: Option<Addr>,
     ) -> Result<Response, ContractError> {
-        let operator = info.sender.clone();
+        let operator = require_operator_or_manager(
+            deps.storage,
+            &info.sender,
+            operator,
+            ManagerPermission::Registration,
+        )?;
         require_service_registered(deps.storage, &service)?;
         require_operator_registered(deps.storage, &operator)?;
 
+        // a manager without the SlashingOptIn permission can't opt in the operator to slashing
+        let can_opt_in = info.sender == operator
+            || state::is_operator_manager(
+                deps.storage,
+                &operator,
+                &info.sender,
+                ManagerPermission::SlashingOptIn,
+            )?;
+
         let key = (&operator, &service);
         let status = get_registration_status(deps.storage, key)?;
 
@@ -309,49 +685,91 @@ mod execute {
             RegistrationStatus::Active => Err(ContractError::InvalidRegistrationStatus {
                 msg: "Registration between operator and service is already active".to_string(),
             }),
+            RegistrationStatus::Exiting => Err(ContractError::InvalidRegistrationStatus {
+                msg: "Registration between operator and service is exiting".to_string(),
+            }),
             RegistrationStatus::OperatorRegistered => {
                 Err(ContractError::InvalidRegistrationStatus {
                     msg: "Operator has already registered this service".to_string(),
                 })
             }
             RegistrationStatus::Inactive => {
-                set_registration_status(
+                // an operator admitted by the service admission policy is active immediately
+                let (status, status_name) = if is_admitted(deps.as_ref(), &service, &operator)? {
+                    (RegistrationStatus::Active, "Active")
+                } else {
+                    (RegistrationStatus::OperatorRegistered, "OperatorRegistered")
+                };
+                set_operator_registration_status(
                     deps.storage,
                     &env,
                     key,
-                    RegistrationStatus::OperatorRegistered,
+                    status.clone(),
+                    can_opt_in,
                 )?;
-                Ok(Response::new().add_event(
-                    Event::new("RegistrationStatusUpdated")
-                        .add_attribute("method", "register_service_to_operator")
-                        .add_attribute("operator", operator)
-                        .add_attribute("service", service)
-                        .add_attribute("status", "OperatorRegistered"),
-                ))
+
+                let hook = RegistryHookMsg::RegistrationStatusUpdated {
+                    operator: operator.to_string(),
+                    status,
+                };
+                let hook = service_hook(deps.storage, &service, hook)?;
+
+                Ok(Response::new()
+                    .add_event(
+                        Event::new("RegistrationStatusUpdated")
+                            .add_attribute("method", "register_service_to_operator")
+                            .add_attribute("operator", operator)
+                            .add_attribute("service", service)
+                            .add_attribute("status", status_name),
+                    )
+                    .add_submessages(hook))
             }
             RegistrationStatus::ServiceRegistered => {
-                set_registration_status(deps.storage, &env, key, RegistrationStatus::Active)?;
+                set_operator_registration_status(
+                    deps.storage,
+                    &env,
+                    key,
+                    RegistrationStatus::Active,
+                    can_opt_in,
+                )?;
 
-                Ok(Response::new().add_event(
-                    Event::new("RegistrationStatusUpdated")
-                        .add_attribute("method", "register_service_to_operator")
-                        .add_attribute("operator", operator)
-                        .add_attribute("service", service)
-                        .add_attribute("status", "Active"),
-                ))
+                let hook = RegistryHookMsg::RegistrationStatusUpdated {
+                    operator: operator.to_string(),
+                    status: RegistrationStatus::Active,
+                };
+                let hook = service_hook(deps.storage, &service, hook)?;
+
+                Ok(Response::new()
+                    .add_event(
+                        Event::new("RegistrationStatusUpdated")
+                            .add_attribute("method", "register_service_to_operator")
+                            .add_attribute("operator", operator)
+                            .add_attribute("service", service)
+                            .add_attribute("status", "Active"),
+                    )
+                    .add_submessages(hook))
             }
         }
     }
 
-    /// Deregister a service from an operator (info.sender is the Operator)
-    /// Set the registration status to [`RegistrationStatus::Inactive`] (0)
+    /// Deregister a service from an operator (info.sender is the Operator or its manager)
+    /// If the registration is [`RegistrationStatus::Active`] (1),
+    /// set the registration status to [`RegistrationStatus::Exiting`] (4) until finalized with
+    /// [`ExecuteMsg::FinalizeDeregistration`](crate::msg::ExecuteMsg::FinalizeDeregistration).
+    /// Else set the registration status to [`RegistrationStatus::Inactive`] (0)
     pub fn deregister_service_from_operator(
         deps: DepsMut,
         info: MessageInfo,
         env: Env,
         service: Addr,
+        operator: Option<Addr>,
     ) -> Result<Response, ContractError> {
-        let operator = info.sender.clone();
+        let operator = require_operator_or_manager(
+            deps.storage,
+            &info.sender,
+            operator,
+            ManagerPermission::Registration,
+        )?;
 
         let key = (&operator, &service);
         let status = get_registration_status(deps.storage, key)?;
@@ -361,1073 +779,5456 @@ mod execute {
                 msg: "Service is not registered with this operator".to_string(),
             })
         } else {
-            set_registration_status(deps.storage, &env, key, RegistrationStatus::Inactive)?;
-
-            Ok(Response::new().add_event(
-                Event::new("RegistrationStatusUpdated")
-                    .add_attribute("method", "deregister_service_from_operator")
-                    .add_attribute("operator", operator)
-                    .add_attribute("service", service)
-                    .add_attribute("status", "Inactive"),
-            ))
+            deregister(deps, env, key, status, "deregister_service_from_operator")
         }
     }
 
-    /// Enable slashing for a service by registering slashing parameters into the registry.
-    ///
-    /// When slashing is enabled, active operators are able to opt in the next block.
-    /// New Operator <-> Service registration will automatically opt in the operator to slashing.
-    /// To update the slashing parameters, the service must call this function again.
-    /// When the slashing parameters are updated,
-    /// all active operators
-    /// that are already registered to the service will have their opt-in status reset for the new slashing parameters
-    /// and have to manually opt in again.
-    pub fn enable_slashing(
-        deps: DepsMut,
-        env: Env,
+    /// Register multiple operators to a service (info.sender is the service),
+    /// see [`register_operator_to_service`].
+    /// All registrations are applied atomically, with an event for each operator.
+    pub fn register_operators_to_service(
+        mut deps: DepsMut,
         info: MessageInfo,
-        slashing_parameters: SlashingParameters,
+        env: Env,
+        operators: Vec<Addr>,
     ) -> Result<Response, ContractError> {
-        // service is the sender
-        let service = info.sender;
-
-        // service must be registered
-        require_service_registered(deps.storage, &service)?;
+        state::validate_batch_size(operators.len())?;
 
-        // clear opt-in mapping
-        state::reset_slashing_opt_in(deps.storage, &env, &service)?;
+        let mut response = Response::new();
+        for operator in operators {
+            let res =
+                register_operator_to_service(deps.branch(), info.clone(), env.clone(), operator)?;
+            response = response
+                .add_events(res.events)
+                .add_submessages(res.messages);
+        }
+        Ok(response)
+    }
 
-        // update slashing parameters
-        state::enable_slashing(deps.storage, deps.api, &env, &service, &slashing_parameters)?;
+    /// Deregister multiple operators from a service (info.sender is the service),
+    /// see [`deregister_operator_from_service`].
+    /// All deregistrations are applied atomically, with an event for each operator.
+    pub fn deregister_operators_from_service(
+        mut deps: DepsMut,
+        info: MessageInfo,
+        env: Env,
+        operators: Vec<Addr>,
+    ) -> Result<Response, ContractError> {
+        state::validate_batch_size(operators.len())?;
 
-        Ok(Response::new().add_event(
-            Event::new("SlashingParametersEnabled")
-                .add_attribute("service", service)
-                .add_attribute(
-                    "destination",
-                    slashing_parameters
-                        .destination
-                        .map(|x| x.to_string())
-                        .unwrap_or_default(),
-                )
-                .add_attribute(
-                    "max_slashing_bips",
-                    slashing_parameters.max_slashing_bips.to_string(),
-                )
-                .add_attribute(
-                    "resolution_window",
-                    slashing_parameters.resolution_window.to_string(),
-                ),
-        ))
+        let mut response = Response::new();
+        for operator in operators {
+            let res = deregister_operator_from_service(
+                deps.branch(),
+                info.clone(),
+                env.clone(),
+                operator,
+            )?;
+            response = response
+                .add_events(res.events)
+                .add_submessages(res.messages);
+        }
+        Ok(response)
     }
 
-    /// Disable slashing for a service by removing slashing parameters from the registry.
-    ///
-    /// When slashing is disabled,
-    /// all operators
-    /// that are opted in to the slashing parameters will be removed from the slashing opt in.
-    /// All active operators will remain actively registered to the service.
-    pub fn disable_slashing(
-        deps: DepsMut,
-        env: Env,
+    /// Register multiple services to an operator (info.sender is the operator or its manager),
+    /// see [`register_service_to_operator`].
+    /// All registrations are applied atomically, with an event for each service.
+    pub fn register_services_to_operator(
+        mut deps: DepsMut,
         info: MessageInfo,
+        env: Env,
+        services: Vec<Addr>,
+        operator: Option<Addr>,
     ) -> Result<Response, ContractError> {
-        // service is the sender
-        let service = info.sender;
-
-        // service must be registered
-        require_service_registered(deps.storage, &service)?;
+        state::validate_batch_size(services.len())?;
 
-        // clear opt-in mapping
-        state::reset_slashing_opt_in(deps.storage, &env, &service)?;
+        let mut response = Response::new();
+        for service in services {
+            let res = register_service_to_operator(
+                deps.branch(),
+                info.clone(),
+                env.clone(),
+                service,
+                operator.clone(),
+            )?;
+            response = response
+                .add_events(res.events)
+                .add_submessages(res.messages);
+        }
+        Ok(response)
+    }
 
-        // remove slashing parameters
-        state::disable_slashing(deps.storage, &env, &service)?;
+    /// Deregister multiple services from an operator (info.sender is the operator or its manager),
+    /// see [`deregister_service_from_operator`].
+    /// All deregistrations are applied atomically, with an event for each service.
+    pub fn deregister_services_from_operator(
+        mut deps: DepsMut,
+        info: MessageInfo,
+        env: Env,
+        services: Vec<Addr>,
+        operator: Option<Addr>,
+    ) -> Result<Response, ContractError> {
+        state::validate_batch_size(services.len())?;
 
-        Ok(Response::new()
-            .add_event(Event::new("SlashingParametersDisabled").add_attribute("service", service)))
+        let mut response = Response::new();
+        for service in services {
+            let res = deregister_service_from_operator(
+                deps.branch(),
+                info.clone(),
+                env.clone(),
+                service,
+                operator.clone(),
+            )?;
+            response = response
+                .add_events(res.events)
+                .add_submessages(res.messages);
+        }
+        Ok(response)
     }
 
-    /// Operator opts in to the service's current slashing parameters.
-    ///
-    /// When slashing is enabled, active operators can opt in to slashing.  
-    /// Newly registered operators, after slashing is enabled, will automatically opt in to slashing
-    /// and don't need to call this function.
-    pub fn operator_opt_in_to_slashing(
+    /// Deregister a non-inactive registration.
+    /// An active registration is set to exiting with the exit timestamp saved in `PENDING_EXITS`,
+    /// a pending registration is set to inactive immediately.
+    fn deregister(
         deps: DepsMut,
         env: Env,
-        info: MessageInfo,
-        service: Addr,
+        key: (&Addr, &Addr),
+        status: RegistrationStatus,
+        method: &str,
     ) -> Result<Response, ContractError> {
-        // operator is the sender
-        let operator = info.sender;
+        let (operator, service) = key;
+        match status {
+            RegistrationStatus::Exiting => Err(ContractError::InvalidRegistrationStatus {
+                msg: "Deregistration is already pending".to_string(),
+            }),
+            RegistrationStatus::Active => {
+                let exit_timestamp = env
+                    .block
+                    .time
+                    .plus_seconds(exit_delay(deps.as_ref(), operator)?)
+                    .seconds();
+                set_registration_status(deps.storage, &env, key, RegistrationStatus::Exiting)?;
+                PENDING_EXITS.save(deps.storage, key, &exit_timestamp)?;
+
+                let hook = RegistryHookMsg::RegistrationStatusUpdated {
+                    operator: operator.to_string(),
+                    status: RegistrationStatus::Exiting,
+                };
+                let hook = service_hook(deps.storage, service, hook)?;
 
-        // operator and service must have Active (1) registration status
-        let key = (&operator, &service);
-        require_active_registration_status(deps.storage, key)?;
+                Ok(Response::new()
+                    .add_event(
+                        Event::new("RegistrationStatusUpdated")
+                            .add_attribute("method", method)
+                            .add_attribute("operator", operator)
+                            .add_attribute("service", service)
+                            .add_attribute("status", "Exiting")
+                            .add_attribute("exit_timestamp", exit_timestamp.to_string()),
+                    )
+                    .add_submessages(hook))
+            }
+            _ => {
+                set_registration_status(deps.storage, &env, key, RegistrationStatus::Inactive)?;
 
-        // check if the slashing is enabled for the service
-        if !is_slashing_enabled(deps.storage, &service, Some(env.block.time.seconds()))? {
-            return Err(ContractError::InvalidSlashingOptIn {
-                msg: "Cannot opt in: slashing is not enabled for this service".to_string(),
-            });
+                let hook = RegistryHookMsg::RegistrationStatusUpdated {
+                    operator: operator.to_string(),
+                    status: RegistrationStatus::Inactive,
+                };
+                let hook = service_hook(deps.storage, service, hook)?;
+
+                Ok(Response::new()
+                    .add_event(
+                        Event::new("RegistrationStatusUpdated")
+                            .add_attribute("method", method)
+                            .add_attribute("operator", operator)
+                            .add_attribute("service", service)
+                            .add_attribute("status", "Inactive"),
+                    )
+                    .add_submessages(hook))
+            }
         }
+    }
 
-        // opt-in to slashing
-        state::opt_in_to_slashing(deps.storage, &env, &service, &operator)?;
+    /// The delay before an exiting registration of the operator can be finalized:
+    /// the deregistration delay, extended to the operator's withdrawal lock period in the `vault-router` (if set).
+    /// The router can slash the operator for incidents as far back as its lock period,
+    /// so the operator must keep validating the service (and be slashable) for at least as long.
+    fn exit_delay(deps: Deps, operator: &Addr) -> Result<u64, ContractError> {
+        let delay = state::get_deregistration_delay(deps.storage)?;
+        let Some(router) = ROUTER.may_load(deps.storage)? else {
+            return Ok(delay);
+        };
 
-        Ok(Response::new().add_event(
-            Event::new("OperatorOptedInToSlashing")
-                .add_attribute("operator", operator)
-                .add_attribute("service", service),
-        ))
+        let lock_period: Uint64 = deps.querier.query_wasm_smart(
+            &router,
+            &RouterQueryMsg::OperatorWithdrawalLockPeriod {
+                operator: operator.to_string(),
+            },
+        )?;
+        Ok(delay.max(lock_period.u64()))
     }
-}
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    match msg {
-        QueryMsg::Status {
-            service,
-            operator,
-            timestamp,
-        } => {
-            let service = deps.api.addr_validate(&service)?;
-            let operator = deps.api.addr_validate(&operator)?;
-            to_json_binary(&query::status(deps, operator, service, timestamp)?)
-        }
-        QueryMsg::IsService(service) => {
-            let service = deps.api.addr_validate(&service)?;
-            to_json_binary(&query::is_service(deps, service)?)
+    /// Finalize the deregistration of an [`RegistrationStatus::Exiting`] (4) registration
+    /// after the deregistration delay has passed
+    /// (info.sender is the operator, its manager with registration permission, or the service).
+    /// Set the registration status to [`RegistrationStatus::Inactive`] (0)
+    pub fn finalize_deregistration(
+        deps: DepsMut,
+        info: MessageInfo,
+        env: Env,
+        operator: Addr,
+        service: Addr,
+    ) -> Result<Response, ContractError> {
+        if info.sender != operator
+            && info.sender != service
+            && !state::is_operator_manager(
+                deps.storage,
+                &operator,
+                &info.sender,
+                ManagerPermission::Registration,
+            )?
+        {
+            return Err(ContractError::Unauthorized {});
         }
-        QueryMsg::IsOperator(operator) => {
-            let operator = deps.api.addr_validate(&operator)?;
-            to_json_binary(&query::is_operator(deps, operator)?)
+
+        let key = (&operator, &service);
+        let exit_timestamp = PENDING_EXITS.may_load(deps.storage, key)?.ok_or(
+            ContractError::InvalidRegistrationStatus {
+                msg: "Registration is not exiting".to_string(),
+            },
+        )?;
+
+        if env.block.time.seconds() < exit_timestamp {
+            return Err(ContractError::InvalidRegistrationStatus {
+                msg: "Deregistration delay has not passed".to_string(),
+            });
         }
-        QueryMsg::IsOperatorActive(operator) => {
-            let operator = deps.api.addr_validate(&operator)?;
-            to_json_binary(&query::is_operator_active(deps, operator)?)
+
+        set_registration_status(deps.storage, &env, key, RegistrationStatus::Inactive)?;
+        PENDING_EXITS.remove(deps.storage, key);
+
+        let hook = RegistryHookMsg::RegistrationStatusUpdated {
+            operator: operator.to_string(),
+            status: RegistrationStatus::Inactive,
+        };
+        let hook = service_hook(deps.storage, &service, hook)?;
+
+        Ok(Response::new()
+            .add_event(
+                Event::new("RegistrationStatusUpdated")
+                    .add_attribute("method", "finalize_deregistration")
+                    .add_attribute("operator", operator)
+                    .add_attribute("service", service)
+                    .add_attribute("status", "Inactive"),
+            )
+            .add_submessages(hook))
+    }
+
+    /// Retire the service (info.sender) and deregister the next `limit` relationships of the service,
+    /// must be called until the [`Sunset`] is completed.
+    ///
+    /// On the first call, the service is retired so no new relationships can be registered,
+    /// and slashing (including all categories) is disabled.
+    /// Incidents before the sunset can still be slashed,
+    /// as slashing is checked against the state at the incident timestamp.
+    /// Active registrations are set to [`RegistrationStatus::Exiting`] and stay slashable
+    /// until finalized after the deregistration delay,
+    /// pending registrations are set to [`RegistrationStatus::Inactive`].
+    pub fn deregister_as_service(
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        limit: Option<u32>,
+    ) -> Result<Response, ContractError> {
+        let service = info.sender;
+        let limit = sunset_limit(limit)?;
+        let mut response = Response::new();
+
+        let mut sunset = match SERVICE_SUNSETS.may_load(deps.storage, &service)? {
+            Some(sunset) => sunset,
+            None => {
+                require_service_registered(deps.storage, &service)?;
+                SERVICES.save(deps.storage, &service, &false)?;
+                state::disable_all_slashing(deps.storage, &env, &service)?;
+                response = response
+                    .add_event(Event::new("ServiceRetired").add_attribute("service", &service));
+                Sunset::default()
+            }
+        };
+
+        if sunset.completed {
+            return Err(ContractError::Retired {
+                msg: "Service sunset has completed".to_string(),
+            });
         }
-        QueryMsg::SlashingParameters { service, timestamp } => {
-            let service = deps.api.addr_validate(&service)?;
-            to_json_binary(&query::get_slashing_parameters(deps, service, timestamp)?)
+
+        let min = sunset.last.as_ref().map(Bound::exclusive);
+        let operators = SERVICE_OPERATORS
+            .prefix(&service)
+            .keys(deps.storage, min, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
+
+        sunset.completed = operators.len() < limit;
+        if let Some(last) = operators.last() {
+            sunset.last = Some(last.clone());
         }
-        QueryMsg::IsOperatorOptedInToSlashing {
-            service,
-            operator,
-            timestamp,
-        } => {
-            let service = deps.api.addr_validate(&service)?;
-            let operator = deps.api.addr_validate(&operator)?;
-            to_json_binary(&query::is_operator_opted_in_to_slashing(
-                deps, service, operator, timestamp,
-            )?)
+
+        SERVICE_SUNSETS.save(deps.storage, &service, &sunset)?;
+
+        for operator in operators {
+            let res = deregister_if_registered(
+                deps.branch(),
+                &env,
+                (&operator, &service),
+                "deregister_as_service",
+            )?;
+            response = response
+                .add_events(res.events)
+                .add_submessages(res.messages);
         }
+
+        Ok(response.add_event(
+            Event::new("ServiceSunset")
+                .add_attribute("service", service)
+                .add_attribute("completed", sunset.completed.to_string()),
+        ))
     }
-}
 
-mod query {
-    use crate::msg::{
-        IsOperatorActiveResponse, IsOperatorOptedInToSlashingResponse, IsOperatorResponse,
-        IsServiceResponse, SlashingParametersResponse, StatusResponse,
-    };
-    use crate::state;
-    use crate::state::{
-        require_operator_registered, require_service_registered, SLASHING_PARAMETERS,
-    };
-    use cosmwasm_std::{Addr, Deps, StdResult};
+    /// Retire the operator (info.sender) and deregister the next `limit` relationships of the operator,
+    /// must be called until the [`Sunset`] is completed.
+    ///
+    /// On the first call, the operator is retired so no new relationships can be registered.
+    /// Active registrations are set to [`RegistrationStatus::Exiting`] and stay slashable
+    /// until finalized after the deregistration delay,
+    /// pending registrations are set to [`RegistrationStatus::Inactive`].
+    pub fn deregister_as_operator(
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        limit: Option<u32>,
+    ) -> Result<Response, ContractError> {
+        let operator = info.sender;
+        let limit = sunset_limit(limit)?;
+        let mut response = Response::new();
 
-    /// Get the registration status of an operator to a service at a given timestamp.
-    /// If timestamp is `None`, it will return the current registration status.  
-    /// Returns: [`StdResult<StatusResponse>`]
-    /// - [`RegistrationStatus::Inactive`] (0) if not registered
-    /// - [`RegistrationStatus::Active`] (1) if registration is active (operator and service are registered to each other)
-    /// - [`RegistrationStatus::OperatorRegistered`] (2) if operator is registered to service, pending service registration
-    /// - [`RegistrationStatus::ServiceRegistered`] (3) if service is registered to operator, pending operator registration
-    pub fn status(
-        deps: Deps,
-        operator: Addr,
-        service: Addr,
-        timestamp: Option<u64>,
-    ) -> StdResult<StatusResponse> {
-        let key = (&operator, &service);
-        let status = match timestamp {
-            Some(t) => state::get_registration_status_at_timestamp(deps.storage, key, t)?,
-            None => state::get_registration_status(deps.storage, key)?,
+        let mut sunset = match OPERATOR_SUNSETS.may_load(deps.storage, &operator)? {
+            Some(sunset) => sunset,
+            None => {
+                require_operator_registered(deps.storage, &operator)?;
+                OPERATORS.save(deps.storage, &operator, &false)?;
+                response = response
+                    .add_event(Event::new("OperatorRetired").add_attribute("operator", &operator));
+                Sunset::default()
+            }
         };
-        Ok(status.into())
-    }
 
-    /// Query if the service is registered or not.
-    pub fn is_service(deps: Deps, service: Addr) -> StdResult<IsServiceResponse> {
-        let is_service_registered = require_service_registered(deps.storage, &service).is_ok();
+        if sunset.completed {
+            return Err(ContractError::Retired {
+                msg: "Operator sunset has completed".to_string(),
+            });
+        }
 
-        Ok(IsServiceResponse(is_service_registered))
-    }
+        let min = sunset.last.as_ref().map(Bound::exclusive);
+        let services = REGISTRATION_STATUS
+            .prefix(&operator)
+            .keys(deps.storage, min, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
 
-    /// Query if the operator is registered or not.
-    pub fn is_operator(deps: Deps, operator: Addr) -> StdResult<IsOperatorResponse> {
-        let is_operator_registered = require_operator_registered(deps.storage, &operator).is_ok();
+        sunset.completed = services.len() < limit;
+        if let Some(last) = services.last() {
+            sunset.last = Some(last.clone());
+        }
+        OPERATOR_SUNSETS.save(deps.storage, &operator, &sunset)?;
 
-        Ok(IsOperatorResponse(is_operator_registered))
-    }
+        for service in services {
+            let res = deregister_if_registered(
+                deps.branch(),
+                &env,
+                (&operator, &service),
+                "deregister_as_operator",
+            )?;
+            response = response
+                .add_events(res.events)
+                .add_submessages(res.messages);
+        }
 
-    /// Query if the operator is actively registered to any service
-    pub fn is_operator_active(deps: Deps, operator: Addr) -> StdResult<IsOperatorActiveResponse> {
-        let is_operator_active = state::is_operator_active(deps.storage, &operator)?;
+        Ok(response.add_event(
+            Event::new("OperatorSunset")
+                .add_attribute("operator", operator)
+                .add_attribute("completed", sunset.completed.to_string()),
+        ))
+    }
 
-        Ok(IsOperatorActiveResponse(is_operator_active))
+    /// Number of relationships to walk in a sunset page, bounded by [`state::MAX_BATCH_SIZE`].
+    /// A page of zero relationships would never complete the sunset, so `Some(0)` is rejected.
+    fn sunset_limit(limit: Option<u32>) -> Result<usize, ContractError> {
+        match limit {
+            Some(0) => Err(ContractError::InvalidBatch {
+                msg: "Limit must be greater than zero".to_string(),
+            }),
+            Some(v) => Ok((v as usize).min(state::MAX_BATCH_SIZE)),
+            None => Ok(state::MAX_BATCH_SIZE),
+        }
     }
 
-    /// Query the slashing registry for a service
-    pub fn get_slashing_parameters(
-        deps: Deps,
-        service: Addr,
-        timestamp: Option<u64>,
-    ) -> StdResult<SlashingParametersResponse> {
-        let slashing_parameters = match timestamp {
-            Some(t) => SLASHING_PARAMETERS.may_load_at_height(deps.storage, &service, t)?,
-            None => SLASHING_PARAMETERS.may_load(deps.storage, &service)?,
-        };
-        Ok(SlashingParametersResponse(slashing_parameters))
+    /// Deregister the registration with [`deregister`]
+    /// unless it is already [`RegistrationStatus::Inactive`] or [`RegistrationStatus::Exiting`].
+    fn deregister_if_registered(
+        deps: DepsMut,
+        env: &Env,
+        key: (&Addr, &Addr),
+        method: &str,
+    ) -> Result<Response, ContractError> {
+        let status = get_registration_status(deps.storage, key)?;
+        match status {
+            RegistrationStatus::Inactive | RegistrationStatus::Exiting => Ok(Response::new()),
+            _ => deregister(deps, env.clone(), key, status, method),
+        }
     }
 
-    /// Query if the operator is opted in to slashing
-    pub fn is_operator_opted_in_to_slashing(
-        deps: Deps,
-        service: Addr,
-        operator: Addr,
-        timestamp: Option<u64>,
-    ) -> StdResult<IsOperatorOptedInToSlashingResponse> {
-        let is_opted_in =
-            state::is_operator_opted_in_to_slashing(deps.storage, &service, &operator, timestamp)?;
-        Ok(IsOperatorOptedInToSlashingResponse(is_opted_in))
+    /// Set the delay (in seconds) between deregistering an active registration and finalizing it.
+    /// Only affects registrations that are deregistered after the update.
+    /// An operator with a longer withdrawal lock period in the `vault-router` exits after its lock period instead.
+    /// Only the `owner` can call this message.
+    pub fn set_deregistration_delay(
+        deps: DepsMut,
+        info: MessageInfo,
+        delay: u64,
+    ) -> Result<Response, ContractError> {
+        ownership::assert_owner(deps.storage, &info)?;
+
+        DEREGISTRATION_DELAY.save(deps.storage, &delay)?;
+
+        Ok(Response::new().add_event(
+            Event::new("SetDeregistrationDelay").add_attribute("delay", delay.to_string()),
+        ))
     }
-}
 
-/// This can only be called by the contract ADMIN, enforced by `wasmd` separate from cosmwasm.
-/// See https://github.com/CosmWasm/cosmwasm/issues/926#issuecomment-851259818
-///
-/// #### 2.0.0
-/// - Internal migrate of [REGISTRATION_STATUS] state from `Map` to `SnapshotMap`.
-///   Storage mapping is not needed because SnapshotMap uses a map with the same namespace.
-///   This migration will also mean
-///   that the current state of REGISTRATION_STATUS will be the default state
-///   when querying for state from earlier timestamp.
-///   For instance, migration happens at block 200 and Operator1 and Service1 is in Active status.
-///   When queried at genesis, the status of Operator1 and Service1 will be Active.
-/// - No storage migration.
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(
-    deps: DepsMut,
-    _env: Env,
-    _msg: Option<MigrateMsg>,
-) -> Result<Response, ContractError> {
-    cw2::ensure_from_older_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-    Ok(Response::default())
-}
+    /// Enable slashing for a service by registering slashing parameters into the registry.
+    ///
+    /// When slashing is enabled, active operators are able to opt in the next block.
+    /// New Operator <-> Service registration will automatically opt in the operator to slashing.
+    /// Can only be called when slashing is not enabled.
+    /// To update the enabled slashing parameters, the service proposes them with [`propose_slashing_parameters`],
+    /// activated with [`activate_slashing_parameters`] after the notice period.
+    pub fn enable_slashing(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        slashing_parameters: SlashingParameters,
+    ) -> Result<Response, ContractError> {
+        // service is the sender
+        let service = info.sender;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::contract::execute::{
-        enable_slashing, register_operator_to_service, register_service_to_operator,
-    };
-    use crate::contract::query::status;
-    use crate::msg::{
-        InstantiateMsg, IsOperatorActiveResponse, IsOperatorOptedInToSlashingResponse,
-        IsOperatorResponse, IsServiceResponse, Metadata, SlashingParametersResponse,
-        StatusResponse,
-    };
-    use crate::state;
-    use crate::state::{
-        increase_operator_active_registration_count, set_registration_status, RegistrationStatus,
-        SlashingParameters, OPERATORS, REGISTRATION_STATUS, SERVICES, SLASHING_OPT_IN,
-        SLASHING_PARAMETERS,
-    };
-    use cosmwasm_std::testing::{
-        message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
-    };
-    use cosmwasm_std::{Event, OwnedDeps, Response, StdError};
+        // service must be registered
+        require_service_registered(deps.storage, &service)?;
+
+        // enabled slashing parameters can only be updated with a notice period
+        if is_slashing_enabled(deps.storage, &service, None)? {
+            return Err(ContractError::InvalidSlashingParameters {
+                msg: "Slashing is already enabled, propose new slashing parameters instead"
+                    .to_string(),
+            });
+        }
+
+        // clear opt-in mapping
+        state::reset_slashing_opt_in(deps.storage, &env, &service)?;
+
+        // update slashing parameters
+        state::enable_slashing(deps.storage, deps.api, &env, &service, &slashing_parameters)?;
+
+        Ok(Response::new().add_event(
+            Event::new("SlashingParametersEnabled")
+                .add_attribute("service", service)
+                .add_attribute(
+                    "destination",
+                    slashing_parameters
+                        .destination
+                        .map(|x| x.to_string())
+                        .unwrap_or_default(),
+                )
+                .add_attribute(
+                    "max_slashing_bips",
+                    slashing_parameters.max_slashing_bips.to_string(),
+                )
+                .add_attribute(
+                    "resolution_window",
+                    slashing_parameters.resolution_window.to_string(),
+                ),
+        ))
+    }
+
+    /// Propose new slashing parameters for a service (info.sender is the service).
+    ///
+    /// The slashing parameters are not effective until activated with [`activate_slashing_parameters`]
+    /// after the notice period, giving operators notice of the change.
+    /// Proposing again replaces the pending slashing parameters and restarts the notice period.
+    pub fn propose_slashing_parameters(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        slashing_parameters: SlashingParameters,
+    ) -> Result<Response, ContractError> {
+        // service is the sender
+        let service = info.sender;
+
+        // service must be registered
+        require_service_registered(deps.storage, &service)?;
+
+        if !is_slashing_enabled(deps.storage, &service, None)? {
+            return Err(ContractError::InvalidSlashingParameters {
+                msg: "Slashing is not enabled, enable slashing instead".to_string(),
+            });
+        }
+
+        let pending = state::propose_slashing_parameters(
+            deps.storage,
+            deps.api,
+            &env,
+            &service,
+            &slashing_parameters,
+        )?;
+
+        Ok(Response::new().add_event(
+            Event::new("SlashingParametersProposed")
+                .add_attribute("service", service)
+                .add_attribute(
+                    "destination",
+                    slashing_parameters
+                        .destination
+                        .map(|x| x.to_string())
+                        .unwrap_or_default(),
+                )
+                .add_attribute(
+                    "max_slashing_bips",
+                    slashing_parameters.max_slashing_bips.to_string(),
+                )
+                .add_attribute(
+                    "resolution_window",
+                    slashing_parameters.resolution_window.to_string(),
+                )
+                .add_attribute(
+                    "effective_timestamp",
+                    pending.effective_timestamp.to_string(),
+                ),
+        ))
+    }
+
+    /// Activate the pending slashing parameters of a service after the notice period (anyone can call).
+    ///
+    /// If the new slashing parameters are stricter than the current,
+    /// all operators that are opted in to the slashing parameters have to opt in again.
+    /// Else the existing opt-in is kept.
+    pub fn activate_slashing_parameters(
+        deps: DepsMut,
+        env: Env,
+        service: Addr,
+    ) -> Result<Response, ContractError> {
+        let (slashing_parameters, opt_in_reset) =
+            state::activate_slashing_parameters(deps.storage, &env, &service)?;
+
+        Ok(Response::new().add_event(
+            Event::new("SlashingParametersEnabled")
+                .add_attribute("service", service)
+                .add_attribute(
+                    "destination",
+                    slashing_parameters
+                        .destination
+                        .map(|x| x.to_string())
+                        .unwrap_or_default(),
+                )
+                .add_attribute(
+                    "max_slashing_bips",
+                    slashing_parameters.max_slashing_bips.to_string(),
+                )
+                .add_attribute(
+                    "resolution_window",
+                    slashing_parameters.resolution_window.to_string(),
+                )
+                .add_attribute("opt_in_reset", opt_in_reset.to_string()),
+        ))
+    }
+
+    /// Add a named slashing category for a service (info.sender is the service).
+    ///
+    /// The slashing parameters of an enabled category can only be updated through
+    /// [`propose_slashing_category`] and [`activate_slashing_category`] after the notice period.
+    pub fn set_slashing_category(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        category: String,
+        slashing_parameters: SlashingParameters,
+    ) -> Result<Response, ContractError> {
+        // service is the sender
+        let service = info.sender;
+
+        // service must be registered
+        require_service_registered(deps.storage, &service)?;
+
+        state::set_slashing_category(
+            deps.storage,
+            deps.api,
+            &env,
+            &service,
+            &category,
+            &slashing_parameters,
+        )?;
+
+        Ok(Response::new().add_event(
+            Event::new("SlashingCategorySet")
+                .add_attribute("service", service)
+                .add_attribute("category", category)
+                .add_attribute(
+                    "destination",
+                    slashing_parameters
+                        .destination
+                        .map(|x| x.to_string())
+                        .unwrap_or_default(),
+                )
+                .add_attribute(
+                    "max_slashing_bips",
+                    slashing_parameters.max_slashing_bips.to_string(),
+                )
+                .add_attribute(
+                    "resolution_window",
+                    slashing_parameters.resolution_window.to_string(),
+                ),
+        ))
+    }
+
+    /// Propose new slashing parameters for a named slashing category of a service
+    /// (info.sender is the service).
+    ///
+    /// The slashing parameters are not effective until activated with [`activate_slashing_category`]
+    /// after the notice period, giving operators notice of the change.
+    /// Proposing again replaces the pending slashing parameters and restarts the notice period.
+    pub fn propose_slashing_category(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        category: String,
+        slashing_parameters: SlashingParameters,
+    ) -> Result<Response, ContractError> {
+        // service is the sender
+        let service = info.sender;
+
+        // service must be registered
+        require_service_registered(deps.storage, &service)?;
+
+        let pending = state::propose_slashing_category(
+            deps.storage,
+            deps.api,
+            &env,
+            &service,
+            &category,
+            &slashing_parameters,
+        )?;
+
+        Ok(Response::new().add_event(
+            Event::new("SlashingCategoryProposed")
+                .add_attribute("service", service)
+                .add_attribute("category", category)
+                .add_attribute(
+                    "destination",
+                    slashing_parameters
+                        .destination
+                        .map(|x| x.to_string())
+                        .unwrap_or_default(),
+                )
+                .add_attribute(
+                    "max_slashing_bips",
+                    slashing_parameters.max_slashing_bips.to_string(),
+                )
+                .add_attribute(
+                    "resolution_window",
+                    slashing_parameters.resolution_window.to_string(),
+                )
+                .add_attribute(
+                    "effective_timestamp",
+                    pending.effective_timestamp.to_string(),
+                ),
+        ))
+    }
+
+    /// Activate the pending slashing parameters of a named slashing category after the notice period
+    /// (anyone can call).
+    ///
+    /// If the new slashing parameters are stricter than the current,
+    /// all operators that are opted in to the category have to opt in again.
+    /// Else the existing opt-in is kept.
+    pub fn activate_slashing_category(
+        deps: DepsMut,
+        env: Env,
+        service: Addr,
+        category: String,
+    ) -> Result<Response, ContractError> {
+        let (slashing_parameters, opt_in_reset) =
+            state::activate_slashing_category(deps.storage, &env, &service, &category)?;
+
+        Ok(Response::new().add_event(
+            Event::new("SlashingCategorySet")
+                .add_attribute("service", service)
+                .add_attribute("category", category)
+                .add_attribute(
+                    "destination",
+                    slashing_parameters
+                        .destination
+                        .map(|x| x.to_string())
+                        .unwrap_or_default(),
+                )
+                .add_attribute(
+                    "max_slashing_bips",
+                    slashing_parameters.max_slashing_bips.to_string(),
+                )
+                .add_attribute(
+                    "resolution_window",
+                    slashing_parameters.resolution_window.to_string(),
+                )
+                .add_attribute("opt_in_reset", opt_in_reset.to_string()),
+        ))
+    }
+
+    /// Remove a named slashing category of a service (info.sender is the service).
+    pub fn remove_slashing_category(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        category: String,
+    ) -> Result<Response, ContractError> {
+        // service is the sender
+        let service = info.sender;
+
+        // service must be registered
+        require_service_registered(deps.storage, &service)?;
+
+        state::remove_slashing_category(deps.storage, &env, &service, &category)?;
+
+        Ok(Response::new().add_event(
+            Event::new("SlashingCategoryRemoved")
+                .add_attribute("service", service)
+                .add_attribute("category", category),
+        ))
+    }
+
+    /// Operator opts in to the service's named slashing category.
+    pub fn operator_opt_in_to_slashing_category(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        service: Addr,
+        category: String,
+        operator: Option<Addr>,
+    ) -> Result<Response, ContractError> {
+        // operator is the sender, or the sender is a manager of the operator
+        let operator = require_operator_or_manager(
+            deps.storage,
+            &info.sender,
+            operator,
+            ManagerPermission::SlashingOptIn,
+        )?;
+
+        // operator and service must have Active (1) registration status
+        let key = (&operator, &service);
+        require_active_registration_status(deps.storage, key)?;
+
+        // check if the category is enabled for the service
+        if state::get_slashing_category(deps.storage, &service, &category, None)?.is_none() {
+            return Err(ContractError::InvalidSlashingOptIn {
+                msg: "Cannot opt in: slashing category is not enabled for this service".to_string(),
+            });
+        }
+
+        state::opt_in_to_slashing_category(deps.storage, &env, &service, &category, &operator)?;
+
+        let hook = RegistryHookMsg::OperatorOptedInToSlashing {
+            operator: operator.to_string(),
+            category: Some(category.clone()),
+        };
+        let hook = service_hook(deps.storage, &service, hook)?;
+
+        Ok(Response::new()
+            .add_event(
+                Event::new("OperatorOptedInToSlashingCategory")
+                    .add_attribute("operator", operator)
+                    .add_attribute("service", service)
+                    .add_attribute("category", category),
+            )
+            .add_submessages(hook))
+    }
+
+    /// Authorize the `manager` to act on behalf of the operator (info.sender) with the `permissions`,
+    /// replacing any previous permissions of the manager.
+    pub fn set_operator_manager(
+        deps: DepsMut,
+        info: MessageInfo,
+        manager: Addr,
+        permissions: Vec<ManagerPermission>,
+    ) -> Result<Response, ContractError> {
+        let operator = info.sender;
+        require_operator_registered(deps.storage, &operator)?;
+
+        if manager == operator {
+            return Err(ContractError::InvalidOperatorManager {
+                msg: "Operator cannot be its own manager".to_string(),
+            });
+        }
+        let mut unique_permissions = Vec::with_capacity(permissions.len());
+        for permission in permissions {
+            if !unique_permissions.contains(&permission) {
+                unique_permissions.push(permission);
+            }
+        }
+        let permissions = unique_permissions;
+        if permissions.is_empty() {
+            return Err(ContractError::InvalidOperatorManager {
+                msg: "Permissions must not be empty, revoke the manager instead".to_string(),
+            });
+        }
+
+        OPERATOR_MANAGERS.save(deps.storage, (&operator, &manager), &permissions)?;
+
+        let permissions = permissions
+            .iter()
+            .map(|permission| format!("{permission:?}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        Ok(Response::new().add_event(
+            Event::new("OperatorManagerSet")
+                .add_attribute("operator", operator)
+                .add_attribute("manager", manager)
+                .add_attribute("permissions", permissions),
+        ))
+    }
+
+    /// Revoke all permissions of the `manager` of the operator (info.sender).
+    pub fn revoke_operator_manager(
+        deps: DepsMut,
+        info: MessageInfo,
+        manager: Addr,
+    ) -> Result<Response, ContractError> {
+        let operator = info.sender;
+
+        let key = (&operator, &manager);
+        if !OPERATOR_MANAGERS.has(deps.storage, key) {
+            return Err(ContractError::InvalidOperatorManager {
+                msg: "Manager is not a manager of this operator".to_string(),
+            });
+        }
+        OPERATOR_MANAGERS.remove(deps.storage, key);
+
+        Ok(Response::new().add_event(
+            Event::new("OperatorManagerRevoked")
+                .add_attribute("operator", operator)
+                .add_attribute("manager", manager),
+        ))
+    }
+
+    /// Enable registry hooks to the service (info.sender).
+    pub fn enable_service_hooks(
+        deps: DepsMut,
+        info: MessageInfo,
+    ) -> Result<Response, ContractError> {
+        let service = info.sender;
+        require_service_registered(deps.storage, &service)?;
+
+        SERVICE_HOOKS.save(deps.storage, &service, &true)?;
+
+        Ok(Response::new()
+            .add_event(Event::new("ServiceHooksEnabled").add_attribute("service", service)))
+    }
+
+    /// Disable registry hooks to the service (info.sender).
+    pub fn disable_service_hooks(
+        deps: DepsMut,
+        info: MessageInfo,
+    ) -> Result<Response, ContractError> {
+        let service = info.sender;
+        require_service_registered(deps.storage, &service)?;
+
+        SERVICE_HOOKS.remove(deps.storage, &service);
+
+        Ok(Response::new()
+            .add_event(Event::new("ServiceHooksDisabled").add_attribute("service", service)))
+    }
+
+    /// Propose the commission (in bips) of the operator (info.sender) for the service,
+    /// activated with [`activate_commission`] after the commission change delay.
+    pub fn propose_commission(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        service: Addr,
+        commission_bips: u16,
+    ) -> Result<Response, ContractError> {
+        let operator = info.sender;
+        require_operator_registered(deps.storage, &operator)?;
+        require_service_registered(deps.storage, &service)?;
+
+        let pending =
+            state::propose_commission(deps.storage, &env, (&operator, &service), commission_bips)?;
+
+        Ok(Response::new().add_event(
+            Event::new("CommissionProposed")
+                .add_attribute("operator", operator)
+                .add_attribute("service", service)
+                .add_attribute("commission_bips", commission_bips.to_string())
+                .add_attribute(
+                    "effective_timestamp",
+                    pending.effective_timestamp.to_string(),
+                ),
+        ))
+    }
+
+    /// Activate the pending commission of the operator for the service
+    /// after the commission change delay (anyone can call).
+    pub fn activate_commission(
+        deps: DepsMut,
+        env: Env,
+        operator: Addr,
+        service: Addr,
+    ) -> Result<Response, ContractError> {
+        let commission_bips =
+            state::activate_commission(deps.storage, &env, (&operator, &service))?;
+
+        Ok(Response::new().add_event(
+            Event::new("CommissionActivated")
+                .add_attribute("operator", operator)
+                .add_attribute("service", service)
+                .add_attribute("commission_bips", commission_bips.to_string()),
+        ))
+    }
+
+    /// Set the delay (in seconds) between proposing a new commission and activating it.
+    /// Only the `owner` can call this message.
+    pub fn set_commission_change_delay(
+        deps: DepsMut,
+        info: MessageInfo,
+        delay: u64,
+    ) -> Result<Response, ContractError> {
+        ownership::assert_owner(deps.storage, &info)?;
+
+        COMMISSION_CHANGE_DELAY.save(deps.storage, &delay)?;
+
+        Ok(Response::new().add_event(
+            Event::new("SetCommissionChangeDelay").add_attribute("delay", delay.to_string()),
+        ))
+    }
+
+    /// Set the `vault-router` contract address.
+    /// Only the `owner` can call this message.
+    pub fn set_router(
+        deps: DepsMut,
+        info: MessageInfo,
+        router: Addr,
+    ) -> Result<Response, ContractError> {
+        ownership::assert_owner(deps.storage, &info)?;
+
+        ROUTER.save(deps.storage, &router)?;
+
+        Ok(Response::new().add_event(Event::new("SetRouter").add_attribute("router", router)))
+    }
+
+    /// Set the admission policy of the service (info.sender).
+    pub fn set_admission_policy(
+        deps: DepsMut,
+        info: MessageInfo,
+        policy: AdmissionPolicy,
+    ) -> Result<Response, ContractError> {
+        let service = info.sender;
+        require_service_registered(deps.storage, &service)?;
+
+        let mut event = Event::new("AdmissionPolicySet").add_attribute("service", service.as_str());
+        event = match &policy {
+            AdmissionPolicy::Manual => event.add_attribute("policy", "manual"),
+            AdmissionPolicy::Open => event.add_attribute("policy", "open"),
+            AdmissionPolicy::Allowlist => event.add_attribute("policy", "allowlist"),
+            AdmissionPolicy::MinStake { asset_id, amount } => {
+                if asset_id.is_empty() {
+                    return Err(ContractError::InvalidAdmissionPolicy {
+                        msg: "Asset id must not be empty".to_string(),
+                    });
+                }
+                if !ROUTER.exists(deps.storage) {
+                    return Err(ContractError::InvalidAdmissionPolicy {
+                        msg: "Router is not set".to_string(),
+                    });
+                }
+                event
+                    .add_attribute("policy", "min_stake")
+                    .add_attribute("asset_id", asset_id)
+                    .add_attribute("amount", amount.to_string())
+            }
+        };
+
+        ADMISSION_POLICIES.save(deps.storage, &service, &policy)?;
+
+        Ok(Response::new().add_event(event))
+    }
+
+    /// Add (or remove) the operators to the admission allowlist of the service (info.sender).
+    pub fn set_admission_allowlist(
+        deps: DepsMut,
+        info: MessageInfo,
+        operators: Vec<Addr>,
+        allowed: bool,
+    ) -> Result<Response, ContractError> {
+        let service = info.sender;
+        require_service_registered(deps.storage, &service)?;
+        state::validate_batch_size(operators.len())?;
+
+        let mut response = Response::new();
+        for operator in operators {
+            if allowed {
+                ADMISSION_ALLOWLIST.save(deps.storage, (&service, &operator), &())?;
+            } else {
+                ADMISSION_ALLOWLIST.remove(deps.storage, (&service, &operator));
+            }
+
+            response = response.add_event(
+                Event::new("AdmissionAllowlistUpdated")
+                    .add_attribute("service", service.as_str())
+                    .add_attribute("operator", operator)
+                    .add_attribute("allowed", allowed.to_string()),
+            );
+        }
+        Ok(response)
+    }
+
+    /// Set (or rotate) the public signing key of the operator (info.sender) for the service.
+    /// The `signature` of the [`state::operator_key_message`] made with the key proves its possession.
+    pub fn set_operator_key(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        service: Addr,
+        key_type: KeyType,
+        public_key: HexBinary,
+        signature: HexBinary,
+    ) -> Result<Response, ContractError> {
+        let operator = info.sender;
+        require_operator_registered(deps.storage, &operator)?;
+        require_service_registered(deps.storage, &service)?;
+
+        state::set_operator_key(
+            deps.storage,
+            deps.api,
+            &env,
+            &service,
+            &operator,
+            key_type.clone(),
+            &public_key,
+            &signature,
+        )?;
+
+        Ok(Response::new().add_event(
+            Event::new("OperatorKeySet")
+                .add_attribute("operator", operator)
+                .add_attribute("service", service)
+                .add_attribute("key_type", format!("{key_type:?}"))
+                .add_attribute("public_key", public_key.to_hex()),
+        ))
+    }
+
+    /// Remove the public signing key of the operator (info.sender) for the service.
+    pub fn remove_operator_key(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        service: Addr,
+        key_type: KeyType,
+    ) -> Result<Response, ContractError> {
+        let operator = info.sender;
+
+        state::remove_operator_key(deps.storage, &env, &service, &operator, key_type.clone())?;
+
+        Ok(Response::new().add_event(
+            Event::new("OperatorKeyRemoved")
+                .add_attribute("operator", operator)
+                .add_attribute("service", service)
+                .add_attribute("key_type", format!("{key_type:?}")),
+        ))
+    }
+
+    /// Set the notice period (in seconds) between proposing and activating new slashing parameters.
+    /// Only the `owner` can call this message.
+    pub fn set_slashing_parameters_notice_period(
+        deps: DepsMut,
+        info: MessageInfo,
+        notice_period: u64,
+    ) -> Result<Response, ContractError> {
+        ownership::assert_owner(deps.storage, &info)?;
+
+        SLASHING_PARAMETERS_NOTICE_PERIOD.save(deps.storage, &notice_period)?;
+
+        Ok(Response::new().add_event(
+            Event::new("SetSlashingParametersNoticePeriod")
+                .add_attribute("notice_period", notice_period.to_string()),
+        ))
+    }
+
+    /// Disable slashing for a service by removing slashing parameters (and any pending slashing parameters) from the registry.
+    ///
+    /// When slashing is disabled,
+    /// all operators
+    /// that are opted in to the slashing parameters will be removed from the slashing opt in.
+    /// All active operators will remain actively registered to the service.
+    pub fn disable_slashing(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+    ) -> Result<Response, ContractError> {
+        // service is the sender
+        let service = info.sender;
+
+        // service must be registered
+        require_service_registered(deps.storage, &service)?;
+
+        // clear opt-in mapping
+        state::reset_slashing_opt_in(deps.storage, &env, &service)?;
+
+        // remove slashing parameters
+        state::disable_slashing(deps.storage, &env, &service)?;
+
+        Ok(Response::new()
+            .add_event(Event::new("SlashingParametersDisabled").add_attribute("service", service)))
+    }
+
+    /// Operator opts in to the service's current slashing parameters.
+    ///
+    /// When slashing is enabled, active operators can opt in to slashing.  
+    /// Newly registered operators, after slashing is enabled, will automatically opt in to slashing
+    /// and don't need to call this function.
+    pub fn operator_opt_in_to_slashing(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        service: Addr,
+        operator: Option<Addr>,
+    ) -> Result<Response, ContractError> {
+        // operator is the sender, or the sender is a manager of the operator
+        let operator = require_operator_or_manager(
+            deps.storage,
+            &info.sender,
+            operator,
+            ManagerPermission::SlashingOptIn,
+        )?;
+
+        // operator and service must have Active (1) registration status
+        let key = (&operator, &service);
+        require_active_registration_status(deps.storage, key)?;
+
+        // check if the slashing is enabled for the service
+        if !is_slashing_enabled(deps.storage, &service, Some(env.block.time.seconds()))? {
+            return Err(ContractError::InvalidSlashingOptIn {
+                msg: "Cannot opt in: slashing is not enabled for this service".to_string(),
+            });
+        }
+
+        // opt-in to slashing
+        state::opt_in_to_slashing(deps.storage, &env, &service, &operator)?;
+
+        let hook = RegistryHookMsg::OperatorOptedInToSlashing {
+            operator: operator.to_string(),
+            category: None,
+        };
+        let hook = service_hook(deps.storage, &service, hook)?;
+
+        Ok(Response::new()
+            .add_event(
+                Event::new("OperatorOptedInToSlashing")
+                    .add_attribute("operator", operator)
+                    .add_attribute("service", service),
+            )
+            .add_submessages(hook))
+    }
+
+    /// The max active relationships must be greater than zero.
+    fn validate_max_active_relationships(max: u64) -> Result<(), ContractError> {
+        if max == 0 {
+            return Err(ContractError::InvalidMaxActiveRelationships {
+                msg: "Max active relationships must be greater than zero".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Set the global default max active relationships per operator and per service.
+    /// Only the `owner` can call this message.
+    pub fn set_max_active_relationships(
+        deps: DepsMut,
+        info: MessageInfo,
+        per_operator: u64,
+        per_service: u64,
+    ) -> Result<Response, ContractError> {
+        ownership::assert_owner(deps.storage, &info)?;
+
+        validate_max_active_relationships(per_operator)?;
+        validate_max_active_relationships(per_service)?;
+
+        MAX_ACTIVE_RELATIONSHIPS_PER_OPERATOR.save(deps.storage, &per_operator)?;
+        MAX_ACTIVE_RELATIONSHIPS_PER_SERVICE.save(deps.storage, &per_service)?;
+
+        Ok(Response::new().add_event(
+            Event::new("SetMaxActiveRelationships")
+                .add_attribute("per_operator", per_operator.to_string())
+                .add_attribute("per_service", per_service.to_string()),
+        ))
+    }
+
+    /// Override (or remove the override if `None`) the max active relationships of an operator.
+    /// Only the `owner` can call this message.
+    pub fn set_max_active_relationships_for_operator(
+        deps: DepsMut,
+        info: MessageInfo,
+        operator: Addr,
+        max: Option<u64>,
+    ) -> Result<Response, ContractError> {
+        ownership::assert_owner(deps.storage, &info)?;
+
+        match max {
+            Some(max) => {
+                validate_max_active_relationships(max)?;
+                OPERATOR_MAX_ACTIVE_RELATIONSHIPS.save(deps.storage, &operator, &max)?;
+            }
+            None => OPERATOR_MAX_ACTIVE_RELATIONSHIPS.remove(deps.storage, &operator),
+        }
+
+        Ok(Response::new().add_event(
+            Event::new("SetMaxActiveRelationshipsForOperator")
+                .add_attribute("operator", operator)
+                .add_attribute("max", max.map(|x| x.to_string()).unwrap_or_default()),
+        ))
+    }
+
+    /// Override (or remove the override if `None`) the max active relationships of a service.
+    /// Only the `owner` can call this message.
+    pub fn set_max_active_relationships_for_service(
+        deps: DepsMut,
+        info: MessageInfo,
+        service: Addr,
+        max: Option<u64>,
+    ) -> Result<Response, ContractError> {
+        ownership::assert_owner(deps.storage, &info)?;
+
+        match max {
+            Some(max) => {
+                validate_max_active_relationships(max)?;
+                SERVICE_MAX_ACTIVE_RELATIONSHIPS.save(deps.storage, &service, &max)?;
+            }
+            None => SERVICE_MAX_ACTIVE_RELATIONSHIPS.remove(deps.storage, &service),
+        }
+
+        Ok(Response::new().add_event(
+            Event::new("SetMaxActiveRelationshipsForService")
+                .add_attribute("service", service)
+                .add_attribute("max", max.map(|x| x.to_string()).unwrap_or_default()),
+        ))
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Status {
+            service,
+            operator,
+            timestamp,
+        } => {
+            let service = deps.api.addr_validate(&service)?;
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::status(deps, operator, service, timestamp)?)
+        }
+        QueryMsg::RegistrationHistory {
+            service,
+            operator,
+            limit,
+            start_after,
+        } => {
+            let service = deps.api.addr_validate(&service)?;
+            let operator = deps.api.addr_validate(&operator)?;
+            let limit = limit.map_or(100, |v| v.min(100));
+            to_json_binary(&query::registration_history(
+                deps,
+                service,
+                operator,
+                limit,
+                start_after,
+            )?)
+        }
+        QueryMsg::SlashingOptInHistory {
+            service,
+            operator,
+            limit,
+            start_after,
+        } => {
+            let service = deps.api.addr_validate(&service)?;
+            let operator = deps.api.addr_validate(&operator)?;
+            let limit = limit.map_or(100, |v| v.min(100));
+            to_json_binary(&query::slashing_opt_in_history(
+                deps,
+                service,
+                operator,
+                limit,
+                start_after,
+            )?)
+        }
+        QueryMsg::SlashingParametersHistory {
+            service,
+            limit,
+            start_after,
+        } => {
+            let service = deps.api.addr_validate(&service)?;
+            let limit = limit.map_or(100, |v| v.min(100));
+            to_json_binary(&query::slashing_parameters_history(
+                deps,
+                service,
+                limit,
+                start_after,
+            )?)
+        }
+        QueryMsg::IsService(service) => {
+            let service = deps.api.addr_validate(&service)?;
+            to_json_binary(&query::is_service(deps, service)?)
+        }
+        QueryMsg::IsOperator(operator) => {
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::is_operator(deps, operator)?)
+        }
+        QueryMsg::IsOperatorActive(operator) => {
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::is_operator_active(deps, operator)?)
+        }
+        QueryMsg::ServiceSunset(service) => {
+            let service = deps.api.addr_validate(&service)?;
+            to_json_binary(&query::service_sunset(deps, service)?)
+        }
+        QueryMsg::OperatorSunset(operator) => {
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::operator_sunset(deps, operator)?)
+        }
+        QueryMsg::ServiceHooksEnabled(service) => {
+            let service = deps.api.addr_validate(&service)?;
+            to_json_binary(&query::service_hooks_enabled(deps, service)?)
+        }
+        QueryMsg::OperatorManagers {
+            operator,
+            limit,
+            start_after,
+        } => {
+            let operator = deps.api.addr_validate(&operator)?;
+            let limit = limit.map_or(100, |v| v.min(100));
+            let start_after = start_after
+                .map(|manager| deps.api.addr_validate(&manager))
+                .transpose()?;
+            to_json_binary(&query::operator_managers(
+                deps,
+                operator,
+                limit,
+                start_after,
+            )?)
+        }
+        QueryMsg::OperatorKey {
+            service,
+            operator,
+            key_type,
+            timestamp,
+        } => {
+            let service = deps.api.addr_validate(&service)?;
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::operator_key(
+                deps, service, operator, key_type, timestamp,
+            )?)
+        }
+        QueryMsg::ServiceMetadata { service, timestamp } => {
+            let service = deps.api.addr_validate(&service)?;
+            to_json_binary(&query::service_metadata(deps, service, timestamp)?)
+        }
+        QueryMsg::OperatorMetadata {
+            operator,
+            timestamp,
+        } => {
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::operator_metadata(deps, operator, timestamp)?)
+        }
+        QueryMsg::SlashingParameters { service, timestamp } => {
+            let service = deps.api.addr_validate(&service)?;
+            to_json_binary(&query::get_slashing_parameters(deps, service, timestamp)?)
+        }
+        QueryMsg::IsOperatorOptedInToSlashing {
+            service,
+            operator,
+            timestamp,
+        } => {
+            let service = deps.api.addr_validate(&service)?;
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::is_operator_opted_in_to_slashing(
+                deps, service, operator, timestamp,
+            )?)
+        }
+        QueryMsg::ActiveOperatorCount(service) => {
+            let service = deps.api.addr_validate(&service)?;
+            to_json_binary(&query::active_operator_count(deps, service)?)
+        }
+        QueryMsg::ActiveServiceCount(operator) => {
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::active_service_count(deps, operator)?)
+        }
+        QueryMsg::MaxActiveRelationshipsForOperator(operator) => {
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::max_active_relationships_for_operator(
+                deps, operator,
+            )?)
+        }
+        QueryMsg::MaxActiveRelationshipsForService(service) => {
+            let service = deps.api.addr_validate(&service)?;
+            to_json_binary(&query::max_active_relationships_for_service(deps, service)?)
+        }
+        QueryMsg::ListOperatorsByService {
+            service,
+            status,
+            timestamp,
+            limit,
+            start_after,
+        } => {
+            let service = deps.api.addr_validate(&service)?;
+            let limit = limit.map_or(100, |v| v.min(100));
+            let start_after = start_after
+                .map(|s| deps.api.addr_validate(&s))
+                .transpose()?;
+            to_json_binary(&query::list_operators_by_service(
+                deps,
+                service,
+                status,
+                timestamp,
+                limit,
+                start_after,
+            )?)
+        }
+        QueryMsg::ListServicesByOperator {
+            operator,
+            status,
+            timestamp,
+            limit,
+            start_after,
+        } => {
+            let operator = deps.api.addr_validate(&operator)?;
+            let limit = limit.map_or(100, |v| v.min(100));
+            let start_after = start_after
+                .map(|s| deps.api.addr_validate(&s))
+                .transpose()?;
+            to_json_binary(&query::list_services_by_operator(
+                deps,
+                operator,
+                status,
+                timestamp,
+                limit,
+                start_after,
+            )?)
+        }
+        QueryMsg::SlashingCategory {
+            service,
+            category,
+            timestamp,
+        } => {
+            let service = deps.api.addr_validate(&service)?;
+            to_json_binary(&query::slashing_category(
+                deps, service, category, timestamp,
+            )?)
+        }
+        QueryMsg::ListSlashingCategories {
+            service,
+            limit,
+            start_after,
+        } => {
+            let service = deps.api.addr_validate(&service)?;
+            let limit = limit.map_or(100, |v| v.min(100));
+            to_json_binary(&query::list_slashing_categories(
+                deps,
+                service,
+                limit,
+                start_after,
+            )?)
+        }
+        QueryMsg::IsOperatorOptedInToSlashingCategory {
+            service,
+            category,
+            operator,
+            timestamp,
+        } => {
+            let service = deps.api.addr_validate(&service)?;
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::is_operator_opted_in_to_slashing_category(
+                deps, service, category, operator, timestamp,
+            )?)
+        }
+        QueryMsg::PendingSlashingParameters { service } => {
+            let service = deps.api.addr_validate(&service)?;
+            to_json_binary(&query::pending_slashing_parameters(deps, service)?)
+        }
+        QueryMsg::PendingSlashingCategory { service, category } => {
+            let service = deps.api.addr_validate(&service)?;
+            to_json_binary(&query::pending_slashing_category(deps, service, category)?)
+        }
+        QueryMsg::SlashingParametersNoticePeriod {} => {
+            to_json_binary(&query::slashing_parameters_notice_period(deps)?)
+        }
+        QueryMsg::Commission {
+            service,
+            operator,
+            timestamp,
+        } => {
+            let service = deps.api.addr_validate(&service)?;
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::commission(deps, operator, service, timestamp)?)
+        }
+        QueryMsg::PendingCommission { service, operator } => {
+            let service = deps.api.addr_validate(&service)?;
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::pending_commission(deps, operator, service)?)
+        }
+        QueryMsg::CommissionChangeDelay {} => {
+            to_json_binary(&query::commission_change_delay(deps)?)
+        }
+        QueryMsg::Router {} => to_json_binary(&query::router(deps)?),
+        QueryMsg::AdmissionPolicy(service) => {
+            let service = deps.api.addr_validate(&service)?;
+            to_json_binary(&query::admission_policy(deps, service)?)
+        }
+        QueryMsg::IsOperatorAllowlisted { service, operator } => {
+            let service = deps.api.addr_validate(&service)?;
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::is_operator_allowlisted(deps, service, operator)?)
+        }
+        QueryMsg::DeregistrationDelay {} => to_json_binary(&query::deregistration_delay(deps)?),
+        QueryMsg::PendingExit { service, operator } => {
+            let service = deps.api.addr_validate(&service)?;
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::pending_exit(deps, operator, service)?)
+        }
+        QueryMsg::ListPendingExitsByOperator {
+            operator,
+            limit,
+            start_after,
+        } => {
+            let operator = deps.api.addr_validate(&operator)?;
+            let limit = limit.map_or(100, |v| v.min(100));
+            let start_after = start_after
+                .map(|s| deps.api.addr_validate(&s))
+                .transpose()?;
+            to_json_binary(&query::list_pending_exits_by_operator(
+                deps,
+                operator,
+                limit,
+                start_after,
+            )?)
+        }
+        QueryMsg::ListPendingExitsByService {
+            service,
+            limit,
+            start_after,
+        } => {
+            let service = deps.api.addr_validate(&service)?;
+            let limit = limit.map_or(100, |v| v.min(100));
+            let start_after = start_after
+                .map(|s| deps.api.addr_validate(&s))
+                .transpose()?;
+            to_json_binary(&query::list_pending_exits_by_service(
+                deps,
+                service,
+                limit,
+                start_after,
+            )?)
+        }
+        QueryMsg::ListServices { limit, start_after } => {
+            let limit = limit.map_or(100, |v| v.min(100));
+            let start_after = start_after
+                .map(|s| deps.api.addr_validate(&s))
+                .transpose()?;
+            to_json_binary(&query::list_services(deps, limit, start_after)?)
+        }
+        QueryMsg::ListOperators { limit, start_after } => {
+            let limit = limit.map_or(100, |v| v.min(100));
+            let start_after = start_after
+                .map(|s| deps.api.addr_validate(&s))
+                .transpose()?;
+            to_json_binary(&query::list_operators(deps, limit, start_after)?)
+        }
+    }
+}
+
+mod query {
+    use crate::msg::{
+        ActiveCountResponse, AdmissionPolicyResponse, CommissionChangeDelayResponse,
+        CommissionResponse, DeregistrationDelayResponse, IsOperatorActiveResponse,
+        IsOperatorAllowlistedResponse, IsOperatorOptedInToSlashingResponse, IsOperatorResponse,
+        IsServiceResponse, MaxActiveRelationshipsResponse, MetadataResponse, OperatorKeyResponse,
+        OperatorListResponse, OperatorManager, OperatorManagerListResponse,
+        PendingCommissionResponse, PendingExit, PendingExitListResponse, PendingExitResponse,
+        PendingSlashingParametersResponse, Registration, RegistrationHistoryResponse,
+        RegistrationListResponse, RegistrationStatusChange, RouterResponse,
+        ServiceHooksEnabledResponse, ServiceListResponse, SlashingCategory,
+        SlashingCategoryListResponse, SlashingOptInChange, SlashingOptInHistoryResponse,
+        SlashingParametersChange, SlashingParametersHistoryResponse,
+        SlashingParametersNoticePeriodResponse, SlashingParametersResponse, StatusResponse,
+        SunsetResponse,
+    };
+    use crate::state;
+    use crate::state::{
+        require_operator_registered, require_service_registered, KeyType, RegistrationStatus,
+        OPERATORS, OPERATOR_MANAGERS, OPERATOR_SUNSETS, PENDING_COMMISSIONS, PENDING_EXITS,
+        PENDING_SLASHING_CATEGORIES, PENDING_SLASHING_PARAMETERS, REGISTRATION_STATUS, ROUTER,
+        SERVICES, SERVICE_OPERATORS, SERVICE_SUNSETS, SLASHING_CATEGORIES, SLASHING_OPT_IN,
+        SLASHING_PARAMETERS,
+    };
+    use bvs_library::addr::{Operator, Service};
+    use cosmwasm_std::{Addr, Deps, Order, StdResult};
+    use cw_storage_plus::Bound;
+
+    /// Load the registration status at the given timestamp, or the current status if `None`.
+    fn load_status(
+        deps: Deps,
+        key: (&Operator, &Service),
+        timestamp: Option<u64>,
+    ) -> StdResult<RegistrationStatus> {
+        match timestamp {
+            Some(t) => state::get_registration_status_at_timestamp(deps.storage, key, t),
+            None => state::get_registration_status(deps.storage, key),
+        }
+    }
+
+    /// Returns whether the registration should be listed given the `status` filter.
+    /// Without a filter, only registrations that are not [`RegistrationStatus::Inactive`] are listed.
+    fn is_listed(
+        registration: &StdResult<Registration>,
+        status: &Option<RegistrationStatus>,
+    ) -> bool {
+        match registration {
+            Ok(registration) => match status {
+                Some(status) => registration.status == u8::from(status.clone()),
+                None => registration.status != u8::from(RegistrationStatus::Inactive),
+            },
+            Err(_) => true,
+        }
+    }
+
+    /// Get the registration status of an operator to a service at a given timestamp.
+    /// If timestamp is `None`, it will return the current registration status.  
+    /// Returns: [`StdResult<StatusResponse>`]
+    /// - [`RegistrationStatus::Inactive`] (0) if not registered
+    /// - [`RegistrationStatus::Active`] (1) if registration is active (operator and service are registered to each other)
+    /// - [`RegistrationStatus::OperatorRegistered`] (2) if operator is registered to service, pending service registration
+    /// - [`RegistrationStatus::ServiceRegistered`] (3) if service is registered to operator, pending operator registration
+    /// - [`RegistrationStatus::Exiting`] (4) if registration is deregistered, pending finalization
+    pub fn status(
+        deps: Deps,
+        operator: Addr,
+        service: Addr,
+        timestamp: Option<u64>,
+    ) -> StdResult<StatusResponse> {
+        let status = load_status(deps, (&operator, &service), timestamp)?;
+        Ok(status.into())
+    }
+
+    /// Query if the service is registered or not.
+    pub fn is_service(deps: Deps, service: Addr) -> StdResult<IsServiceResponse> {
+        let is_service_registered = require_service_registered(deps.storage, &service).is_ok();
+
+        Ok(IsServiceResponse(is_service_registered))
+    }
+
+    /// Query if the operator is registered or not.
+    pub fn is_operator(deps: Deps, operator: Addr) -> StdResult<IsOperatorResponse> {
+        let is_operator_registered = require_operator_registered(deps.storage, &operator).is_ok();
+
+        Ok(IsOperatorResponse(is_operator_registered))
+    }
+
+    /// Query if the operator is actively registered to any service
+    pub fn is_operator_active(deps: Deps, operator: Addr) -> StdResult<IsOperatorActiveResponse> {
+        let is_operator_active = state::is_operator_active(deps.storage, &operator)?;
+
+        Ok(IsOperatorActiveResponse(is_operator_active))
+    }
+
+    /// Query the registration status changes between the service and operator.
+    /// Support pagination.
+    pub fn registration_history(
+        deps: Deps,
+        service: Service,
+        operator: Operator,
+        limit: u32,
+        start_after: Option<u64>,
+    ) -> StdResult<RegistrationHistoryResponse> {
+        let history = state::load_snapshot_history(
+            deps.storage,
+            &REGISTRATION_STATUS,
+            (&operator, &service),
+            start_after,
+            limit as usize,
+        )?
+        .into_iter()
+        .map(|(timestamp, status)| RegistrationStatusChange {
+            timestamp,
+            status: status.unwrap_or(RegistrationStatus::Inactive.into()),
+        })
+        .collect();
+        Ok(RegistrationHistoryResponse(history))
+    }
+
+    /// Query the slashing opt-in changes of the operator to the service.
+    /// Support pagination.
+    pub fn slashing_opt_in_history(
+        deps: Deps,
+        service: Service,
+        operator: Operator,
+        limit: u32,
+        start_after: Option<u64>,
+    ) -> StdResult<SlashingOptInHistoryResponse> {
+        let history = state::load_snapshot_history(
+            deps.storage,
+            &SLASHING_OPT_IN,
+            (&service, &operator),
+            start_after,
+            limit as usize,
+        )?
+        .into_iter()
+        .map(|(timestamp, opted_in)| SlashingOptInChange {
+            timestamp,
+            opted_in: opted_in.unwrap_or(false),
+        })
+        .collect();
+        Ok(SlashingOptInHistoryResponse(history))
+    }
+
+    /// Query the slashing parameters changes of the service.
+    /// Support pagination.
+    pub fn slashing_parameters_history(
+        deps: Deps,
+        service: Service,
+        limit: u32,
+        start_after: Option<u64>,
+    ) -> StdResult<SlashingParametersHistoryResponse> {
+        let history = state::load_snapshot_history(
+            deps.storage,
+            &SLASHING_PARAMETERS,
+            &service,
+            start_after,
+            limit as usize,
+        )?
+        .into_iter()
+        .map(
+            |(timestamp, slashing_parameters)| SlashingParametersChange {
+                timestamp,
+                slashing_parameters,
+            },
+        )
+        .collect();
+        Ok(SlashingParametersHistoryResponse(history))
+    }
+
+    /// Query the sunset progress of the service, `None` if the service is not retired.
+    pub fn service_sunset(deps: Deps, service: Addr) -> StdResult<SunsetResponse> {
+        let sunset = SERVICE_SUNSETS.may_load(deps.storage, &service)?;
+        Ok(SunsetResponse(sunset))
+    }
+
+    /// Query the sunset progress of the operator, `None` if the operator is not retired.
+    pub fn operator_sunset(deps: Deps, operator: Addr) -> StdResult<SunsetResponse> {
+        let sunset = OPERATOR_SUNSETS.may_load(deps.storage, &operator)?;
+        Ok(SunsetResponse(sunset))
+    }
+
+    /// Query whether the service has enabled registry hooks
+    pub fn service_hooks_enabled(
+        deps: Deps,
+        service: Service,
+    ) -> StdResult<ServiceHooksEnabledResponse> {
+        let enabled = state::is_service_hooks_enabled(deps.storage, &service)?;
+        Ok(ServiceHooksEnabledResponse(enabled))
+    }
+
+    /// List the managers of an operator and their permissions.
+    /// Support pagination.
+    pub fn operator_managers(
+        deps: Deps,
+        operator: Operator,
+        limit: u32,
+        start_after: Option<Addr>,
+    ) -> StdResult<OperatorManagerListResponse> {
+        let range_max = start_after.as_ref().map(Bound::exclusive);
+        let managers = OPERATOR_MANAGERS
+            .prefix(&operator)
+            .range(deps.storage, None, range_max, Order::Descending)
+            .map(|item| {
+                let (manager, permissions) = item?;
+                Ok(OperatorManager {
+                    manager,
+                    permissions,
+                })
+            })
+            .take(limit as usize)
+            .collect::<StdResult<_>>()?;
+        Ok(OperatorManagerListResponse(managers))
+    }
+
+    /// Query the public signing key of the operator for the service
+    /// at the given timestamp (or current if `None`)
+    pub fn operator_key(
+        deps: Deps,
+        service: Addr,
+        operator: Addr,
+        key_type: KeyType,
+        timestamp: Option<u64>,
+    ) -> StdResult<OperatorKeyResponse> {
+        let public_key =
+            state::get_operator_key(deps.storage, &service, &operator, key_type, timestamp)?;
+        Ok(OperatorKeyResponse(public_key))
+    }
+
+    /// Query the metadata of the service at the given timestamp (or current if `None`)
+    pub fn service_metadata(
+        deps: Deps,
+        service: Addr,
+        timestamp: Option<u64>,
+    ) -> StdResult<MetadataResponse> {
+        let metadata = state::get_service_metadata(deps.storage, &service, timestamp)?;
+        Ok(MetadataResponse(metadata))
+    }
+
+    /// Query the metadata of the operator at the given timestamp (or current if `None`)
+    pub fn operator_metadata(
+        deps: Deps,
+        operator: Addr,
+        timestamp: Option<u64>,
+    ) -> StdResult<MetadataResponse> {
+        let metadata = state::get_operator_metadata(deps.storage, &operator, timestamp)?;
+        Ok(MetadataResponse(metadata))
+    }
+
+    /// Query the slashing registry for a service
+    pub fn get_slashing_parameters(
+        deps: Deps,
+        service: Addr,
+        timestamp: Option<u64>,
+    ) -> StdResult<SlashingParametersResponse> {
+        let slashing_parameters = match timestamp {
+            Some(t) => SLASHING_PARAMETERS.may_load_at_height(deps.storage, &service, t)?,
+            None => SLASHING_PARAMETERS.may_load(deps.storage, &service)?,
+        };
+        Ok(SlashingParametersResponse(slashing_parameters))
+    }
+
+    /// Query if the operator is opted in to slashing
+    pub fn is_operator_opted_in_to_slashing(
+        deps: Deps,
+        service: Addr,
+        operator: Addr,
+        timestamp: Option<u64>,
+    ) -> StdResult<IsOperatorOptedInToSlashingResponse> {
+        let is_opted_in =
+            state::is_operator_opted_in_to_slashing(deps.storage, &service, &operator, timestamp)?;
+        Ok(IsOperatorOptedInToSlashingResponse(is_opted_in))
+    }
+
+    /// Query the slashing parameters of the named slashing category at the given timestamp
+    pub fn slashing_category(
+        deps: Deps,
+        service: Addr,
+        category: String,
+        timestamp: Option<u64>,
+    ) -> StdResult<SlashingParametersResponse> {
+        let slashing_parameters =
+            state::get_slashing_category(deps.storage, &service, &category, timestamp)?;
+        Ok(SlashingParametersResponse(slashing_parameters))
+    }
+
+    /// List the current slashing categories of a service.
+    /// Support pagination.
+    pub fn list_slashing_categories(
+        deps: Deps,
+        service: Addr,
+        limit: u32,
+        start_after: Option<String>,
+    ) -> StdResult<SlashingCategoryListResponse> {
+        let range_max = start_after.as_deref().map(Bound::exclusive);
+        let categories = SLASHING_CATEGORIES
+            .prefix(&service)
+            .range(deps.storage, None, range_max, Order::Descending)
+            .map(|item| {
+                let (category, slashing_parameters) = item?;
+                Ok(SlashingCategory {
+                    category,
+                    slashing_parameters,
+                })
+            })
+            .take(limit as usize)
+            .collect::<StdResult<_>>()?;
+
+        Ok(SlashingCategoryListResponse(categories))
+    }
+
+    /// Query if the operator is opted in to the named slashing category
+    pub fn is_operator_opted_in_to_slashing_category(
+        deps: Deps,
+        service: Addr,
+        category: String,
+        operator: Addr,
+        timestamp: Option<u64>,
+    ) -> StdResult<IsOperatorOptedInToSlashingResponse> {
+        let is_opted_in = state::is_operator_opted_in_to_slashing_category(
+            deps.storage,
+            &service,
+            &category,
+            &operator,
+            timestamp,
+        )?;
+        Ok(IsOperatorOptedInToSlashingResponse(is_opted_in))
+    }
+
+    /// Query the number of operators actively registered to the service
+    pub fn active_operator_count(deps: Deps, service: Addr) -> StdResult<ActiveCountResponse> {
+        let count = state::get_service_active_registration_count(deps.storage, &service)?;
+        Ok(ActiveCountResponse(count))
+    }
+
+    /// Query the number of services the operator is actively registered to
+    pub fn active_service_count(deps: Deps, operator: Addr) -> StdResult<ActiveCountResponse> {
+        let count = state::get_operator_active_registration_count(deps.storage, &operator)?;
+        Ok(ActiveCountResponse(count))
+    }
+
+    /// Query the effective max active relationships of the operator
+    pub fn max_active_relationships_for_operator(
+        deps: Deps,
+        operator: Addr,
+    ) -> StdResult<MaxActiveRelationshipsResponse> {
+        let max = state::get_operator_max_active_relationships(deps.storage, &operator)?;
+        Ok(MaxActiveRelationshipsResponse(max))
+    }
+
+    /// Query the effective max active relationships of the service
+    pub fn max_active_relationships_for_service(
+        deps: Deps,
+        service: Addr,
+    ) -> StdResult<MaxActiveRelationshipsResponse> {
+        let max = state::get_service_max_active_relationships(deps.storage, &service)?;
+        Ok(MaxActiveRelationshipsResponse(max))
+    }
+
+    /// List the operators registered to a service, filtered by `status` at the `timestamp`.
+    /// Support pagination.
+    pub fn list_operators_by_service(
+        deps: Deps,
+        service: Addr,
+        status: Option<RegistrationStatus>,
+        timestamp: Option<u64>,
+        limit: u32,
+        start_after: Option<Addr>,
+    ) -> StdResult<RegistrationListResponse> {
+        let range_max = start_after.as_ref().map(Bound::exclusive);
+        let registrations = SERVICE_OPERATORS
+            .prefix(&service)
+            .keys(deps.storage, None, range_max, Order::Descending)
+            .map(|operator| {
+                let operator = operator?;
+                let registration_status = load_status(deps, (&operator, &service), timestamp)?;
+                Ok(Registration {
+                    service: service.clone(),
+                    operator,
+                    status: registration_status.into(),
+                })
+            })
+            .filter(|registration| is_listed(registration, &status))
+            .take(limit as usize)
+            .collect::<StdResult<_>>()?;
+
+        Ok(RegistrationListResponse(registrations))
+    }
+
+    /// List the services registered to an operator, filtered by `status` at the `timestamp`.
+    /// Support pagination.
+    pub fn list_services_by_operator(
+        deps: Deps,
+        operator: Addr,
+        status: Option<RegistrationStatus>,
+        timestamp: Option<u64>,
+        limit: u32,
+        start_after: Option<Addr>,
+    ) -> StdResult<RegistrationListResponse> {
+        let range_max = start_after.as_ref().map(Bound::exclusive);
+        let registrations = REGISTRATION_STATUS
+            .prefix(&operator)
+            .keys(deps.storage, None, range_max, Order::Descending)
+            .map(|service| {
+                let service = service?;
+                let registration_status = load_status(deps, (&operator, &service), timestamp)?;
+                Ok(Registration {
+                    service,
+                    operator: operator.clone(),
+                    status: registration_status.into(),
+                })
+            })
+            .filter(|registration| is_listed(registration, &status))
+            .take(limit as usize)
+            .collect::<StdResult<_>>()?;
+
+        Ok(RegistrationListResponse(registrations))
+    }
+
+    /// Query the proposed slashing parameters of the service pending activation
+    pub fn pending_slashing_parameters(
+        deps: Deps,
+        service: Addr,
+    ) -> StdResult<PendingSlashingParametersResponse> {
+        let pending = PENDING_SLASHING_PARAMETERS.may_load(deps.storage, &service)?;
+        Ok(PendingSlashingParametersResponse(pending))
+    }
+
+    /// Query the proposed slashing parameters of the slashing category pending activation
+    pub fn pending_slashing_category(
+        deps: Deps,
+        service: Addr,
+        category: String,
+    ) -> StdResult<PendingSlashingParametersResponse> {
+        let pending =
+            PENDING_SLASHING_CATEGORIES.may_load(deps.storage, (&service, category.as_str()))?;
+        Ok(PendingSlashingParametersResponse(pending))
+    }
+
+    /// Query the notice period (in seconds) between proposing and activating new slashing parameters
+    pub fn slashing_parameters_notice_period(
+        deps: Deps,
+    ) -> StdResult<SlashingParametersNoticePeriodResponse> {
+        let notice_period = state::get_slashing_parameters_notice_period(deps.storage)?;
+        Ok(SlashingParametersNoticePeriodResponse(notice_period))
+    }
+
+    /// Query the commission (in bips) of the operator for the service at a given timestamp.
+    /// If timestamp is `None`, it will return the current commission.
+    pub fn commission(
+        deps: Deps,
+        operator: Addr,
+        service: Addr,
+        timestamp: Option<u64>,
+    ) -> StdResult<CommissionResponse> {
+        let commission = state::get_commission(deps.storage, (&operator, &service), timestamp)?;
+        Ok(CommissionResponse(commission))
+    }
+
+    /// Query the proposed commission of the operator for the service pending activation
+    pub fn pending_commission(
+        deps: Deps,
+        operator: Addr,
+        service: Addr,
+    ) -> StdResult<PendingCommissionResponse> {
+        let pending = PENDING_COMMISSIONS.may_load(deps.storage, (&operator, &service))?;
+        Ok(PendingCommissionResponse(pending))
+    }
+
+    /// Query the delay (in seconds) between proposing and activating a new commission
+    pub fn commission_change_delay(deps: Deps) -> StdResult<CommissionChangeDelayResponse> {
+        let delay = state::get_commission_change_delay(deps.storage)?;
+        Ok(CommissionChangeDelayResponse(delay))
+    }
+
+    /// Query the `vault-router` contract address
+    pub fn router(deps: Deps) -> StdResult<RouterResponse> {
+        let router = ROUTER.may_load(deps.storage)?;
+        Ok(RouterResponse(router))
+    }
+
+    /// Query the admission policy of the service
+    pub fn admission_policy(deps: Deps, service: Addr) -> StdResult<AdmissionPolicyResponse> {
+        let policy = state::get_admission_policy(deps.storage, &service)?;
+        Ok(AdmissionPolicyResponse(policy))
+    }
+
+    /// Query if the operator is in the admission allowlist of the service
+    pub fn is_operator_allowlisted(
+        deps: Deps,
+        service: Addr,
+        operator: Addr,
+    ) -> StdResult<IsOperatorAllowlistedResponse> {
+        let allowlisted = state::is_operator_allowlisted(deps.storage, &service, &operator);
+        Ok(IsOperatorAllowlistedResponse(allowlisted))
+    }
+
+    /// Query the delay (in seconds) between deregistering an active registration and finalizing it
+    pub fn deregistration_delay(deps: Deps) -> StdResult<DeregistrationDelayResponse> {
+        let delay = state::get_deregistration_delay(deps.storage)?;
+        Ok(DeregistrationDelayResponse(delay))
+    }
+
+    /// Query the timestamp at which the exiting registration can be finalized
+    pub fn pending_exit(
+        deps: Deps,
+        operator: Addr,
+        service: Addr,
+    ) -> StdResult<PendingExitResponse> {
+        let exit_timestamp = PENDING_EXITS.may_load(deps.storage, (&operator, &service))?;
+        Ok(PendingExitResponse(exit_timestamp))
+    }
+
+    /// List the exiting registrations of an operator.
+    /// Support pagination.
+    pub fn list_pending_exits_by_operator(
+        deps: Deps,
+        operator: Addr,
+        limit: u32,
+        start_after: Option<Addr>,
+    ) -> StdResult<PendingExitListResponse> {
+        let range_max = start_after.as_ref().map(Bound::exclusive);
+        let exits = PENDING_EXITS
+            .prefix(&operator)
+            .range(deps.storage, None, range_max, Order::Descending)
+            .map(|item| {
+                let (service, exit_timestamp) = item?;
+                Ok(PendingExit {
+                    service,
+                    operator: operator.clone(),
+                    exit_timestamp,
+                })
+            })
+            .take(limit as usize)
+            .collect::<StdResult<_>>()?;
+
+        Ok(PendingExitListResponse(exits))
+    }
+
+    /// List the exiting registrations of a service.
+    /// Support pagination.
+    pub fn list_pending_exits_by_service(
+        deps: Deps,
+        service: Addr,
+        limit: u32,
+        start_after: Option<Addr>,
+    ) -> StdResult<PendingExitListResponse> {
+        let range_max = start_after.as_ref().map(Bound::exclusive);
+        let exits = SERVICE_OPERATORS
+            .prefix(&service)
+            .keys(deps.storage, None, range_max, Order::Descending)
+            .filter_map(|operator| {
+                let operator = match operator {
+                    Ok(operator) => operator,
+                    Err(e) => return Some(Err(e)),
+                };
+                match PENDING_EXITS.may_load(deps.storage, (&operator, &service)) {
+                    Ok(Some(exit_timestamp)) => Some(Ok(PendingExit {
+                        service: service.clone(),
+                        operator,
+                        exit_timestamp,
+                    })),
+                    Ok(None) => None,
+                    Err(e) => Some(Err(e)),
+                }
+            })
+            .take(limit as usize)
+            .collect::<StdResult<_>>()?;
+
+        Ok(PendingExitListResponse(exits))
+    }
+
+    /// List all registered services, excluding the retired services.
+    /// Support pagination.
+    pub fn list_services(
+        deps: Deps,
+        limit: u32,
+        start_after: Option<Addr>,
+    ) -> StdResult<ServiceListResponse> {
+        let range_max = start_after.as_ref().map(Bound::exclusive);
+        let services = SERVICES
+            .range(deps.storage, None, range_max, Order::Descending)
+            .filter(|item| !matches!(item, Ok((_, false))))
+            .map(|item| item.map(|(service, _)| service))
+            .take(limit as usize)
+            .collect::<StdResult<_>>()?;
+
+        Ok(ServiceListResponse(services))
+    }
+
+    /// List all registered operators, excluding the retired operators.
+    /// Support pagination.
+    pub fn list_operators(
+        deps: Deps,
+        limit: u32,
+        start_after: Option<Addr>,
+    ) -> StdResult<OperatorListResponse> {
+        let range_max = start_after.as_ref().map(Bound::exclusive);
+        let operators = OPERATORS
+            .range(deps.storage, None, range_max, Order::Descending)
+            .filter(|item| !matches!(item, Ok((_, false))))
+            .map(|item| item.map(|(operator, _)| operator))
+            .take(limit as usize)
+            .collect::<StdResult<_>>()?;
+
+        Ok(OperatorListResponse(operators))
+    }
+}
+
+/// Handles the error reply of a service hook sub-message.
+/// The error is caught and emitted as a `ServiceHookFailed` event,
+/// so a failing service hook never reverts the registry action (e.g. an operator exiting).
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        SERVICE_HOOK_REPLY_ID => match msg.result {
+            SubMsgResult::Err(error) => {
+                let service = String::from_utf8_lossy(msg.payload.as_slice()).to_string();
+                Ok(Response::new().add_event(
+                    Event::new("ServiceHookFailed")
+                        .add_attribute("service", service)
+                        .add_attribute("error", error),
+                ))
+            }
+            SubMsgResult::Ok(_) => Ok(Response::new()),
+        },
+        id => Err(StdError::generic_err(format!("Unknown reply id: {id}")).into()),
+    }
+}
+
+/// This can only be called by the contract ADMIN, enforced by `wasmd` separate from cosmwasm.
+/// See https://github.com/CosmWasm/cosmwasm/issues/926#issuecomment-851259818
+///
+/// #### 2.0.0
+/// - Internal migrate of [REGISTRATION_STATUS] state from `Map` to `SnapshotMap`.
+///   Storage mapping is not needed because SnapshotMap uses a map with the same namespace.
+///   This migration will also mean
+///   that the current state of REGISTRATION_STATUS will be the default state
+///   when querying for state from earlier timestamp.
+///   For instance, migration happens at block 200 and Operator1 and Service1 is in Active status.
+///   When queried at genesis, the status of Operator1 and Service1 will be Active.
+/// - No storage migration.
+///
+/// #### 2.0.0 to 3.0.0
+/// New `SERVICE_OPERATORS: Map<(&Service, &Operator), ()>` index is created
+/// to allow operators to be listed by service.
+/// The existing `REGISTRATION_STATUS` keys are iterated over and added to `SERVICE_OPERATORS`.
+///
+/// New `SERVICE_ACTIVE_REGISTRATION_COUNT: Map<&Service, u64>` is created
+/// to enforce the max active relationships per service.
+/// The existing active `REGISTRATION_STATUS` entries are counted per service.
+///
+/// New `SERVICE_METADATA` and `OPERATOR_METADATA` snapshot maps store the latest metadata.
+/// Metadata was previously only emitted as events,
+/// existing services and operators have no stored metadata until they update it.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    mut deps: DepsMut,
+    _env: Env,
+    _msg: Option<MigrateMsg>,
+) -> Result<Response, ContractError> {
+    let old_version =
+        cw2::ensure_from_older_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    match old_version.major {
+        2 => {
+            migrate::registration_status_to_index_service(deps.branch())?;
+            migrate::service_active_registration_count(deps.branch())?;
+            let events = migrate::grandfather_max_active_relationships(deps.branch())?;
+            Ok(Response::new().add_events(events))
+        }
+        _ => Ok(Response::default()),
+    }
+}
+
+mod migrate {
+    use super::*;
+    use crate::state::{
+        get_operator_max_active_relationships, get_service_max_active_relationships,
+        RegistrationStatus, OPERATOR_ACTIVE_REGISTRATION_COUNT, OPERATOR_MAX_ACTIVE_RELATIONSHIPS,
+        REGISTRATION_STATUS, SERVICE_ACTIVE_REGISTRATION_COUNT, SERVICE_MAX_ACTIVE_RELATIONSHIPS,
+        SERVICE_OPERATORS,
+    };
+
+    pub fn registration_status_to_index_service(deps: DepsMut) -> Result<(), ContractError> {
+        let keys = REGISTRATION_STATUS
+            .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+
+        for (operator, service) in keys {
+            SERVICE_OPERATORS.save(deps.storage, (&service, &operator), &())?;
+        }
+
+        Ok(())
+    }
+
+    pub fn service_active_registration_count(deps: DepsMut) -> Result<(), ContractError> {
+        let active = u8::from(RegistrationStatus::Active);
+        let services = REGISTRATION_STATUS
+            .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .filter_map(|item| match item {
+                Ok(((_, service), status)) if status == active => Some(Ok(service)),
+                Ok(_) => None,
+                Err(e) => Some(Err(e)),
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+
+        for service in services {
+            SERVICE_ACTIVE_REGISTRATION_COUNT.update(
+                deps.storage,
+                &service,
+                |count| -> StdResult<_> { Ok(count.unwrap_or(0) + 1) },
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Deployments before the max active relationships had no cap,
+    /// operators and services already above the (default) max are grandfathered
+    /// with an override of their current active count, so they can't become active with more.
+    /// An event is emitted for each grandfathered operator and service.
+    pub fn grandfather_max_active_relationships(
+        deps: DepsMut,
+    ) -> Result<Vec<Event>, ContractError> {
+        let mut events = vec![];
+
+        let operators = OPERATOR_ACTIVE_REGISTRATION_COUNT
+            .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (operator, count) in operators {
+            if count > get_operator_max_active_relationships(deps.storage, &operator)? {
+                OPERATOR_MAX_ACTIVE_RELATIONSHIPS.save(deps.storage, &operator, &count)?;
+                events.push(
+                    Event::new("MaxActiveRelationshipsGrandfathered")
+                        .add_attribute("operator", operator.as_ref())
+                        .add_attribute("max", count.to_string()),
+                );
+            }
+        }
+
+        let services = SERVICE_ACTIVE_REGISTRATION_COUNT
+            .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (service, count) in services {
+            if count > get_service_max_active_relationships(deps.storage, &service)? {
+                SERVICE_MAX_ACTIVE_RELATIONSHIPS.save(deps.storage, &service, &count)?;
+                events.push(
+                    Event::new("MaxActiveRelationshipsGrandfathered")
+                        .add_attribute("service", service.as_ref())
+                        .add_attribute("max", count.to_string()),
+                );
+            }
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::execute::{
+        enable_slashing, register_operator_to_service, register_service_to_operator,
+    };
+    use crate::contract::query::status;
+    use crate::msg::{
+        ActiveCountResponse, AdmissionPolicyResponse, CommissionResponse, InstantiateMsg,
+        IsOperatorActiveResponse, IsOperatorAllowlistedResponse,
+        IsOperatorOptedInToSlashingResponse, IsOperatorResponse, IsServiceResponse,
+        MaxActiveRelationshipsResponse, Metadata, MetadataResponse, OperatorKeyResponse,
+        OperatorListResponse, PendingCommissionResponse, PendingExitResponse,
+        PendingSlashingParametersResponse, Registration, RegistrationHistoryResponse,
+        RegistrationListResponse, RegistrationStatusChange, RegistryHookMsg, RouterResponse,
+        ServiceHooksEnabledResponse, ServiceListResponse, SlashingCategory,
+        SlashingCategoryListResponse, SlashingOptInChange, SlashingOptInHistoryResponse,
+        SlashingParametersChange, SlashingParametersHistoryResponse, SlashingParametersResponse,
+        StatusResponse, SunsetResponse,
+    };
+    use crate::state;
+    use crate::state::{
+        increase_operator_active_registration_count, set_registration_status, AdmissionPolicy,
+        KeyType, ManagerPermission, PendingCommission, PendingSlashingParameters,
+        RegistrationStatus, SlashingParameters, Sunset, MAX_METADATA_NAME_LENGTH,
+        MAX_METADATA_URI_LENGTH, OPERATORS, REGISTRATION_STATUS, SERVICES, SLASHING_OPT_IN,
+        SLASHING_PARAMETERS,
+    };
+    use bvs_library::testing::Account;
+    use cosmwasm_std::testing::{
+        message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::{
+        Addr, ContractResult, Event, HexBinary, OwnedDeps, QuerierResult, Response, StdError,
+        SubMsg, SystemError, SystemResult, Uint128, Uint64, WasmQuery,
+    };
+
+    fn mock_contract() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let owner = deps.api.addr_make("owner");
+        let pauser = deps.api.addr_make("pauser");
+        let owner_info = message_info(&owner, &[]);
+
+        instantiate(
+            deps.as_mut(),
+            env,
+            owner_info.clone(),
+            InstantiateMsg {
+                owner: owner.to_string(),
+                pauser: pauser.to_string(),
+            },
+        )
+        .unwrap();
+
+        deps
+    }
+
+    #[test]
+    fn test_register_as_service() {
+        let mut deps = mock_dependencies();
+
+        let service = deps.api.addr_make("service");
+        let service_info = message_info(&service, &[]);
+
+        let res = execute::register_as_service(
+            deps.as_mut(),
+            mock_env(),
+            service_info,
+            Metadata {
+                uri: Some("uri".to_string()),
+                name: Some("name".to_string()),
+            },
+        );
+
+        assert_eq!(
+            res,
+            Ok(Response::new()
+                .add_event(
+                    Event::new("ServiceRegistered").add_attribute("service", service.as_ref())
+                )
+                .add_event(
+                    Event::new("MetadataUpdated")
+                        .add_attribute("metadata.uri", "uri")
+                        .add_attribute("metadata.name", "name")
+                        .add_attribute("service", service.as_ref())
+                ))
+        );
+
+        let registered = SERVICES.load(&deps.storage, &service).unwrap();
+        assert!(registered);
+    }
+
+    #[test]
+    fn test_register_service_optional_metadata() {
+        let mut deps = mock_dependencies();
+
+        let service = deps.api.addr_make("service");
+        let service_info = message_info(&service, &[]);
+
+        let res = execute::register_as_service(
+            deps.as_mut(),
+            mock_env(),
+            service_info,
+            Metadata {
+                uri: None,
+                name: Some("Meta Bridging".to_string()),
+            },
+        );
+
+        assert_eq!(
+            res,
+            Ok(Response::new()
+                .add_event(
+                    Event::new("ServiceRegistered").add_attribute("service", service.as_ref())
+                )
+                .add_event(
+                    Event::new("MetadataUpdated")
+                        .add_attribute("metadata.name", "Meta Bridging")
+                        .add_attribute("service", service.as_ref())
+                ))
+        );
+
+        let registered = SERVICES.load(&deps.storage, &service).unwrap();
+        assert!(registered);
+    }
+
+    #[test]
+    fn test_service_update_metadata() {
+        let mut deps = mock_dependencies();
+
+        let service = deps.api.addr_make("service");
+        let service_info = message_info(&service, &[]);
+
+        SERVICES.save(&mut deps.storage, &service, &true).unwrap();
+
+        let res = execute::service_update_metadata(
+            deps.as_mut(),
+            mock_env(),
+            service_info,
+            Metadata {
+                uri: Some("new_uri".to_string()),
+                name: Some("new_name".to_string()),
+            },
+        );
+
+        assert_eq!(
+            res,
+            Ok(Response::new().add_event(
+                Event::new("MetadataUpdated")
+                    .add_attribute("metadata.uri", "new_uri")
+                    .add_attribute("metadata.name", "new_name")
+                    .add_attribute("service", service.clone())
+            ))
+        );
+    }
+
+    #[test]
+    fn test_register_as_operator() {
+        let mut deps = mock_dependencies();
+
+        let operator = deps.api.addr_make("operator");
+        let operator_info = message_info(&operator, &[]);
+
+        let res = execute::register_as_operator(
+            deps.as_mut(),
+            mock_env(),
+            operator_info,
+            Metadata {
+                uri: Some("uri".to_string()),
+                name: Some("operator1".to_string()),
+            },
+        );
+
+        assert_eq!(
+            res,
+            Ok(Response::new()
+                .add_event(
+                    Event::new("OperatorRegistered").add_attribute("operator", operator.clone())
+                )
+                .add_event(
+                    Event::new("MetadataUpdated")
+                        .add_attribute("metadata.uri", "uri")
+                        .add_attribute("metadata.name", "operator1")
+                        .add_attribute("operator", operator.clone())
+                ))
+        );
+
+        let operator_registered = OPERATORS.load(&deps.storage, &operator).is_ok();
+        assert!(operator_registered);
+    }
+
+    #[test]
+    fn test_register_as_operator_more_than_once() {
+        let mut deps = mock_dependencies();
+
+        let operator = deps.api.addr_make("operator");
+        let operator_info = message_info(&operator, &[]);
+
+        {
+            // register operator the first time - success
+            execute::register_as_operator(
+                deps.as_mut(),
+                mock_env(),
+                operator_info.clone(),
+                Metadata {
+                    uri: Some("uri".to_string()),
+                    name: Some("operator1".to_string()),
+                },
+            )
+            .expect("register operator failed");
+
+            let operator_registered = OPERATORS.load(&deps.storage, &operator).is_ok();
+            assert!(operator_registered);
+        }
+
+        // register operator the second time - error
+        let err = execute::register_as_operator(
+            deps.as_mut(),
+            mock_env(),
+            operator_info,
+            Metadata {
+                uri: Some("uri".to_string()),
+                name: Some("operator1".to_string()),
+            },
+        );
+        assert_eq!(err, Err(ContractError::OperatorRegistered {}),);
+    }
+
+    #[test]
+    fn test_update_operator_metadata_uri() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+
+        let operator = deps.api.addr_make("operator");
+        let operator_info = message_info(&operator, &[]);
+
+        {
+            // register operator the first time
+            let res = execute::register_as_operator(
+                deps.as_mut(),
+                env.clone(),
+                operator_info.clone(),
+                Metadata {
+                    uri: Some("uri".to_string()),
+                    name: Some("operator1".to_string()),
+                },
+            );
+
+            // assert OperatorMetadataURIUpdated event
+            assert_eq!(
+                res,
+                Ok(Response::new()
+                    .add_event(
+                        Event::new("OperatorRegistered")
+                            .add_attribute("operator", operator.as_ref())
+                    )
+                    .add_event(
+                        Event::new("MetadataUpdated")
+                            .add_attribute("metadata.uri", "uri")
+                            .add_attribute("metadata.name", "operator1")
+                            .add_attribute("operator", operator.clone())
+                    ))
+            );
+        }
+
+        let registered_timestamp = env.block.time.seconds();
+        env.block.time = env.block.time.plus_seconds(10);
+
+        // update operator details
+        let res = execute::update_operator_metadata(
+            deps.as_mut(),
+            env.clone(),
+            operator_info,
+            Metadata {
+                uri: Some("uri2".to_string()),
+                name: None,
+            },
+            None,
+        );
+
+        // assert event
+        assert_eq!(
+            res,
+            Ok(Response::new().add_event(
+                Event::new("MetadataUpdated")
+                    .add_attribute("metadata.uri", "uri2") // updated uri
+                    .add_attribute("operator", operator.clone())
+            ))
+        );
+
+        // uri is updated, name is kept
+        let res = query::operator_metadata(deps.as_ref(), operator.clone(), None).unwrap();
+        assert_eq!(
+            res,
+            MetadataResponse(Some(Metadata {
+                uri: Some("uri2".to_string()),
+                name: Some("operator1".to_string()),
+            }))
+        );
+
+        // metadata at a past timestamp
+        let res = query::operator_metadata(
+            deps.as_ref(),
+            operator.clone(),
+            Some(registered_timestamp + 1),
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            MetadataResponse(Some(Metadata {
+                uri: Some("uri".to_string()),
+                name: Some("operator1".to_string()),
+            }))
+        );
+
+        // no metadata before registration
+        let res =
+            query::operator_metadata(deps.as_ref(), operator, Some(registered_timestamp)).unwrap();
+        assert_eq!(res, MetadataResponse(None));
+    }
+
+    #[test]
+    fn test_metadata_exceeds_max_length() {
+        let mut deps = mock_dependencies();
+
+        let service = deps.api.addr_make("service");
+        let service_info = message_info(&service, &[]);
+
+        let err = execute::register_as_service(
+            deps.as_mut(),
+            mock_env(),
+            service_info.clone(),
+            Metadata {
+                uri: None,
+                name: Some("a".repeat(MAX_METADATA_NAME_LENGTH + 1)),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidMetadata {
+                msg: "Name must not exceed 128 characters".to_string(),
+            }
+        );
+
+        execute::register_as_service(
+            deps.as_mut(),
+            mock_env(),
+            service_info.clone(),
+            Metadata {
+                uri: None,
+                name: Some("a".repeat(MAX_METADATA_NAME_LENGTH)),
+            },
+        )
+        .expect("register service failed");
+
+        let err = execute::service_update_metadata(
+            deps.as_mut(),
+            mock_env(),
+            service_info,
+            Metadata {
+                uri: Some("a".repeat(MAX_METADATA_URI_LENGTH + 1)),
+                name: None,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidMetadata {
+                msg: "URI must not exceed 512 characters".to_string(),
+            }
+        );
+
+        let res = query::service_metadata(deps.as_ref(), service, None).unwrap();
+        assert_eq!(
+            res,
+            MetadataResponse(Some(Metadata {
+                uri: None,
+                name: Some("a".repeat(MAX_METADATA_NAME_LENGTH)),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_register_lifecycle_operator_then_service() {
+        let mut deps = mock_contract();
+        let env = mock_env();
+
+        let operator = deps.api.addr_make("operator");
+        let service = deps.api.addr_make("service");
+        let operator_info = message_info(&operator, &[]);
+        let service_info = message_info(&service, &[]);
+
+        // register service + operator
+        execute::register_as_service(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            Metadata {
+                uri: None,
+                name: None,
+            },
+        )
+        .expect("register service failed");
+
+        execute::register_as_operator(
+            deps.as_mut(),
+            env.clone(),
+            operator_info.clone(),
+            Metadata {
+                uri: Some("uri".to_string()),
+                name: Some("operator1".to_string()),
+            },
+        )
+        .expect("register operator failed");
+
+        let res = execute::register_service_to_operator(
+            deps.as_mut(),
+            operator_info.clone(),
+            env.clone(),
+            service.clone(),
+            None,
+        );
+        assert_eq!(
+            res,
+            Ok(Response::new().add_event(
+                Event::new("RegistrationStatusUpdated")
+                    .add_attribute("method", "register_service_to_operator")
+                    .add_attribute("operator", operator.as_ref())
+                    .add_attribute("service", service.as_ref())
+                    .add_attribute("status", "OperatorRegistered")
+            )),
+        );
+
+        let status = state::get_registration_status(&deps.storage, (&operator, &service)).unwrap();
+        assert_eq!(status, RegistrationStatus::OperatorRegistered);
+
+        let res = execute::register_operator_to_service(
+            deps.as_mut(),
+            service_info.clone(),
+            env.clone(),
+            operator.clone(),
+        );
+        assert_eq!(
+            res,
+            Ok(Response::new().add_event(
+                Event::new("RegistrationStatusUpdated")
+                    .add_attribute("method", "register_operator_to_service")
+                    .add_attribute("operator", operator.as_ref())
+                    .add_attribute("service", service.as_ref())
+                    .add_attribute("status", "Active")
+            )),
+        );
+
+        let status = state::get_registration_status(&deps.storage, (&operator, &service)).unwrap();
+        assert_eq!(status, RegistrationStatus::Active);
+    }
+
+    #[test]
+    fn test_register_lifecycle_service_then_operator() {
+        let mut deps = mock_contract();
+        let env = mock_env();
+
+        let operator = deps.api.addr_make("operator");
+        let service = deps.api.addr_make("service");
+        let operator_info = message_info(&operator, &[]);
+        let service_info = message_info(&service, &[]);
+
+        // register service + operator
+        execute::register_as_service(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            Metadata {
+                uri: None,
+                name: None,
+            },
+        )
+        .expect("register service failed");
+
+        execute::register_as_operator(
+            deps.as_mut(),
+            env.clone(),
+            operator_info.clone(),
+            Metadata {
+                uri: Some("uri".to_string()),
+                name: Some("operator1".to_string()),
+            },
+        )
+        .expect("register operator failed");
+
+        let res = execute::register_operator_to_service(
+            deps.as_mut(),
+            service_info.clone(),
+            env.clone(),
+            operator.clone(),
+        );
+        assert_eq!(
+            res,
+            Ok(Response::new().add_event(
+                Event::new("RegistrationStatusUpdated")
+                    .add_attribute("method", "register_operator_to_service")
+                    .add_attribute("operator", operator.as_ref())
+                    .add_attribute("service", service.as_ref())
+                    .add_attribute("status", "ServiceRegistered")
+            )),
+        );
+
+        let status = state::get_registration_status(&deps.storage, (&operator, &service)).unwrap();
+        assert_eq!(status, RegistrationStatus::ServiceRegistered);
+
+        let res = execute::register_service_to_operator(
+            deps.as_mut(),
+            operator_info.clone(),
+            env.clone(),
+            service.clone(),
+            None,
+        );
+        assert_eq!(
+            res,
+            Ok(Response::new().add_event(
+                Event::new("RegistrationStatusUpdated")
+                    .add_attribute("method", "register_service_to_operator")
+                    .add_attribute("operator", operator.as_ref())
+                    .add_attribute("service", service.as_ref())
+                    .add_attribute("status", "Active")
+            )),
+        );
+
+        let status = state::get_registration_status(&deps.storage, (&operator, &service)).unwrap();
+        assert_eq!(status, RegistrationStatus::Active);
+    }
+
+    #[test]
+    fn test_register_operator_already_registered() {
+        let mut deps = mock_contract();
+        let env = mock_env();
+
+        let operator = deps.api.addr_make("operator/2");
+        let service = deps.api.addr_make("service/2");
+        let operator_info = message_info(&operator, &[]);
+
+        // register service + operator
+        SERVICES.save(&mut deps.storage, &service, &true).unwrap();
+        OPERATORS.save(&mut deps.storage, &operator, &true).unwrap();
+
+        state::set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service),
+            RegistrationStatus::OperatorRegistered,
+        )
+        .unwrap();
+
+        let res = execute::register_service_to_operator(
+            deps.as_mut(),
+            operator_info.clone(),
+            env.clone(),
+            service.clone(),
+            None,
+        );
+        assert_eq!(
+            res,
+            Err(ContractError::InvalidRegistrationStatus {
+                msg: "Operator has already registered this service".to_string(),
+            }),
+        );
+    }
+
+    #[test]
+    fn test_register_service_already_registered() {
+        let mut deps = mock_contract();
+        let env = mock_env();
+
+        let operator = deps.api.addr_make("operator/3");
+        let service = deps.api.addr_make("service/3");
+        let service_info = message_info(&service, &[]);
+
+        SERVICES.save(&mut deps.storage, &service, &true).unwrap();
+        OPERATORS.save(&mut deps.storage, &operator, &true).unwrap();
+
+        state::set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service),
+            RegistrationStatus::ServiceRegistered,
+        )
+        .unwrap();
+
+        let res = execute::register_operator_to_service(
+            deps.as_mut(),
+            service_info.clone(),
+            env.clone(),
+            operator.clone(),
+        );
+        assert_eq!(
+            res,
+            Err(ContractError::InvalidRegistrationStatus {
+                msg: "Service has already registered this operator".to_string(),
+            }),
+        );
+    }
+
+    #[test]
+    fn test_register_already_active() {
+        let mut deps = mock_contract();
+        let env = mock_env();
+
+        let operator = deps.api.addr_make("operator/4");
+        let service = deps.api.addr_make("service/4");
+        let operator_info = message_info(&operator, &[]);
+        let service_info = message_info(&service, &[]);
+
+        // register service + operator
+        SERVICES.save(&mut deps.storage, &service, &true).unwrap();
+        OPERATORS.save(&mut deps.storage, &operator, &true).unwrap();
+
+        state::set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service),
+            RegistrationStatus::Active,
+        )
+        .unwrap();
+
+        let res = execute::register_service_to_operator(
+            deps.as_mut(),
+            operator_info.clone(),
+            env.clone(),
+            service.clone(),
+            None,
+        );
+        assert_eq!(
+            res,
+            Err(ContractError::InvalidRegistrationStatus {
+                msg: "Registration between operator and service is already active".to_string(),
+            }),
+        );
+
+        let res = execute::register_operator_to_service(
+            deps.as_mut(),
+            service_info.clone(),
+            env.clone(),
+            operator.clone(),
+        );
+        assert_eq!(
+            res,
+            Err(ContractError::InvalidRegistrationStatus {
+                msg: "Registration between operator and service is already active".to_string(),
+            }),
+        );
+    }
+
+    #[test]
+    fn test_service_deregister_operator() {
+        let mut deps = mock_contract();
+        let env = mock_env();
+
+        let operator = deps.api.addr_make("operator");
+        let service = deps.api.addr_make("service");
+        let service_info = message_info(&service, &[]);
+
+        SERVICES.save(&mut deps.storage, &service, &true).unwrap();
+        state::set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service),
+            RegistrationStatus::Active,
+        )
+        .unwrap();
+        state::increase_operator_active_registration_count(&mut deps.storage, &operator)
+            .expect("failed to increase operator active registration count");
+
+        let res = execute::deregister_operator_from_service(
+            deps.as_mut(),
+            service_info.clone(),
+            env.clone(),
+            operator.clone(),
+        );
+        assert_eq!(
+            res,
+            Ok(Response::new().add_event(
+                Event::new("RegistrationStatusUpdated")
+                    .add_attribute("method", "deregister_operator_from_service")
+                    .add_attribute("operator", operator.as_ref())
+                    .add_attribute("service", service.as_ref())
+                    .add_attribute("status", "Exiting")
+                    .add_attribute(
+                        "exit_timestamp",
+                        (env.block.time.seconds() + state::DEFAULT_DEREGISTRATION_DELAY)
+                            .to_string()
+                    )
+            )),
+        );
+
+        let status = state::get_registration_status(&deps.storage, (&operator, &service)).unwrap();
+        assert_eq!(status, RegistrationStatus::Exiting);
+
+        // exiting operator is still active
+        let res = query::is_operator_active(deps.as_ref(), operator.clone()).unwrap();
+        assert_eq!(res, IsOperatorActiveResponse(true));
+
+        // finalize before the delay
+        let err = execute::finalize_deregistration(
+            deps.as_mut(),
+            service_info.clone(),
+            env.clone(),
+            operator.clone(),
+            service.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidRegistrationStatus {
+                msg: "Deregistration delay has not passed".to_string(),
+            }
+        );
+
+        let mut env = env;
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(state::DEFAULT_DEREGISTRATION_DELAY);
+
+        let res = execute::finalize_deregistration(
+            deps.as_mut(),
+            service_info.clone(),
+            env.clone(),
+            operator.clone(),
+            service.clone(),
+        );
+        assert_eq!(
+            res,
+            Ok(Response::new().add_event(
+                Event::new("RegistrationStatusUpdated")
+                    .add_attribute("method", "finalize_deregistration")
+                    .add_attribute("operator", operator.as_ref())
+                    .add_attribute("service", service.as_ref())
+                    .add_attribute("status", "Inactive")
+            )),
+        );
+
+        let status = state::get_registration_status(&deps.storage, (&operator, &service)).unwrap();
+        assert_eq!(status, RegistrationStatus::Inactive);
+
+        let res = query::pending_exit(deps.as_ref(), operator, service).unwrap();
+        assert_eq!(res, PendingExitResponse(None));
+    }
+
+    #[test]
+    fn test_operator_deregister_service() {
+        let mut deps = mock_contract();
+        let env = mock_env();
+
+        let operator = deps.api.addr_make("operator");
+        let service = deps.api.addr_make("service");
+        let operator_info = message_info(&operator, &[]);
+
+        SERVICES.save(&mut deps.storage, &service, &true).unwrap();
+        state::set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service),
+            RegistrationStatus::Active,
+        )
+        .unwrap();
+        state::increase_operator_active_registration_count(&mut deps.storage, &operator)
+            .expect("failed to increase operator active registration count");
+
+        let res = execute::deregister_service_from_operator(
+            deps.as_mut(),
+            operator_info.clone(),
+            env.clone(),
+            service.clone(),
+            None,
+        );
+        assert_eq!(
+            res,
+            Ok(Response::new().add_event(
+                Event::new("RegistrationStatusUpdated")
+                    .add_attribute("method", "deregister_service_from_operator")
+                    .add_attribute("operator", operator.as_ref())
+                    .add_attribute("service", service.as_ref())
+                    .add_attribute("status", "Exiting")
+                    .add_attribute(
+                        "exit_timestamp",
+                        (env.block.time.seconds() + state::DEFAULT_DEREGISTRATION_DELAY)
+                            .to_string()
+                    )
+            )),
+        );
+
+        let status = state::get_registration_status(&deps.storage, (&operator, &service)).unwrap();
+        assert_eq!(status, RegistrationStatus::Exiting);
+
+        let res = query::pending_exit(deps.as_ref(), operator.clone(), service.clone()).unwrap();
+        assert_eq!(
+            res,
+            PendingExitResponse(Some(
+                env.block.time.seconds() + state::DEFAULT_DEREGISTRATION_DELAY
+            ))
+        );
+
+        // only the operator or service can finalize
+        let stranger = deps.api.addr_make("stranger");
+        let err = execute::finalize_deregistration(
+            deps.as_mut(),
+            message_info(&stranger, &[]),
+            env.clone(),
+            operator.clone(),
+            service.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+    }
+
+    #[test]
+    fn test_exit_delay_router_lock_period() {
+        let mut deps = mock_contract();
+        let env = mock_env();
+
+        let router = deps.api.addr_make("router");
+        let service = deps.api.addr_make("service");
+        let operator = deps.api.addr_make("operator");
+        let operator2 = deps.api.addr_make("operator2");
+        let service_info = message_info(&service, &[]);
+
+        // operator has a longer lock period than the deregistration delay, operator2 shorter
+        let long_lock_period = state::DEFAULT_DEREGISTRATION_DELAY * 2;
+        let (router_addr, operator_addr) = (router.to_string(), operator.to_string());
+        deps.querier
+            .update_wasm(move |req: &WasmQuery| -> QuerierResult {
+                let WasmQuery::Smart { contract_addr, msg } = req else {
+                    return SystemResult::Err(SystemError::Unknown {});
+                };
+                if *contract_addr != router_addr {
+                    return SystemResult::Err(SystemError::NoSuchContract {
+                        addr: contract_addr.to_string(),
+                    });
+                }
+                let lock_period = if String::from_utf8_lossy(msg).contains(&operator_addr) {
+                    long_lock_period
+                } else {
+                    86_400
+                };
+                let response = to_json_binary(&Uint64::new(lock_period)).unwrap();
+                SystemResult::Ok(ContractResult::Ok(response))
+            });
+
+        let owner = deps.api.addr_make("owner");
+        execute::set_router(deps.as_mut(), message_info(&owner, &[]), router).unwrap();
+        SERVICES.save(&mut deps.storage, &service, &true).unwrap();
+
+        for (operator, delay) in [
+            (&operator, long_lock_period),
+            (&operator2, state::DEFAULT_DEREGISTRATION_DELAY),
+        ] {
+            state::set_registration_status(
+                &mut deps.storage,
+                &env,
+                (operator, &service),
+                RegistrationStatus::Active,
+            )
+            .unwrap();
+            state::increase_operator_active_registration_count(&mut deps.storage, operator)
+                .unwrap();
+
+            execute::deregister_operator_from_service(
+                deps.as_mut(),
+                service_info.clone(),
+                env.clone(),
+                operator.clone(),
+            )
+            .unwrap();
+
+            let res =
+                query::pending_exit(deps.as_ref(), operator.clone(), service.clone()).unwrap();
+            assert_eq!(
+                res,
+                PendingExitResponse(Some(env.block.time.seconds() + delay))
+            );
+        }
+
+        // operator can't exit after the deregistration delay, only after its lock period
+        let mut env = env;
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(state::DEFAULT_DEREGISTRATION_DELAY);
+        let err = execute::finalize_deregistration(
+            deps.as_mut(),
+            service_info.clone(),
+            env.clone(),
+            operator.clone(),
+            service.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidRegistrationStatus {
+                msg: "Deregistration delay has not passed".to_string(),
+            }
+        );
+        execute::finalize_deregistration(
+            deps.as_mut(),
+            service_info.clone(),
+            env.clone(),
+            operator2,
+            service.clone(),
+        )
+        .unwrap();
+
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(long_lock_period - state::DEFAULT_DEREGISTRATION_DELAY);
+        execute::finalize_deregistration(deps.as_mut(), service_info, env, operator, service)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_deregister_pending_registration() {
+        let mut deps = mock_contract();
+        let env = mock_env();
+
+        let operator = deps.api.addr_make("operator");
+        let service = deps.api.addr_make("service");
+        let operator_info = message_info(&operator, &[]);
+
+        SERVICES.save(&mut deps.storage, &service, &true).unwrap();
+        state::set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service),
+            RegistrationStatus::ServiceRegistered,
+        )
+        .unwrap();
+
+        // pending registration is deregistered immediately
+        execute::deregister_service_from_operator(
+            deps.as_mut(),
+            operator_info.clone(),
+            env.clone(),
+            service.clone(),
+            None,
+        )
+        .unwrap();
+
+        let status = state::get_registration_status(&deps.storage, (&operator, &service)).unwrap();
+        assert_eq!(status, RegistrationStatus::Inactive);
+
+        let res = query::pending_exit(deps.as_ref(), operator, service).unwrap();
+        assert_eq!(res, PendingExitResponse(None));
+    }
+
+    #[test]
+    fn test_already_deregistered() {
+        let mut deps = mock_contract();
+        let env = mock_env();
+
+        let operator = deps.api.addr_make("operator");
+        let service = deps.api.addr_make("service");
+        let operator_info = message_info(&operator, &[]);
+        let service_info = message_info(&service, &[]);
+
+        SERVICES.save(&mut deps.storage, &service, &true).unwrap();
+        OPERATORS.save(&mut deps.storage, &operator, &true).unwrap();
+        state::set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service),
+            RegistrationStatus::Active,
+        )
+        .unwrap();
+
+        // deregister for the first time - success
+        execute::deregister_service_from_operator(
+            deps.as_mut(),
+            operator_info.clone(),
+            env.clone(),
+            service.clone(),
+            None,
+        )
+        .expect("failed to deregister service from operator");
+
+        let res = execute::deregister_service_from_operator(
+            deps.as_mut(),
+            operator_info.clone(),
+            env.clone(),
+            service.clone(),
+            None,
+        );
+        assert_eq!(
+            res,
+            Err(ContractError::InvalidRegistrationStatus {
+                msg: "Deregistration is already pending".to_string(),
+            }),
+        );
+
+        let res = execute::register_service_to_operator(
+            deps.as_mut(),
+            operator_info.clone(),
+            env.clone(),
+            service.clone(),
+            None,
+        );
+        assert_eq!(
+            res,
+            Err(ContractError::InvalidRegistrationStatus {
+                msg: "Registration between operator and service is exiting".to_string(),
+            }),
+        );
+
+        let mut env = env;
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(state::DEFAULT_DEREGISTRATION_DELAY);
+        execute::finalize_deregistration(
+            deps.as_mut(),
+            operator_info.clone(),
+            env.clone(),
+            operator.clone(),
+            service.clone(),
+        )
+        .expect("failed to finalize deregistration");
+
+        let res = execute::deregister_service_from_operator(
+            deps.as_mut(),
+            operator_info.clone(),
+            env.clone(),
+            service.clone(),
+            None,
+        );
+        assert_eq!(
+            res,
+            Err(ContractError::InvalidRegistrationStatus {
+                msg: "Service is not registered with this operator".to_string(),
+            }),
+        );
+
+        let res = execute::deregister_operator_from_service(
+            deps.as_mut(),
+            service_info.clone(),
+            env.clone(),
+            operator.clone(),
+        );
+        assert_eq!(
+            res,
+            Err(ContractError::InvalidRegistrationStatus {
+                msg: "Operator is not registered with this service".to_string(),
+            }),
+        );
+    }
+
+    #[test]
+    fn test_enable_slashing() {
+        let mut deps = mock_contract();
+        let env = mock_env();
+
+        let service = deps.api.addr_make("service");
+        let destination = deps.api.addr_make("destination");
+        let service_info = message_info(&service, &[]);
+
+        // register service
+        execute::register_as_service(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            Metadata {
+                uri: None,
+                name: None,
+            },
+        )
+        .expect("register service failed");
+
+        // enable slashing
+        let res = execute::enable_slashing(
+            deps.as_mut(),
+            env,
+            service_info.clone(),
+            SlashingParameters {
+                destination: Some(destination.clone()),
+                max_slashing_bips: 10,
+                resolution_window: 1000,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        );
+
+        // assert SLASHING_PARAMETERS state is updated
+        let slashing_parameters = SLASHING_PARAMETERS
+            .load(&deps.storage, &service)
+            .expect("failed to load slashing parameters");
+        assert_eq!(
+            slashing_parameters,
+            SlashingParameters {
+                destination: Some(destination.clone()),
+                max_slashing_bips: 10,
+                resolution_window: 1000,
+                destinations: vec![],
+                asset_ids: vec![],
+            }
+        );
+
+        assert_eq!(
+            res,
+            Ok(Response::new().add_event(
+                Event::new("SlashingParametersEnabled")
+                    .add_attribute("service", service.as_ref())
+                    .add_attribute("destination", destination.to_string())
+                    .add_attribute("max_slashing_bips", "10")
+                    .add_attribute("resolution_window", "1000")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_re_enable_slashing() {
+        let mut deps = mock_contract();
+        let env = mock_env();
+
+        let service = deps.api.addr_make("service");
+        let destination = deps.api.addr_make("destination");
+        let service_info = message_info(&service, &[]);
+
+        // register service
+        execute::register_as_service(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            Metadata {
+                uri: None,
+                name: None,
+            },
+        )
+        .expect("register service failed");
+
+        // enable slashing for the first time
+        execute::enable_slashing(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            SlashingParameters {
+                destination: Some(destination.clone()),
+                max_slashing_bips: 1000,
+                resolution_window: 1000,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        )
+        .expect("enable slashing failed");
+
+        // operators opt-in to slashing
+        for i in 0..3 {
+            let operator = deps.api.addr_make(format!("operator{i}").as_str());
+            SLASHING_OPT_IN
+                .save(
+                    &mut deps.storage,
+                    (&service, &operator),
+                    &true,
+                    env.block.time.seconds(),
+                )
+                .expect("failed to save slashing opt-in");
+        }
+
+        // re-enable slashing is not allowed, the slashing parameters must be proposed
+        let err = execute::enable_slashing(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            SlashingParameters {
+                destination: Some(destination.clone()),
+                max_slashing_bips: 9999,
+                resolution_window: 2000,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidSlashingParameters {
+                msg: "Slashing is already enabled, propose new slashing parameters instead"
+                    .to_string(),
+            }
+        );
+
+        // propose stricter slashing parameters
+        let res = execute::propose_slashing_parameters(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            SlashingParameters {
+                destination: Some(destination.clone()),
+                max_slashing_bips: 9999,
+                resolution_window: 2000,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        )
+        .unwrap();
+        let effective_timestamp =
+            env.block.time.seconds() + state::DEFAULT_SLASHING_PARAMETERS_NOTICE_PERIOD;
+        assert_eq!(
+            res,
+            Response::new().add_event(
+                Event::new("SlashingParametersProposed")
+                    .add_attribute("service", service.as_ref())
+                    .add_attribute("destination", destination.as_ref())
+                    .add_attribute("max_slashing_bips", "9999")
+                    .add_attribute("resolution_window", "2000")
+                    .add_attribute("effective_timestamp", effective_timestamp.to_string())
+            )
+        );
+
+        let res = query::pending_slashing_parameters(deps.as_ref(), service.clone()).unwrap();
+        assert_eq!(
+            res,
+            PendingSlashingParametersResponse(Some(PendingSlashingParameters {
+                slashing_parameters: SlashingParameters {
+                    destination: Some(destination.clone()),
+                    max_slashing_bips: 9999,
+                    resolution_window: 2000,
+                    destinations: vec![],
+                    asset_ids: vec![],
+                },
+                effective_timestamp,
+            }))
+        );
+
+        // activate before the notice period
+        let err =
+            execute::activate_slashing_parameters(deps.as_mut(), env.clone(), service.clone())
+                .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidSlashingParameters {
+                msg: "Pending slashing parameters are not yet effective".to_string(),
+            }
+        );
+
+        // assert that SLASHING_PARAMETERS state is not updated yet
+        let slashing_parameters = SLASHING_PARAMETERS
+            .load(&deps.storage, &service)
+            .expect("failed to load slashing parameters");
+        assert_eq!(slashing_parameters.max_slashing_bips, 1000);
+
+        // activate after the notice period
+        let mut env = env;
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(state::DEFAULT_SLASHING_PARAMETERS_NOTICE_PERIOD);
+        let res =
+            execute::activate_slashing_parameters(deps.as_mut(), env.clone(), service.clone())
+                .unwrap();
+        assert_eq!(
+            res,
+            Response::new().add_event(
+                Event::new("SlashingParametersEnabled")
+                    .add_attribute("service", service.as_ref())
+                    .add_attribute("destination", destination.as_ref())
+                    .add_attribute("max_slashing_bips", "9999")
+                    .add_attribute("resolution_window", "2000")
+                    .add_attribute("opt_in_reset", "true")
+            )
+        );
+
+        // assert that SLASHING_PARAMETERS state is updated
+        let slashing_parameters = SLASHING_PARAMETERS
+            .load(&deps.storage, &service)
+            .expect("failed to load slashing parameters");
+        assert_eq!(
+            slashing_parameters,
+            SlashingParameters {
+                destination: Some(destination.clone()),
+                max_slashing_bips: 9999,
+                resolution_window: 2000,
+                destinations: vec![],
+                asset_ids: vec![],
+            }
+        );
+
+        let res = query::pending_slashing_parameters(deps.as_ref(), service.clone()).unwrap();
+        assert_eq!(res, PendingSlashingParametersResponse(None));
+
+        // assert that the opt-in mapping is cleared
+        for i in 0..3 {
+            let operator = deps.api.addr_make(format!("operator{i}").as_str());
+            let opt_in = SLASHING_OPT_IN
+                .may_load(&deps.storage, (&service, &operator))
+                .unwrap();
+            assert!(opt_in.is_none());
+        }
+    }
+
+    #[test]
+    fn test_loosen_slashing_parameters_keeps_opt_in() {
+        let mut deps = mock_contract();
+        let mut env = mock_env();
+
+        let service = deps.api.addr_make("service");
+        let operator = deps.api.addr_make("operator");
+        let service_info = message_info(&service, &[]);
+
+        execute::register_as_service(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            Metadata {
+                uri: None,
+                name: None,
+            },
+        )
+        .expect("register service failed");
+
+        execute::enable_slashing(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            SlashingParameters {
+                destination: None,
+                max_slashing_bips: 1000,
+                resolution_window: 1000,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        )
+        .expect("enable slashing failed");
+
+        SLASHING_OPT_IN
+            .save(
+                &mut deps.storage,
+                (&service, &operator),
+                &true,
+                env.block.time.seconds(),
+            )
+            .expect("failed to save slashing opt-in");
+
+        // lower max_slashing_bips and longer resolution_window
+        execute::propose_slashing_parameters(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            SlashingParameters {
+                destination: None,
+                max_slashing_bips: 500,
+                resolution_window: 2000,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        )
+        .expect("propose slashing parameters failed");
+
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(state::DEFAULT_SLASHING_PARAMETERS_NOTICE_PERIOD);
+        let res =
+            execute::activate_slashing_parameters(deps.as_mut(), env.clone(), service.clone())
+                .unwrap();
+        assert_eq!(
+            res,
+            Response::new().add_event(
+                Event::new("SlashingParametersEnabled")
+                    .add_attribute("service", service.as_ref())
+                    .add_attribute("destination", "")
+                    .add_attribute("max_slashing_bips", "500")
+                    .add_attribute("resolution_window", "2000")
+                    .add_attribute("opt_in_reset", "false")
+            )
+        );
+
+        // assert that the opt-in is kept
+        let opt_in = SLASHING_OPT_IN
+            .may_load(&deps.storage, (&service, &operator))
+            .unwrap();
+        assert_eq!(opt_in, Some(true));
+
+        // nothing left to activate
+        let err =
+            execute::activate_slashing_parameters(deps.as_mut(), env.clone(), service).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidSlashingParameters {
+                msg: "No pending slashing parameters".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_slashing_category() {
+        let mut deps = mock_contract();
+        let mut env = mock_env();
+
+        let service = deps.api.addr_make("service");
+        let operator = deps.api.addr_make("operator");
+        let service_info = message_info(&service, &[]);
+        let operator_info = message_info(&operator, &[]);
+
+        execute::register_as_service(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            Metadata {
+                uri: None,
+                name: None,
+            },
+        )
+        .expect("register service failed");
+
+        // invalid category name
+        let err = execute::set_slashing_category(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            "live ness".to_string(),
+            SlashingParameters {
+                destination: None,
+                max_slashing_bips: 100,
+                resolution_window: 1000,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidSlashingCategory {
+                msg: "Category must only contain alphanumeric, '_' or '-' characters".to_string(),
+            }
+        );
+
+        for (category, max_slashing_bips) in [("liveness", 100), ("equivocation", 5000)] {
+            let res = execute::set_slashing_category(
+                deps.as_mut(),
+                env.clone(),
+                service_info.clone(),
+                category.to_string(),
+                SlashingParameters {
+                    destination: None,
+                    max_slashing_bips,
+                    resolution_window: 1000,
+                    destinations: vec![],
+                    asset_ids: vec![],
+                },
+            )
+            .unwrap();
+            assert_eq!(
+                res,
+                Response::new().add_event(
+                    Event::new("SlashingCategorySet")
+                        .add_attribute("service", service.as_ref())
+                        .add_attribute("category", category)
+                        .add_attribute("destination", "")
+                        .add_attribute("max_slashing_bips", max_slashing_bips.to_string())
+                        .add_attribute("resolution_window", "1000")
+                )
+            );
+        }
+
+        let res =
+            query::list_slashing_categories(deps.as_ref(), service.clone(), 100, None).unwrap();
+        assert_eq!(
+            res,
+            SlashingCategoryListResponse(vec![
+                SlashingCategory {
+                    category: "liveness".to_string(),
+                    slashing_parameters: SlashingParameters {
+                        destination: None,
+                        max_slashing_bips: 100,
+                        resolution_window: 1000,
+                        destinations: vec![],
+                        asset_ids: vec![],
+                    },
+                },
+                SlashingCategory {
+                    category: "equivocation".to_string(),
+                    slashing_parameters: SlashingParameters {
+                        destination: None,
+                        max_slashing_bips: 5000,
+                        resolution_window: 1000,
+                        destinations: vec![],
+                        asset_ids: vec![],
+                    },
+                },
+            ])
+        );
+
+        // operator must be active to opt in
+        let err = execute::operator_opt_in_to_slashing_category(
+            deps.as_mut(),
+            env.clone(),
+            operator_info.clone(),
+            service.clone(),
+            "liveness".to_string(),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidRegistrationStatus {
+                msg: "Operator and service must have active registration".to_string()
+            }
+        );
+
+        set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service),
+            RegistrationStatus::Active,
+        )
+        .unwrap();
+
+        // category must be enabled to opt in
+        let err = execute::operator_opt_in_to_slashing_category(
+            deps.as_mut(),
+            env.clone(),
+            operator_info.clone(),
+            service.clone(),
+            "unknown".to_string(),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidSlashingOptIn {
+                msg: "Cannot opt in: slashing category is not enabled for this service".to_string(),
+            }
+        );
+
+        for category in ["liveness", "equivocation"] {
+            execute::operator_opt_in_to_slashing_category(
+                deps.as_mut(),
+                env.clone(),
+                operator_info.clone(),
+                service.clone(),
+                category.to_string(),
+                None,
+            )
+            .expect("opt in to slashing category failed");
+        }
+
+        // opt-in per category is independent of the default slashing opt-in
+        let res = query::is_operator_opted_in_to_slashing(
+            deps.as_ref(),
+            service.clone(),
+            operator.clone(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(res, IsOperatorOptedInToSlashingResponse(false));
+
+        env.block.time = env.block.time.plus_seconds(10);
+
+        let stricter_liveness = SlashingParameters {
+            destination: None,
+            max_slashing_bips: 200,
+            resolution_window: 1000,
+            destinations: vec![],
+            asset_ids: vec![],
+        };
+
+        // an enabled category can't be updated immediately
+        let err = execute::set_slashing_category(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            "liveness".to_string(),
+            stricter_liveness.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidSlashingCategory {
+                msg: "Category is already enabled, propose new slashing parameters instead"
+                    .to_string(),
+            }
+        );
+
+        // only an enabled category can be proposed
+        let err = execute::propose_slashing_category(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            "unknown".to_string(),
+            stricter_liveness.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidSlashingCategory {
+                msg: "Category is not enabled for this service".to_string(),
+            }
+        );
+
+        let res = execute::propose_slashing_category(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            "liveness".to_string(),
+            stricter_liveness.clone(),
+        )
+        .unwrap();
+        let effective_timestamp =
+            env.block.time.seconds() + state::DEFAULT_SLASHING_PARAMETERS_NOTICE_PERIOD;
+        assert_eq!(
+            res,
+            Response::new().add_event(
+                Event::new("SlashingCategoryProposed")
+                    .add_attribute("service", service.as_ref())
+                    .add_attribute("category", "liveness")
+                    .add_attribute("destination", "")
+                    .add_attribute("max_slashing_bips", "200")
+                    .add_attribute("resolution_window", "1000")
+                    .add_attribute("effective_timestamp", effective_timestamp.to_string())
+            )
+        );
+
+        let res = query::pending_slashing_category(
+            deps.as_ref(),
+            service.clone(),
+            "liveness".to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            PendingSlashingParametersResponse(Some(PendingSlashingParameters {
+                slashing_parameters: stricter_liveness.clone(),
+                effective_timestamp,
+            }))
+        );
 
-    fn mock_contract() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
-        let mut deps = mock_dependencies();
-        let env = mock_env();
-        let owner = deps.api.addr_make("owner");
-        let pauser = deps.api.addr_make("pauser");
-        let owner_info = message_info(&owner, &[]);
+        // not effective before the notice period
+        let err = execute::activate_slashing_category(
+            deps.as_mut(),
+            env.clone(),
+            service.clone(),
+            "liveness".to_string(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidSlashingCategory {
+                msg: "Pending slashing parameters are not yet effective".to_string(),
+            }
+        );
+        let res =
+            query::slashing_category(deps.as_ref(), service.clone(), "liveness".to_string(), None)
+                .unwrap();
+        assert_eq!(res.0.unwrap().max_slashing_bips, 100);
 
-        instantiate(
+        env.block.time = env.block.time.plus_seconds(10);
+
+        // looser equivocation category keeps the equivocation opt-in
+        execute::propose_slashing_category(
             deps.as_mut(),
-            env,
-            owner_info.clone(),
-            InstantiateMsg {
-                owner: owner.to_string(),
-                pauser: pauser.to_string(),
+            env.clone(),
+            service_info.clone(),
+            "equivocation".to_string(),
+            SlashingParameters {
+                destination: None,
+                max_slashing_bips: 4000,
+                resolution_window: 1000,
+                destinations: vec![],
+                asset_ids: vec![],
             },
         )
         .unwrap();
 
-        deps
-    }
-
-    #[test]
-    fn test_register_as_service() {
-        let mut deps = mock_dependencies();
-
-        let service = deps.api.addr_make("service");
-        let service_info = message_info(&service, &[]);
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(state::DEFAULT_SLASHING_PARAMETERS_NOTICE_PERIOD);
 
-        let res = execute::register_as_service(
+        let res = execute::activate_slashing_category(
             deps.as_mut(),
-            service_info,
-            Metadata {
-                uri: Some("uri".to_string()),
-                name: Some("name".to_string()),
-            },
+            env.clone(),
+            service.clone(),
+            "equivocation".to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            res.events[0].attributes.last().unwrap().value,
+            "false".to_string()
         );
 
+        // stricter liveness category resets the liveness opt-in only
+        let res = execute::activate_slashing_category(
+            deps.as_mut(),
+            env.clone(),
+            service.clone(),
+            "liveness".to_string(),
+        )
+        .unwrap();
         assert_eq!(
             res,
-            Ok(Response::new()
-                .add_event(
-                    Event::new("ServiceRegistered").add_attribute("service", service.as_ref())
-                )
-                .add_event(
-                    Event::new("MetadataUpdated")
-                        .add_attribute("metadata.uri", "uri")
-                        .add_attribute("metadata.name", "name")
-                        .add_attribute("service", service.as_ref())
-                ))
+            Response::new().add_event(
+                Event::new("SlashingCategorySet")
+                    .add_attribute("service", service.as_ref())
+                    .add_attribute("category", "liveness")
+                    .add_attribute("destination", "")
+                    .add_attribute("max_slashing_bips", "200")
+                    .add_attribute("resolution_window", "1000")
+                    .add_attribute("opt_in_reset", "true")
+            )
         );
 
-        let registered = SERVICES.load(&deps.storage, &service).unwrap();
-        assert!(registered);
-    }
+        let res = query::pending_slashing_category(
+            deps.as_ref(),
+            service.clone(),
+            "liveness".to_string(),
+        )
+        .unwrap();
+        assert_eq!(res, PendingSlashingParametersResponse(None));
 
-    #[test]
-    fn test_register_service_optional_metadata() {
-        let mut deps = mock_dependencies();
+        let res = query::is_operator_opted_in_to_slashing_category(
+            deps.as_ref(),
+            service.clone(),
+            "liveness".to_string(),
+            operator.clone(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(res, IsOperatorOptedInToSlashingResponse(false));
 
-        let service = deps.api.addr_make("service");
-        let service_info = message_info(&service, &[]);
+        // opted in to liveness at the previous timestamp
+        let res = query::is_operator_opted_in_to_slashing_category(
+            deps.as_ref(),
+            service.clone(),
+            "liveness".to_string(),
+            operator.clone(),
+            Some(env.block.time.seconds()),
+        )
+        .unwrap();
+        assert_eq!(res, IsOperatorOptedInToSlashingResponse(true));
 
-        let res = execute::register_as_service(
+        let res = query::is_operator_opted_in_to_slashing_category(
+            deps.as_ref(),
+            service.clone(),
+            "equivocation".to_string(),
+            operator.clone(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(res, IsOperatorOptedInToSlashingResponse(true));
+
+        // remove equivocation category
+        execute::remove_slashing_category(
             deps.as_mut(),
-            service_info,
-            Metadata {
-                uri: None,
-                name: Some("Meta Bridging".to_string()),
-            },
-        );
+            env.clone(),
+            service_info.clone(),
+            "equivocation".to_string(),
+        )
+        .expect("remove slashing category failed");
+
+        let res = query::slashing_category(
+            deps.as_ref(),
+            service.clone(),
+            "equivocation".to_string(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(res, SlashingParametersResponse(None));
+
+        let res = query::is_operator_opted_in_to_slashing_category(
+            deps.as_ref(),
+            service.clone(),
+            "equivocation".to_string(),
+            operator.clone(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(res, IsOperatorOptedInToSlashingResponse(false));
 
+        let err = execute::remove_slashing_category(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            "equivocation".to_string(),
+        )
+        .unwrap_err();
         assert_eq!(
-            res,
-            Ok(Response::new()
-                .add_event(
-                    Event::new("ServiceRegistered").add_attribute("service", service.as_ref())
-                )
-                .add_event(
-                    Event::new("MetadataUpdated")
-                        .add_attribute("metadata.name", "Meta Bridging")
-                        .add_attribute("service", service.as_ref())
-                ))
+            err,
+            ContractError::InvalidSlashingCategory {
+                msg: "Category is not enabled for this service".to_string(),
+            }
         );
-
-        let registered = SERVICES.load(&deps.storage, &service).unwrap();
-        assert!(registered);
     }
 
     #[test]
-    fn test_service_update_metadata() {
-        let mut deps = mock_dependencies();
+    fn test_deregister_as_service() {
+        let mut deps = mock_contract();
+        let env = mock_env();
 
         let service = deps.api.addr_make("service");
         let service_info = message_info(&service, &[]);
+        let mut operators = vec![
+            deps.api.addr_make("operator/1"),
+            deps.api.addr_make("operator/2"),
+            deps.api.addr_make("operator/3"),
+        ];
+        operators.sort();
 
         SERVICES.save(&mut deps.storage, &service, &true).unwrap();
+        for operator in &operators {
+            OPERATORS.save(&mut deps.storage, operator, &true).unwrap();
+        }
 
-        let res = execute::service_update_metadata(
+        let slashing_parameters = SlashingParameters {
+            destination: Some(deps.api.addr_make("destination")),
+            max_slashing_bips: 100,
+            resolution_window: 1000,
+            destinations: vec![],
+            asset_ids: vec![],
+        };
+        state::enable_slashing(
+            &mut deps.storage,
+            &deps.api,
+            &env,
+            &service,
+            &slashing_parameters,
+        )
+        .unwrap();
+        state::set_slashing_category(
+            &mut deps.storage,
+            &deps.api,
+            &env,
+            &service,
+            "liveness",
+            &slashing_parameters,
+        )
+        .unwrap();
+
+        // first and last operators are active, the second is pending
+        let statuses = [
+            RegistrationStatus::Active,
+            RegistrationStatus::ServiceRegistered,
+            RegistrationStatus::Active,
+        ];
+        for (operator, status) in operators.iter().zip(statuses) {
+            set_registration_status(&mut deps.storage, &env, (operator, &service), status).unwrap();
+        }
+
+        // a zero limit would never complete the sunset
+        let err = execute::deregister_as_service(
             deps.as_mut(),
-            service_info,
-            Metadata {
-                uri: Some("new_uri".to_string()),
-                name: Some("new_name".to_string()),
-            },
+            env.clone(),
+            service_info.clone(),
+            Some(0),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidBatch {
+                msg: "Limit must be greater than zero".to_string(),
+            }
         );
 
+        let res = execute::deregister_as_service(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            Some(2),
+        )
+        .unwrap();
         assert_eq!(
-            res,
-            Ok(Response::new().add_event(
-                Event::new("MetadataUpdated")
-                    .add_attribute("metadata.uri", "new_uri")
-                    .add_attribute("metadata.name", "new_name")
-                    .add_attribute("service", service.clone())
-            ))
+            res.events.first(),
+            Some(&Event::new("ServiceRetired").add_attribute("service", service.to_string()))
+        );
+        assert_eq!(
+            res.events.last(),
+            Some(
+                &Event::new("ServiceSunset")
+                    .add_attribute("service", service.to_string())
+                    .add_attribute("completed", "false")
+            )
         );
-    }
-
-    #[test]
-    fn test_register_as_operator() {
-        let mut deps = mock_dependencies();
-
-        let operator = deps.api.addr_make("operator");
-        let operator_info = message_info(&operator, &[]);
 
-        let res = execute::register_as_operator(
-            deps.as_mut(),
-            operator_info,
-            Metadata {
-                uri: Some("uri".to_string()),
-                name: Some("operator1".to_string()),
-            },
+        // retired service is no longer a service and has slashing disabled
+        let res = query::is_service(deps.as_ref(), service.clone()).unwrap();
+        assert_eq!(res, IsServiceResponse(false));
+        assert!(!state::is_slashing_enabled(&deps.storage, &service, None).unwrap());
+        assert!(
+            state::get_slashing_category(&deps.storage, &service, "liveness", None)
+                .unwrap()
+                .is_none()
         );
 
+        let res = query::service_sunset(deps.as_ref(), service.clone()).unwrap();
         assert_eq!(
             res,
-            Ok(Response::new()
-                .add_event(
-                    Event::new("OperatorRegistered").add_attribute("operator", operator.clone())
-                )
-                .add_event(
-                    Event::new("MetadataUpdated")
-                        .add_attribute("metadata.uri", "uri")
-                        .add_attribute("metadata.name", "operator1")
-                        .add_attribute("operator", operator.clone())
-                ))
+            SunsetResponse(Some(Sunset {
+                last: Some(operators[1].clone()),
+                completed: false,
+            }))
         );
 
-        let operator_registered = OPERATORS.load(&deps.storage, &operator).is_ok();
-        assert!(operator_registered);
-    }
-
-    #[test]
-    fn test_register_as_operator_more_than_once() {
-        let mut deps = mock_dependencies();
-
-        let operator = deps.api.addr_make("operator");
-        let operator_info = message_info(&operator, &[]);
-
-        {
-            // register operator the first time - success
-            execute::register_as_operator(
-                deps.as_mut(),
-                operator_info.clone(),
-                Metadata {
-                    uri: Some("uri".to_string()),
-                    name: Some("operator1".to_string()),
-                },
+        let res = execute::deregister_as_service(
+            deps.as_mut(),
+            env.clone(),
+            service_info.clone(),
+            Some(2),
+        )
+        .unwrap();
+        assert_eq!(
+            res.events.last(),
+            Some(
+                &Event::new("ServiceSunset")
+                    .add_attribute("service", service.to_string())
+                    .add_attribute("completed", "true")
             )
-            .expect("register operator failed");
+        );
 
-            let operator_registered = OPERATORS.load(&deps.storage, &operator).is_ok();
-            assert!(operator_registered);
+        // active registrations are exiting, pending registrations are inactive
+        let expected = [
+            RegistrationStatus::Exiting,
+            RegistrationStatus::Inactive,
+            RegistrationStatus::Exiting,
+        ];
+        for (operator, expected) in operators.iter().zip(expected) {
+            let res = status(deps.as_ref(), operator.clone(), service.clone(), None).unwrap();
+            assert_eq!(res, expected.into());
         }
 
-        // register operator the second time - error
-        let err = execute::register_as_operator(
+        let err =
+            execute::deregister_as_service(deps.as_mut(), env.clone(), service_info.clone(), None)
+                .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Retired {
+                msg: "Service sunset has completed".to_string(),
+            }
+        );
+
+        let err = execute::register_as_service(
             deps.as_mut(),
-            operator_info,
+            env.clone(),
+            service_info.clone(),
             Metadata {
-                uri: Some("uri".to_string()),
-                name: Some("operator1".to_string()),
+                name: None,
+                uri: None,
             },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Retired {
+                msg: "Service has been retired".to_string(),
+            }
         );
-        assert_eq!(err, Err(ContractError::OperatorRegistered {}),);
+
+        let err =
+            register_operator_to_service(deps.as_mut(), service_info, env, operators[1].clone())
+                .unwrap_err();
+        assert_eq!(err, ContractError::Std(StdError::not_found("service")));
     }
 
     #[test]
-    fn test_update_operator_metadata_uri() {
-        let mut deps = mock_dependencies();
+    fn test_deregister_as_operator() {
+        let mut deps = mock_contract();
+        let env = mock_env();
 
         let operator = deps.api.addr_make("operator");
         let operator_info = message_info(&operator, &[]);
+        let service1 = deps.api.addr_make("service/1");
+        let service2 = deps.api.addr_make("service/2");
 
-        {
-            // register operator the first time
-            let res = execute::register_as_operator(
-                deps.as_mut(),
-                operator_info.clone(),
-                Metadata {
-                    uri: Some("uri".to_string()),
-                    name: Some("operator1".to_string()),
-                },
-            );
+        let err = execute::deregister_as_operator(
+            deps.as_mut(),
+            env.clone(),
+            operator_info.clone(),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Std(StdError::not_found("operator")));
 
-            // assert OperatorMetadataURIUpdated event
-            assert_eq!(
-                res,
-                Ok(Response::new()
-                    .add_event(
-                        Event::new("OperatorRegistered")
-                            .add_attribute("operator", operator.as_ref())
-                    )
-                    .add_event(
-                        Event::new("MetadataUpdated")
-                            .add_attribute("metadata.uri", "uri")
-                            .add_attribute("metadata.name", "operator1")
-                            .add_attribute("operator", operator.clone())
-                    ))
-            );
-        }
+        OPERATORS.save(&mut deps.storage, &operator, &true).unwrap();
+        SERVICES.save(&mut deps.storage, &service1, &true).unwrap();
+        SERVICES.save(&mut deps.storage, &service2, &true).unwrap();
 
-        // update operator details
-        let res = execute::update_operator_metadata(
+        set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service1),
+            RegistrationStatus::Active,
+        )
+        .unwrap();
+        set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service2),
+            RegistrationStatus::OperatorRegistered,
+        )
+        .unwrap();
+
+        let res = execute::deregister_as_operator(
+            deps.as_mut(),
+            env.clone(),
+            operator_info.clone(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            res.events.last(),
+            Some(
+                &Event::new("OperatorSunset")
+                    .add_attribute("operator", operator.to_string())
+                    .add_attribute("completed", "true")
+            )
+        );
+
+        let res = query::is_operator(deps.as_ref(), operator.clone()).unwrap();
+        assert_eq!(res, IsOperatorResponse(false));
+
+        let res = status(deps.as_ref(), operator.clone(), service1.clone(), None).unwrap();
+        assert_eq!(res, RegistrationStatus::Exiting.into());
+        let res = status(deps.as_ref(), operator.clone(), service2.clone(), None).unwrap();
+        assert_eq!(res, RegistrationStatus::Inactive.into());
+
+        // exiting registration is still active until finalized
+        let res = query::is_operator_active(deps.as_ref(), operator.clone()).unwrap();
+        assert_eq!(res, IsOperatorActiveResponse(true));
+
+        let res = query::operator_sunset(deps.as_ref(), operator.clone()).unwrap();
+        assert_eq!(
+            res,
+            SunsetResponse(Some(Sunset {
+                last: Some(std::cmp::max(service1, service2)),
+                completed: true,
+            }))
+        );
+
+        let err = execute::register_as_operator(
             deps.as_mut(),
+            env,
             operator_info,
             Metadata {
-                uri: Some("uri2".to_string()),
                 name: None,
+                uri: None,
             },
-        );
-
-        // assert event
+        )
+        .unwrap_err();
         assert_eq!(
-            res,
-            Ok(Response::new().add_event(
-                Event::new("MetadataUpdated")
-                    .add_attribute("metadata.uri", "uri2") // updated uri
-                    .add_attribute("operator", operator.clone())
-            ))
+            err,
+            ContractError::Retired {
+                msg: "Operator has been retired".to_string(),
+            }
         );
     }
 
     #[test]
-    fn test_register_lifecycle_operator_then_service() {
+    fn test_commission() {
         let mut deps = mock_contract();
-        let env = mock_env();
+        let mut env = mock_env();
 
         let operator = deps.api.addr_make("operator");
         let service = deps.api.addr_make("service");
         let operator_info = message_info(&operator, &[]);
-        let service_info = message_info(&service, &[]);
 
-        // register service + operator
-        execute::register_as_service(
+        let err = execute::propose_commission(
             deps.as_mut(),
-            service_info.clone(),
-            Metadata {
-                uri: None,
-                name: None,
-            },
+            env.clone(),
+            operator_info.clone(),
+            service.clone(),
+            500,
         )
-        .expect("register service failed");
+        .unwrap_err();
+        assert_eq!(err, ContractError::Std(StdError::not_found("operator")));
 
-        execute::register_as_operator(
+        OPERATORS.save(&mut deps.storage, &operator, &true).unwrap();
+        SERVICES.save(&mut deps.storage, &service, &true).unwrap();
+
+        let err = execute::propose_commission(
             deps.as_mut(),
+            env.clone(),
             operator_info.clone(),
-            Metadata {
-                uri: Some("uri".to_string()),
-                name: Some("operator1".to_string()),
-            },
+            service.clone(),
+            10_001,
         )
-        .expect("register operator failed");
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidCommission {
+                msg: "Commission exceeds 10,000 bips (100%)".to_string(),
+            }
+        );
 
-        let res = execute::register_service_to_operator(
+        let res = execute::propose_commission(
             deps.as_mut(),
-            operator_info.clone(),
             env.clone(),
+            operator_info.clone(),
             service.clone(),
-        );
+            500,
+        )
+        .unwrap();
+        let effective_timestamp = env
+            .block
+            .time
+            .plus_seconds(state::DEFAULT_COMMISSION_CHANGE_DELAY)
+            .seconds();
         assert_eq!(
             res,
-            Ok(Response::new().add_event(
-                Event::new("RegistrationStatusUpdated")
-                    .add_attribute("method", "register_service_to_operator")
-                    .add_attribute("operator", operator.as_ref())
-                    .add_attribute("service", service.as_ref())
-                    .add_attribute("status", "OperatorRegistered")
-            )),
+            Response::new().add_event(
+                Event::new("CommissionProposed")
+                    .add_attribute("operator", operator.to_string())
+                    .add_attribute("service", service.to_string())
+                    .add_attribute("commission_bips", "500")
+                    .add_attribute("effective_timestamp", effective_timestamp.to_string())
+            )
         );
 
-        let status = state::get_registration_status(&deps.storage, (&operator, &service)).unwrap();
-        assert_eq!(status, RegistrationStatus::OperatorRegistered);
+        let res =
+            query::pending_commission(deps.as_ref(), operator.clone(), service.clone()).unwrap();
+        assert_eq!(
+            res,
+            PendingCommissionResponse(Some(PendingCommission {
+                commission_bips: 500,
+                effective_timestamp,
+            }))
+        );
 
-        let res = execute::register_operator_to_service(
+        let err = execute::activate_commission(
             deps.as_mut(),
-            service_info.clone(),
             env.clone(),
             operator.clone(),
+            service.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidCommission {
+                msg: "Pending commission is not yet effective".to_string(),
+            }
         );
+
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(state::DEFAULT_COMMISSION_CHANGE_DELAY);
+        let activated_at = env.block.time.seconds();
+        let res = execute::activate_commission(
+            deps.as_mut(),
+            env.clone(),
+            operator.clone(),
+            service.clone(),
+        )
+        .unwrap();
         assert_eq!(
             res,
-            Ok(Response::new().add_event(
-                Event::new("RegistrationStatusUpdated")
-                    .add_attribute("method", "register_operator_to_service")
-                    .add_attribute("operator", operator.as_ref())
-                    .add_attribute("service", service.as_ref())
-                    .add_attribute("status", "Active")
-            )),
+            Response::new().add_event(
+                Event::new("CommissionActivated")
+                    .add_attribute("operator", operator.to_string())
+                    .add_attribute("service", service.to_string())
+                    .add_attribute("commission_bips", "500")
+            )
         );
 
-        let status = state::get_registration_status(&deps.storage, (&operator, &service)).unwrap();
-        assert_eq!(status, RegistrationStatus::Active);
+        let res =
+            query::commission(deps.as_ref(), operator.clone(), service.clone(), None).unwrap();
+        assert_eq!(res, CommissionResponse(500));
+
+        // commission is only effective from the next timestamp
+        let res = query::commission(
+            deps.as_ref(),
+            operator.clone(),
+            service.clone(),
+            Some(activated_at),
+        )
+        .unwrap();
+        assert_eq!(res, CommissionResponse(0));
+        let res = query::commission(
+            deps.as_ref(),
+            operator.clone(),
+            service.clone(),
+            Some(activated_at + 1),
+        )
+        .unwrap();
+        assert_eq!(res, CommissionResponse(500));
+
+        let res =
+            query::pending_commission(deps.as_ref(), operator.clone(), service.clone()).unwrap();
+        assert_eq!(res, PendingCommissionResponse(None));
+
+        let err = execute::activate_commission(deps.as_mut(), env, operator, service).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidCommission {
+                msg: "No pending commission".to_string(),
+            }
+        );
     }
 
     #[test]
-    fn test_register_lifecycle_service_then_operator() {
+    fn test_admission_policy() {
         let mut deps = mock_contract();
         let env = mock_env();
 
-        let operator = deps.api.addr_make("operator");
         let service = deps.api.addr_make("service");
-        let operator_info = message_info(&operator, &[]);
         let service_info = message_info(&service, &[]);
+        let operator1 = deps.api.addr_make("operator/1");
+        let operator2 = deps.api.addr_make("operator/2");
+        let operator3 = deps.api.addr_make("operator/3");
+        let operator4 = deps.api.addr_make("operator/4");
 
-        // register service + operator
-        execute::register_as_service(
+        SERVICES.save(&mut deps.storage, &service, &true).unwrap();
+        for operator in [&operator1, &operator2, &operator3, &operator4] {
+            OPERATORS.save(&mut deps.storage, operator, &true).unwrap();
+        }
+
+        // manual (default) admission keeps the registration pending
+        let res = query::admission_policy(deps.as_ref(), service.clone()).unwrap();
+        assert_eq!(res, AdmissionPolicyResponse(AdmissionPolicy::Manual));
+
+        register_service_to_operator(
             deps.as_mut(),
-            service_info.clone(),
-            Metadata {
-                uri: None,
-                name: None,
-            },
+            message_info(&operator1, &[]),
+            env.clone(),
+            service.clone(),
+            None,
         )
-        .expect("register service failed");
+        .unwrap();
+        let res = status(deps.as_ref(), operator1.clone(), service.clone(), None).unwrap();
+        assert_eq!(res, RegistrationStatus::OperatorRegistered.into());
 
-        execute::register_as_operator(
+        // allowlist admission
+        let res = execute::set_admission_policy(
             deps.as_mut(),
-            operator_info.clone(),
-            Metadata {
-                uri: Some("uri".to_string()),
-                name: Some("operator1".to_string()),
-            },
+            service_info.clone(),
+            AdmissionPolicy::Allowlist,
         )
-        .expect("register operator failed");
+        .unwrap();
+        assert_eq!(
+            res,
+            Response::new().add_event(
+                Event::new("AdmissionPolicySet")
+                    .add_attribute("service", service.to_string())
+                    .add_attribute("policy", "allowlist")
+            )
+        );
 
-        let res = execute::register_operator_to_service(
+        execute::set_admission_allowlist(
             deps.as_mut(),
             service_info.clone(),
+            vec![operator2.clone()],
+            true,
+        )
+        .unwrap();
+        let res = query::is_operator_allowlisted(deps.as_ref(), service.clone(), operator2.clone())
+            .unwrap();
+        assert_eq!(res, IsOperatorAllowlistedResponse(true));
+
+        // an operator that isn't allowlisted is pending the manual approval of the service
+        let res = register_service_to_operator(
+            deps.as_mut(),
+            message_info(&operator3, &[]),
             env.clone(),
-            operator.clone(),
-        );
+            service.clone(),
+            None,
+        )
+        .unwrap();
         assert_eq!(
-            res,
-            Ok(Response::new().add_event(
-                Event::new("RegistrationStatusUpdated")
-                    .add_attribute("method", "register_operator_to_service")
-                    .add_attribute("operator", operator.as_ref())
-                    .add_attribute("service", service.as_ref())
-                    .add_attribute("status", "ServiceRegistered")
-            )),
+            res.events,
+            vec![Event::new("RegistrationStatusUpdated")
+                .add_attribute("method", "register_service_to_operator")
+                .add_attribute("operator", operator3.to_string())
+                .add_attribute("service", service.to_string())
+                .add_attribute("status", "OperatorRegistered")]
         );
+        register_operator_to_service(
+            deps.as_mut(),
+            service_info.clone(),
+            env.clone(),
+            operator3.clone(),
+        )
+        .unwrap();
+        let res = status(deps.as_ref(), operator3.clone(), service.clone(), None).unwrap();
+        assert_eq!(res, RegistrationStatus::Active.into());
 
-        let status = state::get_registration_status(&deps.storage, (&operator, &service)).unwrap();
-        assert_eq!(status, RegistrationStatus::ServiceRegistered);
-
-        let res = execute::register_service_to_operator(
+        let res = register_service_to_operator(
             deps.as_mut(),
-            operator_info.clone(),
+            message_info(&operator2, &[]),
             env.clone(),
             service.clone(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::new("RegistrationStatusUpdated")
+                .add_attribute("method", "register_service_to_operator")
+                .add_attribute("operator", operator2.to_string())
+                .add_attribute("service", service.to_string())
+                .add_attribute("status", "Active")]
         );
+        let res = query::active_operator_count(deps.as_ref(), service.clone()).unwrap();
+        assert_eq!(res, ActiveCountResponse(2));
+
+        // open admission
+        execute::set_admission_policy(deps.as_mut(), service_info.clone(), AdmissionPolicy::Open)
+            .unwrap();
+        register_service_to_operator(
+            deps.as_mut(),
+            message_info(&operator4, &[]),
+            env.clone(),
+            service.clone(),
+            None,
+        )
+        .unwrap();
+        let res = status(deps.as_ref(), operator4.clone(), service.clone(), None).unwrap();
+        assert_eq!(res, RegistrationStatus::Active.into());
+
+        // min stake requires the router to be set
+        let err = execute::set_admission_policy(
+            deps.as_mut(),
+            service_info,
+            AdmissionPolicy::MinStake {
+                asset_id: "cosmos:wasm-1/bank:denom".to_string(),
+                amount: Uint128::new(1000),
+            },
+        )
+        .unwrap_err();
         assert_eq!(
-            res,
-            Ok(Response::new().add_event(
-                Event::new("RegistrationStatusUpdated")
-                    .add_attribute("method", "register_service_to_operator")
-                    .add_attribute("operator", operator.as_ref())
-                    .add_attribute("service", service.as_ref())
-                    .add_attribute("status", "Active")
-            )),
+            err,
+            ContractError::InvalidAdmissionPolicy {
+                msg: "Router is not set".to_string(),
+            }
         );
-
-        let status = state::get_registration_status(&deps.storage, (&operator, &service)).unwrap();
-        assert_eq!(status, RegistrationStatus::Active);
     }
 
     #[test]
-    fn test_register_operator_already_registered() {
+    fn test_admission_policy_min_stake() {
         let mut deps = mock_contract();
         let env = mock_env();
 
-        let operator = deps.api.addr_make("operator/2");
-        let service = deps.api.addr_make("service/2");
-        let operator_info = message_info(&operator, &[]);
+        let owner = deps.api.addr_make("owner");
+        let service = deps.api.addr_make("service");
+        let service_info = message_info(&service, &[]);
+        let operator = deps.api.addr_make("operator");
+        let router = deps.api.addr_make("router");
+        let vault1 = deps.api.addr_make("vault/1");
+        let vault2 = deps.api.addr_make("vault/2");
+        let vault3 = deps.api.addr_make("vault/3");
 
-        // register service + operator
         SERVICES.save(&mut deps.storage, &service, &true).unwrap();
         OPERATORS.save(&mut deps.storage, &operator, &true).unwrap();
 
-        state::set_registration_status(
-            &mut deps.storage,
-            &env,
-            (&operator, &service),
-            RegistrationStatus::OperatorRegistered,
+        execute::set_router(deps.as_mut(), message_info(&owner, &[]), router.clone()).unwrap();
+        let res = query::router(deps.as_ref()).unwrap();
+        assert_eq!(res, RouterResponse(Some(router.clone())));
+
+        // vault1 and vault2 hold the asset, vault2 is not whitelisted, vault3 holds another asset
+        let vaults = format!(
+            r#"[{{"vault":"{vault1}","whitelisted":true}},{{"vault":"{vault2}","whitelisted":false}},{{"vault":"{vault3}","whitelisted":true}}]"#
+        );
+        let (router_addr, vault1_addr, vault3_addr) =
+            (router.to_string(), vault1.to_string(), vault3.to_string());
+        deps.querier
+            .update_wasm(move |req: &WasmQuery| -> QuerierResult {
+                let WasmQuery::Smart { contract_addr, .. } = req else {
+                    return SystemResult::Err(SystemError::Unknown {});
+                };
+                let response = if *contract_addr == router_addr {
+                    vaults.clone()
+                } else if *contract_addr == vault1_addr {
+                    r#"{"total_assets":"600","asset_id":"cosmos:wasm-1/bank:denom"}"#.to_string()
+                } else if *contract_addr == vault3_addr {
+                    r#"{"total_assets":"5000","asset_id":"cosmos:wasm-1/bank:other"}"#.to_string()
+                } else {
+                    return SystemResult::Err(SystemError::NoSuchContract {
+                        addr: contract_addr.to_string(),
+                    });
+                };
+                SystemResult::Ok(ContractResult::Ok(Binary::from(response.as_bytes())))
+            });
+
+        let set_min_stake = |deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>, amount| {
+            execute::set_admission_policy(
+                deps.as_mut(),
+                service_info.clone(),
+                AdmissionPolicy::MinStake {
+                    asset_id: "cosmos:wasm-1/bank:denom".to_string(),
+                    amount: Uint128::new(amount),
+                },
+            )
+            .unwrap();
+        };
+
+        // below the min stake, pending the manual approval of the service
+        set_min_stake(&mut deps, 601);
+        register_service_to_operator(
+            deps.as_mut(),
+            message_info(&operator, &[]),
+            env.clone(),
+            service.clone(),
+            None,
         )
         .unwrap();
-
-        let res = execute::register_service_to_operator(
+        let res = status(deps.as_ref(), operator.clone(), service.clone(), None).unwrap();
+        assert_eq!(res, RegistrationStatus::OperatorRegistered.into());
+        execute::deregister_service_from_operator(
             deps.as_mut(),
-            operator_info.clone(),
+            message_info(&operator, &[]),
             env.clone(),
             service.clone(),
-        );
-        assert_eq!(
-            res,
-            Err(ContractError::InvalidRegistrationStatus {
-                msg: "Operator has already registered this service".to_string(),
-            }),
-        );
+            None,
+        )
+        .unwrap();
+
+        set_min_stake(&mut deps, 600);
+        register_service_to_operator(
+            deps.as_mut(),
+            message_info(&operator, &[]),
+            env,
+            service.clone(),
+            None,
+        )
+        .unwrap();
+        let res = status(deps.as_ref(), operator, service, None).unwrap();
+        assert_eq!(res, RegistrationStatus::Active.into());
     }
 
     #[test]
-    fn test_register_service_already_registered() {
+    fn test_service_hooks() {
         let mut deps = mock_contract();
         let env = mock_env();
 
-        let operator = deps.api.addr_make("operator/3");
-        let service = deps.api.addr_make("service/3");
+        let service = deps.api.addr_make("service");
+        let operator = deps.api.addr_make("operator");
         let service_info = message_info(&service, &[]);
 
+        let err = execute::enable_service_hooks(deps.as_mut(), service_info.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Std(StdError::not_found("service")));
+
         SERVICES.save(&mut deps.storage, &service, &true).unwrap();
         OPERATORS.save(&mut deps.storage, &operator, &true).unwrap();
 
-        state::set_registration_status(
-            &mut deps.storage,
-            &env,
-            (&operator, &service),
-            RegistrationStatus::ServiceRegistered,
-        )
-        .unwrap();
+        let res = execute::enable_service_hooks(deps.as_mut(), service_info.clone()).unwrap();
+        assert_eq!(
+            res,
+            Response::new().add_event(
+                Event::new("ServiceHooksEnabled").add_attribute("service", service.to_string())
+            )
+        );
 
-        let res = execute::register_operator_to_service(
+        let res = query::service_hooks_enabled(deps.as_ref(), service.clone()).unwrap();
+        assert_eq!(res, ServiceHooksEnabledResponse(true));
+
+        let res = register_operator_to_service(
             deps.as_mut(),
             service_info.clone(),
             env.clone(),
             operator.clone(),
+        )
+        .unwrap();
+
+        let hook = RegistryHookMsg::RegistrationStatusUpdated {
+            operator: operator.to_string(),
+            status: RegistrationStatus::ServiceRegistered,
+        };
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::reply_on_error(
+                hook.into_cosmos_msg(&service).unwrap(),
+                SERVICE_HOOK_REPLY_ID
+            )
+            .with_gas_limit(SERVICE_HOOK_GAS_LIMIT)
+            .with_payload(service.as_bytes())]
         );
+
+        let res = execute::disable_service_hooks(deps.as_mut(), service_info.clone()).unwrap();
         assert_eq!(
             res,
-            Err(ContractError::InvalidRegistrationStatus {
-                msg: "Service has already registered this operator".to_string(),
-            }),
+            Response::new().add_event(
+                Event::new("ServiceHooksDisabled").add_attribute("service", service.to_string())
+            )
         );
+
+        let res = query::service_hooks_enabled(deps.as_ref(), service.clone()).unwrap();
+        assert_eq!(res, ServiceHooksEnabledResponse(false));
+
+        let operator_info = message_info(&operator, &[]);
+        let res =
+            register_service_to_operator(deps.as_mut(), operator_info, env, service, None).unwrap();
+        assert!(res.messages.is_empty());
     }
 
     #[test]
-    fn test_register_already_active() {
+    fn test_reply_service_hook_failed() {
         let mut deps = mock_contract();
         let env = mock_env();
 
-        let operator = deps.api.addr_make("operator/4");
-        let service = deps.api.addr_make("service/4");
-        let operator_info = message_info(&operator, &[]);
-        let service_info = message_info(&service, &[]);
-
-        // register service + operator
-        SERVICES.save(&mut deps.storage, &service, &true).unwrap();
-        OPERATORS.save(&mut deps.storage, &operator, &true).unwrap();
-
-        state::set_registration_status(
-            &mut deps.storage,
-            &env,
-            (&operator, &service),
-            RegistrationStatus::Active,
-        )
-        .unwrap();
+        let service = deps.api.addr_make("service");
 
-        let res = execute::register_service_to_operator(
+        let res = reply(
             deps.as_mut(),
-            operator_info.clone(),
             env.clone(),
-            service.clone(),
-        );
+            Reply {
+                id: SERVICE_HOOK_REPLY_ID,
+                payload: Binary::from(service.as_bytes()),
+                gas_used: 0,
+                result: SubMsgResult::Err("out of gas".to_string()),
+            },
+        )
+        .unwrap();
         assert_eq!(
             res,
-            Err(ContractError::InvalidRegistrationStatus {
-                msg: "Registration between operator and service is already active".to_string(),
-            }),
+            Response::new().add_event(
+                Event::new("ServiceHookFailed")
+                    .add_attribute("service", service.to_string())
+                    .add_attribute("error", "out of gas")
+            )
         );
 
-        let res = execute::register_operator_to_service(
+        let err = reply(
             deps.as_mut(),
-            service_info.clone(),
-            env.clone(),
-            operator.clone(),
-        );
+            env,
+            Reply {
+                id: 99,
+                payload: Binary::default(),
+                gas_used: 0,
+                result: SubMsgResult::Err("error".to_string()),
+            },
+        )
+        .unwrap_err();
         assert_eq!(
-            res,
-            Err(ContractError::InvalidRegistrationStatus {
-                msg: "Registration between operator and service is already active".to_string(),
-            }),
+            err.to_string(),
+            "Generic error: Unknown reply id: 99".to_string()
         );
     }
 
     #[test]
-    fn test_service_deregister_operator() {
+    fn test_set_operator_manager() {
         let mut deps = mock_contract();
-        let env = mock_env();
 
         let operator = deps.api.addr_make("operator");
-        let service = deps.api.addr_make("service");
-        let service_info = message_info(&service, &[]);
+        let manager = deps.api.addr_make("manager");
+        let operator_info = message_info(&operator, &[]);
 
-        SERVICES.save(&mut deps.storage, &service, &true).unwrap();
-        state::set_registration_status(
-            &mut deps.storage,
-            &env,
-            (&operator, &service),
-            RegistrationStatus::Active,
-        )
-        .unwrap();
-        state::increase_operator_active_registration_count(&mut deps.storage, &operator)
-            .expect("failed to increase operator active registration count");
+        OPERATORS.save(&mut deps.storage, &operator, &true).unwrap();
 
-        let res = execute::deregister_operator_from_service(
+        let err = execute::set_operator_manager(
             deps.as_mut(),
-            service_info.clone(),
-            env.clone(),
+            operator_info.clone(),
             operator.clone(),
+            vec![ManagerPermission::Registration],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidOperatorManager {
+                msg: "Operator cannot be its own manager".to_string(),
+            }
+        );
+
+        let err = execute::set_operator_manager(
+            deps.as_mut(),
+            operator_info.clone(),
+            manager.clone(),
+            vec![],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidOperatorManager {
+                msg: "Permissions must not be empty, revoke the manager instead".to_string(),
+            }
         );
+
+        // duplicate permissions are removed
+        let res = execute::set_operator_manager(
+            deps.as_mut(),
+            operator_info.clone(),
+            manager.clone(),
+            vec![
+                ManagerPermission::SlashingOptIn,
+                ManagerPermission::Metadata,
+                ManagerPermission::SlashingOptIn,
+            ],
+        )
+        .unwrap();
         assert_eq!(
             res,
-            Ok(Response::new().add_event(
-                Event::new("RegistrationStatusUpdated")
-                    .add_attribute("method", "deregister_operator_from_service")
+            Response::new().add_event(
+                Event::new("OperatorManagerSet")
                     .add_attribute("operator", operator.as_ref())
-                    .add_attribute("service", service.as_ref())
-                    .add_attribute("status", "Inactive")
-            )),
+                    .add_attribute("manager", manager.as_ref())
+                    .add_attribute("permissions", "SlashingOptIn,Metadata")
+            )
         );
 
-        let status = state::get_registration_status(&deps.storage, (&operator, &service)).unwrap();
-        assert_eq!(status, RegistrationStatus::Inactive);
+        assert!(state::is_operator_manager(
+            &deps.storage,
+            &operator,
+            &manager,
+            ManagerPermission::Metadata
+        )
+        .unwrap());
+        assert!(!state::is_operator_manager(
+            &deps.storage,
+            &operator,
+            &manager,
+            ManagerPermission::Registration
+        )
+        .unwrap());
+
+        execute::revoke_operator_manager(deps.as_mut(), operator_info.clone(), manager.clone())
+            .expect("revoke operator manager failed");
+
+        let err =
+            execute::revoke_operator_manager(deps.as_mut(), operator_info, manager).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidOperatorManager {
+                msg: "Manager is not a manager of this operator".to_string(),
+            }
+        );
     }
 
     #[test]
-    fn test_operator_deregister_service() {
+    fn test_manager_registration_without_slashing_opt_in() {
         let mut deps = mock_contract();
-        let env = mock_env();
+        let mut env = mock_env();
 
         let operator = deps.api.addr_make("operator");
+        let manager = deps.api.addr_make("manager");
         let service = deps.api.addr_make("service");
-        let operator_info = message_info(&operator, &[]);
+        let service2 = deps.api.addr_make("service2");
+        let manager_info = message_info(&manager, &[]);
 
-        SERVICES.save(&mut deps.storage, &service, &true).unwrap();
-        state::set_registration_status(
-            &mut deps.storage,
-            &env,
-            (&operator, &service),
-            RegistrationStatus::Active,
+        OPERATORS.save(&mut deps.storage, &operator, &true).unwrap();
+        for service in [&service, &service2] {
+            SERVICES.save(&mut deps.storage, service, &true).unwrap();
+            state::enable_slashing(
+                &mut deps.storage,
+                &deps.api,
+                &env,
+                service,
+                &SlashingParameters {
+                    destination: None,
+                    max_slashing_bips: 100,
+                    resolution_window: 1000,
+                    destinations: vec![],
+                    asset_ids: vec![],
+                },
+            )
+            .unwrap();
+            set_registration_status(
+                &mut deps.storage,
+                &env,
+                (&operator, service),
+                RegistrationStatus::ServiceRegistered,
+            )
+            .unwrap();
+        }
+
+        env.block.time = env.block.time.plus_seconds(10);
+
+        // a manager with only the Registration permission activates the registration
+        // without opting in the operator to slashing
+        execute::set_operator_manager(
+            deps.as_mut(),
+            message_info(&operator, &[]),
+            manager.clone(),
+            vec![ManagerPermission::Registration],
         )
         .unwrap();
-        state::increase_operator_active_registration_count(&mut deps.storage, &operator)
-            .expect("failed to increase operator active registration count");
-
-        let res = execute::deregister_service_from_operator(
+        register_service_to_operator(
             deps.as_mut(),
-            operator_info.clone(),
+            manager_info.clone(),
             env.clone(),
             service.clone(),
-        );
-        assert_eq!(
-            res,
-            Ok(Response::new().add_event(
-                Event::new("RegistrationStatusUpdated")
-                    .add_attribute("method", "deregister_service_from_operator")
-                    .add_attribute("operator", operator.as_ref())
-                    .add_attribute("service", service.as_ref())
-                    .add_attribute("status", "Inactive")
-            )),
-        );
+            Some(operator.clone()),
+        )
+        .expect("register service to operator failed");
+
+        let status = state::get_registration_status(&deps.storage, (&operator, &service)).unwrap();
+        assert_eq!(status, RegistrationStatus::Active);
+        let res = query::is_operator_opted_in_to_slashing(
+            deps.as_ref(),
+            service.clone(),
+            operator.clone(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(res, IsOperatorOptedInToSlashingResponse(false));
+
+        // a manager with the SlashingOptIn permission opts in the operator
+        execute::set_operator_manager(
+            deps.as_mut(),
+            message_info(&operator, &[]),
+            manager.clone(),
+            vec![
+                ManagerPermission::Registration,
+                ManagerPermission::SlashingOptIn,
+            ],
+        )
+        .unwrap();
+        register_service_to_operator(
+            deps.as_mut(),
+            manager_info,
+            env.clone(),
+            service2.clone(),
+            Some(operator.clone()),
+        )
+        .expect("register service to operator failed");
 
-        let status = state::get_registration_status(&deps.storage, (&operator, &service)).unwrap();
-        assert_eq!(status, RegistrationStatus::Inactive);
+        let res = query::is_operator_opted_in_to_slashing(deps.as_ref(), service2, operator, None)
+            .unwrap();
+        assert_eq!(res, IsOperatorOptedInToSlashingResponse(true));
     }
 
     #[test]
-    fn test_already_deregistered() {
+    fn test_operator_key_rotation() {
         let mut deps = mock_contract();
-        let env = mock_env();
+        let mut env = mock_env();
 
-        let operator = deps.api.addr_make("operator");
         let service = deps.api.addr_make("service");
+        let operator = deps.api.addr_make("operator");
         let operator_info = message_info(&operator, &[]);
-        let service_info = message_info(&service, &[]);
 
-        SERVICES.save(&mut deps.storage, &service, &true).unwrap();
-        state::set_registration_status(
-            &mut deps.storage,
-            &env,
-            (&operator, &service),
-            RegistrationStatus::Active,
-        )
-        .unwrap();
+        let account1 = Account::new("operator/key1");
+        let account2 = Account::new("operator/key2");
+        let key1 = HexBinary::from(account1.public_key.serialize());
+        let key2 = HexBinary::from(account2.public_key.serialize());
+        let sign = |account: &Account, operator: &Addr, key: &HexBinary| {
+            let message = state::operator_key_message(&service, operator, &KeyType::Secp256k1, key);
+            HexBinary::from(account.sign(message.to_vec()).serialize_compact())
+        };
+        let signature1 = sign(&account1, &operator, &key1);
 
-        // deregister for the first time - success
-        execute::deregister_service_from_operator(
+        // operator and service must be registered
+        let err = execute::set_operator_key(
             deps.as_mut(),
-            operator_info.clone(),
             env.clone(),
+            operator_info.clone(),
             service.clone(),
+            KeyType::Secp256k1,
+            key1.clone(),
+            signature1.clone(),
         )
-        .expect("failed to deregister service from operator");
+        .unwrap_err();
+        assert_eq!(err, ContractError::Std(StdError::not_found("operator")));
 
-        let res = execute::deregister_service_from_operator(
+        OPERATORS.save(&mut deps.storage, &operator, &true).unwrap();
+        SERVICES.save(&mut deps.storage, &service, &true).unwrap();
+
+        // invalid key length
+        let err = execute::set_operator_key(
             deps.as_mut(),
-            operator_info.clone(),
             env.clone(),
+            operator_info.clone(),
             service.clone(),
-        );
+            KeyType::Ed25519,
+            key1.clone(),
+            signature1.clone(),
+        )
+        .unwrap_err();
         assert_eq!(
-            res,
-            Err(ContractError::InvalidRegistrationStatus {
-                msg: "Service is not registered with this operator".to_string(),
-            }),
+            err,
+            ContractError::InvalidOperatorKey {
+                msg: "Invalid public key length for Ed25519".to_string(),
+            }
         );
 
-        let res = execute::deregister_operator_from_service(
+        // invalid secp256k1 prefix
+        for public_key in [
+            HexBinary::from(vec![4u8; 33]),
+            HexBinary::from(vec![2u8; 65]),
+        ] {
+            let err = execute::set_operator_key(
+                deps.as_mut(),
+                env.clone(),
+                operator_info.clone(),
+                service.clone(),
+                KeyType::Secp256k1,
+                public_key,
+                signature1.clone(),
+            )
+            .unwrap_err();
+            assert_eq!(
+                err,
+                ContractError::InvalidOperatorKey {
+                    msg: "Invalid public key prefix for Secp256k1".to_string(),
+                }
+            );
+        }
+
+        // proof of possession must be signed by the key for this operator
+        for signature in [
+            sign(&account2, &operator, &key1),
+            sign(&account1, &deps.api.addr_make("other"), &key1),
+            HexBinary::from(vec![0u8; 64]),
+        ] {
+            let err = execute::set_operator_key(
+                deps.as_mut(),
+                env.clone(),
+                operator_info.clone(),
+                service.clone(),
+                KeyType::Secp256k1,
+                key1.clone(),
+                signature,
+            )
+            .unwrap_err();
+            assert_eq!(
+                err,
+                ContractError::InvalidOperatorKey {
+                    msg: "Invalid proof of possession signature".to_string(),
+                }
+            );
+        }
+
+        let res = execute::set_operator_key(
             deps.as_mut(),
-            service_info.clone(),
             env.clone(),
-            operator.clone(),
-        );
+            operator_info.clone(),
+            service.clone(),
+            KeyType::Secp256k1,
+            key1.clone(),
+            signature1,
+        )
+        .unwrap();
         assert_eq!(
             res,
-            Err(ContractError::InvalidRegistrationStatus {
-                msg: "Operator is not registered with this service".to_string(),
-            }),
+            Response::new().add_event(
+                Event::new("OperatorKeySet")
+                    .add_attribute("operator", operator.as_ref())
+                    .add_attribute("service", service.as_ref())
+                    .add_attribute("key_type", "Secp256k1")
+                    .add_attribute("public_key", key1.to_hex())
+            )
         );
-    }
+        let set_timestamp = env.block.time.seconds();
+
+        // ed25519 key
+        let signing_key = ed25519_zebra::SigningKey::from([7u8; 32]);
+        let ed25519_key =
+            HexBinary::from(ed25519_zebra::VerificationKeyBytes::from(&signing_key).as_ref());
+        let message =
+            state::operator_key_message(&service, &operator, &KeyType::Ed25519, &ed25519_key);
+        execute::set_operator_key(
+            deps.as_mut(),
+            env.clone(),
+            operator_info.clone(),
+            service.clone(),
+            KeyType::Ed25519,
+            ed25519_key.clone(),
+            HexBinary::from(signing_key.sign(&message).to_bytes()),
+        )
+        .expect("set ed25519 operator key failed");
 
-    #[test]
-    fn test_enable_slashing() {
-        let mut deps = mock_contract();
-        let env = mock_env();
+        let res = query::operator_key(
+            deps.as_ref(),
+            service.clone(),
+            operator.clone(),
+            KeyType::Ed25519,
+            None,
+        )
+        .unwrap();
+        assert_eq!(res, OperatorKeyResponse(Some(ed25519_key.clone())));
 
-        let service = deps.api.addr_make("service");
-        let destination = deps.api.addr_make("destination");
-        let service_info = message_info(&service, &[]);
+        env.block.time = env.block.time.plus_seconds(10);
 
-        // register service
-        execute::register_as_service(
+        // rotate key
+        execute::set_operator_key(
             deps.as_mut(),
-            service_info.clone(),
-            Metadata {
-                uri: None,
-                name: None,
-            },
+            env.clone(),
+            operator_info.clone(),
+            service.clone(),
+            KeyType::Secp256k1,
+            key2.clone(),
+            sign(&account2, &operator, &key2),
         )
-        .expect("register service failed");
+        .expect("rotate operator key failed");
 
-        // enable slashing
-        let res = execute::enable_slashing(
-            deps.as_mut(),
-            env,
-            service_info.clone(),
-            SlashingParameters {
-                destination: Some(destination.clone()),
-                max_slashing_bips: 10,
-                resolution_window: 1000,
-            },
-        );
+        let res = query::operator_key(
+            deps.as_ref(),
+            service.clone(),
+            operator.clone(),
+            KeyType::Secp256k1,
+            None,
+        )
+        .unwrap();
+        assert_eq!(res, OperatorKeyResponse(Some(key2.clone())));
 
-        // assert SLASHING_PARAMETERS state is updated
-        let slashing_parameters = SLASHING_PARAMETERS
-            .load(&deps.storage, &service)
-            .expect("failed to load slashing parameters");
-        assert_eq!(
-            slashing_parameters,
-            SlashingParameters {
-                destination: Some(destination.clone()),
-                max_slashing_bips: 10,
-                resolution_window: 1000,
-            }
-        );
+        // key before it was set
+        let res = query::operator_key(
+            deps.as_ref(),
+            service.clone(),
+            operator.clone(),
+            KeyType::Secp256k1,
+            Some(set_timestamp),
+        )
+        .unwrap();
+        assert_eq!(res, OperatorKeyResponse(None));
+
+        // key valid before rotation
+        let res = query::operator_key(
+            deps.as_ref(),
+            service.clone(),
+            operator.clone(),
+            KeyType::Secp256k1,
+            Some(env.block.time.seconds()),
+        )
+        .unwrap();
+        assert_eq!(res, OperatorKeyResponse(Some(key1)));
+
+        // other key types are independent
+        let res = query::operator_key(
+            deps.as_ref(),
+            service.clone(),
+            operator.clone(),
+            KeyType::Ed25519,
+            None,
+        )
+        .unwrap();
+        assert_eq!(res, OperatorKeyResponse(Some(ed25519_key)));
+
+        env.block.time = env.block.time.plus_seconds(10);
 
+        let res = execute::remove_operator_key(
+            deps.as_mut(),
+            env.clone(),
+            operator_info.clone(),
+            service.clone(),
+            KeyType::Secp256k1,
+        )
+        .unwrap();
         assert_eq!(
             res,
-            Ok(Response::new().add_event(
-                Event::new("SlashingParametersEnabled")
+            Response::new().add_event(
+                Event::new("OperatorKeyRemoved")
+                    .add_attribute("operator", operator.as_ref())
                     .add_attribute("service", service.as_ref())
-                    .add_attribute("destination", destination.to_string())
-                    .add_attribute("max_slashing_bips", "10")
-                    .add_attribute("resolution_window", "1000")
-            ))
+                    .add_attribute("key_type", "Secp256k1")
+            )
+        );
+
+        let res = query::operator_key(
+            deps.as_ref(),
+            service.clone(),
+            operator.clone(),
+            KeyType::Secp256k1,
+            Some(env.block.time.seconds()),
+        )
+        .unwrap();
+        assert_eq!(res, OperatorKeyResponse(Some(key2)));
+
+        let err = execute::remove_operator_key(
+            deps.as_mut(),
+            env.clone(),
+            operator_info,
+            service.clone(),
+            KeyType::Secp256k1,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidOperatorKey {
+                msg: "Operator key is not set for this service".to_string(),
+            }
         );
     }
 
     #[test]
-    fn test_re_enable_slashing() {
+    fn test_history_queries() {
         let mut deps = mock_contract();
-        let env = mock_env();
+        let mut env = mock_env();
 
         let service = deps.api.addr_make("service");
-        let destination = deps.api.addr_make("destination");
-        let service_info = message_info(&service, &[]);
+        let operator = deps.api.addr_make("operator");
 
-        // register service
-        execute::register_as_service(
-            deps.as_mut(),
-            service_info.clone(),
-            Metadata {
-                uri: None,
-                name: None,
-            },
+        let t0 = env.block.time.seconds();
+        set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service),
+            RegistrationStatus::ServiceRegistered,
         )
-        .expect("register service failed");
+        .unwrap();
+        let slashing_parameters = SlashingParameters {
+            destination: None,
+            max_slashing_bips: 100,
+            resolution_window: 1000,
+            destinations: vec![],
+            asset_ids: vec![],
+        };
+        state::enable_slashing(
+            &mut deps.storage,
+            &deps.api,
+            &env,
+            &service,
+            &slashing_parameters,
+        )
+        .unwrap();
 
-        // enable slashing for the first time
-        execute::enable_slashing(
-            deps.as_mut(),
-            env.clone(),
-            service_info.clone(),
-            SlashingParameters {
-                destination: Some(destination.clone()),
-                max_slashing_bips: 1000,
-                resolution_window: 1000,
-            },
+        env.block.time = env.block.time.plus_seconds(10);
+        let t1 = env.block.time.seconds();
+        set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service),
+            RegistrationStatus::Active,
         )
-        .expect("enable slashing failed");
+        .unwrap();
+        state::opt_in_to_slashing(&mut deps.storage, &env, &service, &operator).unwrap();
 
-        // operators opt-in to slashing
-        for i in 0..3 {
-            let operator = deps.api.addr_make(format!("operator{i}").as_str());
-            SLASHING_OPT_IN
-                .save(
-                    &mut deps.storage,
-                    (&service, &operator),
-                    &true,
-                    env.block.time.seconds(),
-                )
-                .expect("failed to save slashing opt-in");
-        }
+        env.block.time = env.block.time.plus_seconds(10);
+        let t2 = env.block.time.seconds();
+        set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service),
+            RegistrationStatus::Exiting,
+        )
+        .unwrap();
+        state::reset_slashing_opt_in(&mut deps.storage, &env, &service).unwrap();
+        state::disable_slashing(&mut deps.storage, &env, &service).unwrap();
+
+        let res = query::registration_history(
+            deps.as_ref(),
+            service.clone(),
+            operator.clone(),
+            100,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            RegistrationHistoryResponse(vec![
+                RegistrationStatusChange {
+                    timestamp: t0,
+                    status: RegistrationStatus::ServiceRegistered.into(),
+                },
+                RegistrationStatusChange {
+                    timestamp: t1,
+                    status: RegistrationStatus::Active.into(),
+                },
+                RegistrationStatusChange {
+                    timestamp: t2,
+                    status: RegistrationStatus::Exiting.into(),
+                },
+            ])
+        );
 
-        // re-enable slashing
-        execute::enable_slashing(
-            deps.as_mut(),
-            env.clone(),
-            service_info.clone(),
-            SlashingParameters {
-                destination: Some(destination.clone()),
-                max_slashing_bips: 9999,
-                resolution_window: 2000,
-            },
+        // paginate
+        let res =
+            query::registration_history(deps.as_ref(), service.clone(), operator.clone(), 1, None)
+                .unwrap();
+        assert_eq!(
+            res,
+            RegistrationHistoryResponse(vec![RegistrationStatusChange {
+                timestamp: t0,
+                status: RegistrationStatus::ServiceRegistered.into(),
+            }])
+        );
+        let res = query::registration_history(
+            deps.as_ref(),
+            service.clone(),
+            operator.clone(),
+            1,
+            Some(t0),
         )
         .unwrap();
+        assert_eq!(
+            res,
+            RegistrationHistoryResponse(vec![RegistrationStatusChange {
+                timestamp: t1,
+                status: RegistrationStatus::Active.into(),
+            }])
+        );
 
-        // assert that SLASHING_PARAMETERS state is updated
-        let slashing_parameters = SLASHING_PARAMETERS
-            .load(&deps.storage, &service)
-            .expect("failed to load slashing parameters");
+        let res = query::slashing_opt_in_history(
+            deps.as_ref(),
+            service.clone(),
+            operator.clone(),
+            100,
+            None,
+        )
+        .unwrap();
         assert_eq!(
-            slashing_parameters,
-            SlashingParameters {
-                destination: Some(destination.clone()),
-                max_slashing_bips: 9999,
-                resolution_window: 2000,
-            }
+            res,
+            SlashingOptInHistoryResponse(vec![
+                SlashingOptInChange {
+                    timestamp: t1,
+                    opted_in: true,
+                },
+                SlashingOptInChange {
+                    timestamp: t2,
+                    opted_in: false,
+                },
+            ])
         );
 
-        // assert that the opt-in mapping is cleared
-        for i in 0..3 {
-            let operator = deps.api.addr_make(format!("operator{i}").as_str());
-            let opt_in = SLASHING_OPT_IN
-                .may_load(&deps.storage, (&service, &operator))
-                .unwrap();
-            assert!(opt_in.is_none());
-        }
+        let res =
+            query::slashing_parameters_history(deps.as_ref(), service.clone(), 100, None).unwrap();
+        assert_eq!(
+            res,
+            SlashingParametersHistoryResponse(vec![
+                SlashingParametersChange {
+                    timestamp: t0,
+                    slashing_parameters: Some(slashing_parameters),
+                },
+                SlashingParametersChange {
+                    timestamp: t2,
+                    slashing_parameters: None,
+                },
+            ])
+        );
     }
 
     #[test]
@@ -1448,6 +6249,8 @@ mod tests {
                 destination: Some(destination.clone()),
                 max_slashing_bips: 10,
                 resolution_window: 1000,
+                destinations: vec![],
+                asset_ids: vec![],
             },
         );
 
@@ -1472,6 +6275,7 @@ mod tests {
                 env.clone(),
                 operator_info.clone(),
                 service.clone(),
+                None,
             )
             .unwrap_err();
             assert_eq!(
@@ -1516,6 +6320,7 @@ mod tests {
                 env.clone(),
                 operator_info.clone(),
                 service.clone(),
+                None,
             )
             .unwrap_err();
             assert_eq!(
@@ -1535,6 +6340,8 @@ mod tests {
                 destination: Some(operator.clone()),
                 max_slashing_bips: 1000,
                 resolution_window: 1000,
+                destinations: vec![],
+                asset_ids: vec![],
             },
         )
         .expect("enable slashing failed");
@@ -1548,6 +6355,7 @@ mod tests {
             env.clone(),
             operator_info.clone(),
             service.clone(),
+            None,
         );
 
         // assert events
@@ -1580,6 +6388,7 @@ mod tests {
                 env.clone(),
                 message_info(&operator3, &[]),
                 service.clone(),
+                None,
             )
             .unwrap_err();
             assert_eq!(
@@ -1590,8 +6399,8 @@ mod tests {
             );
         }
 
-        // service re-enable slashing
-        enable_slashing(
+        // service updates to stricter slashing parameters
+        execute::propose_slashing_parameters(
             deps.as_mut(),
             env.clone(),
             message_info(&service, &[]),
@@ -1599,9 +6408,18 @@ mod tests {
                 destination: Some(operator.clone()),
                 max_slashing_bips: 5000,
                 resolution_window: 1000,
+                destinations: vec![],
+                asset_ids: vec![],
             },
         )
-        .expect("enable slashing failed");
+        .expect("propose slashing parameters failed");
+
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(state::DEFAULT_SLASHING_PARAMETERS_NOTICE_PERIOD);
+        execute::activate_slashing_parameters(deps.as_mut(), env.clone(), service.clone())
+            .expect("activate slashing parameters failed");
 
         // assert that the opt-in mapping is cleared
         let opted_in = SLASHING_OPT_IN
@@ -1615,6 +6433,7 @@ mod tests {
             env.clone(),
             message_info(&operator2, &[]),
             service.clone(),
+            None,
         )
         .expect("operator2 opt-in to slashing failed");
         let opted_in = SLASHING_OPT_IN
@@ -1843,6 +6662,7 @@ mod tests {
             message_info(&operator, &[]),
             env.clone(),
             service.clone(),
+            None,
         )
         .expect("register service to operator failed");
 
@@ -1864,6 +6684,7 @@ mod tests {
         // register service
         execute::register_as_service(
             deps.as_mut(),
+            env.clone(),
             service_info.clone(),
             Metadata {
                 uri: None,
@@ -1881,6 +6702,8 @@ mod tests {
                 destination: Some(destination.clone()),
                 max_slashing_bips: 1000,
                 resolution_window: 1000,
+                destinations: vec![],
+                asset_ids: vec![],
             },
         )
         .expect("enable slashing failed");
@@ -1894,6 +6717,8 @@ mod tests {
                 destination: Some(destination.clone()),
                 max_slashing_bips: 1000,
                 resolution_window: 1000,
+                destinations: vec![],
+                asset_ids: vec![],
             })
         );
 
@@ -1901,7 +6726,7 @@ mod tests {
         env.block.time = env.block.time.plus_seconds(1);
 
         // update slashing parameters
-        execute::enable_slashing(
+        execute::propose_slashing_parameters(
             deps.as_mut(),
             env.clone(),
             service_info.clone(),
@@ -1909,9 +6734,20 @@ mod tests {
                 destination: None,
                 max_slashing_bips: 5000,
                 resolution_window: 999,
+                destinations: vec![],
+                asset_ids: vec![],
             },
         )
-        .expect("update slashing failed");
+        .expect("propose slashing parameters failed");
+
+        // move blockchain past the notice period
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(state::DEFAULT_SLASHING_PARAMETERS_NOTICE_PERIOD);
+
+        execute::activate_slashing_parameters(deps.as_mut(), env.clone(), service.clone())
+            .expect("activate slashing parameters failed");
 
         // move blockchain
         env.block.time = env.block.time.plus_seconds(1);
@@ -1925,6 +6761,8 @@ mod tests {
                 destination: None,
                 max_slashing_bips: 5000,
                 resolution_window: 999,
+                destinations: vec![],
+                asset_ids: vec![],
             })
         );
 
@@ -1941,6 +6779,8 @@ mod tests {
                 destination: Some(destination.clone()),
                 max_slashing_bips: 1000,
                 resolution_window: 1000,
+                destinations: vec![],
+                asset_ids: vec![],
             })
         );
 
@@ -1969,6 +6809,8 @@ mod tests {
                 destination: None,
                 max_slashing_bips: 5000,
                 resolution_window: 999,
+                destinations: vec![],
+                asset_ids: vec![],
             })
         );
     }
@@ -2041,4 +6883,393 @@ mod tests {
             IsOperatorOptedInToSlashingResponse(false)
         );
     }
+
+    #[test]
+    fn query_list_operators_by_service() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+
+        let service = deps.api.addr_make("service");
+        let operators = (0..3)
+            .map(|i| deps.api.addr_make(format!("operator{i}").as_str()))
+            .collect::<Vec<_>>();
+
+        set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operators[0], &service),
+            RegistrationStatus::Active,
+        )
+        .unwrap();
+        set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operators[1], &service),
+            RegistrationStatus::ServiceRegistered,
+        )
+        .unwrap();
+        set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operators[2], &service),
+            RegistrationStatus::Active,
+        )
+        .unwrap();
+
+        // move blockchain
+        env.block.time = env.block.time.plus_seconds(10);
+
+        // operator2 deregisters
+        set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operators[2], &service),
+            RegistrationStatus::Inactive,
+        )
+        .unwrap();
+
+        // list without filter excludes inactive registration
+        let RegistrationListResponse(registrations) =
+            query::list_operators_by_service(deps.as_ref(), service.clone(), None, None, 100, None)
+                .unwrap();
+        let mut listed = registrations
+            .iter()
+            .map(|r| (r.operator.clone(), r.status))
+            .collect::<Vec<_>>();
+        listed.sort();
+        let mut expected = vec![(operators[0].clone(), 1), (operators[1].clone(), 3)];
+        expected.sort();
+        assert_eq!(listed, expected);
+
+        // list with Active filter
+        let RegistrationListResponse(registrations) = query::list_operators_by_service(
+            deps.as_ref(),
+            service.clone(),
+            Some(RegistrationStatus::Active),
+            None,
+            100,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            registrations,
+            vec![Registration {
+                service: service.clone(),
+                operator: operators[0].clone(),
+                status: 1,
+            }]
+        );
+
+        // list with Active filter at previous timestamp includes operator2
+        let RegistrationListResponse(registrations) = query::list_operators_by_service(
+            deps.as_ref(),
+            service.clone(),
+            Some(RegistrationStatus::Active),
+            Some(env.block.time.minus_seconds(5).seconds()),
+            100,
+            None,
+        )
+        .unwrap();
+        assert_eq!(registrations.len(), 2);
+
+        // paginate one by one
+        let RegistrationListResponse(page1) =
+            query::list_operators_by_service(deps.as_ref(), service.clone(), None, None, 1, None)
+                .unwrap();
+        assert_eq!(page1.len(), 1);
+        let RegistrationListResponse(page2) = query::list_operators_by_service(
+            deps.as_ref(),
+            service.clone(),
+            None,
+            None,
+            1,
+            Some(page1[0].operator.clone()),
+        )
+        .unwrap();
+        assert_eq!(page2.len(), 1);
+        assert_ne!(page1[0].operator, page2[0].operator);
+
+        // list services by operator
+        let RegistrationListResponse(registrations) = query::list_services_by_operator(
+            deps.as_ref(),
+            operators[1].clone(),
+            None,
+            None,
+            100,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            registrations,
+            vec![Registration {
+                service: service.clone(),
+                operator: operators[1].clone(),
+                status: 3,
+            }]
+        );
+    }
+
+    #[test]
+    fn query_list_services_and_operators() {
+        let mut deps = mock_dependencies();
+
+        let services = (0..3)
+            .map(|i| deps.api.addr_make(format!("service{i}").as_str()))
+            .collect::<Vec<_>>();
+        for service in services.iter() {
+            SERVICES.save(&mut deps.storage, service, &true).unwrap();
+        }
+        let operator = deps.api.addr_make("operator");
+        OPERATORS.save(&mut deps.storage, &operator, &true).unwrap();
+
+        let ServiceListResponse(listed) = query::list_services(deps.as_ref(), 2, None).unwrap();
+        assert_eq!(listed.len(), 2);
+
+        let ServiceListResponse(rest) =
+            query::list_services(deps.as_ref(), 100, Some(listed[1].clone())).unwrap();
+        assert_eq!(rest.len(), 1);
+
+        let mut all = [listed, rest].concat();
+        all.sort();
+        let mut expected = services.clone();
+        expected.sort();
+        assert_eq!(all, expected);
+
+        let OperatorListResponse(listed) = query::list_operators(deps.as_ref(), 100, None).unwrap();
+        assert_eq!(listed, vec![operator.clone()]);
+
+        // retired services and operators are not listed
+        SERVICES
+            .save(&mut deps.storage, &services[0], &false)
+            .unwrap();
+        OPERATORS
+            .save(&mut deps.storage, &operator, &false)
+            .unwrap();
+
+        let ServiceListResponse(mut listed) =
+            query::list_services(deps.as_ref(), 100, None).unwrap();
+        listed.sort();
+        let mut expected = services[1..].to_vec();
+        expected.sort();
+        assert_eq!(listed, expected);
+
+        let OperatorListResponse(listed) = query::list_operators(deps.as_ref(), 100, None).unwrap();
+        assert!(listed.is_empty());
+    }
+
+    #[test]
+    fn test_set_max_active_relationships() {
+        let mut deps = mock_contract();
+
+        let owner = deps.api.addr_make("owner");
+        let operator = deps.api.addr_make("operator");
+        let service = deps.api.addr_make("service");
+
+        // only owner
+        let err = execute::set_max_active_relationships(
+            deps.as_mut(),
+            message_info(&operator, &[]),
+            10,
+            10,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Ownership(bvs_library::ownership::OwnershipError::Unauthorized)
+        );
+
+        // zero is rejected
+        let err =
+            execute::set_max_active_relationships(deps.as_mut(), message_info(&owner, &[]), 0, 10)
+                .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidMaxActiveRelationships {
+                msg: "Max active relationships must be greater than zero".to_string()
+            }
+        );
+
+        let res =
+            execute::set_max_active_relationships(deps.as_mut(), message_info(&owner, &[]), 5, 50)
+                .unwrap();
+        assert_eq!(
+            res,
+            Response::new().add_event(
+                Event::new("SetMaxActiveRelationships")
+                    .add_attribute("per_operator", "5")
+                    .add_attribute("per_service", "50")
+            )
+        );
+
+        let res =
+            query::max_active_relationships_for_operator(deps.as_ref(), operator.clone()).unwrap();
+        assert_eq!(res, MaxActiveRelationshipsResponse(5));
+        let res =
+            query::max_active_relationships_for_service(deps.as_ref(), service.clone()).unwrap();
+        assert_eq!(res, MaxActiveRelationshipsResponse(50));
+
+        // override per operator and service
+        execute::set_max_active_relationships_for_operator(
+            deps.as_mut(),
+            message_info(&owner, &[]),
+            operator.clone(),
+            Some(7),
+        )
+        .unwrap();
+        execute::set_max_active_relationships_for_service(
+            deps.as_mut(),
+            message_info(&owner, &[]),
+            service.clone(),
+            Some(70),
+        )
+        .unwrap();
+
+        let res =
+            query::max_active_relationships_for_operator(deps.as_ref(), operator.clone()).unwrap();
+        assert_eq!(res, MaxActiveRelationshipsResponse(7));
+        let res =
+            query::max_active_relationships_for_service(deps.as_ref(), service.clone()).unwrap();
+        assert_eq!(res, MaxActiveRelationshipsResponse(70));
+
+        // remove the operator override
+        let res = execute::set_max_active_relationships_for_operator(
+            deps.as_mut(),
+            message_info(&owner, &[]),
+            operator.clone(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            Response::new().add_event(
+                Event::new("SetMaxActiveRelationshipsForOperator")
+                    .add_attribute("operator", operator.as_str())
+                    .add_attribute("max", "")
+            )
+        );
+
+        let res = query::max_active_relationships_for_operator(deps.as_ref(), operator).unwrap();
+        assert_eq!(res, MaxActiveRelationshipsResponse(5));
+    }
+
+    #[test]
+    fn test_migrate_service_active_registration_count() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let service = deps.api.addr_make("service");
+        let operator = deps.api.addr_make("operator");
+        let operator2 = deps.api.addr_make("operator2");
+        let operator3 = deps.api.addr_make("operator3");
+
+        for (operator, status) in [
+            (&operator, RegistrationStatus::Active),
+            (&operator2, RegistrationStatus::Active),
+            (&operator3, RegistrationStatus::ServiceRegistered),
+        ] {
+            REGISTRATION_STATUS
+                .save(
+                    &mut deps.storage,
+                    (operator, &service),
+                    &status.into(),
+                    env.block.time.seconds(),
+                )
+                .unwrap();
+        }
+
+        migrate::service_active_registration_count(deps.as_mut()).unwrap();
+
+        let res = query::active_operator_count(deps.as_ref(), service).unwrap();
+        assert_eq!(res, ActiveCountResponse(2));
+    }
+
+    #[test]
+    fn test_migrate_grandfather_max_active_relationships() {
+        let mut deps = mock_dependencies();
+
+        let service = deps.api.addr_make("service");
+        let service2 = deps.api.addr_make("service2");
+        let operator = deps.api.addr_make("operator");
+        let operator2 = deps.api.addr_make("operator2");
+
+        let over_operator_max = state::DEFAULT_MAX_ACTIVE_RELATIONSHIPS_PER_OPERATOR + 5;
+        let over_service_max = state::DEFAULT_MAX_ACTIVE_RELATIONSHIPS_PER_SERVICE + 1;
+        state::OPERATOR_ACTIVE_REGISTRATION_COUNT
+            .save(&mut deps.storage, &operator, &over_operator_max)
+            .unwrap();
+        state::OPERATOR_ACTIVE_REGISTRATION_COUNT
+            .save(&mut deps.storage, &operator2, &1)
+            .unwrap();
+        state::SERVICE_ACTIVE_REGISTRATION_COUNT
+            .save(&mut deps.storage, &service, &over_service_max)
+            .unwrap();
+        state::SERVICE_ACTIVE_REGISTRATION_COUNT
+            .save(&mut deps.storage, &service2, &1)
+            .unwrap();
+
+        let events = migrate::grandfather_max_active_relationships(deps.as_mut()).unwrap();
+        assert_eq!(
+            events,
+            vec![
+                Event::new("MaxActiveRelationshipsGrandfathered")
+                    .add_attribute("operator", operator.as_ref())
+                    .add_attribute("max", over_operator_max.to_string()),
+                Event::new("MaxActiveRelationshipsGrandfathered")
+                    .add_attribute("service", service.as_ref())
+                    .add_attribute("max", over_service_max.to_string()),
+            ]
+        );
+
+        let res = query::max_active_relationships_for_operator(deps.as_ref(), operator).unwrap();
+        assert_eq!(res, MaxActiveRelationshipsResponse(over_operator_max));
+        let res = query::max_active_relationships_for_operator(deps.as_ref(), operator2).unwrap();
+        assert_eq!(
+            res,
+            MaxActiveRelationshipsResponse(state::DEFAULT_MAX_ACTIVE_RELATIONSHIPS_PER_OPERATOR)
+        );
+        let res = query::max_active_relationships_for_service(deps.as_ref(), service).unwrap();
+        assert_eq!(res, MaxActiveRelationshipsResponse(over_service_max));
+        let res = query::max_active_relationships_for_service(deps.as_ref(), service2).unwrap();
+        assert_eq!(
+            res,
+            MaxActiveRelationshipsResponse(state::DEFAULT_MAX_ACTIVE_RELATIONSHIPS_PER_SERVICE)
+        );
+
+        // idempotent, the overrides are not above the max anymore
+        let events = migrate::grandfather_max_active_relationships(deps.as_mut()).unwrap();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_registration_status_to_index_service() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let service = deps.api.addr_make("service");
+        let operator = deps.api.addr_make("operator");
+
+        // populate REGISTRATION_STATUS without the index
+        REGISTRATION_STATUS
+            .save(
+                &mut deps.storage,
+                (&operator, &service),
+                &RegistrationStatus::Active.into(),
+                env.block.time.seconds(),
+            )
+            .unwrap();
+
+        migrate::registration_status_to_index_service(deps.as_mut()).unwrap();
+
+        let RegistrationListResponse(registrations) =
+            query::list_operators_by_service(deps.as_ref(), service.clone(), None, None, 100, None)
+                .unwrap();
+        assert_eq!(
+            registrations,
+            vec![Registration {
+                service,
+                operator,
+                status: 1,
+            }]
+        );
+    }
 }
@@ -24,9 +24,39 @@ pub enum ContractError {
     #[error("Invalid registration status: {msg}")]
     InvalidRegistrationStatus { msg: String },
 
+    #[error("Retired: {msg}")]
+    Retired { msg: String },
+
+    #[error("Invalid batch: {msg}")]
+    InvalidBatch { msg: String },
+
+    #[error("Invalid operator manager: {msg}")]
+    InvalidOperatorManager { msg: String },
+
+    #[error("Invalid metadata: {msg}")]
+    InvalidMetadata { msg: String },
+
     #[error("Invalid slashing parameters: {msg}")]
     InvalidSlashingParameters { msg: String },
 
     #[error("Invalid slashing opt-in: {msg}")]
     InvalidSlashingOptIn { msg: String },
+
+    #[error("Invalid slashing category: {msg}")]
+    InvalidSlashingCategory { msg: String },
+
+    #[error("Invalid admission policy: {msg}")]
+    InvalidAdmissionPolicy { msg: String },
+
+    #[error("Invalid commission: {msg}")]
+    InvalidCommission { msg: String },
+
+    #[error("Invalid operator key: {msg}")]
+    InvalidOperatorKey { msg: String },
+
+    #[error("Max active relationships exceeded: {msg}")]
+    MaxActiveRelationshipsExceeded { msg: String },
+
+    #[error("Invalid max active relationships: {msg}")]
+    InvalidMaxActiveRelationships { msg: String },
 }
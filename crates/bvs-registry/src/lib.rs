@@ -7,5 +7,14 @@ mod error;
 mod state;
 
 pub use crate::error::ContractError;
+pub use crate::state::operator_key_message;
+pub use crate::state::AdmissionPolicy;
+pub use crate::state::KeyType;
+pub use crate::state::ManagerPermission;
+pub use crate::state::PendingCommission;
+pub use crate::state::PendingSlashingParameters;
 pub use crate::state::RegistrationStatus;
 pub use crate::state::SlashingParameters;
+pub use crate::state::SlashingRecipient;
+pub use crate::state::Sunset;
+pub use crate::state::WeightedDestination;
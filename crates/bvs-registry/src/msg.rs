@@ -1,5 +1,9 @@
-use crate::state::{RegistrationStatus, SlashingParameters};
+use crate::state::{
+    AdmissionPolicy, KeyType, ManagerPermission, PendingCommission, PendingSlashingParameters,
+    RegistrationStatus, SlashingParameters, Sunset,
+};
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, HexBinary, StdResult, WasmMsg};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -18,32 +22,328 @@ pub enum ExecuteMsg {
         metadata: Metadata,
     },
     UpdateOperatorMetadata(Metadata),
+    /// ExecuteMsg UpdateOperatorMetadataAsManager updates the metadata of the `operator`,
+    /// info.sender must be a manager of the operator with [`ManagerPermission::Metadata`].
+    UpdateOperatorMetadataAsManager {
+        operator: String,
+        metadata: Metadata,
+    },
     RegisterOperatorToService {
         operator: String,
     },
     DeregisterOperatorFromService {
         operator: String,
     },
+    /// ExecuteMsg RegisterServiceToOperator registers the service to the operator.
+    /// The `operator` defaults to info.sender,
+    /// else info.sender must be a manager of the operator with [`ManagerPermission::Registration`].
+    /// A manager without [`ManagerPermission::SlashingOptIn`] activating the registration
+    /// doesn't opt in the operator to the slashing of the service.
     RegisterServiceToOperator {
         service: String,
+        operator: Option<String>,
     },
+    /// ExecuteMsg DeregisterServiceFromOperator deregisters the service from the operator.
+    /// The `operator` defaults to info.sender,
+    /// else info.sender must be a manager of the operator with [`ManagerPermission::Registration`].
     DeregisterServiceFromOperator {
         service: String,
+        operator: Option<String>,
+    },
+    /// ExecuteMsg RegisterOperatorsToService registers multiple operators to the service (info.sender)
+    /// atomically, as if [`ExecuteMsg::RegisterOperatorToService`] was sent for each operator.
+    /// The batch size is bounded by [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+    RegisterOperatorsToService {
+        operators: Vec<String>,
+    },
+    /// ExecuteMsg DeregisterOperatorsFromService deregisters multiple operators from the service (info.sender)
+    /// atomically, as if [`ExecuteMsg::DeregisterOperatorFromService`] was sent for each operator.
+    /// The batch size is bounded by [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+    DeregisterOperatorsFromService {
+        operators: Vec<String>,
+    },
+    /// ExecuteMsg RegisterServicesToOperator registers multiple services to the operator
+    /// atomically, as if [`ExecuteMsg::RegisterServiceToOperator`] was sent for each service.
+    /// The batch size is bounded by [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+    RegisterServicesToOperator {
+        services: Vec<String>,
+        operator: Option<String>,
+    },
+    /// ExecuteMsg DeregisterServicesFromOperator deregisters multiple services from the operator
+    /// atomically, as if [`ExecuteMsg::DeregisterServiceFromOperator`] was sent for each service.
+    /// The batch size is bounded by [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+    DeregisterServicesFromOperator {
+        services: Vec<String>,
+        operator: Option<String>,
+    },
+    /// ExecuteMsg FinalizeDeregistration finalizes an [`RegistrationStatus::Exiting`] registration
+    /// after the deregistration delay has passed, setting it to [`RegistrationStatus::Inactive`].
+    /// Can be called by either the operator, a manager of the operator
+    /// with [`ManagerPermission::Registration`], or the service.
+    FinalizeDeregistration {
+        service: String,
+        operator: String,
+    },
+    /// ExecuteMsg DeregisterAsService retires the service (info.sender)
+    /// and deregisters up to `limit` of its relationships, must be called until the sunset is completed.
+    /// On the first call, the service is retired and its slashing (including categories) is disabled.
+    /// Active registrations become [`RegistrationStatus::Exiting`], pending registrations become
+    /// [`RegistrationStatus::Inactive`].
+    /// The `limit` must be greater than zero, the max `limit` is [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+    DeregisterAsService {
+        limit: Option<u32>,
     },
+    /// ExecuteMsg DeregisterAsOperator retires the operator (info.sender)
+    /// and deregisters up to `limit` of its relationships, must be called until the sunset is completed.
+    /// Active registrations become [`RegistrationStatus::Exiting`], pending registrations become
+    /// [`RegistrationStatus::Inactive`].
+    /// The `limit` must be greater than zero, the max `limit` is [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+    DeregisterAsOperator {
+        limit: Option<u32>,
+    },
+    /// ExecuteMsg EnableSlashing enables slashing for the service (info.sender) immediately.
+    /// Can only be called when slashing is not enabled,
+    /// use [`ExecuteMsg::ProposeSlashingParameters`] to update enabled slashing parameters.
     EnableSlashing {
         slashing_parameters: SlashingParameters,
     },
     DisableSlashing {},
+    /// ExecuteMsg ProposeSlashingParameters proposes new slashing parameters for the service (info.sender),
+    /// replacing any pending slashing parameters.
+    /// The new slashing parameters can be activated with [`ExecuteMsg::ActivateSlashingParameters`]
+    /// after the slashing parameters notice period.
+    ProposeSlashingParameters {
+        slashing_parameters: SlashingParameters,
+    },
+    /// ExecuteMsg ActivateSlashingParameters activates the pending slashing parameters of the service
+    /// after the notice period has passed. Can be called by anyone.
+    /// If the new slashing parameters are stricter (higher `max_slashing_bips` or shorter `resolution_window`),
+    /// operators have to opt in again, else their opt-in is kept.
+    ActivateSlashingParameters {
+        service: String,
+    },
+    /// ExecuteMsg SetSlashingCategory adds a named slashing category
+    /// for the service (info.sender) with its own slashing parameters, effective immediately.
+    /// Operators must opt in to each category with [`ExecuteMsg::OperatorOptInToSlashingCategory`].
+    /// Can only be called when the category is not enabled,
+    /// use [`ExecuteMsg::ProposeSlashingCategory`] to update an enabled category.
+    SetSlashingCategory {
+        category: String,
+        slashing_parameters: SlashingParameters,
+    },
+    /// ExecuteMsg ProposeSlashingCategory proposes new slashing parameters for the enabled
+    /// named slashing category of the service (info.sender), replacing any pending slashing parameters.
+    /// The new slashing parameters can be activated with [`ExecuteMsg::ActivateSlashingCategory`]
+    /// after the slashing parameters notice period.
+    ProposeSlashingCategory {
+        category: String,
+        slashing_parameters: SlashingParameters,
+    },
+    /// ExecuteMsg ActivateSlashingCategory activates the pending slashing parameters of the named
+    /// slashing category of the service after the notice period has passed. Can be called by anyone.
+    /// If the new slashing parameters are stricter, operators have to opt in to the category again,
+    /// else their opt-in is kept.
+    ActivateSlashingCategory {
+        service: String,
+        category: String,
+    },
+    /// ExecuteMsg RemoveSlashingCategory removes the named slashing category of the service (info.sender),
+    /// resetting the category opt-in of all operators.
+    RemoveSlashingCategory {
+        category: String,
+    },
+    /// ExecuteMsg OperatorOptInToSlashingCategory opts in the operator
+    /// to the named slashing category of the service.
+    /// The operator and service must have an active registration.
+    /// The `operator` defaults to info.sender,
+    /// else info.sender must be a manager of the operator with [`ManagerPermission::SlashingOptIn`].
+    OperatorOptInToSlashingCategory {
+        service: String,
+        category: String,
+        operator: Option<String>,
+    },
+    /// ExecuteMsg OperatorOptInToSlashing opts in the operator to the slashing of the service.
+    /// The `operator` defaults to info.sender,
+    /// else info.sender must be a manager of the operator with [`ManagerPermission::SlashingOptIn`].
     OperatorOptInToSlashing {
         service: String,
+        operator: Option<String>,
     },
     TransferOwnership {
         /// See [`bvs_library::ownership::transfer_ownership`] for more information on this field
         new_owner: String,
     },
+    /// ExecuteMsg SetMaxActiveRelationships sets the global default max number of active relationships
+    /// an operator (to services) and a service (to operators) can have.
+    /// Existing active relationships above the new max are not affected,
+    /// but no new relationship can become active until the count is below the max.
+    /// Only the `owner` can call this message.
+    SetMaxActiveRelationships {
+        per_operator: u64,
+        per_service: u64,
+    },
+    /// ExecuteMsg SetMaxActiveRelationshipsForOperator overrides the global default for the operator.
+    /// Set `max` to `None` to remove the override.
+    /// Only the `owner` can call this message.
+    SetMaxActiveRelationshipsForOperator {
+        operator: String,
+        max: Option<u64>,
+    },
+    /// ExecuteMsg SetMaxActiveRelationshipsForService overrides the global default for the service.
+    /// Set `max` to `None` to remove the override.
+    /// Only the `owner` can call this message.
+    SetMaxActiveRelationshipsForService {
+        service: String,
+        max: Option<u64>,
+    },
+    /// ExecuteMsg SetDeregistrationDelay sets the delay (in seconds) an exiting registration
+    /// must wait before it can be finalized with [`ExecuteMsg::FinalizeDeregistration`].
+    /// When the router is set (see [`ExecuteMsg::SetRouter`]), an operator whose withdrawal lock period
+    /// in the `vault-router` is longer waits for its lock period instead,
+    /// so it stays slashable for the whole look-back of the router.
+    /// Registrations that are already exiting keep their exit timestamp.
+    /// Only the `owner` can call this message.
+    SetDeregistrationDelay {
+        delay: u64,
+    },
+    /// ExecuteMsg SetSlashingParametersNoticePeriod sets the notice period (in seconds) between
+    /// proposing new slashing parameters and activating them.
+    /// Slashing parameters that are already pending keep their effective timestamp.
+    /// Only the `owner` can call this message.
+    SetSlashingParametersNoticePeriod {
+        notice_period: u64,
+    },
+    /// ExecuteMsg ProposeCommission proposes the commission (in bips) the operator (info.sender)
+    /// takes from the rewards of the service, replacing any pending commission.
+    /// The commission can be activated with [`ExecuteMsg::ActivateCommission`]
+    /// after the commission change delay.
+    /// The operator and service must be registered.
+    ProposeCommission {
+        service: String,
+        commission_bips: u16,
+    },
+    /// ExecuteMsg ActivateCommission activates the pending commission of the operator for the service
+    /// after the commission change delay has passed. Can be called by anyone.
+    ActivateCommission {
+        service: String,
+        operator: String,
+    },
+    /// ExecuteMsg SetCommissionChangeDelay sets the delay (in seconds) between
+    /// proposing a new commission and activating it.
+    /// Commissions that are already pending keep their effective timestamp.
+    /// Only the `owner` can call this message.
+    SetCommissionChangeDelay {
+        delay: u64,
+    },
+    /// ExecuteMsg SetRouter sets the `vault-router` contract address,
+    /// used to query the vaults of an operator for [`AdmissionPolicy::MinStake`]
+    /// and the withdrawal lock period of an exiting operator (see [`ExecuteMsg::SetDeregistrationDelay`]).
+    /// Only the `owner` can call this message.
+    SetRouter {
+        router: String,
+    },
+    /// ExecuteMsg SetAdmissionPolicy sets the [`AdmissionPolicy`] of the service (info.sender).
+    /// When an admitted operator registers the service with [`ExecuteMsg::RegisterServiceToOperator`],
+    /// the registration becomes [`RegistrationStatus::Active`] immediately.
+    /// With [`AdmissionPolicy::Manual`] (default), or for an operator that isn't admitted,
+    /// the service must register the operator.
+    SetAdmissionPolicy {
+        policy: AdmissionPolicy,
+    },
+    /// ExecuteMsg SetAdmissionAllowlist adds (`allowed: true`) or removes (`allowed: false`)
+    /// the operators to the admission allowlist of the service (info.sender),
+    /// used by [`AdmissionPolicy::Allowlist`].
+    /// The batch size is bounded by [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+    SetAdmissionAllowlist {
+        operators: Vec<String>,
+        allowed: bool,
+    },
+    /// ExecuteMsg SetOperatorKey sets (or rotates) the public signing key of the operator (info.sender)
+    /// for the service, one key per `key_type`.
+    /// The service can verify attestations signed by the operator with the key
+    /// valid at the time of the attestation, see [`QueryMsg::OperatorKey`].
+    /// The operator and service must be registered.
+    ///
+    /// The `signature` proves possession of the key: the signature made with the key of
+    /// `sha256(service || operator || key_type || public_key)`,
+    /// the addresses as UTF-8 strings and the key type as a single byte (`Secp256k1` = 1, `Ed25519` = 2).
+    /// 64 bytes (r || s) for `Secp256k1`, 64 bytes for `Ed25519`.
+    SetOperatorKey {
+        service: String,
+        key_type: KeyType,
+        public_key: HexBinary,
+        signature: HexBinary,
+    },
+    /// ExecuteMsg RemoveOperatorKey removes the public signing key of the operator (info.sender)
+    /// of the `key_type` for the service.
+    RemoveOperatorKey {
+        service: String,
+        key_type: KeyType,
+    },
+    /// ExecuteMsg SetOperatorManager authorizes the `manager` to act on behalf of
+    /// the operator (info.sender) with the `permissions`, replacing any previous permissions.
+    /// Only the operator can set its managers.
+    SetOperatorManager {
+        manager: String,
+        permissions: Vec<ManagerPermission>,
+    },
+    /// ExecuteMsg RevokeOperatorManager revokes all permissions of the `manager`
+    /// of the operator (info.sender).
+    RevokeOperatorManager {
+        manager: String,
+    },
+    /// ExecuteMsg EnableServiceHooks opts in the service (info.sender) to receive
+    /// [`RegistryHookExecuteMsg`] hooks from the registry
+    /// whenever a relationship of the service changes.
+    /// The service must be a contract that handles [`RegistryHookExecuteMsg`].
+    EnableServiceHooks {},
+    /// ExecuteMsg DisableServiceHooks stops the hooks to the service (info.sender).
+    DisableServiceHooks {},
 }
 
-/// metadata is emitted as events and not stored on-chain.
+/// Hook sent by the registry to a service contract that has enabled hooks
+/// with [`ExecuteMsg::EnableServiceHooks`].
+/// The service contract must handle [`RegistryHookExecuteMsg`] in its `ExecuteMsg`.
+///
+/// Hooks are sent with a gas limit and their failure is isolated:
+/// a failing hook does not revert the registry action.
+#[cw_serde]
+pub enum RegistryHookMsg {
+    /// The registration status between the `operator` and the service has been updated.
+    RegistrationStatusUpdated {
+        operator: String,
+        status: RegistrationStatus,
+    },
+    /// The `operator` has opted in to slashing of the service,
+    /// or the slashing `category` if set.
+    OperatorOptedInToSlashing {
+        operator: String,
+        category: Option<String>,
+    },
+}
+
+impl RegistryHookMsg {
+    /// Serializes the hook as [`RegistryHookExecuteMsg`] to execute on the service contract.
+    pub fn into_cosmos_msg(self, contract_addr: impl Into<String>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: contract_addr.into(),
+            msg: to_json_binary(&RegistryHookExecuteMsg::RegistryHook(self))?,
+            funds: vec![],
+        }
+        .into())
+    }
+}
+
+/// The `ExecuteMsg` variant a service contract must handle to receive registry hooks,
+/// i.e. `{"registry_hook": {...}}`.
+#[cw_serde]
+pub enum RegistryHookExecuteMsg {
+    RegistryHook(RegistryHookMsg),
+}
+
+/// Metadata of a service or operator.
+/// The latest metadata is stored on-chain (with history) and also emitted as `MetadataUpdated` events.
+/// When updating the metadata, fields that are `None` are not updated.
 #[cw_serde]
 pub struct Metadata {
     pub name: Option<String>,
@@ -79,6 +379,41 @@ pub enum QueryMsg {
         timestamp: Option<u64>,
     },
 
+    /// QueryMsg RegistrationHistory: returns every registration status change
+    /// between the service and operator, in ascending order of timestamp.
+    /// You can provide `limit` and `start_after` (timestamp) to paginate the results.
+    /// The max `limit` is 100.
+    #[returns(RegistrationHistoryResponse)]
+    RegistrationHistory {
+        service: String,
+        operator: String,
+        limit: Option<u32>,
+        start_after: Option<u64>,
+    },
+
+    /// QueryMsg SlashingOptInHistory: returns every slashing opt-in change
+    /// of the operator to the service, in ascending order of timestamp.
+    /// You can provide `limit` and `start_after` (timestamp) to paginate the results.
+    /// The max `limit` is 100.
+    #[returns(SlashingOptInHistoryResponse)]
+    SlashingOptInHistory {
+        service: String,
+        operator: String,
+        limit: Option<u32>,
+        start_after: Option<u64>,
+    },
+
+    /// QueryMsg SlashingParametersHistory: returns every slashing parameters change
+    /// of the service, in ascending order of timestamp.
+    /// You can provide `limit` and `start_after` (timestamp) to paginate the results.
+    /// The max `limit` is 100.
+    #[returns(SlashingParametersHistoryResponse)]
+    SlashingParametersHistory {
+        service: String,
+        limit: Option<u32>,
+        start_after: Option<u64>,
+    },
+
     #[returns(IsServiceResponse)]
     IsService(String),
 
@@ -88,6 +423,22 @@ pub enum QueryMsg {
     #[returns(IsOperatorActiveResponse)]
     IsOperatorActive(String),
 
+    /// QueryMsg ServiceMetadata: returns the metadata of the service
+    /// at the given `timestamp` (or current if `None`), `None` if no metadata is stored.
+    #[returns(MetadataResponse)]
+    ServiceMetadata {
+        service: String,
+        timestamp: Option<u64>,
+    },
+
+    /// QueryMsg OperatorMetadata: returns the metadata of the operator
+    /// at the given `timestamp` (or current if `None`), `None` if no metadata is stored.
+    #[returns(MetadataResponse)]
+    OperatorMetadata {
+        operator: String,
+        timestamp: Option<u64>,
+    },
+
     #[returns(SlashingParametersResponse)]
     SlashingParameters {
         service: String,
@@ -100,6 +451,209 @@ pub enum QueryMsg {
         operator: String,
         timestamp: Option<u64>,
     },
+
+    /// QueryMsg ActiveOperatorCount: returns the number of operators actively registered to the service.
+    #[returns(ActiveCountResponse)]
+    ActiveOperatorCount(String),
+
+    /// QueryMsg ActiveServiceCount: returns the number of services the operator is actively registered to.
+    #[returns(ActiveCountResponse)]
+    ActiveServiceCount(String),
+
+    /// QueryMsg MaxActiveRelationshipsForOperator: returns the effective max active relationships of the operator.
+    #[returns(MaxActiveRelationshipsResponse)]
+    MaxActiveRelationshipsForOperator(String),
+
+    /// QueryMsg MaxActiveRelationshipsForService: returns the effective max active relationships of the service.
+    #[returns(MaxActiveRelationshipsResponse)]
+    MaxActiveRelationshipsForService(String),
+
+    /// QueryMsg ListOperatorsByService: returns the operators that have a registration with the service.
+    /// Filtered by `status` if provided,
+    /// else all registrations that are not [`RegistrationStatus::Inactive`] are returned.
+    /// If `timestamp` is provided, the status at that timestamp is used for filtering.
+    /// You can provide `limit` and `start_after` (operator) to paginate the results.
+    /// The max `limit` is 100.
+    #[returns(RegistrationListResponse)]
+    ListOperatorsByService {
+        service: String,
+        status: Option<RegistrationStatus>,
+        timestamp: Option<u64>,
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
+
+    /// QueryMsg ListServicesByOperator: returns the services that have a registration with the operator.
+    /// Filtered by `status` if provided,
+    /// else all registrations that are not [`RegistrationStatus::Inactive`] are returned.
+    /// If `timestamp` is provided, the status at that timestamp is used for filtering.
+    /// You can provide `limit` and `start_after` (service) to paginate the results.
+    /// The max `limit` is 100.
+    #[returns(RegistrationListResponse)]
+    ListServicesByOperator {
+        operator: String,
+        status: Option<RegistrationStatus>,
+        timestamp: Option<u64>,
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
+
+    /// QueryMsg SlashingCategory: returns the slashing parameters of the named slashing category
+    /// of the service at the given `timestamp` (or current if `None`),
+    /// `None` if the category isn't enabled.
+    #[returns(SlashingParametersResponse)]
+    SlashingCategory {
+        service: String,
+        category: String,
+        timestamp: Option<u64>,
+    },
+
+    /// QueryMsg ListSlashingCategories: returns the current slashing categories of the service.
+    /// You can provide `limit` and `start_after` (category) to paginate the results.
+    /// The max `limit` is 100.
+    #[returns(SlashingCategoryListResponse)]
+    ListSlashingCategories {
+        service: String,
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
+
+    /// QueryMsg IsOperatorOptedInToSlashingCategory: returns whether the operator has opted in
+    /// to the named slashing category of the service at the given `timestamp` (or current if `None`).
+    #[returns(IsOperatorOptedInToSlashingResponse)]
+    IsOperatorOptedInToSlashingCategory {
+        service: String,
+        category: String,
+        operator: String,
+        timestamp: Option<u64>,
+    },
+
+    /// QueryMsg PendingSlashingParameters: returns the proposed slashing parameters of the service
+    /// pending activation, `None` if there are none.
+    #[returns(PendingSlashingParametersResponse)]
+    PendingSlashingParameters { service: String },
+
+    /// QueryMsg PendingSlashingCategory: returns the proposed slashing parameters of the named
+    /// slashing category of the service pending activation, `None` if there are none.
+    #[returns(PendingSlashingParametersResponse)]
+    PendingSlashingCategory { service: String, category: String },
+
+    /// QueryMsg SlashingParametersNoticePeriod: returns the notice period (in seconds) between
+    /// proposing new slashing parameters and activating them.
+    #[returns(SlashingParametersNoticePeriodResponse)]
+    SlashingParametersNoticePeriod {},
+
+    /// QueryMsg Commission: returns the commission (in bips) the operator takes from the rewards
+    /// of the service at the given `timestamp` (or current if `None`), 0 if not set.
+    #[returns(CommissionResponse)]
+    Commission {
+        service: String,
+        operator: String,
+        timestamp: Option<u64>,
+    },
+
+    /// QueryMsg PendingCommission: returns the proposed commission of the operator for the service
+    /// pending activation, `None` if there is none.
+    #[returns(PendingCommissionResponse)]
+    PendingCommission { service: String, operator: String },
+
+    /// QueryMsg CommissionChangeDelay: returns the delay (in seconds) between
+    /// proposing a new commission and activating it.
+    #[returns(CommissionChangeDelayResponse)]
+    CommissionChangeDelay {},
+
+    /// QueryMsg Router: returns the `vault-router` contract address, `None` if not set.
+    #[returns(RouterResponse)]
+    Router {},
+
+    /// QueryMsg AdmissionPolicy: returns the [`AdmissionPolicy`] of the service.
+    #[returns(AdmissionPolicyResponse)]
+    AdmissionPolicy(String),
+
+    /// QueryMsg IsOperatorAllowlisted: returns whether the operator is in the admission allowlist
+    /// of the service.
+    #[returns(IsOperatorAllowlistedResponse)]
+    IsOperatorAllowlisted { service: String, operator: String },
+
+    /// QueryMsg DeregistrationDelay: returns the delay (in seconds) between deregistering
+    /// an active registration and finalizing it.
+    #[returns(DeregistrationDelayResponse)]
+    DeregistrationDelay {},
+
+    /// QueryMsg PendingExit: returns the timestamp (in seconds) at which the exiting registration
+    /// can be finalized, `None` if the registration is not exiting.
+    #[returns(PendingExitResponse)]
+    PendingExit { service: String, operator: String },
+
+    /// QueryMsg ListPendingExitsByOperator: returns the exiting registrations of the operator.
+    /// You can provide `limit` and `start_after` (service) to paginate the results.
+    /// The max `limit` is 100.
+    #[returns(PendingExitListResponse)]
+    ListPendingExitsByOperator {
+        operator: String,
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
+
+    /// QueryMsg ListPendingExitsByService: returns the exiting registrations of the service.
+    /// You can provide `limit` and `start_after` (operator) to paginate the results.
+    /// The max `limit` is 100.
+    #[returns(PendingExitListResponse)]
+    ListPendingExitsByService {
+        service: String,
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
+
+    /// QueryMsg ListServices: returns all the registered services, excluding the retired services.
+    /// You can provide `limit` and `start_after` to paginate the results.
+    /// The max `limit` is 100.
+    #[returns(ServiceListResponse)]
+    ListServices {
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
+
+    /// QueryMsg ListOperators: returns all the registered operators, excluding the retired operators.
+    /// You can provide `limit` and `start_after` to paginate the results.
+    /// The max `limit` is 100.
+    #[returns(OperatorListResponse)]
+    ListOperators {
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
+
+    /// QueryMsg ServiceSunset: returns the sunset progress of the service, `None` if not retired.
+    #[returns(SunsetResponse)]
+    ServiceSunset(String),
+
+    /// QueryMsg OperatorSunset: returns the sunset progress of the operator, `None` if not retired.
+    #[returns(SunsetResponse)]
+    OperatorSunset(String),
+
+    /// QueryMsg ServiceHooksEnabled: returns whether the service has enabled registry hooks.
+    #[returns(ServiceHooksEnabledResponse)]
+    ServiceHooksEnabled(String),
+
+    /// QueryMsg OperatorManagers: returns the managers of the operator and their permissions.
+    /// You can provide `limit` and `start_after` (manager) to paginate the results.
+    /// The max `limit` is 100.
+    #[returns(OperatorManagerListResponse)]
+    OperatorManagers {
+        operator: String,
+        limit: Option<u32>,
+        start_after: Option<String>,
+    },
+
+    /// QueryMsg OperatorKey: returns the public signing key of the operator of the `key_type`
+    /// for the service at the given `timestamp` (or current if `None`), `None` if not set.
+    #[returns(OperatorKeyResponse)]
+    OperatorKey {
+        service: String,
+        operator: String,
+        key_type: KeyType,
+        timestamp: Option<u64>,
+    },
 }
 
 #[cw_serde]
@@ -120,12 +674,147 @@ pub struct IsOperatorResponse(pub bool);
 #[cw_serde]
 pub struct IsOperatorActiveResponse(pub bool);
 
+#[cw_serde]
+pub struct MetadataResponse(pub Option<Metadata>);
+
 #[cw_serde]
 pub struct SlashingParametersResponse(pub Option<SlashingParameters>);
 
 #[cw_serde]
 pub struct IsOperatorOptedInToSlashingResponse(pub bool);
 
+/// The response to the `ListSlashingCategories` query.
+#[cw_serde]
+pub struct SlashingCategoryListResponse(pub Vec<SlashingCategory>);
+
+#[cw_serde]
+pub struct SlashingCategory {
+    pub category: String,
+    pub slashing_parameters: SlashingParameters,
+}
+
+#[cw_serde]
+pub struct PendingSlashingParametersResponse(pub Option<PendingSlashingParameters>);
+
+#[cw_serde]
+pub struct SlashingParametersNoticePeriodResponse(pub u64);
+
+#[cw_serde]
+pub struct CommissionResponse(pub u16);
+
+#[cw_serde]
+pub struct RouterResponse(pub Option<Addr>);
+
+#[cw_serde]
+pub struct AdmissionPolicyResponse(pub AdmissionPolicy);
+
+#[cw_serde]
+pub struct IsOperatorAllowlistedResponse(pub bool);
+
+#[cw_serde]
+pub struct PendingCommissionResponse(pub Option<PendingCommission>);
+
+#[cw_serde]
+pub struct CommissionChangeDelayResponse(pub u64);
+
+#[cw_serde]
+pub struct ActiveCountResponse(pub u64);
+
+#[cw_serde]
+pub struct MaxActiveRelationshipsResponse(pub u64);
+
+/// The response to the `ListOperatorsByService` and `ListServicesByOperator` queries.
+/// For pagination, the `start_after` field is the last `operator` or `service` from the previous page.
+#[cw_serde]
+pub struct RegistrationListResponse(pub Vec<Registration>);
+
+#[cw_serde]
+pub struct Registration {
+    pub service: Addr,
+    pub operator: Addr,
+    /// The [`RegistrationStatus`] as u8, see [`StatusResponse`].
+    pub status: u8,
+}
+
+/// The response to the `ListServices` query.
+/// For pagination, the `start_after` field is the last `service` from the previous page.
+#[cw_serde]
+pub struct ServiceListResponse(pub Vec<Addr>);
+
+/// The response to the `ListOperators` query.
+/// For pagination, the `start_after` field is the last `operator` from the previous page.
+#[cw_serde]
+pub struct OperatorListResponse(pub Vec<Addr>);
+
+#[cw_serde]
+pub struct DeregistrationDelayResponse(pub u64);
+
+#[cw_serde]
+pub struct PendingExitResponse(pub Option<u64>);
+
+/// The response to the `ListPendingExitsByOperator` and `ListPendingExitsByService` queries.
+#[cw_serde]
+pub struct PendingExitListResponse(pub Vec<PendingExit>);
+
+#[cw_serde]
+pub struct PendingExit {
+    pub service: Addr,
+    pub operator: Addr,
+    /// Timestamp (in seconds) at which the deregistration can be finalized
+    pub exit_timestamp: u64,
+}
+
+/// A registration status change at `timestamp` (in seconds),
+/// the new status is effective from `timestamp + 1` in timestamp queries.
+#[cw_serde]
+pub struct RegistrationStatusChange {
+    pub timestamp: u64,
+    pub status: u8,
+}
+
+#[cw_serde]
+pub struct RegistrationHistoryResponse(pub Vec<RegistrationStatusChange>);
+
+/// A slashing opt-in change at `timestamp` (in seconds),
+/// `opted_in` is `false` if the opt-in was reset.
+#[cw_serde]
+pub struct SlashingOptInChange {
+    pub timestamp: u64,
+    pub opted_in: bool,
+}
+
+#[cw_serde]
+pub struct SlashingOptInHistoryResponse(pub Vec<SlashingOptInChange>);
+
+/// A slashing parameters change at `timestamp` (in seconds),
+/// `slashing_parameters` is `None` if slashing was disabled.
+#[cw_serde]
+pub struct SlashingParametersChange {
+    pub timestamp: u64,
+    pub slashing_parameters: Option<SlashingParameters>,
+}
+
+#[cw_serde]
+pub struct SlashingParametersHistoryResponse(pub Vec<SlashingParametersChange>);
+
+#[cw_serde]
+pub struct SunsetResponse(pub Option<Sunset>);
+
+#[cw_serde]
+pub struct ServiceHooksEnabledResponse(pub bool);
+
+#[cw_serde]
+pub struct OperatorManager {
+    pub manager: Addr,
+    pub permissions: Vec<ManagerPermission>,
+}
+
+#[cw_serde]
+pub struct OperatorManagerListResponse(pub Vec<OperatorManager>);
+
+#[cw_serde]
+pub struct OperatorKeyResponse(pub Option<HexBinary>);
+
 #[cw_serde]
 pub struct MigrateMsg {}
 
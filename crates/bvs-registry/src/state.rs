@@ -1,9 +1,14 @@
 use crate::error::ContractError;
+use crate::msg::Metadata;
 use bvs_library::addr::{Operator, Service};
 use bvs_library::storage::EVERY_SECOND;
+use bvs_library::time::DAYS;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Api, Env, Order, StdError, StdResult, Storage};
-use cw_storage_plus::{Map, SnapshotMap};
+use cosmwasm_std::{Addr, Api, Env, HexBinary, Order, StdError, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Item, KeyDeserialize, Map, Prefixer, PrimaryKey, SnapshotMap};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
 
 /// Mapping of service address to boolean value
 /// indicating if the service is registered with the registry
@@ -40,9 +45,178 @@ pub fn require_operator_registered(
     Ok(())
 }
 
+/// Max number of items in a batch registration or deregistration message
+pub const MAX_BATCH_SIZE: usize = 100;
+
+/// Validate the batch is not empty and doesn't exceed [MAX_BATCH_SIZE] items.
+pub fn validate_batch_size(size: usize) -> Result<(), ContractError> {
+    if size == 0 {
+        return Err(ContractError::InvalidBatch {
+            msg: "Batch must not be empty".to_string(),
+        });
+    }
+    if size > MAX_BATCH_SIZE {
+        return Err(ContractError::InvalidBatch {
+            msg: format!("Batch size exceeds the maximum of {MAX_BATCH_SIZE}"),
+        });
+    }
+    Ok(())
+}
+
+/// Max length (in bytes) of the metadata name
+pub const MAX_METADATA_NAME_LENGTH: usize = 128;
+
+/// Max length (in bytes) of the metadata uri
+pub const MAX_METADATA_URI_LENGTH: usize = 512;
+
+/// Validate the metadata,
+/// `name` must not exceed [MAX_METADATA_NAME_LENGTH] and `uri` must not exceed [MAX_METADATA_URI_LENGTH].
+pub fn validate_metadata(metadata: &Metadata) -> Result<(), ContractError> {
+    if let Some(name) = &metadata.name {
+        if name.len() > MAX_METADATA_NAME_LENGTH {
+            return Err(ContractError::InvalidMetadata {
+                msg: format!("Name must not exceed {MAX_METADATA_NAME_LENGTH} characters"),
+            });
+        }
+    }
+    if let Some(uri) = &metadata.uri {
+        if uri.len() > MAX_METADATA_URI_LENGTH {
+            return Err(ContractError::InvalidMetadata {
+                msg: format!("URI must not exceed {MAX_METADATA_URI_LENGTH} characters"),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Mapping of service to the latest metadata of the service.
+pub(crate) const SERVICE_METADATA: SnapshotMap<&Service, Metadata> = SnapshotMap::new(
+    "service_metadata",
+    "service_metadata_checkpoint",
+    "service_metadata_changelog",
+    EVERY_SECOND,
+);
+
+/// Mapping of operator to the latest metadata of the operator.
+pub(crate) const OPERATOR_METADATA: SnapshotMap<&Operator, Metadata> = SnapshotMap::new(
+    "operator_metadata",
+    "operator_metadata_checkpoint",
+    "operator_metadata_changelog",
+    EVERY_SECOND,
+);
+
+/// Merge the `update` into the `current` metadata, fields that are `None` are not updated.
+fn merge_metadata(current: Option<Metadata>, update: &Metadata) -> Metadata {
+    let current = current.unwrap_or(Metadata {
+        name: None,
+        uri: None,
+    });
+    Metadata {
+        name: update.name.clone().or(current.name),
+        uri: update.uri.clone().or(current.uri),
+    }
+}
+
+/// Validate and update the metadata of the service at current timestamp.
+/// Fields that are `None` keep their previous value.
+pub fn set_service_metadata(
+    store: &mut dyn Storage,
+    env: &Env,
+    service: &Service,
+    metadata: &Metadata,
+) -> Result<(), ContractError> {
+    validate_metadata(metadata)?;
+    let current = SERVICE_METADATA.may_load(store, service)?;
+    let metadata = merge_metadata(current, metadata);
+    SERVICE_METADATA.save(store, service, &metadata, env.block.time.seconds())?;
+    Ok(())
+}
+
+/// Get the metadata of the service at the given timestamp (or current if `None`).
+pub fn get_service_metadata(
+    store: &dyn Storage,
+    service: &Service,
+    timestamp: Option<u64>,
+) -> StdResult<Option<Metadata>> {
+    match timestamp {
+        Some(t) => SERVICE_METADATA.may_load_at_height(store, service, t),
+        None => SERVICE_METADATA.may_load(store, service),
+    }
+}
+
+/// Validate and update the metadata of the operator at current timestamp.
+/// Fields that are `None` keep their previous value.
+pub fn set_operator_metadata(
+    store: &mut dyn Storage,
+    env: &Env,
+    operator: &Operator,
+    metadata: &Metadata,
+) -> Result<(), ContractError> {
+    validate_metadata(metadata)?;
+    let current = OPERATOR_METADATA.may_load(store, operator)?;
+    let metadata = merge_metadata(current, metadata);
+    OPERATOR_METADATA.save(store, operator, &metadata, env.block.time.seconds())?;
+    Ok(())
+}
+
+/// Get the metadata of the operator at the given timestamp (or current if `None`).
+pub fn get_operator_metadata(
+    store: &dyn Storage,
+    operator: &Operator,
+    timestamp: Option<u64>,
+) -> StdResult<Option<Metadata>> {
+    match timestamp {
+        Some(t) => OPERATOR_METADATA.may_load_at_height(store, operator, t),
+        None => OPERATOR_METADATA.may_load(store, operator),
+    }
+}
+
+/// Load the history of changes of the `key` in the snapshot `map` from its changelog,
+/// in ascending order of the timestamp the value changed at.
+/// Each change is the value after the change, `None` if the key was removed.
+///
+/// The changelog only stores the value before each change,
+/// so the value after a change is the value before the next change, or the current value.
+/// Changes made before the map was a `SnapshotMap` are not recorded.
+pub fn load_snapshot_history<'a, K, T>(
+    store: &dyn Storage,
+    map: &SnapshotMap<K, T>,
+    key: K,
+    start_after: Option<u64>,
+    limit: usize,
+) -> StdResult<Vec<(u64, Option<T>)>>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    K: PrimaryKey<'a> + Prefixer<'a> + KeyDeserialize + Clone,
+{
+    let changes = map
+        .changelog()
+        .prefix(key.clone())
+        .range(
+            store,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit + 1)
+        .map(|item| item.map(|(timestamp, change)| (timestamp, change.old)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut history = Vec::with_capacity(limit);
+    for (i, (timestamp, _)) in changes.iter().take(limit).enumerate() {
+        let value = match changes.get(i + 1) {
+            Some((_, old)) => old.clone(),
+            None => map.may_load(store, key.clone())?,
+        };
+        history.push((*timestamp, value));
+    }
+    Ok(history)
+}
+
 /// Registered status of the Operator to Service
 /// Can be initiated by the Operator or the Service
 /// Becomes Active when the Operator and Service both have registered
+/// Becomes Exiting when the Operator or Service have deregistered an Active registration
 /// Becomes Inactive when the Operator or Service have unregistered (default state)
 #[cw_serde]
 pub enum RegistrationStatus {
@@ -63,6 +237,22 @@ pub enum RegistrationStatus {
     /// indicating a pending registration from the Operator side
     /// This is Service-initiated registration, waiting for Operator to finalize
     ServiceRegistered = 3,
+
+    /// State when either the Operator or Service has deregistered an Active registration,
+    /// and the deregistration delay has not been finalized.
+    /// The Operator is still considered validating and slashable for the Service in this state.
+    Exiting = 4,
+}
+
+impl RegistrationStatus {
+    /// Whether the Operator is validating the Service in this state,
+    /// i.e. [`RegistrationStatus::Active`] or [`RegistrationStatus::Exiting`].
+    pub fn is_validating(&self) -> bool {
+        matches!(
+            self,
+            RegistrationStatus::Active | RegistrationStatus::Exiting
+        )
+    }
 }
 
 impl From<RegistrationStatus> for u8 {
@@ -80,6 +270,7 @@ impl TryFrom<u8> for RegistrationStatus {
             1 => Ok(RegistrationStatus::Active),
             2 => Ok(RegistrationStatus::OperatorRegistered),
             3 => Ok(RegistrationStatus::ServiceRegistered),
+            4 => Ok(RegistrationStatus::Exiting),
             _ => Err(StdError::generic_err("RegistrationStatus out of range")),
         }
     }
@@ -95,6 +286,58 @@ pub(crate) const REGISTRATION_STATUS: SnapshotMap<(&Operator, &Service), u8> = S
     EVERY_SECOND,
 );
 
+/// Secondary index of [REGISTRATION_STATUS] keyed by (service_operator) address,
+/// to allow the operators of a service to be listed.
+/// The operator side can be listed directly from the [REGISTRATION_STATUS] prefix.
+///
+/// Entries are never removed, so relationships can also be listed at an earlier timestamp.
+/// The status must be loaded from [REGISTRATION_STATUS] to filter out inactive relationships.
+pub(crate) const SERVICE_OPERATORS: Map<(&Service, &Operator), ()> = Map::new("service_operators");
+
+/// Default delay (in seconds) between deregistering an active registration and finalizing it.
+/// Equal to the default withdrawal lock period of the vault router,
+/// so that the operator stays slashable for incidents during the exit.
+/// When the router is set, an operator with a longer withdrawal lock period exits after its lock period instead.
+pub const DEFAULT_DEREGISTRATION_DELAY: u64 = 7 * DAYS;
+
+/// Delay (in seconds) an [`RegistrationStatus::Exiting`] registration must wait before it can be finalized.
+/// Use [get_deregistration_delay] to get the delay with the default applied.
+pub(crate) const DEREGISTRATION_DELAY: Item<u64> = Item::new("deregistration_delay");
+
+/// Get the deregistration delay, [DEFAULT_DEREGISTRATION_DELAY] if not set
+pub fn get_deregistration_delay(store: &dyn Storage) -> StdResult<u64> {
+    let delay = DEREGISTRATION_DELAY
+        .may_load(store)?
+        .unwrap_or(DEFAULT_DEREGISTRATION_DELAY);
+    Ok(delay)
+}
+
+/// Mapping of (operator_service) address to the timestamp (in seconds)
+/// at which the [`RegistrationStatus::Exiting`] registration can be finalized.
+/// An entry only exists while the registration is exiting.
+pub(crate) const PENDING_EXITS: Map<(&Operator, &Service), u64> = Map::new("pending_exits");
+
+/// Progress of the sunset of a retired service or operator,
+/// see [`ExecuteMsg::DeregisterAsService`](crate::msg::ExecuteMsg::DeregisterAsService)
+/// and [`ExecuteMsg::DeregisterAsOperator`](crate::msg::ExecuteMsg::DeregisterAsOperator).
+#[cw_serde]
+#[derive(Default)]
+pub struct Sunset {
+    /// The counterparty of the last walked relationship,
+    /// relationships are walked in ascending order of the counterparty address.
+    pub last: Option<Addr>,
+    /// Whether all relationships have been walked and deregistered
+    pub completed: bool,
+}
+
+/// Mapping of a retired service to the [Sunset] progress of its relationships.
+/// An entry only exists once the service has called `DeregisterAsService`.
+pub(crate) const SERVICE_SUNSETS: Map<&Service, Sunset> = Map::new("service_sunsets");
+
+/// Mapping of a retired operator to the [Sunset] progress of its relationships.
+/// An entry only exists once the operator has called `DeregisterAsOperator`.
+pub(crate) const OPERATOR_SUNSETS: Map<&Operator, Sunset> = Map::new("operator_sunsets");
+
 /// Get the registration status of the Operator to Service
 pub fn get_registration_status(
     store: &dyn Storage,
@@ -127,6 +370,13 @@ pub fn get_registration_status_at_timestamp(
 
 /// Set the registration status of the Operator to Service at current timestamp
 ///
+/// When the relationship becomes [`RegistrationStatus::Active`],
+/// the active registration count of both the operator and the service is increased
+/// and checked against their max active relationships,
+/// and the operator is opted in to slashing if the service has enabled slashing.
+/// A relationship that is [`RegistrationStatus::Exiting`] is still counted as active.
+/// When the relationship is no longer active or exiting, the counts are decreased.
+///
 /// #### Warning
 /// This function will only save the state at the end of the block.
 /// So the new state will only be available at timestamp + 1.
@@ -136,26 +386,87 @@ pub fn set_registration_status(
     env: &Env,
     key: (&Operator, &Service),
     status: RegistrationStatus,
-) -> StdResult<()> {
+) -> Result<(), ContractError> {
+    let (operator, service) = key;
+    let is_active = status == RegistrationStatus::Active;
+    set_registration_status_without_opt_in(store, env, key, status)?;
+
+    // if service has enabled slashing, opt-in operator to slashing
+    if is_active && is_slashing_enabled(store, service, Some(env.block.time.seconds()))? {
+        opt_in_to_slashing(store, env, service, operator)?;
+    }
+    Ok(())
+}
+
+/// Set the registration status of the Operator to Service at current timestamp,
+/// see [set_registration_status],
+/// without opting in the operator to slashing when the relationship becomes [`RegistrationStatus::Active`].
+/// Used when the sender isn't permitted to opt in the operator,
+/// e.g. a manager without [`ManagerPermission::SlashingOptIn`].
+pub fn set_registration_status_without_opt_in(
+    store: &mut dyn Storage,
+    env: &Env,
+    key: (&Operator, &Service),
+    status: RegistrationStatus,
+) -> Result<(), ContractError> {
     let (operator, service) = key;
+    let prev_status = get_registration_status(store, key)?;
     match status {
         RegistrationStatus::Active => {
-            increase_operator_active_registration_count(store, operator)?;
-            // if service has enabled slashing, opt-in operator to slashing
-            if is_slashing_enabled(store, service, Some(env.block.time.seconds()))? {
-                opt_in_to_slashing(store, env, service, operator)?;
+            if !prev_status.is_validating() {
+                increase_active_registration_count(store, key)?;
+            }
+        }
+        RegistrationStatus::Exiting => {
+            if prev_status != RegistrationStatus::Active {
+                return Err(ContractError::InvalidRegistrationStatus {
+                    msg: "Only an active registration can be exiting".to_string(),
+                });
             }
         }
-        RegistrationStatus::Inactive => {
-            decrease_operator_active_registration_count(store, operator)?;
+        _ => {
+            if prev_status.is_validating() {
+                decrease_operator_active_registration_count(store, operator)?;
+                decrease_service_active_registration_count(store, service)?;
+            }
         }
-        _ => {}
     }
 
+    SERVICE_OPERATORS.save(store, (service, operator), &())?;
     REGISTRATION_STATUS.save(store, key, &status.into(), env.block.time.seconds())?;
     Ok(())
 }
 
+/// Increase the active registration count of both the operator and the service by 1,
+/// erroring if either has reached its max active relationships.
+fn increase_active_registration_count(
+    store: &mut dyn Storage,
+    key: (&Operator, &Service),
+) -> Result<(), ContractError> {
+    let (operator, service) = key;
+
+    if get_operator_active_registration_count(store, operator)?
+        >= get_operator_max_active_relationships(store, operator)?
+    {
+        return Err(ContractError::MaxActiveRelationshipsExceeded {
+            msg: "Operator has reached its max active relationships".to_string(),
+        });
+    }
+
+    if get_service_active_registration_count(store, service)?
+        >= get_service_max_active_relationships(store, service)?
+    {
+        return Err(ContractError::MaxActiveRelationshipsExceeded {
+            msg: "Service has reached its max active relationships".to_string(),
+        });
+    }
+
+    increase_operator_active_registration_count(store, operator)?;
+    increase_service_active_registration_count(store, service)?;
+
+    Ok(())
+}
+
 pub fn require_active_registration_status(
     store: &dyn Storage,
     key: (&Operator, &Service),
@@ -175,9 +486,7 @@ pub(crate) const OPERATOR_ACTIVE_REGISTRATION_COUNT: Map<&Operator, u64> =
 
 /// Check if the operator is actively registered to any service
 pub fn is_operator_active(store: &dyn Storage, operator: &Operator) -> StdResult<bool> {
-    let active_count = OPERATOR_ACTIVE_REGISTRATION_COUNT
-        .may_load(store, operator)?
-        .unwrap_or(0);
+    let active_count = get_operator_active_registration_count(store, operator)?;
 
     Ok(active_count > 0)
 }
@@ -208,6 +517,116 @@ pub fn decrease_operator_active_registration_count(
     })
 }
 
+/// Stores the active registration count of the service to operators.
+pub(crate) const SERVICE_ACTIVE_REGISTRATION_COUNT: Map<&Service, u64> =
+    Map::new("service_active_registration_count");
+
+/// Get the number of operators actively registered to the service
+pub fn get_service_active_registration_count(
+    store: &dyn Storage,
+    service: &Service,
+) -> StdResult<u64> {
+    let active_count = SERVICE_ACTIVE_REGISTRATION_COUNT
+        .may_load(store, service)?
+        .unwrap_or(0);
+
+    Ok(active_count)
+}
+
+/// Get the number of services the operator is actively registered to
+pub fn get_operator_active_registration_count(
+    store: &dyn Storage,
+    operator: &Operator,
+) -> StdResult<u64> {
+    let active_count = OPERATOR_ACTIVE_REGISTRATION_COUNT
+        .may_load(store, operator)?
+        .unwrap_or(0);
+
+    Ok(active_count)
+}
+
+/// Increase the service active registration count by 1
+pub fn increase_service_active_registration_count(
+    store: &mut dyn Storage,
+    service: &Service,
+) -> StdResult<u64> {
+    SERVICE_ACTIVE_REGISTRATION_COUNT.update(store, service, |count| {
+        let new_count = count.unwrap_or(0).checked_add(1);
+        new_count.ok_or_else(|| {
+            StdError::generic_err("Increase service active registration count failed")
+        })
+    })
+}
+
+/// Decrease the service active registration count by 1
+pub fn decrease_service_active_registration_count(
+    store: &mut dyn Storage,
+    service: &Service,
+) -> StdResult<u64> {
+    SERVICE_ACTIVE_REGISTRATION_COUNT.update(store, service, |count| {
+        let new_count = count.unwrap_or(0).checked_sub(1);
+        new_count.ok_or_else(|| {
+            StdError::generic_err("Decrease service active registration count failed")
+        })
+    })
+}
+
+/// This is used when [MAX_ACTIVE_RELATIONSHIPS_PER_OPERATOR] is not set.
+pub const DEFAULT_MAX_ACTIVE_RELATIONSHIPS_PER_OPERATOR: u64 = 100;
+
+/// This is used when [MAX_ACTIVE_RELATIONSHIPS_PER_SERVICE] is not set.
+pub const DEFAULT_MAX_ACTIVE_RELATIONSHIPS_PER_SERVICE: u64 = 1000;
+
+/// Stores the global default of the max number of services an operator can be actively registered to.
+/// Set by the owner, overridden per operator by [OPERATOR_MAX_ACTIVE_RELATIONSHIPS].
+pub(crate) const MAX_ACTIVE_RELATIONSHIPS_PER_OPERATOR: Item<u64> =
+    Item::new("max_active_relationships_per_operator");
+
+/// Stores the global default of the max number of operators a service can be actively registered to.
+/// Set by the owner, overridden per service by [SERVICE_MAX_ACTIVE_RELATIONSHIPS].
+pub(crate) const MAX_ACTIVE_RELATIONSHIPS_PER_SERVICE: Item<u64> =
+    Item::new("max_active_relationships_per_service");
+
+/// Per operator override of [MAX_ACTIVE_RELATIONSHIPS_PER_OPERATOR].
+pub(crate) const OPERATOR_MAX_ACTIVE_RELATIONSHIPS: Map<&Operator, u64> =
+    Map::new("operator_max_active_relationships");
+
+/// Per service override of [MAX_ACTIVE_RELATIONSHIPS_PER_SERVICE].
+pub(crate) const SERVICE_MAX_ACTIVE_RELATIONSHIPS: Map<&Service, u64> =
+    Map::new("service_max_active_relationships");
+
+/// Get the effective max active relationships of the operator,
+/// the operator override if set, else the global default.
+pub fn get_operator_max_active_relationships(
+    store: &dyn Storage,
+    operator: &Operator,
+) -> StdResult<u64> {
+    if let Some(max) = OPERATOR_MAX_ACTIVE_RELATIONSHIPS.may_load(store, operator)? {
+        return Ok(max);
+    }
+
+    let max = MAX_ACTIVE_RELATIONSHIPS_PER_OPERATOR
+        .may_load(store)?
+        .unwrap_or(DEFAULT_MAX_ACTIVE_RELATIONSHIPS_PER_OPERATOR);
+    Ok(max)
+}
+
+/// Get the effective max active relationships of the service,
+/// the service override if set, else the global default.
+pub fn get_service_max_active_relationships(
+    store: &dyn Storage,
+    service: &Service,
+) -> StdResult<u64> {
+    if let Some(max) = SERVICE_MAX_ACTIVE_RELATIONSHIPS.may_load(store, service)? {
+        return Ok(max);
+    }
+
+    let max = MAX_ACTIVE_RELATIONSHIPS_PER_SERVICE
+        .may_load(store)?
+        .unwrap_or(DEFAULT_MAX_ACTIVE_RELATIONSHIPS_PER_SERVICE);
+    Ok(max)
+}
+
 #[cw_serde]
 pub struct SlashingParameters {
     /// The address to which the slashed funds will be sent after the slashing is finalized.  
@@ -223,6 +642,78 @@ pub struct SlashingParameters {
     /// To prevent restaker's early withdrawal of their assets from the vault due to the impending slash,
     /// defeating the purpose of shared security.
     pub resolution_window: u64,
+    /// The weighted destinations the slashed funds are split between after the slashing is finalized.
+    /// If not empty, `destination` must be `None`,
+    /// and the `bips` of the destinations must add up to 10_000 (100%).
+    /// Max 10 destinations.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub destinations: Vec<WeightedDestination>,
+    /// The asset ids (CAIP-19) of the vaults the service accepts as security and can slash.
+    /// Empty, indicates that the vaults of every asset can be slashed.
+    /// Max 10 asset ids.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub asset_ids: Vec<String>,
+}
+
+/// The max number of weighted destinations in [`SlashingParameters`].
+pub const MAX_SLASHING_DESTINATIONS: usize = 10;
+
+/// The max number of accepted asset ids in [`SlashingParameters`].
+pub const MAX_SLASHING_ASSET_IDS: usize = 10;
+
+#[cw_serde]
+pub struct WeightedDestination {
+    pub recipient: SlashingRecipient,
+    /// The share of the slashed funds in bips (basis points), where 100 bips = 1%.
+    /// Must be greater than zero.
+    pub bips: u16,
+}
+
+impl WeightedDestination {
+    /// Validate the weighted destinations (e.g., of [`SlashingParameters::destinations`]),
+    /// the `bips` must add up to 10_000 (100%), with max 10 destinations.
+    pub fn validate_all(
+        api: &dyn Api,
+        destinations: &[WeightedDestination],
+    ) -> Result<(), ContractError> {
+        if destinations.len() > MAX_SLASHING_DESTINATIONS {
+            return Err(ContractError::InvalidSlashingParameters {
+                msg: "Destinations exceed the max of 10".to_string(),
+            });
+        }
+
+        let mut total_bips: u32 = 0;
+        for destination in destinations {
+            if let SlashingRecipient::Address(address) = &destination.recipient {
+                api.addr_validate(address.as_str()).map_err(|_| {
+                    ContractError::InvalidSlashingParameters {
+                        msg: "Invalid destination address format".to_string(),
+                    }
+                })?;
+            }
+            if destination.bips == 0 {
+                return Err(ContractError::InvalidSlashingParameters {
+                    msg: "Destination bips must be greater than zero".to_string(),
+                });
+            }
+            total_bips += u32::from(destination.bips);
+        }
+
+        if total_bips != 10_000 {
+            return Err(ContractError::InvalidSlashingParameters {
+                msg: "Destinations bips must add up to 10,000 bips (100%)".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cw_serde]
+pub enum SlashingRecipient {
+    /// The slashed funds are sent to the address.
+    Address(Addr),
+    /// The slashed funds are burned.
+    Burn,
 }
 
 impl SlashingParameters {
@@ -239,8 +730,84 @@ impl SlashingParameters {
                 msg: "Max slashing bips exceeds 10,000 bips (100%)".to_string(),
             });
         }
+        if !self.destinations.is_empty() {
+            self.validate_destinations(api)?;
+        }
+        if !self.asset_ids.is_empty() {
+            self.validate_asset_ids()?;
+        }
+        Ok(())
+    }
+
+    fn validate_asset_ids(&self) -> Result<(), ContractError> {
+        if self.asset_ids.len() > MAX_SLASHING_ASSET_IDS {
+            return Err(ContractError::InvalidSlashingParameters {
+                msg: "Asset ids exceed the max of 10".to_string(),
+            });
+        }
+        for (i, asset_id) in self.asset_ids.iter().enumerate() {
+            if !is_caip19_asset_id(asset_id) {
+                return Err(ContractError::InvalidSlashingParameters {
+                    msg: "Invalid asset id format".to_string(),
+                });
+            }
+            if self.asset_ids[..i].contains(asset_id) {
+                return Err(ContractError::InvalidSlashingParameters {
+                    msg: "Duplicate asset id".to_string(),
+                });
+            }
+        }
         Ok(())
     }
+
+    /// Whether the vaults of the asset id can be slashed by the service,
+    /// i.e. the asset id is accepted or every asset is accepted.
+    pub fn accepts_asset_id(&self, asset_id: &str) -> bool {
+        self.asset_ids.is_empty() || self.asset_ids.iter().any(|a| a == asset_id)
+    }
+
+    fn validate_destinations(&self, api: &dyn Api) -> Result<(), ContractError> {
+        if self.destination.is_some() {
+            return Err(ContractError::InvalidSlashingParameters {
+                msg: "Destination must be None when destinations are set".to_string(),
+            });
+        }
+        WeightedDestination::validate_all(api, &self.destinations)
+    }
+
+    /// Whether these slashing parameters are stricter for the operator than the `current` parameters,
+    /// i.e. a higher `max_slashing_bips`, a shorter `resolution_window`
+    /// or an asset accepted (slashable) that wasn't accepted before.
+    /// A change of `destination` (or `destinations`) alone is not stricter.
+    pub fn is_stricter_than(&self, current: &SlashingParameters) -> bool {
+        self.max_slashing_bips > current.max_slashing_bips
+            || self.resolution_window < current.resolution_window
+            || self.accepts_more_assets_than(current)
+    }
+
+    fn accepts_more_assets_than(&self, current: &SlashingParameters) -> bool {
+        if current.asset_ids.is_empty() {
+            return false;
+        }
+        self.asset_ids.is_empty()
+            || self
+                .asset_ids
+                .iter()
+                .any(|asset_id| !current.asset_ids.contains(asset_id))
+    }
+}
+
+/// Whether the asset id has the CAIP-19 format `{namespace}:{reference}/{asset_namespace}:{asset_reference}`,
+/// e.g. `cosmos:bbn-1/bank:ubbn`.
+fn is_caip19_asset_id(asset_id: &str) -> bool {
+    let is_part = |part: &str| {
+        part.split_once(':')
+            .is_some_and(|(namespace, reference)| !namespace.is_empty() && !reference.is_empty())
+    };
+    asset_id.len() <= 250
+        && asset_id
+            .split_once('/')
+            .is_some_and(|(chain_id, asset_type)| is_part(chain_id) && is_part(asset_type))
 }
 
 /// Mapping of service to the latest slashing parameters.
@@ -289,12 +856,113 @@ pub fn enable_slashing(
     Ok(())
 }
 
-/// Disable slashing for the given service at current timestamp
+/// Disable slashing for the given service at current timestamp.
+/// Any pending slashing parameters are removed.
 pub fn disable_slashing(store: &mut dyn Storage, env: &Env, service: &Service) -> StdResult<()> {
     SLASHING_PARAMETERS.remove(store, service, env.block.time.seconds())?;
+    PENDING_SLASHING_PARAMETERS.remove(store, service);
     Ok(())
 }
 
+/// Default notice period (in seconds) between proposing new slashing parameters
+/// and the new slashing parameters becoming effective.
+pub const DEFAULT_SLASHING_PARAMETERS_NOTICE_PERIOD: u64 = 7 * DAYS;
+
+/// Notice period (in seconds) between proposing new slashing parameters and activating them.
+/// Use [get_slashing_parameters_notice_period] to get the notice period with the default applied.
+pub(crate) const SLASHING_PARAMETERS_NOTICE_PERIOD: Item<u64> =
+    Item::new("slashing_parameters_notice_period");
+
+/// Get the slashing parameters notice period, [DEFAULT_SLASHING_PARAMETERS_NOTICE_PERIOD] if not set
+pub fn get_slashing_parameters_notice_period(store: &dyn Storage) -> StdResult<u64> {
+    let notice_period = SLASHING_PARAMETERS_NOTICE_PERIOD
+        .may_load(store)?
+        .unwrap_or(DEFAULT_SLASHING_PARAMETERS_NOTICE_PERIOD);
+    Ok(notice_period)
+}
+
+#[cw_serde]
+pub struct PendingSlashingParameters {
+    /// The proposed slashing parameters.
+    pub slashing_parameters: SlashingParameters,
+    /// The timestamp (in seconds) at which the proposed slashing parameters can be activated.
+    pub effective_timestamp: u64,
+}
+
+/// Mapping of service to the proposed slashing parameters pending activation.
+/// A service can only have one pending slashing parameters, proposing again replaces it.
+pub(crate) const PENDING_SLASHING_PARAMETERS: Map<&Service, PendingSlashingParameters> =
+    Map::new("pending_slashing_parameters");
+
+/// Propose new slashing parameters for the given service,
+/// effective after the slashing parameters notice period.
+pub fn propose_slashing_parameters(
+    store: &mut dyn Storage,
+    api: &dyn Api,
+    env: &Env,
+    service: &Service,
+    slashing_parameters: &SlashingParameters,
+) -> Result<PendingSlashingParameters, ContractError> {
+    slashing_parameters.validate(api)?;
+
+    let pending = PendingSlashingParameters {
+        slashing_parameters: slashing_parameters.clone(),
+        effective_timestamp: env
+            .block
+            .time
+            .plus_seconds(get_slashing_parameters_notice_period(store)?)
+            .seconds(),
+    };
+    PENDING_SLASHING_PARAMETERS.save(store, service, &pending)?;
+    Ok(pending)
+}
+
+/// Activate the pending slashing parameters for the given service at current timestamp.
+///
+/// If the pending slashing parameters are stricter than the current slashing parameters,
+/// the slashing opt-in of all operators is reset, and they have to opt in again.
+/// Else the existing opt-in is kept.
+///
+/// Returns the activated slashing parameters and whether the opt-in was reset.
+pub fn activate_slashing_parameters(
+    store: &mut dyn Storage,
+    env: &Env,
+    service: &Service,
+) -> Result<(SlashingParameters, bool), ContractError> {
+    let pending = PENDING_SLASHING_PARAMETERS
+        .may_load(store, service)?
+        .ok_or(ContractError::InvalidSlashingParameters {
+            msg: "No pending slashing parameters".to_string(),
+        })?;
+
+    if env.block.time.seconds() < pending.effective_timestamp {
+        return Err(ContractError::InvalidSlashingParameters {
+            msg: "Pending slashing parameters are not yet effective".to_string(),
+        });
+    }
+
+    let current = SLASHING_PARAMETERS.may_load(store, service)?.ok_or(
+        ContractError::InvalidSlashingParameters {
+            msg: "Slashing is not enabled".to_string(),
+        },
+    )?;
+
+    let is_stricter = pending.slashing_parameters.is_stricter_than(&current);
+    if is_stricter {
+        reset_slashing_opt_in(store, env, service)?;
+    }
+
+    SLASHING_PARAMETERS.save(
+        store,
+        service,
+        &pending.slashing_parameters,
+        env.block.time.seconds(),
+    )?;
+    PENDING_SLASHING_PARAMETERS.remove(store, service);
+
+    Ok((pending.slashing_parameters, is_stricter))
+}
+
 /// Stores the slashing parameters opt-in status for (service, operator) pair.
 ///
 /// If value is `true`,
@@ -360,15 +1028,702 @@ pub fn reset_slashing_opt_in(
     Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use bvs_library::time::{DAYS, MINUTES};
-    use cosmwasm_std::testing::{mock_dependencies, mock_env};
-
-    #[test]
-    fn test_is_operator_active() {
-        let mut deps = mock_dependencies();
+/// Max length (in bytes) of a slashing category name
+pub const MAX_SLASHING_CATEGORY_LENGTH: usize = 32;
+
+/// Validate the slashing category name,
+/// must be 1 to [MAX_SLASHING_CATEGORY_LENGTH] bytes of ASCII alphanumeric, `_` or `-` characters.
+pub fn validate_slashing_category(category: &str) -> Result<(), ContractError> {
+    if category.is_empty() || category.len() > MAX_SLASHING_CATEGORY_LENGTH {
+        return Err(ContractError::InvalidSlashingCategory {
+            msg: format!(
+                "Category must be between 1 and {MAX_SLASHING_CATEGORY_LENGTH} characters"
+            ),
+        });
+    }
+    if !category
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(ContractError::InvalidSlashingCategory {
+            msg: "Category must only contain alphanumeric, '_' or '-' characters".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Mapping of (service, category) to the slashing parameters of the named slashing category.
+///
+/// Slashing categories are independent of the service default [SLASHING_PARAMETERS],
+/// allowing different faults (e.g. liveness vs equivocation) to have different limits.
+/// The presence of the key in the map indicates that the category is enabled for that service.
+pub(crate) const SLASHING_CATEGORIES: SnapshotMap<(&Service, &str), SlashingParameters> =
+    SnapshotMap::new(
+        "slashing_categories",
+        "slashing_categories_checkpoint",
+        "slashing_categories_changelog",
+        EVERY_SECOND,
+    );
+
+/// Stores the slashing category opt-in status for (service, category, operator).
+///
+/// If value is `true`,
+/// operator has opted in to the slashing category of that service at the given timestamp.
+/// If key isn't found, it means the operator hasn't opted in to the slashing category.
+/// The `false` value is not used.
+pub(crate) const SLASHING_CATEGORY_OPT_IN: SnapshotMap<(&Service, &str, &Operator), bool> =
+    SnapshotMap::new(
+        "slashing_category_opt_in",
+        "slashing_category_opt_in_checkpoint",
+        "slashing_category_opt_in_changelog",
+        EVERY_SECOND,
+    );
+
+/// Get the slashing parameters of the slashing category at the given timestamp.
+/// `None` if the category isn't enabled for the service.
+pub fn get_slashing_category(
+    store: &dyn Storage,
+    service: &Service,
+    category: &str,
+    timestamp: Option<u64>,
+) -> StdResult<Option<SlashingParameters>> {
+    match timestamp {
+        Some(t) => SLASHING_CATEGORIES.may_load_at_height(store, (service, category), t),
+        None => SLASHING_CATEGORIES.may_load(store, (service, category)),
+    }
+}
+
+/// Add the slashing category of the given service at current timestamp.
+///
+/// An enabled category can only be updated with a notice period,
+/// through [propose_slashing_category] and [activate_slashing_category].
+/// The category opt-in left from a previous category of the same name
+/// (kept by [disable_all_slashing]) is reset, operators have to opt in to the new category.
+pub fn set_slashing_category(
+    store: &mut dyn Storage,
+    api: &dyn Api,
+    env: &Env,
+    service: &Service,
+    category: &str,
+    slashing_parameters: &SlashingParameters,
+) -> Result<(), ContractError> {
+    validate_slashing_category(category)?;
+    slashing_parameters.validate(api)?;
+
+    if get_slashing_category(store, service, category, None)?.is_some() {
+        return Err(ContractError::InvalidSlashingCategory {
+            msg: "Category is already enabled, propose new slashing parameters instead".to_string(),
+        });
+    }
+
+    reset_slashing_category_opt_in(store, env, service, category)?;
+    SLASHING_CATEGORIES.save(
+        store,
+        (service, category),
+        slashing_parameters,
+        env.block.time.seconds(),
+    )?;
+    Ok(())
+}
+
+/// Mapping of (service, category) to the proposed slashing parameters of the category pending activation.
+/// A category can only have one pending slashing parameters, proposing again replaces it.
+pub(crate) const PENDING_SLASHING_CATEGORIES: Map<(&Service, &str), PendingSlashingParameters> =
+    Map::new("pending_slashing_categories");
+
+/// Propose new slashing parameters for the enabled slashing category of the given service,
+/// effective after the slashing parameters notice period.
+pub fn propose_slashing_category(
+    store: &mut dyn Storage,
+    api: &dyn Api,
+    env: &Env,
+    service: &Service,
+    category: &str,
+    slashing_parameters: &SlashingParameters,
+) -> Result<PendingSlashingParameters, ContractError> {
+    slashing_parameters.validate(api)?;
+
+    if get_slashing_category(store, service, category, None)?.is_none() {
+        return Err(ContractError::InvalidSlashingCategory {
+            msg: "Category is not enabled for this service".to_string(),
+        });
+    }
+
+    let pending = PendingSlashingParameters {
+        slashing_parameters: slashing_parameters.clone(),
+        effective_timestamp: env
+            .block
+            .time
+            .plus_seconds(get_slashing_parameters_notice_period(store)?)
+            .seconds(),
+    };
+    PENDING_SLASHING_CATEGORIES.save(store, (service, category), &pending)?;
+    Ok(pending)
+}
+
+/// Activate the pending slashing parameters of the slashing category at current timestamp.
+///
+/// If the pending slashing parameters are stricter than the current slashing parameters of the category,
+/// the category opt-in of all operators is reset, and they have to opt in again.
+/// Else the existing opt-in is kept.
+///
+/// Returns the activated slashing parameters and whether the opt-in was reset.
+pub fn activate_slashing_category(
+    store: &mut dyn Storage,
+    env: &Env,
+    service: &Service,
+    category: &str,
+) -> Result<(SlashingParameters, bool), ContractError> {
+    let pending = PENDING_SLASHING_CATEGORIES
+        .may_load(store, (service, category))?
+        .ok_or(ContractError::InvalidSlashingCategory {
+            msg: "No pending slashing parameters for this category".to_string(),
+        })?;
+
+    if env.block.time.seconds() < pending.effective_timestamp {
+        return Err(ContractError::InvalidSlashingCategory {
+            msg: "Pending slashing parameters are not yet effective".to_string(),
+        });
+    }
+
+    let current = get_slashing_category(store, service, category, None)?.ok_or(
+        ContractError::InvalidSlashingCategory {
+            msg: "Category is not enabled for this service".to_string(),
+        },
+    )?;
+
+    let is_stricter = pending.slashing_parameters.is_stricter_than(&current);
+    if is_stricter {
+        reset_slashing_category_opt_in(store, env, service, category)?;
+    }
+
+    SLASHING_CATEGORIES.save(
+        store,
+        (service, category),
+        &pending.slashing_parameters,
+        env.block.time.seconds(),
+    )?;
+    PENDING_SLASHING_CATEGORIES.remove(store, (service, category));
+
+    Ok((pending.slashing_parameters, is_stricter))
+}
+
+/// Remove the slashing category of the given service at current timestamp,
+/// resetting the category opt-in of all operators.
+/// Any pending slashing parameters of the category are removed.
+pub fn remove_slashing_category(
+    store: &mut dyn Storage,
+    env: &Env,
+    service: &Service,
+    category: &str,
+) -> Result<(), ContractError> {
+    if get_slashing_category(store, service, category, None)?.is_none() {
+        return Err(ContractError::InvalidSlashingCategory {
+            msg: "Category is not enabled for this service".to_string(),
+        });
+    }
+
+    reset_slashing_category_opt_in(store, env, service, category)?;
+    SLASHING_CATEGORIES.remove(store, (service, category), env.block.time.seconds())?;
+    PENDING_SLASHING_CATEGORIES.remove(store, (service, category));
+    Ok(())
+}
+
+/// Disable slashing and remove all slashing categories of the given service at current timestamp.
+/// Any pending slashing parameters of the categories are removed.
+/// The opt-ins are kept, as slashing is not possible without slashing parameters.
+pub fn disable_all_slashing(
+    store: &mut dyn Storage,
+    env: &Env,
+    service: &Service,
+) -> StdResult<()> {
+    disable_slashing(store, env, service)?;
+
+    let categories = SLASHING_CATEGORIES
+        .prefix(service)
+        .keys(store, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+
+    for category in categories {
+        SLASHING_CATEGORIES.remove(
+            store,
+            (service, category.as_str()),
+            env.block.time.seconds(),
+        )?;
+    }
+
+    let pending = PENDING_SLASHING_CATEGORIES
+        .prefix(service)
+        .keys(store, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+    for category in pending {
+        PENDING_SLASHING_CATEGORIES.remove(store, (service, category.as_str()));
+    }
+    Ok(())
+}
+
+/// Opt-in operator to the slashing category of the service at current timestamp
+pub fn opt_in_to_slashing_category(
+    store: &mut dyn Storage,
+    env: &Env,
+    service: &Service,
+    category: &str,
+    operator: &Operator,
+) -> StdResult<()> {
+    SLASHING_CATEGORY_OPT_IN.save(
+        store,
+        (service, category, operator),
+        &true,
+        env.block.time.seconds(),
+    )?;
+    Ok(())
+}
+
+/// Check if the operator has opted in to the slashing category of the service at the given timestamp.
+pub fn is_operator_opted_in_to_slashing_category(
+    store: &dyn Storage,
+    service: &Service,
+    category: &str,
+    operator: &Operator,
+    timestamp: Option<u64>,
+) -> StdResult<bool> {
+    let key = (service, category, operator);
+    let is_opted_in = match timestamp {
+        Some(t) => SLASHING_CATEGORY_OPT_IN
+            .may_load_at_height(store, key, t)?
+            .is_some(),
+        None => SLASHING_CATEGORY_OPT_IN.may_load(store, key)?.is_some(),
+    };
+    Ok(is_opted_in)
+}
+
+/// Clears the slashing category opt-in status for the given service and category at current timestamp.
+pub fn reset_slashing_category_opt_in(
+    store: &mut dyn Storage,
+    env: &Env,
+    service: &Service,
+    category: &str,
+) -> Result<(), ContractError> {
+    let operator_keys = SLASHING_CATEGORY_OPT_IN
+        .prefix((service, category))
+        .keys(store, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Operator>>>()?;
+
+    for operator in operator_keys {
+        SLASHING_CATEGORY_OPT_IN.remove(
+            store,
+            (service, category, &operator),
+            env.block.time.seconds(),
+        )?;
+    }
+    Ok(())
+}
+
+/// Type of the operator signing key.
+/// BLS12-381 keys are out of scope, their proof of possession can't be verified
+/// without the `cosmwasm_2_1` capability, which the target chains don't support.
+#[cw_serde]
+pub enum KeyType {
+    /// secp256k1 public key, 33 bytes compressed or 65 bytes uncompressed SEC1 encoding
+    Secp256k1 = 1,
+
+    /// ed25519 public key, 32 bytes
+    Ed25519 = 2,
+}
+
+impl From<KeyType> for u8 {
+    fn from(value: KeyType) -> u8 {
+        value as u8
+    }
+}
+
+impl KeyType {
+    /// Validate the length (and the SEC1 prefix of secp256k1 keys) of the public key for this key type.
+    pub fn validate(&self, public_key: &HexBinary) -> Result<(), ContractError> {
+        let valid = match self {
+            KeyType::Secp256k1 => matches!(public_key.len(), 33 | 65),
+            KeyType::Ed25519 => public_key.len() == 32,
+        };
+        if !valid {
+            return Err(ContractError::InvalidOperatorKey {
+                msg: format!("Invalid public key length for {self:?}"),
+            });
+        }
+
+        // 0x02 or 0x03 (the parity of y) for compressed keys, 0x04 for uncompressed keys
+        if let KeyType::Secp256k1 = self {
+            if !matches!(
+                (public_key.len(), public_key[0]),
+                (33, 0x02 | 0x03) | (65, 0x04)
+            ) {
+                return Err(ContractError::InvalidOperatorKey {
+                    msg: "Invalid public key prefix for Secp256k1".to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Verify the proof of possession `signature` of the public key,
+    /// the signature of the [`operator_key_message`] made with the key.
+    /// secp256k1 signatures are 64 bytes (r || s) over the message hash,
+    /// ed25519 signatures are 64 bytes over the message hash.
+    pub fn verify_proof_of_possession(
+        &self,
+        api: &dyn Api,
+        message: &[u8],
+        public_key: &HexBinary,
+        signature: &HexBinary,
+    ) -> Result<(), ContractError> {
+        let verified = match self {
+            KeyType::Secp256k1 => api.secp256k1_verify(message, signature, public_key),
+            KeyType::Ed25519 => api.ed25519_verify(message, signature, public_key),
+        };
+        if !matches!(verified, Ok(true)) {
+            return Err(ContractError::InvalidOperatorKey {
+                msg: "Invalid proof of possession signature".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// The message the operator signs with its key to prove possession of it,
+/// the SHA-256 hash of `service || operator || key_type || public_key`
+/// (the addresses as UTF-8 strings, the key type as a single byte).
+/// Binding the service and operator prevents registering a key (or replaying a proof) of another operator.
+pub fn operator_key_message(
+    service: &Service,
+    operator: &Operator,
+    key_type: &KeyType,
+    public_key: &HexBinary,
+) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(service.as_bytes())
+        .chain_update(operator.as_bytes())
+        .chain_update([u8::from(key_type.clone())])
+        .chain_update(public_key.as_slice())
+        .finalize()
+        .into()
+}
+
+/// Mapping of (service, operator, key type) to the operator's public signing key for that service.
+///
+/// Allows the operator to sign attestations for the service with a key other than its account,
+/// services can verify the signature against the key valid at the time of the attestation.
+/// One key per key type is valid at a time, setting a new key rotates the previous key.
+pub(crate) const OPERATOR_KEYS: SnapshotMap<(&Service, &Operator, u8), HexBinary> =
+    SnapshotMap::new(
+        "operator_keys",
+        "operator_keys_checkpoint",
+        "operator_keys_changelog",
+        EVERY_SECOND,
+    );
+
+/// Set (or rotate) the operator's public key of the key type for the service at current timestamp.
+/// The `signature` must prove possession of the key, see [`KeyType::verify_proof_of_possession`].
+#[allow(clippy::too_many_arguments)]
+pub fn set_operator_key(
+    store: &mut dyn Storage,
+    api: &dyn Api,
+    env: &Env,
+    service: &Service,
+    operator: &Operator,
+    key_type: KeyType,
+    public_key: &HexBinary,
+    signature: &HexBinary,
+) -> Result<(), ContractError> {
+    key_type.validate(public_key)?;
+    let message = operator_key_message(service, operator, &key_type, public_key);
+    key_type.verify_proof_of_possession(api, &message, public_key, signature)?;
+    OPERATOR_KEYS.save(
+        store,
+        (service, operator, key_type.into()),
+        public_key,
+        env.block.time.seconds(),
+    )?;
+    Ok(())
+}
+
+/// Remove the operator's public key of the key type for the service at current timestamp.
+pub fn remove_operator_key(
+    store: &mut dyn Storage,
+    env: &Env,
+    service: &Service,
+    operator: &Operator,
+    key_type: KeyType,
+) -> Result<(), ContractError> {
+    let key = (service, operator, key_type.into());
+    if OPERATOR_KEYS.may_load(store, key)?.is_none() {
+        return Err(ContractError::InvalidOperatorKey {
+            msg: "Operator key is not set for this service".to_string(),
+        });
+    }
+    OPERATOR_KEYS.remove(store, key, env.block.time.seconds())?;
+    Ok(())
+}
+
+/// Get the operator's public key of the key type for the service
+/// at the given timestamp (or current if `None`).
+pub fn get_operator_key(
+    store: &dyn Storage,
+    service: &Service,
+    operator: &Operator,
+    key_type: KeyType,
+    timestamp: Option<u64>,
+) -> StdResult<Option<HexBinary>> {
+    let key = (service, operator, key_type.into());
+    match timestamp {
+        Some(t) => OPERATOR_KEYS.may_load_at_height(store, key, t),
+        None => OPERATOR_KEYS.may_load(store, key),
+    }
+}
+
+/// Max commission (in bips) an operator can take from the rewards of a service, 10_000 bips (100%).
+pub const MAX_COMMISSION_BIPS: u16 = 10_000;
+
+/// Default delay (in seconds) between proposing a new commission and activating it.
+pub const DEFAULT_COMMISSION_CHANGE_DELAY: u64 = 7 * DAYS;
+
+/// Delay (in seconds) between proposing a new commission and activating it.
+/// Use [get_commission_change_delay] to get the delay with the default applied.
+pub(crate) const COMMISSION_CHANGE_DELAY: Item<u64> = Item::new("commission_change_delay");
+
+/// Get the commission change delay, [DEFAULT_COMMISSION_CHANGE_DELAY] if not set
+pub fn get_commission_change_delay(store: &dyn Storage) -> StdResult<u64> {
+    let delay = COMMISSION_CHANGE_DELAY
+        .may_load(store)?
+        .unwrap_or(DEFAULT_COMMISSION_CHANGE_DELAY);
+    Ok(delay)
+}
+
+/// Mapping of (operator, service) to the commission (in bips)
+/// the operator takes from the rewards of the service.
+/// If the key isn't found, the commission is 0.
+/// Use [get_commission] to get the commission at a timestamp.
+pub(crate) const OPERATOR_COMMISSIONS: SnapshotMap<(&Operator, &Service), u16> = SnapshotMap::new(
+    "operator_commissions",
+    "operator_commissions_checkpoint",
+    "operator_commissions_changelog",
+    EVERY_SECOND,
+);
+
+#[cw_serde]
+pub struct PendingCommission {
+    /// The proposed commission (in bips).
+    pub commission_bips: u16,
+    /// The timestamp (in seconds) at which the proposed commission can be activated.
+    pub effective_timestamp: u64,
+}
+
+/// Mapping of (operator, service) to the proposed commission pending activation.
+/// An operator can only have one pending commission per service, proposing again replaces it.
+pub(crate) const PENDING_COMMISSIONS: Map<(&Operator, &Service), PendingCommission> =
+    Map::new("pending_commissions");
+
+/// Get the commission (in bips) of the operator for the service at the given timestamp,
+/// or the current commission if `None`. 0 if not set.
+///
+/// #### Warning
+/// A commission activated at `timestamp` is only effective from `timestamp + 1`.
+pub fn get_commission(
+    store: &dyn Storage,
+    key: (&Operator, &Service),
+    timestamp: Option<u64>,
+) -> StdResult<u16> {
+    let commission = match timestamp {
+        Some(t) => OPERATOR_COMMISSIONS.may_load_at_height(store, key, t)?,
+        None => OPERATOR_COMMISSIONS.may_load(store, key)?,
+    };
+    Ok(commission.unwrap_or(0))
+}
+
+/// Propose a new commission (in bips) of the operator for the service,
+/// effective after the commission change delay.
+pub fn propose_commission(
+    store: &mut dyn Storage,
+    env: &Env,
+    key: (&Operator, &Service),
+    commission_bips: u16,
+) -> Result<PendingCommission, ContractError> {
+    if commission_bips > MAX_COMMISSION_BIPS {
+        return Err(ContractError::InvalidCommission {
+            msg: "Commission exceeds 10,000 bips (100%)".to_string(),
+        });
+    }
+
+    let pending = PendingCommission {
+        commission_bips,
+        effective_timestamp: env
+            .block
+            .time
+            .plus_seconds(get_commission_change_delay(store)?)
+            .seconds(),
+    };
+    PENDING_COMMISSIONS.save(store, key, &pending)?;
+    Ok(pending)
+}
+
+/// Activate the pending commission of the operator for the service at current timestamp.
+/// Returns the activated commission (in bips).
+pub fn activate_commission(
+    store: &mut dyn Storage,
+    env: &Env,
+    key: (&Operator, &Service),
+) -> Result<u16, ContractError> {
+    let pending =
+        PENDING_COMMISSIONS
+            .may_load(store, key)?
+            .ok_or(ContractError::InvalidCommission {
+                msg: "No pending commission".to_string(),
+            })?;
+
+    if env.block.time.seconds() < pending.effective_timestamp {
+        return Err(ContractError::InvalidCommission {
+            msg: "Pending commission is not yet effective".to_string(),
+        });
+    }
+
+    OPERATOR_COMMISSIONS.save(
+        store,
+        key,
+        &pending.commission_bips,
+        env.block.time.seconds(),
+    )?;
+    PENDING_COMMISSIONS.remove(store, key);
+    Ok(pending.commission_bips)
+}
+
+/// Permission of an operator manager to act on behalf of the operator.
+#[cw_serde]
+pub enum ManagerPermission {
+    /// Register, deregister and finalize deregistration of services to the operator.
+    /// Without [`ManagerPermission::SlashingOptIn`], an activated registration
+    /// doesn't opt in the operator to the slashing of the service.
+    Registration,
+
+    /// Opt in to slashing and slashing categories of services
+    SlashingOptIn,
+
+    /// Update the operator metadata
+    Metadata,
+}
+
+/// Mapping of (operator, manager) to the permissions the operator granted to the manager.
+///
+/// A manager can act on behalf of the operator for the granted permissions,
+/// allowing the operator account (e.g. a cold multisig) to not sign every action.
+/// Only the operator can set or revoke its managers.
+pub(crate) const OPERATOR_MANAGERS: Map<(&Operator, &Addr), Vec<ManagerPermission>> =
+    Map::new("operator_managers");
+
+/// Returns the operator the message is executed for.
+///
+/// If `operator` is `None` or the `sender`, the `sender` is the operator.
+/// Else the `sender` must be a manager of the operator with the `permission`.
+pub fn require_operator_or_manager(
+    store: &dyn Storage,
+    sender: &Addr,
+    operator: Option<Addr>,
+    permission: ManagerPermission,
+) -> Result<Operator, ContractError> {
+    let operator = match operator {
+        Some(operator) if operator != sender => operator,
+        _ => return Ok(sender.clone()),
+    };
+
+    if is_operator_manager(store, &operator, sender, permission)? {
+        Ok(operator)
+    } else {
+        Err(ContractError::Unauthorized {})
+    }
+}
+
+/// Whether the `manager` is a manager of the `operator` with the `permission`.
+pub fn is_operator_manager(
+    store: &dyn Storage,
+    operator: &Operator,
+    manager: &Addr,
+    permission: ManagerPermission,
+) -> StdResult<bool> {
+    let permissions = OPERATOR_MANAGERS
+        .may_load(store, (operator, manager))?
+        .unwrap_or_default();
+    Ok(permissions.contains(&permission))
+}
+
+/// Set of services that have enabled registry hooks.
+/// The `false` value is not used, the key is removed when hooks are disabled.
+pub(crate) const SERVICE_HOOKS: Map<&Service, bool> = Map::new("service_hooks");
+
+/// Returns whether the service has enabled registry hooks.
+pub fn is_service_hooks_enabled(store: &dyn Storage, service: &Service) -> StdResult<bool> {
+    Ok(SERVICE_HOOKS.may_load(store, service)?.unwrap_or(false))
+}
+
+/// The `vault-router` contract address, used to query the vaults of an operator.
+/// Set by the owner, required for [`AdmissionPolicy::MinStake`].
+pub(crate) const ROUTER: Item<Addr> = Item::new("router");
+
+/// Admission policy of a service,
+/// applied when an operator registers the service with `RegisterServiceToOperator`.
+/// An admitted operator's registration becomes [`RegistrationStatus::Active`] immediately,
+/// without the service having to register the operator.
+/// An operator that isn't admitted is pending until the service registers it, as with [`AdmissionPolicy::Manual`].
+#[cw_serde]
+#[derive(Default)]
+pub enum AdmissionPolicy {
+    /// The service must register each operator, the operator registration is pending until then.
+    #[default]
+    Manual,
+
+    /// Any registered operator is admitted.
+    Open,
+
+    /// Only operators in the service admission allowlist are admitted.
+    Allowlist,
+
+    /// Only operators with at least `amount` of the `asset_id` (CAIP-19)
+    /// in their whitelisted vaults of the `vault-router` are admitted.
+    MinStake { asset_id: String, amount: Uint128 },
+}
+
+/// Mapping of service to its [AdmissionPolicy].
+/// If the key isn't found, the policy is [`AdmissionPolicy::Manual`].
+pub(crate) const ADMISSION_POLICIES: Map<&Service, AdmissionPolicy> =
+    Map::new("admission_policies");
+
+/// Set of (service, operator) allowlisted by the service for [`AdmissionPolicy::Allowlist`].
+/// The key is removed when the operator is removed from the allowlist.
+pub(crate) const ADMISSION_ALLOWLIST: Map<(&Service, &Operator), ()> =
+    Map::new("admission_allowlist");
+
+/// Get the admission policy of the service, [`AdmissionPolicy::Manual`] if not set
+pub fn get_admission_policy(store: &dyn Storage, service: &Service) -> StdResult<AdmissionPolicy> {
+    let policy = ADMISSION_POLICIES
+        .may_load(store, service)?
+        .unwrap_or_default();
+    Ok(policy)
+}
+
+/// Returns whether the operator is in the admission allowlist of the service.
+pub fn is_operator_allowlisted(
+    store: &dyn Storage,
+    service: &Service,
+    operator: &Operator,
+) -> bool {
+    ADMISSION_ALLOWLIST.has(store, (service, operator))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bvs_library::time::MINUTES;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    #[test]
+    fn test_is_operator_active() {
+        let mut deps = mock_dependencies();
 
         let operator = deps.api.addr_make("operator");
         let operator2 = deps.api.addr_make("operator2");
@@ -464,6 +1819,125 @@ mod tests {
         assert_eq!(status, RegistrationStatus::ServiceRegistered);
     }
 
+    #[test]
+    fn test_max_active_relationships() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let operator = deps.api.addr_make("operator");
+        let service = deps.api.addr_make("service");
+        let service2 = deps.api.addr_make("service2");
+
+        assert_eq!(
+            get_operator_max_active_relationships(&deps.storage, &operator).unwrap(),
+            DEFAULT_MAX_ACTIVE_RELATIONSHIPS_PER_OPERATOR
+        );
+        assert_eq!(
+            get_service_max_active_relationships(&deps.storage, &service).unwrap(),
+            DEFAULT_MAX_ACTIVE_RELATIONSHIPS_PER_SERVICE
+        );
+
+        OPERATOR_MAX_ACTIVE_RELATIONSHIPS
+            .save(&mut deps.storage, &operator, &1)
+            .unwrap();
+
+        set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service),
+            RegistrationStatus::Active,
+        )
+        .unwrap();
+        assert_eq!(
+            get_operator_active_registration_count(&deps.storage, &operator).unwrap(),
+            1
+        );
+        assert_eq!(
+            get_service_active_registration_count(&deps.storage, &service).unwrap(),
+            1
+        );
+
+        // setting the same relationship active again does not double count
+        set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service),
+            RegistrationStatus::Active,
+        )
+        .unwrap();
+        assert_eq!(
+            get_operator_active_registration_count(&deps.storage, &operator).unwrap(),
+            1
+        );
+
+        // pending relationships are not counted
+        set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service2),
+            RegistrationStatus::ServiceRegistered,
+        )
+        .unwrap();
+
+        let err = set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service2),
+            RegistrationStatus::Active,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::MaxActiveRelationshipsExceeded {
+                msg: "Operator has reached its max active relationships".to_string()
+            }
+        );
+
+        // deactivating frees up a slot
+        set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service),
+            RegistrationStatus::Inactive,
+        )
+        .unwrap();
+        assert_eq!(
+            get_operator_active_registration_count(&deps.storage, &operator).unwrap(),
+            0
+        );
+        assert_eq!(
+            get_service_active_registration_count(&deps.storage, &service).unwrap(),
+            0
+        );
+
+        set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator, &service2),
+            RegistrationStatus::Active,
+        )
+        .unwrap();
+
+        // service cap
+        MAX_ACTIVE_RELATIONSHIPS_PER_SERVICE
+            .save(&mut deps.storage, &1)
+            .unwrap();
+        let operator2 = deps.api.addr_make("operator2");
+        let err = set_registration_status(
+            &mut deps.storage,
+            &env,
+            (&operator2, &service2),
+            RegistrationStatus::Active,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::MaxActiveRelationshipsExceeded {
+                msg: "Service has reached its max active relationships".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_slashing_parameters_validate() {
         let deps = mock_dependencies();
@@ -475,6 +1949,8 @@ mod tests {
                 destination: Some(Addr::unchecked("invalid_address")),
                 max_slashing_bips: 100,
                 resolution_window: 60 * MINUTES,
+                destinations: vec![],
+                asset_ids: vec![],
             };
 
             assert_eq!(
@@ -490,6 +1966,8 @@ mod tests {
                 destination: Some(deps.api.addr_make("destination")),
                 max_slashing_bips: 10_001,
                 resolution_window: 60 * MINUTES,
+                destinations: vec![],
+                asset_ids: vec![],
             };
 
             assert_eq!(
@@ -507,6 +1985,8 @@ mod tests {
                 destination: Some(deps.api.addr_make("destination")),
                 max_slashing_bips: 10_000,
                 resolution_window: 7 * DAYS,
+                destinations: vec![],
+                asset_ids: vec![],
             };
 
             assert!(valid_slashing_parameters.validate(&deps.api).is_ok());
@@ -517,12 +1997,266 @@ mod tests {
                 destination: None,
                 max_slashing_bips: 0,
                 resolution_window: 0,
+                destinations: vec![],
+                asset_ids: vec![],
             };
 
             assert!(valid_slashing_parameters.validate(&deps.api).is_ok());
         }
     }
 
+    #[test]
+    fn test_slashing_parameters_validate_destinations() {
+        let deps = mock_dependencies();
+        let destination =
+            |recipient: SlashingRecipient, bips: u16| WeightedDestination { recipient, bips };
+        let users = SlashingRecipient::Address(deps.api.addr_make("users"));
+        let insurance = SlashingRecipient::Address(deps.api.addr_make("insurance"));
+
+        let valid = SlashingParameters {
+            destination: None,
+            max_slashing_bips: 1000,
+            resolution_window: 60 * MINUTES,
+            destinations: vec![
+                destination(users.clone(), 7000),
+                destination(insurance.clone(), 2000),
+                destination(SlashingRecipient::Burn, 1000),
+            ],
+            asset_ids: vec![],
+        };
+        assert!(valid.validate(&deps.api).is_ok());
+
+        let cases = [
+            (
+                SlashingParameters {
+                    destination: Some(deps.api.addr_make("destination")),
+                    ..valid.clone()
+                },
+                "Destination must be None when destinations are set",
+            ),
+            (
+                SlashingParameters {
+                    destinations: vec![destination(users.clone(), 1000); 11],
+                    ..valid.clone()
+                },
+                "Destinations exceed the max of 10",
+            ),
+            (
+                SlashingParameters {
+                    destinations: vec![
+                        destination(SlashingRecipient::Address(Addr::unchecked("invalid")), 5000),
+                        destination(users.clone(), 5000),
+                    ],
+                    ..valid.clone()
+                },
+                "Invalid destination address format",
+            ),
+            (
+                SlashingParameters {
+                    destinations: vec![
+                        destination(users.clone(), 10_000),
+                        destination(insurance.clone(), 0),
+                    ],
+                    ..valid.clone()
+                },
+                "Destination bips must be greater than zero",
+            ),
+            (
+                SlashingParameters {
+                    destinations: vec![
+                        destination(users.clone(), 7000),
+                        destination(insurance.clone(), 2000),
+                    ],
+                    ..valid.clone()
+                },
+                "Destinations bips must add up to 10,000 bips (100%)",
+            ),
+        ];
+
+        for (slashing_parameters, msg) in cases {
+            assert_eq!(
+                slashing_parameters.validate(&deps.api).unwrap_err(),
+                ContractError::InvalidSlashingParameters {
+                    msg: msg.to_string()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_slashing_parameters_is_stricter_than() {
+        let current = SlashingParameters {
+            destination: None,
+            max_slashing_bips: 1000,
+            resolution_window: 1000,
+            destinations: vec![],
+            asset_ids: vec![],
+        };
+
+        let higher_bips = SlashingParameters {
+            max_slashing_bips: 1001,
+            ..current.clone()
+        };
+        assert!(higher_bips.is_stricter_than(&current));
+
+        let shorter_window = SlashingParameters {
+            resolution_window: 999,
+            ..current.clone()
+        };
+        assert!(shorter_window.is_stricter_than(&current));
+
+        let loosened = SlashingParameters {
+            destination: None,
+            max_slashing_bips: 999,
+            resolution_window: 1001,
+            destinations: vec![],
+            asset_ids: vec![],
+        };
+        assert!(!loosened.is_stricter_than(&current));
+
+        let new_destination = SlashingParameters {
+            destination: Some(Addr::unchecked("destination")),
+            ..current.clone()
+        };
+        assert!(!new_destination.is_stricter_than(&current));
+
+        // accepting more assets is stricter, accepting fewer isn't
+        let btc = "bip122:000000000019d6689c085ae165831e93/slip44:0".to_string();
+        let bbn = "cosmos:bbn-1/bank:ubbn".to_string();
+        let only_btc = SlashingParameters {
+            asset_ids: vec![btc.clone()],
+            ..current.clone()
+        };
+        assert!(!only_btc.is_stricter_than(&current));
+        assert!(current.is_stricter_than(&only_btc));
+
+        let btc_and_bbn = SlashingParameters {
+            asset_ids: vec![btc, bbn],
+            ..current.clone()
+        };
+        assert!(btc_and_bbn.is_stricter_than(&only_btc));
+        assert!(!only_btc.is_stricter_than(&btc_and_bbn));
+    }
+
+    #[test]
+    fn test_slashing_parameters_validate_asset_ids() {
+        let deps = mock_dependencies();
+        let params = |asset_ids: Vec<&str>| SlashingParameters {
+            destination: None,
+            max_slashing_bips: 1000,
+            resolution_window: 60 * MINUTES,
+            destinations: vec![],
+            asset_ids: asset_ids.into_iter().map(String::from).collect(),
+        };
+
+        let valid = params(vec![
+            "cosmos:bbn-1/bank:ubbn",
+            "cosmos:bbn-1/cw20:bbn1contract",
+            "bip122:000000000019d6689c085ae165831e93/slip44:0",
+        ]);
+        assert_eq!(valid.validate(&deps.api), Ok(()));
+        assert!(valid.accepts_asset_id("cosmos:bbn-1/bank:ubbn"));
+        assert!(!valid.accepts_asset_id("cosmos:bbn-1/bank:uother"));
+        assert!(params(vec![]).accepts_asset_id("cosmos:bbn-1/bank:uother"));
+
+        for invalid in [
+            "",
+            "ubbn",
+            "cosmos:bbn-1",
+            "cosmos:bbn-1/ubbn",
+            ":bbn-1/bank:ubbn",
+        ] {
+            assert_eq!(
+                params(vec![invalid]).validate(&deps.api),
+                Err(ContractError::InvalidSlashingParameters {
+                    msg: "Invalid asset id format".to_string()
+                })
+            );
+        }
+
+        assert_eq!(
+            params(vec!["cosmos:bbn-1/bank:ubbn", "cosmos:bbn-1/bank:ubbn"]).validate(&deps.api),
+            Err(ContractError::InvalidSlashingParameters {
+                msg: "Duplicate asset id".to_string()
+            })
+        );
+
+        let too_many: Vec<String> = (0..11).map(|i| format!("cosmos:bbn-1/bank:u{i}")).collect();
+        assert_eq!(
+            params(too_many.iter().map(String::as_str).collect()).validate(&deps.api),
+            Err(ContractError::InvalidSlashingParameters {
+                msg: "Asset ids exceed the max of 10".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_key_type_validate() {
+        let key = |len: usize| HexBinary::from(vec![1u8; len]);
+        let secp256k1_key = |prefix: u8, len: usize| {
+            let mut key = vec![1u8; len];
+            key[0] = prefix;
+            HexBinary::from(key)
+        };
+
+        assert!(KeyType::Secp256k1.validate(&secp256k1_key(2, 33)).is_ok());
+        assert!(KeyType::Secp256k1.validate(&secp256k1_key(3, 33)).is_ok());
+        assert!(KeyType::Secp256k1.validate(&secp256k1_key(4, 65)).is_ok());
+        assert!(KeyType::Ed25519.validate(&key(32)).is_ok());
+
+        assert_eq!(
+            KeyType::Secp256k1.validate(&key(32)),
+            Err(ContractError::InvalidOperatorKey {
+                msg: "Invalid public key length for Secp256k1".to_string(),
+            })
+        );
+        for public_key in [
+            secp256k1_key(1, 33),
+            secp256k1_key(4, 33),
+            secp256k1_key(2, 65),
+            secp256k1_key(3, 65),
+        ] {
+            assert_eq!(
+                KeyType::Secp256k1.validate(&public_key),
+                Err(ContractError::InvalidOperatorKey {
+                    msg: "Invalid public key prefix for Secp256k1".to_string(),
+                })
+            );
+        }
+        assert_eq!(
+            KeyType::Ed25519.validate(&key(33)),
+            Err(ContractError::InvalidOperatorKey {
+                msg: "Invalid public key length for Ed25519".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_slashing_category() {
+        assert!(validate_slashing_category("liveness").is_ok());
+        assert!(validate_slashing_category("double-sign_v2").is_ok());
+        assert!(validate_slashing_category(&"a".repeat(MAX_SLASHING_CATEGORY_LENGTH)).is_ok());
+
+        assert_eq!(
+            validate_slashing_category(""),
+            Err(ContractError::InvalidSlashingCategory {
+                msg: "Category must be between 1 and 32 characters".to_string(),
+            })
+        );
+        assert_eq!(
+            validate_slashing_category(&"a".repeat(MAX_SLASHING_CATEGORY_LENGTH + 1)),
+            Err(ContractError::InvalidSlashingCategory {
+                msg: "Category must be between 1 and 32 characters".to_string(),
+            })
+        );
+        assert_eq!(
+            validate_slashing_category("live/ness"),
+            Err(ContractError::InvalidSlashingCategory {
+                msg: "Category must only contain alphanumeric, '_' or '-' characters".to_string(),
+            })
+        );
+    }
+
     #[test]
     fn test_is_operator_opted_in_to_slashing() {
         let mut deps = mock_dependencies();
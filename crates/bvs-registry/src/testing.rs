@@ -20,7 +20,8 @@ impl TestingContract<InstantiateMsg, ExecuteMsg, QueryMsg, MigrateMsg> for Regis
                 crate::contract::instantiate,
                 crate::contract::query,
             )
-            .with_migrate(crate::contract::migrate),
+            .with_migrate(crate::contract::migrate)
+            .with_reply(crate::contract::reply),
         )
     }
 
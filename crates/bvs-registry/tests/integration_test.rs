@@ -1,13 +1,22 @@
-use bvs_library::testing::TestingContract;
+use bvs_library::testing::{Account, TestingContract};
 use bvs_pauser::api::PauserError;
 use bvs_pauser::testing::PauserContract;
 use bvs_registry::msg::{
-    ExecuteMsg, IsOperatorOptedInToSlashingResponse, Metadata, QueryMsg, StatusResponse,
+    ActiveCountResponse, CommissionChangeDelayResponse, CommissionResponse,
+    DeregistrationDelayResponse, ExecuteMsg, IsOperatorActiveResponse,
+    IsOperatorOptedInToSlashingResponse, IsServiceResponse, Metadata, MetadataResponse,
+    OperatorKeyResponse, OperatorListResponse, OperatorManager, OperatorManagerListResponse,
+    PendingSlashingParametersResponse, QueryMsg, Registration, RegistrationListResponse,
+    ServiceHooksEnabledResponse, ServiceListResponse, SlashingParametersNoticePeriodResponse,
+    SlashingParametersResponse, StatusResponse, SunsetResponse,
 };
 use bvs_registry::testing::RegistryContract;
-use bvs_registry::{ContractError, RegistrationStatus, SlashingParameters};
+use bvs_registry::{
+    operator_key_message, ContractError, KeyType, ManagerPermission, PendingSlashingParameters,
+    RegistrationStatus, SlashingParameters, Sunset,
+};
 use cosmwasm_std::testing::mock_env;
-use cosmwasm_std::{Addr, Event, StdError};
+use cosmwasm_std::{Addr, Event, HexBinary, StdError};
 use cw_multi_test::App;
 use cw_storage_plus::Map;
 
@@ -115,6 +124,7 @@ fn operator_register_service_but_service_not_registered() {
 
     let register_msg = &ExecuteMsg::RegisterServiceToOperator {
         service: app.api().addr_make("service/11111").to_string(),
+        operator: None,
     };
 
     let err = registry
@@ -144,6 +154,7 @@ fn operator_register_service_but_self_not_operator() {
 
     let register_msg = &ExecuteMsg::RegisterServiceToOperator {
         service: service.to_string(),
+        operator: None,
     };
 
     let err = registry
@@ -187,6 +198,7 @@ fn register_lifecycle_operator_first() {
     // Register Service to Operator
     let register_msg = &ExecuteMsg::RegisterServiceToOperator {
         service: service.to_string(),
+        operator: None,
     };
     let res = registry.execute(&mut app, &operator, register_msg).unwrap();
     assert_eq!(
@@ -313,6 +325,7 @@ fn register_lifecycle_service_first() {
     // Register Service to Operator
     let register_msg = &ExecuteMsg::RegisterServiceToOperator {
         service: service.to_string(),
+        operator: None,
     };
     let res = registry.execute(&mut app, &operator, register_msg).unwrap();
     assert_eq!(
@@ -393,6 +406,24 @@ fn update_metadata_successfully() {
                 .add_attribute("service", service.as_str())
         ]
     );
+
+    // name is kept
+    let metadata: MetadataResponse = registry
+        .query(
+            &app,
+            &QueryMsg::ServiceMetadata {
+                service: service.to_string(),
+                timestamp: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        metadata,
+        MetadataResponse(Some(Metadata {
+            name: Some("New Service Name".to_string()),
+            uri: Some("https://new-new-service.com".to_string()),
+        }))
+    );
 }
 
 #[test]
@@ -509,6 +540,7 @@ fn register_deregister_lifecycle() {
                     &operator,
                     &ExecuteMsg::RegisterServiceToOperator {
                         service: curr_service.to_string(),
+                        operator: None,
                     },
                 )
                 .unwrap();
@@ -518,6 +550,7 @@ fn register_deregister_lifecycle() {
                     &operator2,
                     &ExecuteMsg::RegisterServiceToOperator {
                         service: curr_service.to_string(),
+                        operator: None,
                     },
                 )
                 .unwrap();
@@ -614,6 +647,7 @@ fn register_deregister_lifecycle() {
             &operator,
             &ExecuteMsg::DeregisterServiceFromOperator {
                 service: service.to_string(),
+                operator: None,
             },
         )
         .unwrap();
@@ -630,7 +664,7 @@ fn register_deregister_lifecycle() {
                 },
             )
             .unwrap();
-        assert_eq!(status, StatusResponse(0)); // inactive
+        assert_eq!(status, StatusResponse(4)); // exiting
 
         let status: StatusResponse = registry
             .query(
@@ -651,7 +685,7 @@ fn register_deregister_lifecycle() {
         block.time = block.time.plus_seconds(10);
     });
 
-    // check if service is deregistered from operator at current timestamp - 5
+    // check if service is exiting from operator at current timestamp - 5
     let status: StatusResponse = registry
         .query(
             &app,
@@ -662,6 +696,43 @@ fn register_deregister_lifecycle() {
             },
         )
         .unwrap();
+    assert_eq!(status, StatusResponse(4)); // exiting
+
+    // finalize the deregistration after the deregistration delay
+    let DeregistrationDelayResponse(deregistration_delay) = registry
+        .query(&app, &QueryMsg::DeregistrationDelay {})
+        .unwrap();
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(deregistration_delay);
+    });
+    registry
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::FinalizeDeregistration {
+                service: service.to_string(),
+                operator: operator.to_string(),
+            },
+        )
+        .unwrap();
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(1);
+    });
+
+    // check if service is deregistered from operator at current timestamp
+    let status: StatusResponse = registry
+        .query(
+            &app,
+            &QueryMsg::Status {
+                service: service.to_string(),
+                operator: operator.to_string(),
+                timestamp: Some(app.block_info().time.seconds()),
+            },
+        )
+        .unwrap();
     assert_eq!(status, StatusResponse(0)); // inactive
 
     // check if service2 is still registered to operator at current timestamp - 5
@@ -735,6 +806,7 @@ fn enable_slashing_lifecycle() {
                 &operator,
                 &ExecuteMsg::RegisterServiceToOperator {
                     service: service.to_string(),
+                    operator: None,
                 },
             )
             .unwrap();
@@ -753,6 +825,7 @@ fn enable_slashing_lifecycle() {
     {
         let opt_in_msg = &ExecuteMsg::OperatorOptInToSlashing {
             service: service.to_string(),
+            operator: None,
         };
         let err = registry
             .execute(&mut app, &operator, opt_in_msg)
@@ -773,6 +846,8 @@ fn enable_slashing_lifecycle() {
             destination: Some(burn_address.clone()),
             max_slashing_bips: 5000, // 50%
             resolution_window: 1000,
+            destinations: vec![],
+            asset_ids: vec![],
         };
         let enable_slashing_msg = &ExecuteMsg::EnableSlashing {
             slashing_parameters: slashing_parameters.clone(),
@@ -812,6 +887,7 @@ fn enable_slashing_lifecycle() {
     {
         let opt_in_msg = &ExecuteMsg::OperatorOptInToSlashing {
             service: service.to_string(),
+            operator: None,
         };
         let err = registry
             .execute(&mut app, &operator, opt_in_msg)
@@ -837,6 +913,7 @@ fn enable_slashing_lifecycle() {
     {
         let opt_in_msg = &ExecuteMsg::OperatorOptInToSlashing {
             service: service.to_string(),
+            operator: None,
         };
         let res = registry.execute(&mut app, &operator, opt_in_msg).unwrap();
 
@@ -872,6 +949,7 @@ fn enable_slashing_lifecycle() {
     {
         let opt_in_msg = &ExecuteMsg::OperatorOptInToSlashing {
             service: service.to_string(),
+            operator: None,
         };
         let err = registry
             .execute(&mut app, &operator2, opt_in_msg)
@@ -895,6 +973,7 @@ fn enable_slashing_lifecycle() {
                 &operator2,
                 &ExecuteMsg::RegisterServiceToOperator {
                     service: service.to_string(),
+                    operator: None,
                 },
             )
             .unwrap();
@@ -937,13 +1016,45 @@ fn enable_slashing_lifecycle() {
             destination: Some(burn_address.clone()),
             max_slashing_bips: 9000, // 90%
             resolution_window: 1000,
+            destinations: vec![],
+            asset_ids: vec![],
         };
-        let enable_slashing_msg = &ExecuteMsg::EnableSlashing {
+        let propose_msg = &ExecuteMsg::ProposeSlashingParameters {
             slashing_parameters: slashing_parameters.clone(),
         };
-        registry
-            .execute(&mut app, &service, enable_slashing_msg)
+        registry.execute(&mut app, &service, propose_msg).unwrap();
+
+        // assert pending query
+        let SlashingParametersNoticePeriodResponse(notice_period) = registry
+            .query(&app, &QueryMsg::SlashingParametersNoticePeriod {})
+            .unwrap();
+        let PendingSlashingParametersResponse(pending) = registry
+            .query(
+                &app,
+                &QueryMsg::PendingSlashingParameters {
+                    service: service.to_string(),
+                },
+            )
             .unwrap();
+        assert_eq!(
+            pending,
+            Some(PendingSlashingParameters {
+                slashing_parameters: slashing_parameters.clone(),
+                effective_timestamp: app.block_info().time.plus_seconds(notice_period).seconds(),
+            })
+        );
+
+        // move blockchain past the notice period
+        app.update_block(|block| {
+            block.height += 1;
+            block.time = block.time.plus_seconds(notice_period);
+        });
+
+        // anyone can activate the stricter slashing parameters
+        let activate_msg = &ExecuteMsg::ActivateSlashingParameters {
+            service: service.to_string(),
+        };
+        registry.execute(&mut app, &operator, activate_msg).unwrap();
 
         // assert query
         let slashing_parameters_res: SlashingParameters = registry
@@ -974,6 +1085,8 @@ fn enable_slashing_lifecycle() {
                 destination: Some(burn_address.clone()),
                 max_slashing_bips: 5000, // 50%
                 resolution_window: 1000,
+                destinations: vec![],
+                asset_ids: vec![],
             }
         );
     }
@@ -1020,6 +1133,7 @@ fn enable_slashing_lifecycle() {
     {
         let opt_in_msg = &ExecuteMsg::OperatorOptInToSlashing {
             service: service.to_string(),
+            operator: None,
         };
         let res = registry.execute(&mut app, &operator2, opt_in_msg).unwrap();
 
@@ -1061,6 +1175,7 @@ fn enable_slashing_lifecycle() {
     {
         let deregister_msg = &ExecuteMsg::DeregisterServiceFromOperator {
             service: service.to_string(),
+            operator: None,
         };
         registry
             .execute(&mut app, &operator2, deregister_msg)
@@ -1077,6 +1192,42 @@ fn enable_slashing_lifecycle() {
                 },
             )
             .unwrap();
+        assert_eq!(
+            operator2_status_res,
+            StatusResponse(4) // Exiting
+        );
+    }
+
+    // move blockchain past the deregistration delay
+    let DeregistrationDelayResponse(deregistration_delay) = registry
+        .query(&app, &QueryMsg::DeregistrationDelay {})
+        .unwrap();
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(deregistration_delay);
+    });
+
+    // operator2 finalizes the deregistration
+    {
+        let finalize_msg = &ExecuteMsg::FinalizeDeregistration {
+            service: service.to_string(),
+            operator: operator2.to_string(),
+        };
+        registry
+            .execute(&mut app, &operator2, finalize_msg)
+            .unwrap();
+
+        // assert query
+        let operator2_status_res: StatusResponse = registry
+            .query(
+                &app,
+                &QueryMsg::Status {
+                    service: service.to_string(),
+                    operator: operator2.to_string(),
+                    timestamp: None,
+                },
+            )
+            .unwrap();
         assert_eq!(
             operator2_status_res,
             StatusResponse(0) // Inactive
@@ -1114,6 +1265,140 @@ fn enable_slashing_lifecycle() {
     }
 }
 
+#[test]
+fn list_registrations() {
+    let (mut app, registry, ..) = instantiate();
+
+    let service = app.api().addr_make("service/1");
+    let operator = app.api().addr_make("operator/1");
+    let operator2 = app.api().addr_make("operator/2");
+
+    let metadata = Metadata {
+        name: None,
+        uri: None,
+    };
+    registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::RegisterAsService {
+                metadata: metadata.clone(),
+            },
+        )
+        .unwrap();
+    for curr_operator in [&operator, &operator2] {
+        registry
+            .execute(
+                &mut app,
+                curr_operator,
+                &ExecuteMsg::RegisterAsOperator {
+                    metadata: metadata.clone(),
+                },
+            )
+            .unwrap();
+        registry
+            .execute(
+                &mut app,
+                &service,
+                &ExecuteMsg::RegisterOperatorToService {
+                    operator: curr_operator.to_string(),
+                },
+            )
+            .unwrap();
+    }
+
+    // operator accepts the service, operator2 remains pending
+    registry
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::RegisterServiceToOperator {
+                service: service.to_string(),
+                operator: None,
+            },
+        )
+        .unwrap();
+
+    let RegistrationListResponse(active) = registry
+        .query(
+            &app,
+            &QueryMsg::ListOperatorsByService {
+                service: service.to_string(),
+                status: Some(RegistrationStatus::Active),
+                timestamp: None,
+                limit: None,
+                start_after: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        active,
+        vec![Registration {
+            service: service.clone(),
+            operator: operator.clone(),
+            status: RegistrationStatus::Active as u8,
+        }]
+    );
+
+    let RegistrationListResponse(pending) = registry
+        .query(
+            &app,
+            &QueryMsg::ListOperatorsByService {
+                service: service.to_string(),
+                status: Some(RegistrationStatus::ServiceRegistered),
+                timestamp: None,
+                limit: None,
+                start_after: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        pending,
+        vec![Registration {
+            service: service.clone(),
+            operator: operator2.clone(),
+            status: RegistrationStatus::ServiceRegistered as u8,
+        }]
+    );
+
+    let RegistrationListResponse(services) = registry
+        .query(
+            &app,
+            &QueryMsg::ListServicesByOperator {
+                operator: operator.to_string(),
+                status: None,
+                timestamp: None,
+                limit: None,
+                start_after: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(services.len(), 1);
+    assert_eq!(services[0].service, service);
+
+    let ServiceListResponse(services) = registry
+        .query(
+            &app,
+            &QueryMsg::ListServices {
+                limit: None,
+                start_after: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(services, vec![service.clone()]);
+
+    let OperatorListResponse(operators) = registry
+        .query(
+            &app,
+            &QueryMsg::ListOperators {
+                limit: Some(1),
+                start_after: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(operators.len(), 1);
+}
+
 #[test]
 fn query_status() {
     let (mut app, registry, _) = instantiate();
@@ -1146,6 +1431,9 @@ fn migrate_to_v2() {
     let operator_active_registration_count: Map<&Addr, u8> =
         Map::new("operator_active_registration_count");
 
+    let service_active_registration_count: Map<&Addr, u8> =
+        Map::new("service_active_registration_count");
+
     {
         // save some data into old contract state with same 'registration_status' namespace
         let mut contract_storage = app.contract_storage_mut(&registry.addr);
@@ -1161,6 +1449,10 @@ fn migrate_to_v2() {
             .save(&mut *contract_storage, &operator, &1u8)
             .unwrap();
 
+        service_active_registration_count
+            .save(&mut *contract_storage, &service, &1u8)
+            .unwrap();
+
         // assert that state is populated
         let res = old_registration_status
             .load(&*contract_storage, (&operator, &service))
@@ -1205,6 +1497,7 @@ fn migrate_to_v2() {
         // deregister
         let deregister_msg = &ExecuteMsg::DeregisterServiceFromOperator {
             service: service.to_string(),
+            operator: None,
         };
         registry
             .execute(&mut app, &operator, deregister_msg)
@@ -1221,7 +1514,7 @@ fn migrate_to_v2() {
                 },
             )
             .unwrap();
-        assert_eq!(status, StatusResponse(0));
+        assert_eq!(status, StatusResponse(4)); // exiting
 
         // check if state is changed at timestamp + 1
         let block_info = app.block_info();
@@ -1235,7 +1528,7 @@ fn migrate_to_v2() {
                 },
             )
             .unwrap();
-        assert_eq!(status_at_timestamp, StatusResponse(0));
+        assert_eq!(status_at_timestamp, StatusResponse(4)); // exiting
 
         // check old state at timestamp - 10 -> should be active
         let block_info = app.block_info();
@@ -1252,3 +1545,1193 @@ fn migrate_to_v2() {
         assert_eq!(status_at_timestamp, StatusResponse(1));
     }
 }
+
+#[test]
+fn max_active_relationships_per_service() {
+    let (mut app, registry, ..) = instantiate();
+    let owner = app.api().addr_make("owner");
+
+    let service = app.api().addr_make("service/1");
+    let operator = app.api().addr_make("operator/1");
+    let operator2 = app.api().addr_make("operator/2");
+
+    registry
+        .execute(
+            &mut app,
+            &owner,
+            &ExecuteMsg::SetMaxActiveRelationshipsForService {
+                service: service.to_string(),
+                max: Some(1),
+            },
+        )
+        .unwrap();
+
+    let metadata = Metadata {
+        name: None,
+        uri: None,
+    };
+    registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::RegisterAsService {
+                metadata: metadata.clone(),
+            },
+        )
+        .unwrap();
+    for curr_operator in [&operator, &operator2] {
+        registry
+            .execute(
+                &mut app,
+                curr_operator,
+                &ExecuteMsg::RegisterAsOperator {
+                    metadata: metadata.clone(),
+                },
+            )
+            .unwrap();
+        registry
+            .execute(
+                &mut app,
+                &service,
+                &ExecuteMsg::RegisterOperatorToService {
+                    operator: curr_operator.to_string(),
+                },
+            )
+            .unwrap();
+    }
+
+    registry
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::RegisterServiceToOperator {
+                service: service.to_string(),
+                operator: None,
+            },
+        )
+        .unwrap();
+
+    // second operator exceeds the service max
+    let err = registry
+        .execute(
+            &mut app,
+            &operator2,
+            &ExecuteMsg::RegisterServiceToOperator {
+                service: service.to_string(),
+                operator: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::MaxActiveRelationshipsExceeded {
+            msg: "Service has reached its max active relationships".to_string()
+        }
+        .to_string()
+    );
+
+    let count: ActiveCountResponse = registry
+        .query(&app, &QueryMsg::ActiveOperatorCount(service.to_string()))
+        .unwrap();
+    assert_eq!(count, ActiveCountResponse(1));
+
+    // finalizing the deregistration of the first operator frees up a slot
+    registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::DeregisterOperatorFromService {
+                operator: operator.to_string(),
+            },
+        )
+        .unwrap();
+
+    let DeregistrationDelayResponse(deregistration_delay) = registry
+        .query(&app, &QueryMsg::DeregistrationDelay {})
+        .unwrap();
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(deregistration_delay);
+    });
+    registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::FinalizeDeregistration {
+                service: service.to_string(),
+                operator: operator.to_string(),
+            },
+        )
+        .unwrap();
+    registry
+        .execute(
+            &mut app,
+            &operator2,
+            &ExecuteMsg::RegisterServiceToOperator {
+                service: service.to_string(),
+                operator: None,
+            },
+        )
+        .unwrap();
+
+    let count: ActiveCountResponse = registry
+        .query(&app, &QueryMsg::ActiveServiceCount(operator2.to_string()))
+        .unwrap();
+    assert_eq!(count, ActiveCountResponse(1));
+}
+
+#[test]
+fn batch_register_deregister_operators() {
+    let (mut app, registry, ..) = instantiate();
+
+    let service = app.api().addr_make("service/1");
+    let operators: Vec<Addr> = (0..3)
+        .map(|i| app.api().addr_make(&format!("operator/{i}")))
+        .collect();
+    let unregistered_operator = app.api().addr_make("operator/unregistered");
+
+    let metadata = Metadata {
+        name: None,
+        uri: None,
+    };
+    registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::RegisterAsService {
+                metadata: metadata.clone(),
+            },
+        )
+        .unwrap();
+    for operator in &operators {
+        registry
+            .execute(
+                &mut app,
+                operator,
+                &ExecuteMsg::RegisterAsOperator {
+                    metadata: metadata.clone(),
+                },
+            )
+            .unwrap();
+    }
+
+    // empty batch
+    let err = registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::RegisterOperatorsToService { operators: vec![] },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidBatch {
+            msg: "Batch must not be empty".to_string()
+        }
+        .to_string()
+    );
+
+    // batch exceeds max size
+    let err = registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::RegisterOperatorsToService {
+                operators: vec![operators[0].to_string(); 101],
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidBatch {
+            msg: "Batch size exceeds the maximum of 100".to_string()
+        }
+        .to_string()
+    );
+
+    // batch is atomic, an unregistered operator fails the whole batch
+    let err = registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::RegisterOperatorsToService {
+                operators: vec![operators[0].to_string(), unregistered_operator.to_string()],
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::Std(StdError::not_found("operator")).to_string()
+    );
+    let status: StatusResponse = registry
+        .query(
+            &app,
+            &QueryMsg::Status {
+                service: service.to_string(),
+                operator: operators[0].to_string(),
+                timestamp: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(status, StatusResponse(0));
+
+    // register all operators to service
+    let res = registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::RegisterOperatorsToService {
+                operators: operators.iter().map(|o| o.to_string()).collect(),
+            },
+        )
+        .unwrap();
+    let mut expected_events =
+        vec![Event::new("execute").add_attribute("_contract_address", registry.addr.as_str())];
+    for operator in &operators {
+        expected_events.push(
+            Event::new("wasm-RegistrationStatusUpdated")
+                .add_attribute("_contract_address", registry.addr.as_str())
+                .add_attribute("method", "register_operator_to_service")
+                .add_attribute("operator", operator.as_str())
+                .add_attribute("service", service.as_str())
+                .add_attribute("status", "ServiceRegistered"),
+        );
+    }
+    assert_eq!(res.events, expected_events);
+
+    // operators register service to activate
+    for operator in &operators {
+        registry
+            .execute(
+                &mut app,
+                operator,
+                &ExecuteMsg::RegisterServicesToOperator {
+                    services: vec![service.to_string()],
+                    operator: None,
+                },
+            )
+            .unwrap();
+    }
+
+    let count: ActiveCountResponse = registry
+        .query(&app, &QueryMsg::ActiveOperatorCount(service.to_string()))
+        .unwrap();
+    assert_eq!(count, ActiveCountResponse(3));
+
+    // deregister the first two operators from service
+    let res = registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::DeregisterOperatorsFromService {
+                operators: operators[..2].iter().map(|o| o.to_string()).collect(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.events.len(), 3);
+
+    for (operator, expected) in operators.iter().zip([4, 4, 1]) {
+        let status: StatusResponse = registry
+            .query(
+                &app,
+                &QueryMsg::Status {
+                    service: service.to_string(),
+                    operator: operator.to_string(),
+                    timestamp: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(status, StatusResponse(expected));
+    }
+
+    // operator deregisters the service
+    registry
+        .execute(
+            &mut app,
+            &operators[2],
+            &ExecuteMsg::DeregisterServicesFromOperator {
+                services: vec![service.to_string()],
+                operator: None,
+            },
+        )
+        .unwrap();
+    let status: StatusResponse = registry
+        .query(
+            &app,
+            &QueryMsg::Status {
+                service: service.to_string(),
+                operator: operators[2].to_string(),
+                timestamp: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(status, StatusResponse(4));
+}
+
+#[test]
+fn operator_manager_lifecycle() {
+    let (mut app, registry, ..) = instantiate();
+
+    let service = app.api().addr_make("service/1");
+    let operator = app.api().addr_make("operator/1");
+    let manager = app.api().addr_make("manager/1");
+    let stranger = app.api().addr_make("stranger");
+
+    let metadata = Metadata {
+        name: None,
+        uri: None,
+    };
+    registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::RegisterAsService {
+                metadata: metadata.clone(),
+            },
+        )
+        .unwrap();
+    registry
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::RegisterAsOperator {
+                metadata: metadata.clone(),
+            },
+        )
+        .unwrap();
+    registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::EnableSlashing {
+                slashing_parameters: SlashingParameters {
+                    destination: None,
+                    max_slashing_bips: 100,
+                    resolution_window: 1000,
+                    destinations: vec![],
+                    asset_ids: vec![],
+                },
+            },
+        )
+        .unwrap();
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::RegisterOperatorToService {
+                operator: operator.to_string(),
+            },
+        )
+        .unwrap();
+
+    // not a manager
+    let err = registry
+        .execute(
+            &mut app,
+            &manager,
+            &ExecuteMsg::RegisterServiceToOperator {
+                service: service.to_string(),
+                operator: Some(operator.to_string()),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    // operator sets manager with registration permission
+    let res = registry
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::SetOperatorManager {
+                manager: manager.to_string(),
+                permissions: vec![ManagerPermission::Registration],
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.events,
+        vec![
+            Event::new("execute").add_attribute("_contract_address", registry.addr.as_str()),
+            Event::new("wasm-OperatorManagerSet")
+                .add_attribute("_contract_address", registry.addr.as_str())
+                .add_attribute("operator", operator.as_str())
+                .add_attribute("manager", manager.as_str())
+                .add_attribute("permissions", "Registration"),
+        ]
+    );
+
+    // manager cannot set other managers
+    let err = registry
+        .execute(
+            &mut app,
+            &manager,
+            &ExecuteMsg::SetOperatorManager {
+                manager: stranger.to_string(),
+                permissions: vec![ManagerPermission::Registration],
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::Std(StdError::not_found("operator")).to_string()
+    );
+
+    // manager registers service on behalf of operator
+    let res = registry
+        .execute(
+            &mut app,
+            &manager,
+            &ExecuteMsg::RegisterServiceToOperator {
+                service: service.to_string(),
+                operator: Some(operator.to_string()),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.events,
+        vec![
+            Event::new("execute").add_attribute("_contract_address", registry.addr.as_str()),
+            Event::new("wasm-RegistrationStatusUpdated")
+                .add_attribute("_contract_address", registry.addr.as_str())
+                .add_attribute("method", "register_service_to_operator")
+                .add_attribute("operator", operator.as_str())
+                .add_attribute("service", service.as_str())
+                .add_attribute("status", "Active"),
+        ]
+    );
+
+    // manager without slashing opt-in permission
+    let err = registry
+        .execute(
+            &mut app,
+            &manager,
+            &ExecuteMsg::OperatorOptInToSlashing {
+                service: service.to_string(),
+                operator: Some(operator.to_string()),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    // manager without metadata permission
+    let err = registry
+        .execute(
+            &mut app,
+            &manager,
+            &ExecuteMsg::UpdateOperatorMetadataAsManager {
+                operator: operator.to_string(),
+                metadata: Metadata {
+                    name: Some("operator".to_string()),
+                    uri: None,
+                },
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    // grant all permissions
+    registry
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::SetOperatorManager {
+                manager: manager.to_string(),
+                permissions: vec![
+                    ManagerPermission::Registration,
+                    ManagerPermission::SlashingOptIn,
+                    ManagerPermission::Metadata,
+                ],
+            },
+        )
+        .unwrap();
+
+    let managers: OperatorManagerListResponse = registry
+        .query(
+            &app,
+            &QueryMsg::OperatorManagers {
+                operator: operator.to_string(),
+                limit: None,
+                start_after: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        managers,
+        OperatorManagerListResponse(vec![OperatorManager {
+            manager: manager.clone(),
+            permissions: vec![
+                ManagerPermission::Registration,
+                ManagerPermission::SlashingOptIn,
+                ManagerPermission::Metadata,
+            ],
+        }])
+    );
+
+    registry
+        .execute(
+            &mut app,
+            &manager,
+            &ExecuteMsg::OperatorOptInToSlashing {
+                service: service.to_string(),
+                operator: Some(operator.to_string()),
+            },
+        )
+        .unwrap();
+    registry
+        .execute(
+            &mut app,
+            &manager,
+            &ExecuteMsg::UpdateOperatorMetadataAsManager {
+                operator: operator.to_string(),
+                metadata: Metadata {
+                    name: Some("operator".to_string()),
+                    uri: None,
+                },
+            },
+        )
+        .unwrap();
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    let opted_in: IsOperatorOptedInToSlashingResponse = registry
+        .query(
+            &app,
+            &QueryMsg::IsOperatorOptedInToSlashing {
+                service: service.to_string(),
+                operator: operator.to_string(),
+                timestamp: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(opted_in, IsOperatorOptedInToSlashingResponse(true));
+
+    let operator_metadata: MetadataResponse = registry
+        .query(
+            &app,
+            &QueryMsg::OperatorMetadata {
+                operator: operator.to_string(),
+                timestamp: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        operator_metadata,
+        MetadataResponse(Some(Metadata {
+            name: Some("operator".to_string()),
+            uri: None,
+        }))
+    );
+
+    // operator revokes manager
+    registry
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::RevokeOperatorManager {
+                manager: manager.to_string(),
+            },
+        )
+        .unwrap();
+
+    let err = registry
+        .execute(
+            &mut app,
+            &manager,
+            &ExecuteMsg::DeregisterServiceFromOperator {
+                service: service.to_string(),
+                operator: Some(operator.to_string()),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    let managers: OperatorManagerListResponse = registry
+        .query(
+            &app,
+            &QueryMsg::OperatorManagers {
+                operator: operator.to_string(),
+                limit: None,
+                start_after: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(managers, OperatorManagerListResponse(vec![]));
+}
+
+#[test]
+fn service_hook_failure_does_not_revert() {
+    let (mut app, registry, ..) = instantiate();
+
+    // the service is not a contract, so every hook sent to it fails
+    let service = app.api().addr_make("service");
+    let operator = app.api().addr_make("operator");
+
+    registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::RegisterAsService {
+                metadata: Metadata {
+                    name: None,
+                    uri: None,
+                },
+            },
+        )
+        .unwrap();
+    registry
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::RegisterAsOperator {
+                metadata: Metadata {
+                    name: None,
+                    uri: None,
+                },
+            },
+        )
+        .unwrap();
+
+    registry
+        .execute(&mut app, &service, &ExecuteMsg::EnableServiceHooks {})
+        .unwrap();
+
+    let res: ServiceHooksEnabledResponse = registry
+        .query(&app, &QueryMsg::ServiceHooksEnabled(service.to_string()))
+        .unwrap();
+    assert_eq!(res, ServiceHooksEnabledResponse(true));
+
+    let res = registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::RegisterOperatorToService {
+                operator: operator.to_string(),
+            },
+        )
+        .unwrap();
+
+    let failed = res
+        .events
+        .iter()
+        .find(|event| event.ty == "wasm-ServiceHookFailed")
+        .expect("ServiceHookFailed event");
+    assert!(failed
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "service" && attr.value == service.as_str()));
+
+    let status: StatusResponse = registry
+        .query(
+            &app,
+            &QueryMsg::Status {
+                service: service.to_string(),
+                operator: operator.to_string(),
+                timestamp: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        status,
+        StatusResponse(RegistrationStatus::ServiceRegistered.into())
+    );
+
+    registry
+        .execute(&mut app, &service, &ExecuteMsg::DisableServiceHooks {})
+        .unwrap();
+
+    let res = registry
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::RegisterServiceToOperator {
+                service: service.to_string(),
+                operator: None,
+            },
+        )
+        .unwrap();
+    assert!(!res
+        .events
+        .iter()
+        .any(|event| event.ty == "wasm-ServiceHookFailed"));
+}
+
+#[test]
+fn service_sunset_lifecycle() {
+    let (mut app, registry, ..) = instantiate();
+
+    let service = app.api().addr_make("service");
+    let operator = app.api().addr_make("operator");
+
+    registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::RegisterAsService {
+                metadata: Metadata {
+                    name: None,
+                    uri: None,
+                },
+            },
+        )
+        .unwrap();
+    registry
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::RegisterAsOperator {
+                metadata: Metadata {
+                    name: None,
+                    uri: None,
+                },
+            },
+        )
+        .unwrap();
+    registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::EnableSlashing {
+                slashing_parameters: SlashingParameters {
+                    destination: Some(service.clone()),
+                    max_slashing_bips: 100,
+                    resolution_window: 1000,
+                    destinations: vec![],
+                    asset_ids: vec![],
+                },
+            },
+        )
+        .unwrap();
+    registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::RegisterOperatorToService {
+                operator: operator.to_string(),
+            },
+        )
+        .unwrap();
+    registry
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::RegisterServiceToOperator {
+                service: service.to_string(),
+                operator: None,
+            },
+        )
+        .unwrap();
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+    let sunset_timestamp = app.block_info().time.seconds();
+
+    registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::DeregisterAsService { limit: None },
+        )
+        .unwrap();
+
+    let res: SunsetResponse = registry
+        .query(&app, &QueryMsg::ServiceSunset(service.to_string()))
+        .unwrap();
+    assert_eq!(
+        res,
+        SunsetResponse(Some(Sunset {
+            last: Some(operator.clone()),
+            completed: true,
+        }))
+    );
+
+    let res: IsServiceResponse = registry
+        .query(&app, &QueryMsg::IsService(service.to_string()))
+        .unwrap();
+    assert_eq!(res, IsServiceResponse(false));
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(1);
+    });
+
+    // slashing is disabled, but still enabled at timestamps before the sunset
+    let res: SlashingParametersResponse = registry
+        .query(
+            &app,
+            &QueryMsg::SlashingParameters {
+                service: service.to_string(),
+                timestamp: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res, SlashingParametersResponse(None));
+
+    let res: SlashingParametersResponse = registry
+        .query(
+            &app,
+            &QueryMsg::SlashingParameters {
+                service: service.to_string(),
+                timestamp: Some(sunset_timestamp),
+            },
+        )
+        .unwrap();
+    assert!(res.0.is_some());
+
+    // operator is exiting and still active until the deregistration is finalized
+    let status: StatusResponse = registry
+        .query(
+            &app,
+            &QueryMsg::Status {
+                service: service.to_string(),
+                operator: operator.to_string(),
+                timestamp: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(status, RegistrationStatus::Exiting.into());
+
+    let DeregistrationDelayResponse(deregistration_delay) = registry
+        .query(&app, &QueryMsg::DeregistrationDelay {})
+        .unwrap();
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(deregistration_delay);
+    });
+    registry
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::FinalizeDeregistration {
+                service: service.to_string(),
+                operator: operator.to_string(),
+            },
+        )
+        .unwrap();
+
+    let res: IsOperatorActiveResponse = registry
+        .query(&app, &QueryMsg::IsOperatorActive(operator.to_string()))
+        .unwrap();
+    assert_eq!(res, IsOperatorActiveResponse(false));
+
+    let err = registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::DeregisterAsService { limit: None },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::Retired {
+            msg: "Service sunset has completed".to_string(),
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn commission_lifecycle() {
+    let (mut app, registry, ..) = instantiate();
+
+    let owner = app.api().addr_make("owner");
+    let service = app.api().addr_make("service");
+    let operator = app.api().addr_make("operator");
+
+    registry
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::RegisterAsService {
+                metadata: Metadata {
+                    name: None,
+                    uri: None,
+                },
+            },
+        )
+        .unwrap();
+    registry
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::RegisterAsOperator {
+                metadata: Metadata {
+                    name: None,
+                    uri: None,
+                },
+            },
+        )
+        .unwrap();
+
+    let err = registry
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::SetCommissionChangeDelay { delay: 100 },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    registry
+        .execute(
+            &mut app,
+            &owner,
+            &ExecuteMsg::SetCommissionChangeDelay { delay: 100 },
+        )
+        .unwrap();
+
+    let res: CommissionChangeDelayResponse = registry
+        .query(&app, &QueryMsg::CommissionChangeDelay {})
+        .unwrap();
+    assert_eq!(res, CommissionChangeDelayResponse(100));
+
+    registry
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::ProposeCommission {
+                service: service.to_string(),
+                commission_bips: 1_000,
+            },
+        )
+        .unwrap();
+    let proposed_at = app.block_info().time.seconds();
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(100);
+    });
+
+    // anyone can activate the commission after the delay
+    let anyone = app.api().addr_make("anyone");
+    registry
+        .execute(
+            &mut app,
+            &anyone,
+            &ExecuteMsg::ActivateCommission {
+                service: service.to_string(),
+                operator: operator.to_string(),
+            },
+        )
+        .unwrap();
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(1);
+    });
+
+    let res: CommissionResponse = registry
+        .query(
+            &app,
+            &QueryMsg::Commission {
+                service: service.to_string(),
+                operator: operator.to_string(),
+                timestamp: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res, CommissionResponse(1_000));
+
+    // the proposed commission was not effective before activation
+    let res: CommissionResponse = registry
+        .query(
+            &app,
+            &QueryMsg::Commission {
+                service: service.to_string(),
+                operator: operator.to_string(),
+                timestamp: Some(proposed_at + 1),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, CommissionResponse(0));
+}
+
+#[test]
+fn operator_key_lifecycle() {
+    let (mut app, registry, ..) = instantiate();
+
+    let service = app.api().addr_make("service");
+    let operator = app.api().addr_make("operator");
+
+    for (sender, msg) in [
+        (
+            &service,
+            ExecuteMsg::RegisterAsService {
+                metadata: Metadata {
+                    name: None,
+                    uri: None,
+                },
+            },
+        ),
+        (
+            &operator,
+            ExecuteMsg::RegisterAsOperator {
+                metadata: Metadata {
+                    name: None,
+                    uri: None,
+                },
+            },
+        ),
+    ] {
+        registry.execute(&mut app, sender, &msg).unwrap();
+    }
+
+    let account1 = Account::new("operator/key1");
+    let account2 = Account::new("operator/key2");
+    let key1 = HexBinary::from(account1.public_key.serialize());
+    let key2 = HexBinary::from(account2.public_key.serialize());
+    let set_key_msg = |account: &Account, key: &HexBinary| {
+        let message = operator_key_message(&service, &operator, &KeyType::Secp256k1, key);
+        ExecuteMsg::SetOperatorKey {
+            service: service.to_string(),
+            key_type: KeyType::Secp256k1,
+            public_key: key.clone(),
+            signature: HexBinary::from(account.sign(message.to_vec()).serialize_compact()),
+        }
+    };
+
+    // the proof of possession must be signed with the key
+    let mut msg = set_key_msg(&account2, &key2);
+    if let ExecuteMsg::SetOperatorKey { public_key, .. } = &mut msg {
+        *public_key = key1.clone();
+    }
+    let err = registry.execute(&mut app, &operator, &msg).unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidOperatorKey {
+            msg: "Invalid proof of possession signature".to_string(),
+        }
+        .to_string()
+    );
+
+    // the proof of possession is bound to the operator
+    let stranger = app.api().addr_make("stranger");
+    registry
+        .execute(
+            &mut app,
+            &stranger,
+            &ExecuteMsg::RegisterAsOperator {
+                metadata: Metadata {
+                    name: None,
+                    uri: None,
+                },
+            },
+        )
+        .unwrap();
+    let err = registry
+        .execute(&mut app, &stranger, &set_key_msg(&account1, &key1))
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidOperatorKey {
+            msg: "Invalid proof of possession signature".to_string(),
+        }
+        .to_string()
+    );
+
+    let res = registry
+        .execute(&mut app, &operator, &set_key_msg(&account1, &key1))
+        .unwrap();
+    assert_eq!(
+        res.events,
+        vec![
+            Event::new("execute").add_attribute("_contract_address", registry.addr.as_str()),
+            Event::new("wasm-OperatorKeySet")
+                .add_attribute("_contract_address", registry.addr.as_str())
+                .add_attribute("operator", operator.as_str())
+                .add_attribute("service", service.as_str())
+                .add_attribute("key_type", "Secp256k1")
+                .add_attribute("public_key", key1.to_hex()),
+        ]
+    );
+    let set_at = app.block_info().time.seconds();
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(100);
+    });
+
+    // rotate the key
+    registry
+        .execute(&mut app, &operator, &set_key_msg(&account2, &key2))
+        .unwrap();
+    let rotated_at = app.block_info().time.seconds();
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(100);
+    });
+
+    let operator_key = |app: &App, timestamp: Option<u64>| -> OperatorKeyResponse {
+        registry
+            .query(
+                app,
+                &QueryMsg::OperatorKey {
+                    service: service.to_string(),
+                    operator: operator.to_string(),
+                    key_type: KeyType::Secp256k1,
+                    timestamp,
+                },
+            )
+            .unwrap()
+    };
+
+    assert_eq!(
+        operator_key(&app, None),
+        OperatorKeyResponse(Some(key2.clone()))
+    );
+    // the key valid at the time of an attestation
+    assert_eq!(
+        operator_key(&app, Some(set_at + 1)),
+        OperatorKeyResponse(Some(key1))
+    );
+    assert_eq!(operator_key(&app, Some(set_at)), OperatorKeyResponse(None));
+
+    registry
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::RemoveOperatorKey {
+                service: service.to_string(),
+                key_type: KeyType::Secp256k1,
+            },
+        )
+        .unwrap();
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(1);
+    });
+
+    assert_eq!(operator_key(&app, None), OperatorKeyResponse(None));
+    // the history is kept after removal
+    assert_eq!(
+        operator_key(&app, Some(rotated_at + 1)),
+        OperatorKeyResponse(Some(key2))
+    );
+}
@@ -1,6 +1,8 @@
 use bvs_library::testing::TestingContract;
 use bvs_pauser::testing::PauserContract;
+use bvs_registry::msg::{ExecuteMsg as RegistryExecuteMsg, Metadata};
 use bvs_registry::testing::RegistryContract;
+use bvs_registry::SlashingParameters;
 use bvs_vault_bank_tokenized::msg::{ExecuteMsg, QueryMsg};
 use bvs_vault_bank_tokenized::testing::VaultBankTokenizedContract;
 use bvs_vault_base::error::VaultError;
@@ -9,7 +11,11 @@ use bvs_vault_base::msg::{
     SetApproveProxyParams, VaultInfoResponse,
 };
 use bvs_vault_base::shares::QueuedWithdrawalInfo;
-use bvs_vault_router::{msg::ExecuteMsg as RouterExecuteMsg, testing::VaultRouterContract};
+use bvs_vault_router::msg::{
+    ExecuteMsg as RouterExecuteMsg, RequestSlashingPayload, RequestSlashingResponse,
+    SlashingMetadata,
+};
+use bvs_vault_router::testing::VaultRouterContract;
 use cosmwasm_std::testing::mock_env;
 use cosmwasm_std::{
     coin, coins, to_json_binary, Addr, DenomMetadata, DenomUnit, Event, Timestamp, Uint128, Uint64,
@@ -761,6 +767,133 @@ fn test_redeem_withdrawal_to_successfully() {
     assert_eq!(response.unlock_timestamp, Timestamp::from_seconds(0));
 }
 
+#[test]
+fn test_redeem_withdrawal_to_slash_pending_error() {
+    let (mut app, tc) = TestContracts::init();
+    let app = &mut app;
+    let owner = app.api().addr_make("owner");
+    let staker = app.api().addr_make("staker");
+    let denom = "denom";
+    let token_amount: u128 = 999_999_999_999;
+
+    // Deposit some tokens from staker to Vault
+    {
+        app.send_tokens(owner.clone(), staker.clone(), &coins(token_amount, denom))
+            .unwrap();
+
+        let msg = ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(token_amount),
+        });
+        tc.vault
+            .execute_with_funds(app, &staker, &msg, coins(token_amount, denom))
+            .expect("staker deposit failed");
+    }
+
+    // queue withdrawal to
+    {
+        let msg = RouterExecuteMsg::SetWithdrawalLockPeriod(Uint64::new(100));
+        tc.router.execute(app, &owner, &msg).unwrap();
+
+        let msg = ExecuteMsg::QueueWithdrawalTo(QueueWithdrawalToParams {
+            controller: staker.clone(),
+            owner: staker.clone(),
+            amount: Uint128::new(10000),
+        });
+        tc.vault.execute(app, &staker, &msg).unwrap();
+    }
+
+    // register operator + service with slashing enabled
+    let operator = app.api().addr_make("operator");
+    let service = app.api().addr_make("service");
+    {
+        let metadata = Metadata {
+            name: None,
+            uri: None,
+        };
+        let msg = RegistryExecuteMsg::RegisterAsOperator {
+            metadata: metadata.clone(),
+        };
+        tc.registry.execute(app, &operator, &msg).unwrap();
+        let msg = RegistryExecuteMsg::RegisterAsService { metadata };
+        tc.registry.execute(app, &service, &msg).unwrap();
+
+        let msg = RegistryExecuteMsg::EnableSlashing {
+            slashing_parameters: SlashingParameters {
+                destination: Some(service.clone()),
+                max_slashing_bips: 5000,
+                resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        };
+        tc.registry.execute(app, &service, &msg).unwrap();
+
+        app.update_block(|block| {
+            block.height += 1;
+            block.time = block.time.plus_seconds(10);
+        });
+
+        let msg = RegistryExecuteMsg::RegisterOperatorToService {
+            operator: operator.to_string(),
+        };
+        tc.registry.execute(app, &service, &msg).unwrap();
+        let msg = RegistryExecuteMsg::RegisterServiceToOperator {
+            service: service.to_string(),
+            operator: None,
+        };
+        tc.registry.execute(app, &operator, &msg).unwrap();
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    // service request slashing, freezing the operator's vaults
+    let slashing_request_id = {
+        let msg = RouterExecuteMsg::RequestSlashing(RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time,
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
+        });
+        let response = tc.router.execute(app, &service, &msg).unwrap();
+        let RequestSlashingResponse(id) = response.data.unwrap().into();
+        id
+    };
+
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(115);
+    });
+
+    let msg = ExecuteMsg::RedeemWithdrawalTo(RedeemWithdrawalToParams {
+        controller: staker.clone(),
+        recipient: staker.clone(),
+    });
+    let err = tc.vault.execute(app, &staker, &msg).unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        VaultError::SlashPending {}.to_string()
+    );
+
+    // canceling the slashing request unfreezes the vault
+    let cancel_msg = RouterExecuteMsg::CancelSlashing(slashing_request_id);
+    tc.router.execute(app, &service, &cancel_msg).unwrap();
+
+    tc.vault.execute(app, &staker, &msg).unwrap();
+
+    let msg = QueryMsg::QueuedWithdrawal {
+        controller: staker.to_string(),
+    };
+    let response: QueuedWithdrawalInfo = tc.vault.query(app, &msg).unwrap();
+    assert_eq!(response.queued_shares, Uint128::new(0));
+}
+
 #[test]
 fn test_redeem_withdrawal_to_no_queued_shares_error() {
     let (mut app, tc) = TestContracts::init();
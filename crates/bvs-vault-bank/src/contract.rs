@@ -216,6 +216,8 @@ mod execute {
             return Err(VaultError::locked("The shares are locked").into());
         }
 
+        router::assert_not_slash_pending(&deps.as_ref(), &env)?;
+
         let (vault, claimed_assets) = {
             let balance = bank::query_balance(&deps.as_ref(), &env)?;
             let mut vault = offset::TotalShares::load(&deps.as_ref(), balance)?;
@@ -628,9 +630,11 @@ mod tests {
                         VaultRouterMsg::IsValidating { operator: _ } => {
                             SystemResult::Ok(ContractResult::Ok(to_json_binary(&false).unwrap()))
                         }
-                        VaultRouterMsg::WithdrawalLockPeriod {} => SystemResult::Ok(
-                            ContractResult::Ok(to_json_binary(&withdrawal_lock_period).unwrap()),
-                        ),
+                        VaultRouterMsg::OperatorWithdrawalLockPeriod { operator: _ } => {
+                            SystemResult::Ok(ContractResult::Ok(
+                                to_json_binary(&withdrawal_lock_period).unwrap(),
+                            ))
+                        }
                         _ => panic!("unexpected query"),
                     }
                 }
@@ -760,9 +764,11 @@ mod tests {
                         VaultRouterMsg::IsValidating { operator: _ } => {
                             SystemResult::Ok(ContractResult::Ok(to_json_binary(&true).unwrap()))
                         }
-                        VaultRouterMsg::WithdrawalLockPeriod {} => SystemResult::Ok(
-                            ContractResult::Ok(to_json_binary(&withdrawal_lock_period).unwrap()),
-                        ),
+                        VaultRouterMsg::OperatorWithdrawalLockPeriod { operator: _ } => {
+                            SystemResult::Ok(ContractResult::Ok(
+                                to_json_binary(&withdrawal_lock_period).unwrap(),
+                            ))
+                        }
                         _ => panic!("unexpected query"),
                     }
                 }
@@ -847,9 +853,14 @@ mod tests {
                         VaultRouterMsg::IsValidating { operator: _ } => {
                             SystemResult::Ok(ContractResult::Ok(to_json_binary(&true).unwrap()))
                         }
-                        VaultRouterMsg::WithdrawalLockPeriod {} => SystemResult::Ok(
-                            ContractResult::Ok(to_json_binary(&withdrawal_lock_period).unwrap()),
-                        ),
+                        VaultRouterMsg::IsSlashPending { vault: _ } => {
+                            SystemResult::Ok(ContractResult::Ok(to_json_binary(&false).unwrap()))
+                        }
+                        VaultRouterMsg::OperatorWithdrawalLockPeriod { operator: _ } => {
+                            SystemResult::Ok(ContractResult::Ok(
+                                to_json_binary(&withdrawal_lock_period).unwrap(),
+                            ))
+                        }
                         _ => panic!("unexpected query"),
                     }
                 }
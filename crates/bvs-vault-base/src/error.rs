@@ -15,6 +15,9 @@ pub enum VaultError {
     #[error("Vault is validating, withdrawal must be queued")]
     Validating {},
 
+    #[error("Vault is frozen, a slash is pending against the operator")]
+    SlashPending {},
+
     #[error("Insufficient: {msg}")]
     Insufficient { msg: String },
 
@@ -83,20 +83,46 @@ pub fn assert_not_validating(deps: &Deps) -> Result<(), VaultError> {
     Ok(())
 }
 
-/// Returns the queued withdrawal lock period in seconds.
+/// Asserts that no slash that would slash the current vault is pending in the `vault-router`.
+/// Redemptions are frozen from the slashing request until it is locked, canceled or expired,
+/// so the `total_assets` can't shrink before the slash amount is computed.
+pub fn assert_not_slash_pending(deps: &Deps, env: &Env) -> Result<(), VaultError> {
+    let router = get_router(deps.storage)?;
+    let vault = &env.contract.address;
+
+    let is_slash_pending: bool = deps.querier.query_wasm_smart(
+        router.to_string(),
+        &QueryMsg::IsSlashPending {
+            vault: vault.to_string(),
+        },
+    )?;
+    if is_slash_pending {
+        return Err(VaultError::SlashPending {});
+    }
+    Ok(())
+}
+
+/// Returns the queued withdrawal lock period in seconds,
+/// the effective withdrawal lock period of the vault's operator in the `vault-router`.
 pub fn get_withdrawal_lock_period(deps: &Deps) -> Result<Uint64, VaultError> {
     let router = get_router(deps.storage)?;
+    let operator = get_operator(deps.storage)?;
 
-    let withdrawal_lock_period: Uint64 = deps
-        .querier
-        .query_wasm_smart(router.to_string(), &QueryMsg::WithdrawalLockPeriod {})?;
+    let withdrawal_lock_period: Uint64 = deps.querier.query_wasm_smart(
+        router.to_string(),
+        &QueryMsg::OperatorWithdrawalLockPeriod {
+            operator: operator.to_string(),
+        },
+    )?;
 
     Ok(withdrawal_lock_period)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::router::{assert_not_validating, assert_whitelisted, set_operator, OPERATOR};
+    use crate::router::{
+        assert_not_slash_pending, assert_not_validating, assert_whitelisted, set_operator, OPERATOR,
+    };
     use crate::{
         error::VaultError,
         router,
@@ -259,4 +285,45 @@ mod tests {
         let err = assert_not_validating(&deps.as_ref()).unwrap_err();
         assert_eq!(err.to_string(), VaultError::Validating {}.to_string());
     }
+
+    #[test]
+    fn test_assert_not_slash_pending() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let router = deps.api.addr_make("router/6");
+        ROUTER.save(deps.as_mut().storage, &router).unwrap();
+
+        {
+            deps.querier.update_wasm(move |query| match query {
+                WasmQuery::Smart { .. } => {
+                    SystemResult::Ok(ContractResult::Ok(to_json_binary(&false).unwrap()))
+                }
+                _ => SystemResult::Err(SystemError::Unknown {}),
+            });
+        }
+
+        assert_not_slash_pending(&deps.as_ref(), &env).unwrap();
+    }
+
+    #[test]
+    fn test_assert_not_slash_pending_fail() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let router = deps.api.addr_make("router/6");
+        ROUTER.save(deps.as_mut().storage, &router).unwrap();
+
+        {
+            deps.querier.update_wasm(move |query| match query {
+                WasmQuery::Smart { .. } => {
+                    SystemResult::Ok(ContractResult::Ok(to_json_binary(&true).unwrap()))
+                }
+                _ => SystemResult::Err(SystemError::Unknown {}),
+            });
+        }
+
+        let err = assert_not_slash_pending(&deps.as_ref(), &env).unwrap_err();
+        assert_eq!(err.to_string(), VaultError::SlashPending {}.to_string());
+    }
 }
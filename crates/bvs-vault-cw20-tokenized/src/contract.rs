@@ -364,6 +364,8 @@ mod vault_execute {
             return Err(VaultError::locked("The shares are locked").into());
         }
 
+        router::assert_not_slash_pending(&deps.as_ref(), &env)?;
+
         let claimed_assets = {
             let underlying_token_balance = UnderlyingToken::query_balance(&deps.as_ref(), &env)?;
             let receipt_token_supply =
@@ -228,6 +228,8 @@ mod execute {
             return Err(VaultError::locked("The shares are locked").into());
         }
 
+        router::assert_not_slash_pending(&deps.as_ref(), &env)?;
+
         let (vault, claimed_assets) = {
             let balance = token::query_balance(&deps.as_ref(), &env)?;
             let mut vault = offset::TotalShares::load(&deps.as_ref(), balance)?;
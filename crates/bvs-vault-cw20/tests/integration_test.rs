@@ -1,6 +1,8 @@
 use bvs_library::testing::{Cw20TokenContract, TestingContract};
 use bvs_pauser::testing::PauserContract;
+use bvs_registry::msg::{ExecuteMsg as RegistryExecuteMsg, Metadata};
 use bvs_registry::testing::RegistryContract;
+use bvs_registry::SlashingParameters;
 use bvs_vault_base::msg::{
     Amount, AssetType, QueueWithdrawalToParams, RecipientAmount, RedeemWithdrawalToParams,
     SetApproveProxyParams, VaultInfoResponse,
@@ -9,7 +11,11 @@ use bvs_vault_base::shares::QueuedWithdrawalInfo;
 use bvs_vault_base::VaultError;
 use bvs_vault_cw20::msg::{ExecuteMsg, QueryMsg};
 use bvs_vault_cw20::testing::VaultCw20Contract;
-use bvs_vault_router::{msg::ExecuteMsg as RouterExecuteMsg, testing::VaultRouterContract};
+use bvs_vault_router::msg::{
+    ExecuteMsg as RouterExecuteMsg, RequestSlashingPayload, RequestSlashingResponse,
+    SlashingMetadata,
+};
+use bvs_vault_router::testing::VaultRouterContract;
 use cosmwasm_std::testing::mock_env;
 use cosmwasm_std::{Addr, Event, Timestamp, Uint128, Uint64};
 use cw_multi_test::App;
@@ -651,6 +657,135 @@ fn test_redeem_withdrawal_to_successfully() {
     assert_eq!(response.unlock_timestamp, Timestamp::from_seconds(0));
 }
 
+#[test]
+fn test_redeem_withdrawal_to_slash_pending_error() {
+    let app = &mut App::default();
+    let TestContracts {
+        registry,
+        router,
+        vault,
+        cw20,
+        ..
+    } = TestContracts::init(app);
+
+    let owner = app.api().addr_make("owner");
+    let staker = app.api().addr_make("staker");
+
+    // Deposit some tokens from staker to Vault
+    {
+        let msg = ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(999_999_999_999),
+        });
+        cw20.increase_allowance(app, &staker, vault.addr(), 100e15 as u128);
+        cw20.fund(app, &staker, 100e15 as u128);
+        vault.execute(app, &staker, &msg).unwrap();
+    }
+
+    // queue withdrawal to
+    {
+        let msg = RouterExecuteMsg::SetWithdrawalLockPeriod(Uint64::new(100));
+        router.execute(app, &owner, &msg).unwrap();
+
+        let msg = ExecuteMsg::QueueWithdrawalTo(QueueWithdrawalToParams {
+            controller: staker.clone(),
+            owner: staker.clone(),
+            amount: Uint128::new(10000),
+        });
+        vault.execute(app, &staker, &msg).unwrap();
+    }
+
+    // register operator + service with slashing enabled
+    let operator = app.api().addr_make("operator");
+    let service = app.api().addr_make("service");
+    {
+        let metadata = Metadata {
+            name: None,
+            uri: None,
+        };
+        let msg = RegistryExecuteMsg::RegisterAsOperator {
+            metadata: metadata.clone(),
+        };
+        registry.execute(app, &operator, &msg).unwrap();
+        let msg = RegistryExecuteMsg::RegisterAsService { metadata };
+        registry.execute(app, &service, &msg).unwrap();
+
+        let msg = RegistryExecuteMsg::EnableSlashing {
+            slashing_parameters: SlashingParameters {
+                destination: Some(service.clone()),
+                max_slashing_bips: 5000,
+                resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        };
+        registry.execute(app, &service, &msg).unwrap();
+
+        app.update_block(|block| {
+            block.height += 1;
+            block.time = block.time.plus_seconds(10);
+        });
+
+        let msg = RegistryExecuteMsg::RegisterOperatorToService {
+            operator: operator.to_string(),
+        };
+        registry.execute(app, &service, &msg).unwrap();
+        let msg = RegistryExecuteMsg::RegisterServiceToOperator {
+            service: service.to_string(),
+            operator: None,
+        };
+        registry.execute(app, &operator, &msg).unwrap();
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    // service request slashing, freezing the operator's vaults
+    let slashing_request_id = {
+        let msg = RouterExecuteMsg::RequestSlashing(RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time,
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
+        });
+        let response = router.execute(app, &service, &msg).unwrap();
+        let RequestSlashingResponse(id) = response.data.unwrap().into();
+        id
+    };
+
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(115);
+    });
+
+    let msg = ExecuteMsg::RedeemWithdrawalTo(RedeemWithdrawalToParams {
+        controller: staker.clone(),
+        recipient: staker.clone(),
+    });
+    let err = vault.execute(app, &staker, &msg).unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        VaultError::SlashPending {}.to_string()
+    );
+
+    // canceling the slashing request unfreezes the vault
+    let cancel_msg = RouterExecuteMsg::CancelSlashing(slashing_request_id);
+    router.execute(app, &service, &cancel_msg).unwrap();
+
+    vault.execute(app, &staker, &msg).unwrap();
+
+    let msg = QueryMsg::QueuedWithdrawal {
+        controller: staker.to_string(),
+    };
+    let response: QueuedWithdrawalInfo = vault.query(app, &msg).unwrap();
+    assert_eq!(response.queued_shares, Uint128::new(0));
+}
+
 #[test]
 fn test_redeem_withdrawal_to_no_queued_shares_error() {
     let app = &mut App::default();
@@ -57,6 +57,31 @@ pub fn execute(
         ExecuteMsg::SetWithdrawalLockPeriod {
             0: withdrawal_lock_period,
         } => execute::set_withdrawal_lock_period(deps, env, info, withdrawal_lock_period),
+        ExecuteMsg::SetMinWithdrawalLockPeriod(min_withdrawal_lock_period) => {
+            execute::set_min_withdrawal_lock_period(deps, info, min_withdrawal_lock_period)
+        }
+        ExecuteMsg::SetOperatorWithdrawalLockPeriod(withdrawal_lock_period) => {
+            execute::set_operator_withdrawal_lock_period(deps, env, info, withdrawal_lock_period)
+        }
+        ExecuteMsg::SetMaxVaultsPerOperator(max_vaults_per_operator) => {
+            execute::set_max_vaults_per_operator(deps, info, max_vaults_per_operator)
+        }
+        ExecuteMsg::SetOracle { oracle } => {
+            let oracle = oracle.map(|o| deps.api.addr_validate(&o)).transpose()?;
+            execute::set_oracle(deps, info, oracle)
+        }
+        ExecuteMsg::SetAllocationDelay(allocation_delay) => {
+            execute::set_allocation_delay(deps, info, allocation_delay)
+        }
+        ExecuteMsg::SetAllocation {
+            vault,
+            service,
+            bips,
+        } => {
+            let vault = deps.api.addr_validate(&vault)?;
+            let service = deps.api.addr_validate(&service)?;
+            execute::set_allocation(deps, env, info, vault, service, bips)
+        }
         ExecuteMsg::TransferOwnership { new_owner } => {
             let new_owner = deps.api.addr_validate(&new_owner)?;
             ownership::transfer_ownership(deps.storage, info, new_owner)
@@ -67,7 +92,21 @@ pub fn execute(
         ExecuteMsg::CancelSlashing(slashing_request_id) => {
             execute::cancel_slashing(deps, env, info, slashing_request_id)
         }
+        ExecuteMsg::ExpireSlashing(slashing_request_id) => {
+            execute::expire_slashing(deps, env, info, slashing_request_id)
+        }
         ExecuteMsg::FinalizeSlashing(id) => execute::finalize_slashing(deps, info, id),
+        ExecuteMsg::SweepSlashed {
+            slashing_request_id,
+            recipient,
+        } => {
+            let recipient = deps.api.addr_validate(&recipient)?;
+            execute::sweep_slashed(deps, info, slashing_request_id, recipient)
+        }
+        ExecuteMsg::RedistributeSlashed {
+            slashing_request_id,
+            destinations,
+        } => execute::redistribute_slashed(deps, info, slashing_request_id, destinations),
     }
 }
 
@@ -79,14 +118,33 @@ pub fn execute(
 /// operator.
 /// The existing `VAULTS` iterated over and added to `OPERATOR_VAULTS`.
 /// The `GUARDRAIL` contract is added to the router.
+///
+/// #### 2.0.0 to 3.0.0
+/// Vaults no longer whitelisted are removed from `OPERATOR_VAULTS`.
+///
+/// `SLASHING_REQUESTS` is indexed by service, operator and status.
+/// The existing slashing requests are re-saved to populate the indexes.
+///
+/// New `PENDING_SLASHES` flags the operators with a pending slashing request, consulted by the vaults.
+/// The existing pending slashing requests are flagged.
+///
+/// New `IDLE_SLASHED` tracks the collateral held by the router after finalizing without a destination.
+/// The collateral stranded by slashing requests finalized without a destination before the upgrade
+/// (the router balance of each vault asset not locked for a slashing request)
+/// is backfilled under the [`state::UNATTRIBUTED_IDLE_SLASHED_ID`] slashing request id.
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     let old_version =
         cw2::ensure_from_older_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     match old_version.major {
         1 => {
             migrate::vaults_to_index_operator(deps.branch())?;
-            migrate::add_guardrail_to_state(deps, msg)?;
+            migrate::add_guardrail_to_state(deps.branch(), msg)?;
+            migrate::v2_to_v3(deps, &env)?;
+            Ok(Response::default())
+        }
+        2 => {
+            migrate::v2_to_v3(deps, &env)?;
             Ok(Response::default())
         }
         _ => Ok(Response::default()),
@@ -95,7 +153,10 @@ pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response
 
 mod migrate {
     use super::*;
-    use crate::state::{OPERATOR_VAULTS, VAULTS};
+    use crate::state::{self, SlashingRequestStatus, OPERATOR_VAULTS, SLASHING_REQUESTS, VAULTS};
+    use bvs_library::slashing::SlashingRequestId;
+    use cosmwasm_std::{Addr, StdError, Uint128};
+    use std::collections::BTreeMap;
 
     pub fn vaults_to_index_operator(deps: DepsMut) -> Result<Response, ContractError> {
         let vaults = VAULTS
@@ -111,6 +172,105 @@ mod migrate {
         Ok(Response::default())
     }
 
+    pub fn v2_to_v3(mut deps: DepsMut, env: &Env) -> Result<Response, ContractError> {
+        unindex_non_whitelisted_vaults(deps.branch())?;
+        index_slashing_requests(deps.branch())?;
+        flag_pending_slashes(deps.branch())?;
+        backfill_idle_slashed(deps, env)?;
+        Ok(Response::default())
+    }
+
+    pub fn backfill_idle_slashed(deps: DepsMut, env: &Env) -> Result<Response, ContractError> {
+        // the assets of the vaults (that can still be queried) the router could have slashed
+        let vaults = VAULTS
+            .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        let mut vault_asset_ids: BTreeMap<Addr, String> = BTreeMap::new();
+        let mut assets: BTreeMap<String, (vault::AssetType, String)> = BTreeMap::new();
+        for vault in vaults {
+            let Ok(vault_info) = vault::get_vault_info(deps.as_ref(), &vault) else {
+                continue;
+            };
+            assets.insert(
+                vault_info.asset_id.clone(),
+                (vault_info.asset_type, vault_info.asset_reference),
+            );
+            vault_asset_ids.insert(vault, vault_info.asset_id);
+        }
+
+        // the collateral locked for slashing requests not finalized yet is held for them
+        let mut locked: BTreeMap<String, Uint128> = BTreeMap::new();
+        for item in
+            state::SLASH_LOCKED.range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        {
+            let ((_, vault), amount) = item?;
+            if let Some(asset_id) = vault_asset_ids.get(&vault) {
+                let total = locked.entry(asset_id.clone()).or_default();
+                *total = total.checked_add(amount).map_err(StdError::from)?;
+            }
+        }
+
+        let id = SlashingRequestId::from(state::UNATTRIBUTED_IDLE_SLASHED_ID);
+        for (asset_id, (asset_type, asset_reference)) in assets {
+            let balance = match asset_type {
+                vault::AssetType::Bank => {
+                    deps.querier
+                        .query_balance(&env.contract.address, &asset_reference)?
+                        .amount
+                }
+                vault::AssetType::Cw20 => {
+                    let response: cw20::BalanceResponse = deps.querier.query_wasm_smart(
+                        &asset_reference,
+                        &cw20::Cw20QueryMsg::Balance {
+                            address: env.contract.address.to_string(),
+                        },
+                    )?;
+                    response.balance
+                }
+            };
+            let accounted = locked
+                .get(&asset_id)
+                .copied()
+                .unwrap_or_default()
+                .checked_add(
+                    state::IDLE_SLASHED_TOTALS
+                        .may_load(deps.storage, &asset_id)?
+                        .unwrap_or_default(),
+                )
+                .map_err(StdError::from)?;
+            let stranded = balance.saturating_sub(accounted);
+            if !stranded.is_zero() {
+                state::add_idle_slashed(
+                    deps.storage,
+                    &id,
+                    &asset_id,
+                    asset_type,
+                    asset_reference,
+                    stranded,
+                )?;
+            }
+        }
+
+        Ok(Response::default())
+    }
+
+    pub fn unindex_non_whitelisted_vaults(deps: DepsMut) -> Result<Response, ContractError> {
+        let operator_vaults = OPERATOR_VAULTS
+            .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+
+        for (operator, vault) in operator_vaults {
+            let whitelisted = VAULTS
+                .may_load(deps.storage, &vault)?
+                .is_some_and(|v| v.whitelisted);
+            if !whitelisted {
+                OPERATOR_VAULTS.remove(deps.storage, (&operator, &vault));
+            }
+        }
+
+        Ok(Response::default())
+    }
+
     pub fn add_guardrail_to_state(
         deps: DepsMut,
         msg: MigrateMsg,
@@ -120,31 +280,72 @@ mod migrate {
 
         Ok(Response::default())
     }
+
+    pub fn index_slashing_requests(deps: DepsMut) -> Result<Response, ContractError> {
+        let slashing_requests = SLASHING_REQUESTS
+            .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+
+        for (id, slashing_request) in slashing_requests {
+            SLASHING_REQUESTS.replace(
+                deps.storage,
+                &id,
+                Some(&slashing_request),
+                Some(&slashing_request),
+            )?;
+        }
+
+        Ok(Response::default())
+    }
+
+    pub fn flag_pending_slashes(deps: DepsMut) -> Result<Response, ContractError> {
+        let pending = SLASHING_REQUESTS
+            .idx
+            .status
+            .sub_prefix(SlashingRequestStatus::Pending.into())
+            .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+
+        for (_, slashing_request) in pending {
+            let operator = deps.api.addr_validate(&slashing_request.request.operator)?;
+            state::PENDING_SLASHES.save(
+                deps.storage,
+                (&operator, &slashing_request.service),
+                &slashing_request.request_expiry,
+            )?;
+        }
+
+        Ok(Response::default())
+    }
 }
 
 mod execute {
     use super::*;
-    use crate::contract::query::get_withdrawal_lock_period;
     use crate::contract::vault::AssetType;
     use crate::error::ContractError;
-    use crate::msg::{RequestSlashingPayload, RequestSlashingResponse};
+    use crate::msg::{AssetAum, RequestSlashingPayload, RequestSlashingResponse};
     use crate::state::{
         self, SlashingRequest, SlashingRequestStatus, DEFAULT_WITHDRAWAL_LOCK_PERIOD,
-        SLASHING_REQUESTS, SLASHING_REQUEST_EXPIRY_WINDOW, SLASH_LOCKED, WITHDRAWAL_LOCK_PERIOD,
+        MIN_WITHDRAWAL_LOCK_PERIOD, OPERATOR_WITHDRAWAL_LOCK_PERIODS, SLASHING_REQUESTS,
+        SLASHING_REQUEST_EXPIRY_WINDOW, SLASH_LOCKED, WITHDRAWAL_LOCK_PERIOD,
     };
-    use crate::ContractError::{InvalidSlashingRequest, Unauthorized};
-    use bvs_library::addr::Operator;
+    use crate::ContractError::{InvalidAllocation, InvalidSlashingRequest, Unauthorized};
+    use bvs_library::addr::{Operator, Service};
     use bvs_library::ownership;
     use bvs_library::slashing::SlashingRequestId;
     use bvs_registry::msg::{
-        IsOperatorOptedInToSlashingResponse, SlashingParametersResponse, StatusResponse,
+        IsOperatorOptedInToSlashingResponse, IsOperatorResponse, SlashingParametersResponse,
+        StatusResponse,
+    };
+    use bvs_registry::{
+        RegistrationStatus, SlashingParameters, SlashingRecipient, WeightedDestination,
     };
-    use bvs_registry::RegistrationStatus;
     use cosmwasm_std::{
         Addr, BankMsg, Coin, CosmosMsg, DepsMut, Empty, Env, Event, MessageInfo, Response, Uint128,
         Uint64,
     };
     use cw3::ProposalResponse;
+    use cw_storage_plus::Bound;
 
     /// Set the vault contract in the router and whitelist (true/false) it.
     /// Only the `owner` can call this message.
@@ -163,7 +364,9 @@ mod execute {
         // - we assert that the vault is connected to the router.
         // - we save the operator to vault mapping
         //   if a vault is never whitelisted, it won't be added to the operator mapping.
-        // Otherwise for non-whitelisted, the `state::VAULTS` will only be updated.
+        // Otherwise for non-whitelisted, the vault is removed from the operator mapping
+        // (freeing its slot in the max vaults per operator) if the vault can be queried,
+        // and the `state::VAULTS` is updated.
         // This is to allow the vault to be effectively removed
         // by setting `whitelisted: false` without checks from the router in
         // case the vault is malformed or broken.
@@ -177,7 +380,20 @@ mod execute {
                 });
             }
 
-            state::OPERATOR_VAULTS.save(deps.storage, (&vault_info.operator, &vault), &())?;
+            let operator_vault = (&vault_info.operator, &vault);
+            if !state::OPERATOR_VAULTS.has(deps.storage, operator_vault) {
+                let max_vaults = state::get_max_vaults_per_operator(deps.storage)?;
+                let vault_count = state::count_operator_vaults(deps.storage, &vault_info.operator)?;
+                if vault_count >= max_vaults {
+                    return Err(ContractError::VaultError {
+                        msg: "Operator has reached the max vaults per operator".to_string(),
+                    });
+                }
+            }
+
+            state::OPERATOR_VAULTS.save(deps.storage, operator_vault, &())?;
+        } else if let Ok(vault_info) = vault::get_vault_info(deps.as_ref(), &vault) {
+            state::OPERATOR_VAULTS.remove(deps.storage, (&vault_info.operator, &vault));
         }
 
         state::VAULTS.save(deps.storage, &vault, &state::Vault { whitelisted })?;
@@ -197,11 +413,7 @@ mod execute {
     ) -> Result<Response, ContractError> {
         ownership::assert_owner(deps.storage, &info)?;
 
-        if withdrawal_lock_period.is_zero() {
-            return Err(ContractError::VaultError {
-                msg: "Withdrawal lock period must be greater than zero".to_string(),
-            });
-        }
+        assert_withdrawal_lock_period(deps.as_ref(), withdrawal_lock_period)?;
 
         let prev_withdrawal_lock_period = WITHDRAWAL_LOCK_PERIOD
             .may_load(deps.storage)?
@@ -222,160 +434,614 @@ mod execute {
         ))
     }
 
-    pub fn request_slashing(
+    /// Set the minimum withdrawal lock period.
+    /// Only the `owner` can call this message.
+    /// Withdrawal lock periods (default or per-operator) already set below the new minimum
+    /// are not changed, but are raised to the minimum when read.
+    pub fn set_min_withdrawal_lock_period(
         deps: DepsMut,
-        env: Env,
         info: MessageInfo,
-        data: RequestSlashingPayload,
+        min_withdrawal_lock_period: Uint64,
     ) -> Result<Response, ContractError> {
-        // service is the sender
-        let service = info.sender;
+        ownership::assert_owner(deps.storage, &info)?;
 
-        let operator: Operator = deps.api.addr_validate(data.operator.as_str())?;
+        let prev_min_withdrawal_lock_period = state::get_min_withdrawal_lock_period(deps.storage)?;
 
-        let registry = state::get_registry(deps.storage)?;
+        MIN_WITHDRAWAL_LOCK_PERIOD.save(deps.storage, &min_withdrawal_lock_period)?;
 
-        // ensure that metadata.reason does not exceed MAX_STRING_BYTES bytes
-        if data.metadata.reason.len() > MAX_STRING_BYTES {
-            return Err(InvalidSlashingRequest {
-                msg: "Reason exceeds maximum allowed length".to_string(),
+        Ok(Response::new().add_event(
+            Event::new("SetMinWithdrawalLockPeriod")
+                .add_attribute(
+                    "prev_min_withdrawal_lock_period",
+                    prev_min_withdrawal_lock_period.to_string(),
+                )
+                .add_attribute(
+                    "new_min_withdrawal_lock_period",
+                    min_withdrawal_lock_period.to_string(),
+                ),
+        ))
+    }
+
+    /// Set the max number of vaults per operator.
+    /// Only the `owner` can call this message.
+    /// Operators with more vaults than a lowered max keep their vaults,
+    /// but cannot have new vaults whitelisted until they are below the max.
+    pub fn set_max_vaults_per_operator(
+        deps: DepsMut,
+        info: MessageInfo,
+        max_vaults_per_operator: u32,
+    ) -> Result<Response, ContractError> {
+        ownership::assert_owner(deps.storage, &info)?;
+
+        if max_vaults_per_operator == 0 {
+            return Err(ContractError::VaultError {
+                msg: "Max vaults per operator must be greater than zero".to_string(),
             });
         }
 
-        // ensure that bips is greater than zero
-        if data.bips < 1 {
-            return Err(InvalidSlashingRequest {
-                msg: "Slashing bips must be greater than zero".to_string(),
+        if max_vaults_per_operator > state::MAX_MAX_VAULTS_PER_OPERATOR {
+            return Err(ContractError::VaultError {
+                msg: "Max vaults per operator must not exceed the lock slashing gas limit"
+                    .to_string(),
             });
         }
 
-        // max_slashable_delay is equal to the withdrawal lock period (7 days default)
-        let max_slashable_delay = get_withdrawal_lock_period(deps.as_ref())?;
-        // ensure the timestamp of slash must not be more than MAX_SLASHABLE_DELAY before and not in the future
-        if data.timestamp < env.block.time.minus_seconds(max_slashable_delay.u64())
-            || data.timestamp > env.block.time
-        {
-            return Err(InvalidSlashingRequest {
-                msg: "Slash timestamp must be within the allowable slash period".to_string(),
-            });
+        let prev_max_vaults_per_operator = state::get_max_vaults_per_operator(deps.storage)?;
+
+        state::MAX_VAULTS_PER_OPERATOR.save(deps.storage, &max_vaults_per_operator)?;
+
+        Ok(Response::new().add_event(
+            Event::new("SetMaxVaultsPerOperator")
+                .add_attribute(
+                    "prev_max_vaults_per_operator",
+                    prev_max_vaults_per_operator.to_string(),
+                )
+                .add_attribute(
+                    "new_max_vaults_per_operator",
+                    max_vaults_per_operator.to_string(),
+                ),
+        ))
+    }
+
+    /// Set (or remove with `None`) the oracle contract used to value the assets under management in USD.
+    /// Only the `owner` can call this message.
+    pub fn set_oracle(
+        deps: DepsMut,
+        info: MessageInfo,
+        oracle: Option<Addr>,
+    ) -> Result<Response, ContractError> {
+        ownership::assert_owner(deps.storage, &info)?;
+
+        let prev_oracle = state::ORACLE.may_load(deps.storage)?;
+
+        match &oracle {
+            Some(oracle) => state::ORACLE.save(deps.storage, oracle)?,
+            None => state::ORACLE.remove(deps.storage),
         }
 
-        // require active status between operator and service
-        let StatusResponse(operator_service_status) = deps.querier.query_wasm_smart(
-            registry.to_string(),
-            &bvs_registry::msg::QueryMsg::Status {
-                service: service.to_string(),
-                operator: operator.to_string(),
-                timestamp: Some(data.timestamp.seconds()),
-            },
-        )?;
+        let addr_or_empty = |addr: Option<Addr>| addr.map(Addr::into_string).unwrap_or_default();
+        Ok(Response::new().add_event(
+            Event::new("SetOracle")
+                .add_attribute("prev_oracle", addr_or_empty(prev_oracle))
+                .add_attribute("new_oracle", addr_or_empty(oracle)),
+        ))
+    }
 
-        // this check validate the service calling the locking is the same as the one that
-        // requested the slashing
-        if operator_service_status != u8::from(RegistrationStatus::Active) {
-            return Err(InvalidSlashingRequest {
-                msg: "Service and Operator must be active at the specified timestamp".to_string(),
+    /// Set the delay (in seconds) before an allocation increase becomes slashable.
+    /// Only the `owner` can call this message.
+    /// Pending allocations are not changed.
+    pub fn set_allocation_delay(
+        deps: DepsMut,
+        info: MessageInfo,
+        allocation_delay: Uint64,
+    ) -> Result<Response, ContractError> {
+        ownership::assert_owner(deps.storage, &info)?;
+
+        let prev_allocation_delay = state::get_allocation_delay(deps.storage)?;
+
+        state::ALLOCATION_DELAY.save(deps.storage, &allocation_delay)?;
+
+        Ok(Response::new().add_event(
+            Event::new("SetAllocationDelay")
+                .add_attribute("prev_allocation_delay", prev_allocation_delay.to_string())
+                .add_attribute("new_allocation_delay", allocation_delay.to_string()),
+        ))
+    }
+
+    /// Allocate `bips` of the stake of the vault to the service.
+    /// Only the operator of the vault (info.sender) can call this message.
+    /// The allocation change is pending until it is effective:
+    /// after the allocation delay for an increase,
+    /// after the withdrawal lock period of the operator for a decrease.
+    /// The first allocation of the operator (ending the full-stake slashing of all its services)
+    /// is effective after the longer of the two.
+    pub fn set_allocation(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        vault: Addr,
+        service: Service,
+        bips: u16,
+    ) -> Result<Response, ContractError> {
+        let operator = info.sender;
+
+        if !state::OPERATOR_VAULTS.has(deps.storage, (&operator, &vault)) {
+            return Err(Unauthorized {
+                msg: "Only the operator of the vault can allocate its stake".to_string(),
             });
         }
 
-        // get slashing params of the service at the given timestamp, also checks if slashing is enabled
-        let SlashingParametersResponse(slashing_parameters) = deps.querier.query_wasm_smart(
-            registry.clone(),
-            &bvs_registry::msg::QueryMsg::SlashingParameters {
-                service: service.to_string(),
-                timestamp: Some(data.timestamp.seconds()),
-            },
-        )?;
-        let slashing_parameters = match slashing_parameters {
-            Some(x) => x,
-            None => {
-                return Err(InvalidSlashingRequest {
-                    msg: "Service must have slashing enabled at the specified timestamp"
-                        .to_string(),
-                })
-            }
-        };
+        if bips > state::MAX_ALLOCATION_BIPS {
+            return Err(InvalidAllocation {
+                msg: "Allocation must not exceed 10,000 bips (100%)".to_string(),
+            });
+        }
 
-        // ensure bips must not exceed max_slashing_bips set by service
-        if data.bips > slashing_parameters.max_slashing_bips {
-            return Err(InvalidSlashingRequest {
-                msg: "Slashing bips exceeds the maximum allowed by service".to_string(),
+        let now = env.block.time;
+        let current = state::ALLOCATIONS
+            .may_load(deps.storage, (&vault, &service))?
+            .map(|allocation| allocation.at(now))
+            .unwrap_or(state::Allocation {
+                bips: 0,
+                pending: None,
+            });
+
+        if current.pending.is_some() {
+            return Err(InvalidAllocation {
+                msg: "Pending allocation must be effective before it can be changed".to_string(),
+            });
+        }
+
+        if bips == current.bips {
+            return Err(InvalidAllocation {
+                msg: "Allocation is unchanged".to_string(),
             });
         }
 
-        // ensure that the operator has opted in to slashing at the given timestamp.
-        let IsOperatorOptedInToSlashingResponse(is_operator_opted_in) =
-            deps.querier.query_wasm_smart(
+        let delay = if bips > current.bips {
+            let registry = state::get_registry(deps.storage)?;
+            let StatusResponse(status) = deps.querier.query_wasm_smart(
                 registry,
-                &bvs_registry::msg::QueryMsg::IsOperatorOptedInToSlashing {
+                &bvs_registry::msg::QueryMsg::Status {
                     service: service.to_string(),
                     operator: operator.to_string(),
-                    timestamp: Some(data.timestamp.seconds()),
+                    timestamp: None,
                 },
             )?;
+            if RegistrationStatus::try_from(status)? != RegistrationStatus::Active {
+                return Err(InvalidAllocation {
+                    msg: "Service and Operator must be active to allocate".to_string(),
+                });
+            }
 
-        if !is_operator_opted_in {
-            return Err(InvalidSlashingRequest {
-                msg: "Operator must be opted-in to slashing at the specified timestamp".to_string(),
-            });
-        }
-
-        // get current pending slashing request for (service, operator) pair
-        let prev_slashing_request =
-            state::get_pending_slashing_request(deps.storage, &service, &operator)?;
+            let encumbered = state::sum_encumbered_bips(deps.storage, &vault, &service, now)?;
+            if encumbered + u32::from(bips) > u32::from(state::MAX_ALLOCATION_BIPS) {
+                return Err(InvalidAllocation {
+                    msg: "Allocations of the vault must not exceed 10,000 bips (100%)".to_string(),
+                });
+            }
 
-        // refer to the [get_pending_slashing_request()] function doc for Some() or None return cases
-        if let Some(prev_slashing_request) = prev_slashing_request {
-            match SlashingRequestStatus::try_from(prev_slashing_request.status)? {
-                SlashingRequestStatus::Pending => {
-                    // slashing is pending within the expiry date
-                    if prev_slashing_request.request_expiry > env.block.time {
-                        return Err(ContractError::InvalidSlashingRequest {
-                            msg: "Cannot process new request while previous slashing request is pending".to_string(),
-                        });
-                    } else {
-                        // In this case, new request is eligible and
-                        // will override the previous slashing request.
-                        // Put the previous slashing request to canceled state.
-                        let prev_slash_id = state::SLASHING_REQUEST_IDS
-                            .load(deps.storage, (&service, &operator))?;
-                        state::update_slashing_request_status(
-                            deps.storage,
-                            &prev_slash_id,
-                            SlashingRequestStatus::Canceled,
-                        )?;
-                    }
-                }
-                SlashingRequestStatus::Locked => {
-                    // If a slash is locked that mean previous slash is
-                    // in the middle of slashing lifecycle
-                    // new request are rejected
-                    return Err(ContractError::InvalidSlashingRequest {
-                        msg: "Cannot process new request while previous slashing request is in progress".to_string(),
-                    });
-                }
-                SlashingRequestStatus::Canceled => {
-                    // Previous slash has been canceled
-                    // Cancellation can happen when:
-                    // 1. Operator has refute the request
-                    // 2. Slash Locking handler catches the slash is expired and cancel implicitly
-                    // Eligible for new request
-                }
-                SlashingRequestStatus::Finalized => {
-                    // Previous slash has been finalized, eligible for new request
-                }
+            let allocation_delay = state::get_allocation_delay(deps.storage)?;
+            if state::ALLOCATION_OPERATORS.has(deps.storage, &operator) {
+                allocation_delay
+            } else {
+                // the first allocation deallocates the unallocated stake from every other service,
+                // slashable until it can no longer be withdrawn before the incident
+                allocation_delay.max(state::get_operator_withdrawal_lock_period_at(
+                    deps.storage,
+                    &operator,
+                    now,
+                )?)
             }
-        }
+        } else {
+            // the deallocated stake is slashable until it can no longer be withdrawn before the incident
+            state::get_operator_withdrawal_lock_period_at(deps.storage, &operator, now)?
+        };
+        let effective_time = now.plus_seconds(delay.u64());
 
-        let request_resolution = env
-            .block
-            .time
-            .plus_seconds(slashing_parameters.resolution_window);
+        state::ALLOCATIONS.save(
+            deps.storage,
+            (&vault, &service),
+            &state::Allocation {
+                bips: current.bips,
+                pending: Some(state::PendingAllocation {
+                    bips,
+                    effective_time,
+                }),
+            },
+        )?;
+        state::ALLOCATION_CHECKPOINTS.save(
+            deps.storage,
+            (&vault, &service, effective_time.seconds()),
+            &bips,
+        )?;
+        if !state::ALLOCATION_OPERATORS.has(deps.storage, &operator) {
+            state::ALLOCATION_OPERATORS.save(deps.storage, &operator, &effective_time)?;
+        }
 
-        // request_expiry will be using `resolution_window`
-        // value from the timestamp's slashing_parameters,
-        // instead of the most recent slashing param.
-        // This ensures that both parties agree upon all parameters used.
+        Ok(Response::new().add_event(
+            Event::new("SetAllocation")
+                .add_attribute("operator", operator)
+                .add_attribute("vault", vault)
+                .add_attribute("service", service)
+                .add_attribute("prev_bips", current.bips.to_string())
+                .add_attribute("new_bips", bips.to_string())
+                .add_attribute("effective_time", effective_time.seconds().to_string()),
+        ))
+    }
+
+    /// Set (or remove with `None`) the withdrawal lock period of the operator (info.sender),
+    /// overriding the default withdrawal lock period for its vaults.
+    /// Only a registered operator can call this message,
+    /// and only once the `owner` has set the minimum withdrawal lock period.
+    ///
+    /// An increase is effective immediately.
+    /// A decrease is effective after the current withdrawal lock period of the operator,
+    /// so incidents within the current slashable look-back window remain slashable.
+    /// A new change replaces a pending decrease.
+    pub fn set_operator_withdrawal_lock_period(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        withdrawal_lock_period: Option<Uint64>,
+    ) -> Result<Response, ContractError> {
+        let operator = info.sender;
+
+        let registry = state::get_registry(deps.storage)?;
+        let IsOperatorResponse(is_operator) = deps.querier.query_wasm_smart(
+            registry,
+            &bvs_registry::msg::QueryMsg::IsOperator(operator.to_string()),
+        )?;
+        if !is_operator {
+            return Err(Unauthorized {
+                msg: "Sender is not a registered operator".to_string(),
+            });
+        }
+
+        let new_withdrawal_lock_period = match withdrawal_lock_period {
+            Some(withdrawal_lock_period) => {
+                if !MIN_WITHDRAWAL_LOCK_PERIOD.exists(deps.storage) {
+                    return Err(ContractError::VaultError {
+                        msg: "Minimum withdrawal lock period is not set".to_string(),
+                    });
+                }
+                assert_withdrawal_lock_period(deps.as_ref(), withdrawal_lock_period)?;
+                withdrawal_lock_period
+            }
+            None => state::get_withdrawal_lock_period(deps.storage)?,
+        };
+
+        let now = env.block.time;
+        let current_withdrawal_lock_period =
+            state::get_operator_withdrawal_lock_period_at(deps.storage, &operator, now)?;
+
+        // remove the pending decrease (if any), replaced by this change
+        let pending = OPERATOR_WITHDRAWAL_LOCK_PERIODS
+            .prefix(&operator)
+            .keys(
+                deps.storage,
+                Some(Bound::exclusive(now.seconds())),
+                None,
+                cosmwasm_std::Order::Ascending,
+            )
+            .collect::<StdResult<Vec<_>>>()?;
+        for effective_time in pending {
+            OPERATOR_WITHDRAWAL_LOCK_PERIODS.remove(deps.storage, (&operator, effective_time));
+        }
+
+        let effective_time = if new_withdrawal_lock_period < current_withdrawal_lock_period {
+            now.plus_seconds(current_withdrawal_lock_period.u64())
+        } else {
+            now
+        };
+        OPERATOR_WITHDRAWAL_LOCK_PERIODS.save(
+            deps.storage,
+            (&operator, effective_time.seconds()),
+            &withdrawal_lock_period,
+        )?;
+
+        Ok(Response::new().add_event(
+            Event::new("SetOperatorWithdrawalLockPeriod")
+                .add_attribute("operator", operator)
+                .add_attribute(
+                    "prev_withdrawal_lock_period",
+                    current_withdrawal_lock_period.to_string(),
+                )
+                .add_attribute(
+                    "new_withdrawal_lock_period",
+                    new_withdrawal_lock_period.to_string(),
+                )
+                .add_attribute("effective_time", effective_time.seconds().to_string()),
+        ))
+    }
+
+    /// Asserts that the withdrawal lock period is greater than zero
+    /// and not below the minimum withdrawal lock period.
+    fn assert_withdrawal_lock_period(
+        deps: Deps,
+        withdrawal_lock_period: Uint64,
+    ) -> Result<(), ContractError> {
+        if withdrawal_lock_period.is_zero() {
+            return Err(ContractError::VaultError {
+                msg: "Withdrawal lock period must be greater than zero".to_string(),
+            });
+        }
+
+        if withdrawal_lock_period < state::get_min_withdrawal_lock_period(deps.storage)? {
+            return Err(ContractError::VaultError {
+                msg: "Withdrawal lock period must not be below the minimum".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Get the slashing parameters of the service at the given timestamp from the registry.
+    /// If `category` is provided, the slashing parameters of the named slashing category are returned.
+    /// Errors if slashing (or the category) isn't enabled at the given timestamp.
+    pub(crate) fn get_slashing_parameters(
+        deps: Deps,
+        registry: &Addr,
+        service: &Addr,
+        category: &Option<String>,
+        timestamp: u64,
+    ) -> Result<SlashingParameters, ContractError> {
+        let SlashingParametersResponse(slashing_parameters) = match category {
+            Some(category) => deps.querier.query_wasm_smart(
+                registry.to_string(),
+                &bvs_registry::msg::QueryMsg::SlashingCategory {
+                    service: service.to_string(),
+                    category: category.to_string(),
+                    timestamp: Some(timestamp),
+                },
+            )?,
+            None => deps.querier.query_wasm_smart(
+                registry.to_string(),
+                &bvs_registry::msg::QueryMsg::SlashingParameters {
+                    service: service.to_string(),
+                    timestamp: Some(timestamp),
+                },
+            )?,
+        };
+
+        slashing_parameters.ok_or_else(|| match category {
+            Some(_) => InvalidSlashingRequest {
+                msg: "Service must have the slashing category enabled at the specified timestamp"
+                    .to_string(),
+            },
+            None => InvalidSlashingRequest {
+                msg: "Service must have slashing enabled at the specified timestamp".to_string(),
+            },
+        })
+    }
+
+    /// Asserts that the selected vaults are not empty, not duplicated,
+    /// belong to the operator, and their assets are accepted by the slashing parameters.
+    fn assert_slashable_vaults(
+        deps: Deps,
+        operator: &Operator,
+        slashing_parameters: &SlashingParameters,
+        vaults: &[String],
+    ) -> Result<(), ContractError> {
+        if vaults.is_empty() {
+            return Err(InvalidSlashingRequest {
+                msg: "Vaults must not be empty".to_string(),
+            });
+        }
+
+        for (i, vault) in vaults.iter().enumerate() {
+            let vault = deps.api.addr_validate(vault)?;
+            if vaults[..i].contains(&vault.to_string()) {
+                return Err(InvalidSlashingRequest {
+                    msg: "Duplicate vault".to_string(),
+                });
+            }
+            if !state::OPERATOR_VAULTS.has(deps.storage, (operator, &vault)) {
+                return Err(InvalidSlashingRequest {
+                    msg: "Vault does not belong to the operator".to_string(),
+                });
+            }
+            if !slashing_parameters.asset_ids.is_empty() {
+                let vault_info = vault::get_vault_info(deps, &vault)?;
+                if !slashing_parameters.accepts_asset_id(&vault_info.asset_id) {
+                    return Err(InvalidSlashingRequest {
+                        msg: "Vault asset is not accepted by the service".to_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn request_slashing(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        data: RequestSlashingPayload,
+    ) -> Result<Response, ContractError> {
+        // service is the sender
+        let service = info.sender;
+
+        let operator: Operator = deps.api.addr_validate(data.operator.as_str())?;
+
+        let registry = state::get_registry(deps.storage)?;
+
+        // ensure that metadata.reason does not exceed MAX_STRING_BYTES bytes
+        if data.metadata.reason.len() > MAX_STRING_BYTES {
+            return Err(InvalidSlashingRequest {
+                msg: "Reason exceeds maximum allowed length".to_string(),
+            });
+        }
+
+        // ensure that bips is greater than zero
+        if data.bips < 1 {
+            return Err(InvalidSlashingRequest {
+                msg: "Slashing bips must be greater than zero".to_string(),
+            });
+        }
+
+        // ensure the timestamp of slash is not in the future
+        if data.timestamp > env.block.time {
+            return Err(InvalidSlashingRequest {
+                msg: "Slash timestamp must be within the allowable slash period".to_string(),
+            });
+        }
+
+        // max_slashable_delay is equal to the withdrawal lock period of the operator (7 days default)
+        // in effect at the timestamp of slash, stake at the incident could not be withdrawn before it
+        let max_slashable_delay =
+            state::get_operator_withdrawal_lock_period_at(deps.storage, &operator, data.timestamp)?;
+        // ensure the timestamp of slash must not be more than max_slashable_delay before
+        if data.timestamp < env.block.time.minus_seconds(max_slashable_delay.u64()) {
+            return Err(InvalidSlashingRequest {
+                msg: "Slash timestamp must be within the allowable slash period".to_string(),
+            });
+        }
+
+        // require active (or exiting) status between operator and service,
+        // an exiting operator is still slashable until the deregistration is finalized
+        let StatusResponse(operator_service_status) = deps.querier.query_wasm_smart(
+            registry.to_string(),
+            &bvs_registry::msg::QueryMsg::Status {
+                service: service.to_string(),
+                operator: operator.to_string(),
+                timestamp: Some(data.timestamp.seconds()),
+            },
+        )?;
+
+        // this check validate the service calling the locking is the same as the one that
+        // requested the slashing
+        if !RegistrationStatus::try_from(operator_service_status)?.is_validating() {
+            return Err(InvalidSlashingRequest {
+                msg: "Service and Operator must be active at the specified timestamp".to_string(),
+            });
+        }
+
+        // get slashing params of the service (or category) at the given timestamp,
+        // also checks if slashing (or the category) is enabled
+        let slashing_parameters = get_slashing_parameters(
+            deps.as_ref(),
+            &registry,
+            &service,
+            &data.category,
+            data.timestamp.seconds(),
+        )?;
+
+        // ensure bips must not exceed max_slashing_bips set by service
+        if data.bips > slashing_parameters.max_slashing_bips {
+            return Err(InvalidSlashingRequest {
+                msg: "Slashing bips exceeds the maximum allowed by service".to_string(),
+            });
+        }
+
+        // ensure that the operator has opted in to slashing (or the category) at the given timestamp.
+        let IsOperatorOptedInToSlashingResponse(is_operator_opted_in) = match &data.category {
+            Some(category) => deps.querier.query_wasm_smart(
+                registry,
+                &bvs_registry::msg::QueryMsg::IsOperatorOptedInToSlashingCategory {
+                    service: service.to_string(),
+                    category: category.to_string(),
+                    operator: operator.to_string(),
+                    timestamp: Some(data.timestamp.seconds()),
+                },
+            )?,
+            None => deps.querier.query_wasm_smart(
+                registry,
+                &bvs_registry::msg::QueryMsg::IsOperatorOptedInToSlashing {
+                    service: service.to_string(),
+                    operator: operator.to_string(),
+                    timestamp: Some(data.timestamp.seconds()),
+                },
+            )?,
+        };
+
+        if !is_operator_opted_in {
+            return Err(InvalidSlashingRequest {
+                msg: "Operator must be opted-in to slashing at the specified timestamp".to_string(),
+            });
+        }
+
+        // ensure the selected vaults belong to the operator and their assets are accepted by the service
+        if let Some(vaults) = &data.vaults {
+            assert_slashable_vaults(deps.as_ref(), &operator, &slashing_parameters, vaults)?;
+        }
+
+        // get current pending slashing request for (service, operator) pair
+        let prev_slashing_request =
+            state::get_pending_slashing_request(deps.storage, &service, &operator)?;
+
+        // refer to the [get_pending_slashing_request()] function doc for Some() or None return cases
+        if let Some(prev_slashing_request) = prev_slashing_request {
+            match SlashingRequestStatus::try_from(prev_slashing_request.status)? {
+                SlashingRequestStatus::Pending => {
+                    // slashing is pending within the expiry date (it can still be locked at its expiry)
+                    if prev_slashing_request.request_expiry >= env.block.time {
+                        return Err(ContractError::InvalidSlashingRequest {
+                            msg: "Cannot process new request while previous slashing request is pending".to_string(),
+                        });
+                    } else {
+                        // In this case, the previous slashing request has expired without being locked
+                        // (nobody called ExpireSlashing), new request is eligible and will override it.
+                        // Put the previous slashing request to expired state, as ExpireSlashing would.
+                        let prev_slash_id = state::SLASHING_REQUEST_IDS
+                            .load(deps.storage, (&service, &operator))?;
+                        state::update_slashing_request_status(
+                            deps.storage,
+                            &prev_slash_id,
+                            SlashingRequestStatus::Expired,
+                        )?;
+                        state::start_slashing_request_cooldown(
+                            deps.storage,
+                            &service,
+                            &operator,
+                            prev_slashing_request.request_expiry,
+                        )?;
+                    }
+                }
+                SlashingRequestStatus::Locked => {
+                    // If a slash is locked that mean previous slash is
+                    // in the middle of slashing lifecycle
+                    // new request are rejected
+                    return Err(ContractError::InvalidSlashingRequest {
+                        msg: "Cannot process new request while previous slashing request is in progress".to_string(),
+                    });
+                }
+                SlashingRequestStatus::Canceled => {
+                    // Previous slash has been canceled by the service with CancelSlashing
+                    // (e.g. the operator has resolved the issue)
+                    // Eligible for new request (after the cooldown)
+                }
+                SlashingRequestStatus::Finalized => {
+                    // Previous slash has been finalized, eligible for new request
+                }
+                SlashingRequestStatus::Expired => {
+                    // Previous slash has expired, eligible for new request (after the cooldown)
+                }
+            }
+        }
+
+        // a previous slash that ended without being locked (canceled or expired)
+        // bars the service from requesting again during the cooldown,
+        // so back-to-back requests can't keep the operator's vaults frozen
+        if let Some(cooldown) =
+            state::SLASHING_REQUEST_COOLDOWNS.may_load(deps.storage, (&service, &operator))?
+        {
+            if env.block.time < cooldown {
+                return Err(ContractError::InvalidSlashingRequest {
+                    msg: "Cannot process new request during the cooldown of the previous slashing request".to_string(),
+                });
+            }
+        }
+
+        let request_resolution = env
+            .block
+            .time
+            .plus_seconds(slashing_parameters.resolution_window);
+
+        // request_expiry will be using `resolution_window`
+        // value from the timestamp's slashing_parameters,
+        // instead of the most recent slashing param.
+        // This ensures that both parties agree upon all parameters used.
         let request_expiry = env
             .block
             .time
@@ -442,6 +1108,11 @@ mod execute {
                     msg: "Cannot process a slashing request that has been finalized".to_string(),
                 })
             }
+            SlashingRequestStatus::Expired => {
+                return Err(ContractError::InvalidSlashingRequest {
+                    msg: "Cannot process a slashing request that has expired".to_string(),
+                })
+            }
         }
 
         // Check if the id is the same as the one in the request
@@ -466,22 +1137,62 @@ mod execute {
             });
         };
 
-        let vaults_managed = state::OPERATOR_VAULTS
-            .prefix(&accused_operator)
-            .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-            .collect::<StdResult<Vec<_>>>()?;
+        // the selected vaults of the request, else every vault of the operator
+        let vaults_managed = match &slash_req.request.vaults {
+            Some(vaults) => vaults
+                .iter()
+                .map(|vault| deps.api.addr_validate(vault))
+                .collect::<StdResult<Vec<_>>>()?,
+            None => state::OPERATOR_VAULTS
+                .prefix(&accused_operator)
+                .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?,
+        };
+
+        // only the vaults of the assets accepted by the service (at the timestamp) are slashed
+        let registry = state::get_registry(deps.storage)?;
+        let slashing_parameters = get_slashing_parameters(
+            deps.as_ref(),
+            &registry,
+            &slash_req.service,
+            &slash_req.request.category,
+            slash_req.request.timestamp.seconds(),
+        )?;
 
         // Array instead of map to avoid the overheads
         let mut messages = Vec::new();
         for vault in vaults_managed {
             let vault_info = vault::get_vault_info(deps.as_ref(), &vault)?;
+            if !slashing_parameters.accepts_asset_id(&vault_info.asset_id) {
+                continue;
+            }
             let total_assets = vault_info.total_assets;
 
+            // Only the stake allocated to the service at the incident timestamp is slashable.
+            let allocation_bips = state::get_allocation_bips_at(
+                deps.storage,
+                &accused_operator,
+                &vault,
+                &slash_req.service,
+                slash_req.request.timestamp,
+            )?;
+
             // Due to the nature of the integer division involved, the result is always floored.
-            let slash_absolute =
-                total_assets.multiply_ratio(slash_req.request.bips, Uint128::from(10000_u128));
+            let slash_absolute = total_assets.multiply_ratio(
+                u128::from(slash_req.request.bips) * u128::from(allocation_bips),
+                Uint128::from(10000_u128 * 10000_u128),
+            );
 
             SLASH_LOCKED.save(deps.storage, (id.clone(), &vault), &slash_absolute)?;
+            state::SLASH_LOCKED_AUM.save(
+                deps.storage,
+                (id.clone(), &vault),
+                &AssetAum {
+                    asset_id: vault_info.asset_id,
+                    total_assets,
+                    usd_value: None,
+                },
+            )?;
 
             // Can't slash lock a zero asset vault the vault will reject.
             // But we still need to populate the `SLASH_LOCKED` map to indicate
@@ -501,18 +1212,7 @@ mod execute {
             messages.push(exec_msg);
         }
 
-        state::SLASHING_REQUESTS.save(
-            deps.storage,
-            &id,
-            &SlashingRequest {
-                service: slash_req.service,
-                request: slash_req.request.clone(),
-                request_time: slash_req.request_time,
-                request_resolution: slash_req.request_resolution,
-                request_expiry: slash_req.request_expiry,
-                status: SlashingRequestStatus::Locked.into(),
-            },
-        )?;
+        state::update_slashing_request_status(deps.storage, &id, SlashingRequestStatus::Locked)?;
 
         Ok(Response::new()
             .add_event(
@@ -529,7 +1229,7 @@ mod execute {
     /// Cancel a resolved slashing request that an operator has already resolved the issue.
     pub fn cancel_slashing(
         deps: DepsMut,
-        _env: Env,
+        env: Env,
         info: MessageInfo,
         slashing_request_id: SlashingRequestId,
     ) -> Result<Response, ContractError> {
@@ -565,6 +1265,7 @@ mod execute {
             &slashing_request_id,
             SlashingRequestStatus::Canceled,
         )?;
+        state::start_slashing_request_cooldown(deps.storage, &service, &operator, env.block.time)?;
 
         Ok(Response::new().add_event(
             Event::new("CancelSlashing")
@@ -574,16 +1275,65 @@ mod execute {
         ))
     }
 
-    pub fn finalize_slashing(
+    /// Expires a pending slashing request past its `request_expiry`, callable by anyone.
+    pub fn expire_slashing(
         deps: DepsMut,
+        env: Env,
         info: MessageInfo,
-        id: SlashingRequestId,
+        slashing_request_id: SlashingRequestId,
     ) -> Result<Response, ContractError> {
-        // Service is the sender
-        let service = info.sender;
-
-        // Check guardrail contract that slashing request is passed
-        let guardrail = GUARDRAIL.load(deps.storage)?;
+        let slashing_request = SLASHING_REQUESTS
+            .may_load(deps.storage, &slashing_request_id)?
+            .ok_or(ContractError::InvalidSlashingRequest {
+                msg: "No slashing request found with the provided ID".to_string(),
+            })?;
+
+        if slashing_request.status != SlashingRequestStatus::Pending {
+            return Err(ContractError::InvalidSlashingRequest {
+                msg: "Only slashing requests in pending status can be expired".to_string(),
+            });
+        }
+
+        // a slashing request can still be locked at its request_expiry
+        if env.block.time <= slashing_request.request_expiry {
+            return Err(ContractError::InvalidSlashingRequest {
+                msg: "Slashing request has not expired yet".to_string(),
+            });
+        }
+
+        let operator = deps.api.addr_validate(&slashing_request.request.operator)?;
+        state::remove_slashing_request_id(deps.storage, &slashing_request.service, &operator)?;
+        state::update_slashing_request_status(
+            deps.storage,
+            &slashing_request_id,
+            SlashingRequestStatus::Expired,
+        )?;
+        state::start_slashing_request_cooldown(
+            deps.storage,
+            &slashing_request.service,
+            &operator,
+            slashing_request.request_expiry,
+        )?;
+
+        Ok(Response::new().add_event(
+            Event::new("ExpireSlashing")
+                .add_attribute("sender", info.sender)
+                .add_attribute("service", slashing_request.service)
+                .add_attribute("operator", operator)
+                .add_attribute("slashing_request_id", slashing_request_id.to_string()),
+        ))
+    }
+
+    pub fn finalize_slashing(
+        deps: DepsMut,
+        info: MessageInfo,
+        id: SlashingRequestId,
+    ) -> Result<Response, ContractError> {
+        // Service is the sender
+        let service = info.sender;
+
+        // Check guardrail contract that slashing request is passed
+        let guardrail = GUARDRAIL.load(deps.storage)?;
         let ProposalResponse {
             status: guardrail_proposal_status,
             ..
@@ -630,67 +1380,75 @@ mod execute {
             .addr_validate(slash_req.request.operator.as_str())?;
 
         let registry = state::get_registry(deps.storage)?;
-        // Get slashing params of the service at the given timestamp
-        let SlashingParametersResponse(slashing_parameters) = deps.querier.query_wasm_smart(
-            registry.clone(),
-            &bvs_registry::msg::QueryMsg::SlashingParameters {
-                service: service.to_string(),
-                timestamp: Some(slash_req.request.timestamp.seconds()),
-            },
+        // Get slashing params of the service (or category) at the given timestamp
+        let slashing_parameters = get_slashing_parameters(
+            deps.as_ref(),
+            &registry,
+            &service,
+            &slash_req.request.category,
+            slash_req.request.timestamp.seconds(),
         )?;
-        let slashing_parameters = match slashing_parameters {
-            Some(x) => x,
-            None => {
-                return Err(InvalidSlashingRequest {
-                    msg: "Service must have slashing enabled at the specified timestamp"
-                        .to_string(),
-                })
-            }
-        };
 
-        // transfer all slashed assets to the destination
+        // transfer all slashed assets to the destination(s)
         let mut transfer_msgs: Vec<CosmosMsg> = vec![];
-        for locked_vault_amount in SLASH_LOCKED.prefix(id.clone()).range(
-            deps.storage,
-            None,
-            None,
-            cosmwasm_std::Order::Ascending,
-        ) {
-            let (affected_vault, locked_amount) = locked_vault_amount?;
+        let mut distribution_events: Vec<Event> = vec![];
+        let mut affected_vaults: usize = 0;
+        let locked_vault_amounts = SLASH_LOCKED
+            .prefix(id.clone())
+            .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (affected_vault, locked_amount) in locked_vault_amounts {
             let vault_info = vault::get_vault_info(deps.as_ref(), &affected_vault)?;
 
-            // if destination is None, leave it in the router.
-            // if destination is Some, transfer to the destination.
-            if slashing_parameters.destination.is_some() {
-                let destination = slashing_parameters.clone().destination.unwrap().to_string();
-                match vault_info.asset_type {
-                    AssetType::Cw20 => {
-                        // craft cw20 transfer msg
-                        let msg = &cw20::Cw20ExecuteMsg::Transfer {
-                            recipient: destination,
-                            amount: locked_amount,
-                        };
-                        // convert to CosmosMsg
-                        let exec_msg = cosmwasm_std::WasmMsg::Execute {
-                            contract_addr: vault_info.asset_reference.to_string(),
-                            msg: to_json_binary(msg)?,
-                            funds: vec![],
-                        };
-                        transfer_msgs.push(exec_msg.into());
-                    }
-                    AssetType::Bank => {
-                        // craft bank transfer msg
-                        let exec_msg = BankMsg::Send {
-                            to_address: destination,
-                            amount: vec![Coin {
-                                denom: vault_info.asset_reference,
-                                amount: locked_amount,
-                            }],
-                        };
-                        // convert to CosmosMsg
-                        transfer_msgs.push(exec_msg.into());
+            if !slashing_parameters.destinations.is_empty() {
+                // split between the weighted destinations
+                let shares = split_slashed(locked_amount, &slashing_parameters.destinations);
+                for (destination, amount) in slashing_parameters.destinations.iter().zip(shares) {
+                    if amount.is_zero() {
+                        continue;
                     }
-                };
+                    transfer_msgs.push(slashed_transfer_msg(
+                        &vault_info.asset_type,
+                        &vault_info.asset_reference,
+                        &destination.recipient,
+                        amount,
+                    )?);
+                    distribution_events.push(
+                        Event::new("SlashingDistributed")
+                            .add_attribute("slashing_request_id", id.to_string())
+                            .add_attribute("vault", affected_vault.to_string())
+                            .add_attribute("recipient", recipient_attribute(&destination.recipient))
+                            .add_attribute("bips", destination.bips.to_string())
+                            .add_attribute("amount", amount.to_string()),
+                    );
+                }
+                affected_vaults += 1;
+            } else if let Some(destination) = &slashing_parameters.destination {
+                // if destination is Some, transfer to the destination.
+                transfer_msgs.push(slashed_transfer_msg(
+                    &vault_info.asset_type,
+                    &vault_info.asset_reference,
+                    &SlashingRecipient::Address(destination.clone()),
+                    locked_amount,
+                )?);
+                affected_vaults += 1;
+            } else {
+                // if destination is None, leave it in the router (idle) until it is swept or redistributed.
+                state::add_idle_slashed(
+                    deps.storage,
+                    &id,
+                    &vault_info.asset_id,
+                    vault_info.asset_type.clone(),
+                    vault_info.asset_reference.clone(),
+                    locked_amount,
+                )?;
+                distribution_events.push(
+                    Event::new("IdleSlashed")
+                        .add_attribute("slashing_request_id", id.to_string())
+                        .add_attribute("vault", affected_vault.to_string())
+                        .add_attribute("asset_id", vault_info.asset_id)
+                        .add_attribute("amount", locked_amount.to_string()),
+                );
             }
         }
 
@@ -715,10 +1473,155 @@ mod execute {
                             .destination
                             .unwrap_or(Addr::unchecked("".to_string())),
                     )
-                    .add_attribute("affected_vaults", transfer_msgs.len().to_string()),
+                    .add_attribute("affected_vaults", affected_vaults.to_string()),
             )
+            .add_events(distribution_events)
+            .add_messages(transfer_msgs))
+    }
+
+    /// Transfer all the idle slashed collateral held by the router for the slashing request
+    /// (finalized without a destination) to the `recipient`.
+    /// Only the `owner` or the `guardrail` can call this message.
+    pub fn sweep_slashed(
+        deps: DepsMut,
+        info: MessageInfo,
+        id: SlashingRequestId,
+        recipient: Addr,
+    ) -> Result<Response, ContractError> {
+        let destinations = vec![WeightedDestination {
+            recipient: SlashingRecipient::Address(recipient),
+            bips: 10_000,
+        }];
+        move_idle_slashed(deps, info, id, destinations, "SweepSlashed")
+    }
+
+    /// Split all the idle slashed collateral held by the router for the slashing request
+    /// (finalized without a destination) between the weighted `destinations`.
+    /// Only the `owner` or the `guardrail` can call this message.
+    pub fn redistribute_slashed(
+        deps: DepsMut,
+        info: MessageInfo,
+        id: SlashingRequestId,
+        destinations: Vec<WeightedDestination>,
+    ) -> Result<Response, ContractError> {
+        WeightedDestination::validate_all(deps.api, &destinations)
+            .map_err(|e| InvalidSlashingRequest { msg: e.to_string() })?;
+        move_idle_slashed(deps, info, id, destinations, "RedistributeSlashed")
+    }
+
+    fn move_idle_slashed(
+        deps: DepsMut,
+        info: MessageInfo,
+        id: SlashingRequestId,
+        destinations: Vec<WeightedDestination>,
+        event_name: &str,
+    ) -> Result<Response, ContractError> {
+        // the guardrail approves the move as an alternative to the owner
+        if info.sender != GUARDRAIL.load(deps.storage)? {
+            ownership::assert_owner(deps.storage, &info)?;
+        }
+
+        let idle_slashed = state::take_idle_slashed(deps.storage, &id)?;
+        if idle_slashed.is_empty() {
+            return Err(InvalidSlashingRequest {
+                msg: "No idle slashed collateral for the slashing request".to_string(),
+            });
+        }
+
+        let mut transfer_msgs: Vec<CosmosMsg> = vec![];
+        let mut events: Vec<Event> = vec![];
+        for (asset_id, held) in idle_slashed {
+            let shares = split_slashed(held.amount, &destinations);
+            for (destination, amount) in destinations.iter().zip(shares) {
+                if amount.is_zero() {
+                    continue;
+                }
+                transfer_msgs.push(slashed_transfer_msg(
+                    &held.asset_type,
+                    &held.asset_reference,
+                    &destination.recipient,
+                    amount,
+                )?);
+                events.push(
+                    Event::new(event_name)
+                        .add_attribute("slashing_request_id", id.to_string())
+                        .add_attribute("asset_id", &asset_id)
+                        .add_attribute("recipient", recipient_attribute(&destination.recipient))
+                        .add_attribute("bips", destination.bips.to_string())
+                        .add_attribute("amount", amount.to_string()),
+                );
+            }
+        }
+
+        Ok(Response::new()
+            .add_events(events)
             .add_messages(transfer_msgs))
     }
+
+    fn recipient_attribute(recipient: &SlashingRecipient) -> String {
+        match recipient {
+            SlashingRecipient::Address(address) => address.to_string(),
+            SlashingRecipient::Burn => "burn".to_string(),
+        }
+    }
+
+    /// Split the slashed `amount` between the weighted `destinations`, rounded down.
+    /// The rounding dust goes to the first destination, so the whole `amount` is distributed.
+    fn split_slashed(amount: Uint128, destinations: &[WeightedDestination]) -> Vec<Uint128> {
+        let mut shares: Vec<Uint128> = destinations
+            .iter()
+            .map(|destination| amount.multiply_ratio(destination.bips, 10_000u128))
+            .collect();
+        let distributed = shares.iter().sum::<Uint128>();
+        if let Some(first) = shares.first_mut() {
+            *first += amount - distributed;
+        }
+        shares
+    }
+
+    /// Create the message to transfer (or burn) the slashed `amount` of the asset.
+    fn slashed_transfer_msg(
+        asset_type: &AssetType,
+        asset_reference: &str,
+        recipient: &SlashingRecipient,
+        amount: Uint128,
+    ) -> StdResult<CosmosMsg> {
+        let msg: CosmosMsg = match (asset_type, recipient) {
+            (AssetType::Cw20, SlashingRecipient::Address(recipient)) => {
+                cosmwasm_std::WasmMsg::Execute {
+                    contract_addr: asset_reference.to_string(),
+                    msg: to_json_binary(&cw20::Cw20ExecuteMsg::Transfer {
+                        recipient: recipient.to_string(),
+                        amount,
+                    })?,
+                    funds: vec![],
+                }
+                .into()
+            }
+            (AssetType::Cw20, SlashingRecipient::Burn) => cosmwasm_std::WasmMsg::Execute {
+                contract_addr: asset_reference.to_string(),
+                msg: to_json_binary(&cw20::Cw20ExecuteMsg::Burn { amount })?,
+                funds: vec![],
+            }
+            .into(),
+            (AssetType::Bank, SlashingRecipient::Address(recipient)) => BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: vec![Coin {
+                    denom: asset_reference.to_string(),
+                    amount,
+                }],
+            }
+            .into(),
+            (AssetType::Bank, SlashingRecipient::Burn) => BankMsg::Burn {
+                amount: vec![Coin {
+                    denom: asset_reference.to_string(),
+                    amount,
+                }],
+            }
+            .into(),
+        };
+        Ok(msg)
+    }
 }
 
 /// Snipped implementation of Vault's API
@@ -746,6 +1649,9 @@ pub(crate) mod vault {
         /// Total assets in the vault
         pub total_assets: Uint128,
 
+        /// Asset identifier, using the CAIP-19 format.
+        pub asset_id: String,
+
         /// The asset type, either `AssetType::Cw20` or `AssetType::Bank`.
         pub asset_type: AssetType,
 
@@ -782,7 +1688,7 @@ pub(crate) mod vault {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::IsWhitelisted { vault } => {
             let vault = deps.api.addr_validate(&vault)?;
@@ -792,6 +1698,10 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             let operator = deps.api.addr_validate(&operator)?;
             to_json_binary(&query::is_validating(deps, operator)?)
         }
+        QueryMsg::IsSlashPending { vault } => {
+            let vault = deps.api.addr_validate(&vault)?;
+            to_json_binary(&query::is_slash_pending(deps, env, vault)?)
+        }
         QueryMsg::ListVaults { limit, start_after } => {
             let limit = limit.map_or(100, |v| v.min(100));
             let start_after = start_after
@@ -819,6 +1729,47 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::WithdrawalLockPeriod {} => {
             to_json_binary(&query::get_withdrawal_lock_period(deps)?)
         }
+        QueryMsg::MinWithdrawalLockPeriod {} => {
+            to_json_binary(&query::get_min_withdrawal_lock_period(deps)?)
+        }
+        QueryMsg::OperatorWithdrawalLockPeriod { operator } => {
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::get_operator_withdrawal_lock_period(
+                deps, env, operator,
+            )?)
+        }
+        QueryMsg::MaxVaultsPerOperator {} => {
+            to_json_binary(&query::get_max_vaults_per_operator(deps)?)
+        }
+        QueryMsg::OperatorVaultCount { operator } => {
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::operator_vault_count(deps, operator)?)
+        }
+        QueryMsg::Oracle {} => to_json_binary(&query::oracle(deps)?),
+        QueryMsg::AllocationDelay {} => to_json_binary(&query::get_allocation_delay(deps)?),
+        QueryMsg::Allocation { vault, service } => {
+            let vault = deps.api.addr_validate(&vault)?;
+            let service = deps.api.addr_validate(&service)?;
+            to_json_binary(&query::allocation(deps, env, vault, service)?)
+        }
+        QueryMsg::VaultAllocations { vault } => {
+            let vault = deps.api.addr_validate(&vault)?;
+            to_json_binary(&query::vault_allocations(deps, env, vault)?)
+        }
+        QueryMsg::OperatorAum {
+            operator,
+            slashing_request_id,
+        } => {
+            let operator = deps.api.addr_validate(&operator)?;
+            to_json_binary(&query::operator_aum(deps, operator, slashing_request_id)?)
+        }
+        QueryMsg::VaultAum {
+            vault,
+            slashing_request_id,
+        } => {
+            let vault = deps.api.addr_validate(&vault)?;
+            to_json_binary(&query::vault_aum(deps, vault, slashing_request_id)?)
+        }
         QueryMsg::SlashingRequestId { service, operator } => {
             let service = deps.api.addr_validate(&service)?;
             let operator = deps.api.addr_validate(&operator)?;
@@ -828,21 +1779,47 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::SlashingLocked {
             slashing_request_id,
         } => to_json_binary(&query::slash_locked(deps, slashing_request_id)?),
+        QueryMsg::ListSlashingRequests {
+            service,
+            operator,
+            status,
+            limit,
+            start_after,
+        } => {
+            let limit = limit.map_or(100, |v| v.min(100));
+            let service = service.map(|s| deps.api.addr_validate(&s)).transpose()?;
+            let operator = operator.map(|s| deps.api.addr_validate(&s)).transpose()?;
+            to_json_binary(&query::list_slashing_requests(
+                deps,
+                service,
+                operator,
+                status,
+                limit,
+                start_after,
+            )?)
+        }
+        QueryMsg::IdleSlashedBalances {} => to_json_binary(&query::idle_slashed_balances(deps)?),
+        QueryMsg::IdleSlashed(id) => to_json_binary(&query::idle_slashed(deps, id)?),
     }
 }
 
 mod query {
+    use crate::contract::vault;
     use crate::msg::{
+        AllocationResponse, AssetAum, IdleSlashedBalance, IdleSlashedResponse, OperatorAumResponse,
         SlashingLockedResponse, SlashingLockedResponseItem, SlashingRequestIdResponse,
-        SlashingRequestResponse, Vault, VaultListResponse,
+        SlashingRequestItem, SlashingRequestListResponse, SlashingRequestResponse, Vault,
+        VaultAllocation, VaultAllocationsResponse, VaultAumResponse, VaultListResponse,
     };
     use crate::state::{
-        self, DEFAULT_WITHDRAWAL_LOCK_PERIOD, SLASHING_REQUESTS, SLASHING_REQUEST_IDS,
+        self, Allocation, SlashingRequest, SlashingRequestStatus, SLASHING_REQUESTS,
+        SLASHING_REQUEST_IDS,
     };
     use bvs_library::slashing::SlashingRequestId;
     use bvs_registry::msg::QueryMsg;
-    use cosmwasm_std::{Addr, Deps, StdResult, Uint64};
+    use cosmwasm_std::{Addr, Deps, Env, StdError, StdResult, Uint128, Uint64};
     use cw_storage_plus::Bound;
+    use std::collections::BTreeMap;
 
     /// Returns whether the vault is whitelisted or not.
     /// Called by the vault to check if a vault can accept deposits.
@@ -866,6 +1843,64 @@ mod query {
         Ok(is_operator_active)
     }
 
+    /// Returns whether a slash that would slash the vault is pending,
+    /// a slashing request against the vault's operator that is not locked, canceled or expired yet,
+    /// that selects the vault (if it selects vaults), accepts the vault's asset,
+    /// and has stake of the vault allocated to its service at the incident timestamp.
+    /// Called by the vaults to freeze redemptions until the slash is locked.
+    pub fn is_slash_pending(deps: Deps, env: Env, vault: Addr) -> StdResult<bool> {
+        let vault_info = vault::get_vault_info(deps, &vault)
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+        let operator = vault_info.operator;
+
+        let services = state::list_pending_slashes(deps.storage, &operator, env.block.time)?;
+        if services.is_empty() {
+            return Ok(false);
+        }
+
+        let registry = state::get_registry(deps.storage)?;
+        for service in services {
+            let Some(slashing_request) =
+                state::get_pending_slashing_request(deps.storage, &service, &operator)?
+            else {
+                continue;
+            };
+            let request = slashing_request.request;
+
+            if let Some(vaults) = &request.vaults {
+                if !vaults.contains(&vault.to_string()) {
+                    continue;
+                }
+            }
+
+            let slashing_parameters = super::execute::get_slashing_parameters(
+                deps,
+                &registry,
+                &service,
+                &request.category,
+                request.timestamp.seconds(),
+            )
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+            if !slashing_parameters.accepts_asset_id(&vault_info.asset_id) {
+                continue;
+            }
+
+            let allocation_bips = state::get_allocation_bips_at(
+                deps.storage,
+                &operator,
+                &vault,
+                &service,
+                request.timestamp,
+            )?;
+            if allocation_bips == 0 {
+                continue;
+            }
+
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
     /// List all vaults in the router.
     /// Support pagination.
     pub fn list_vaults(
@@ -894,12 +1929,34 @@ mod query {
         Ok(VaultListResponse(vaults))
     }
 
+    /// Returns the default withdrawal lock period, for operators without an override.
     pub fn get_withdrawal_lock_period(deps: Deps) -> StdResult<Uint64> {
-        let value = state::WITHDRAWAL_LOCK_PERIOD
-            .may_load(deps.storage)?
-            .unwrap_or(DEFAULT_WITHDRAWAL_LOCK_PERIOD);
+        state::get_withdrawal_lock_period(deps.storage)
+    }
+
+    /// Returns the minimum withdrawal lock period.
+    pub fn get_min_withdrawal_lock_period(deps: Deps) -> StdResult<Uint64> {
+        state::get_min_withdrawal_lock_period(deps.storage)
+    }
+
+    /// Returns the effective withdrawal lock period of the operator.
+    /// Called by the vaults to compute the unlock timestamp of queued withdrawals.
+    pub fn get_operator_withdrawal_lock_period(
+        deps: Deps,
+        env: Env,
+        operator: Addr,
+    ) -> StdResult<Uint64> {
+        state::get_operator_withdrawal_lock_period_at(deps.storage, &operator, env.block.time)
+    }
+
+    /// Returns the max number of vaults per operator.
+    pub fn get_max_vaults_per_operator(deps: Deps) -> StdResult<u32> {
+        state::get_max_vaults_per_operator(deps.storage)
+    }
 
-        Ok(value)
+    /// Returns the number of vaults of the operator.
+    pub fn operator_vault_count(deps: Deps, operator: Addr) -> StdResult<u32> {
+        state::count_operator_vaults(deps.storage, &operator)
     }
 
     pub fn list_vaults_by_operator(
@@ -935,6 +1992,211 @@ mod query {
         Ok(VaultListResponse(vaults))
     }
 
+    /// Returns the oracle contract, `None` if not set.
+    pub fn oracle(deps: Deps) -> StdResult<Option<Addr>> {
+        state::ORACLE.may_load(deps.storage)
+    }
+
+    /// Returns the delay (in seconds) before an allocation increase becomes slashable.
+    pub fn get_allocation_delay(deps: Deps) -> StdResult<Uint64> {
+        state::get_allocation_delay(deps.storage)
+    }
+
+    /// Returns the allocation of the vault to the service at the current block time.
+    pub fn allocation(
+        deps: Deps,
+        env: Env,
+        vault: Addr,
+        service: Addr,
+    ) -> StdResult<AllocationResponse> {
+        let allocation = state::ALLOCATIONS
+            .may_load(deps.storage, (&vault, &service))?
+            .map(|allocation| allocation.at(env.block.time))
+            .unwrap_or(Allocation {
+                bips: 0,
+                pending: None,
+            });
+        Ok(AllocationResponse(allocation))
+    }
+
+    /// Returns the allocations of the vault at the current block time,
+    /// with the allocated (including pending deallocations) and free assets of the vault.
+    pub fn vault_allocations(
+        deps: Deps,
+        env: Env,
+        vault: Addr,
+    ) -> StdResult<VaultAllocationsResponse> {
+        let vault_info = vault::get_vault_info(deps, &vault)
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+        let allocations = state::ALLOCATIONS
+            .prefix(&vault)
+            .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .map(|item| {
+                item.map(|(service, allocation)| VaultAllocation {
+                    service,
+                    allocation: allocation.at(env.block.time),
+                })
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+
+        let allocated_bips = allocations
+            .iter()
+            .map(|item| item.allocation.encumbered_bips())
+            .sum::<u16>();
+        let allocated_assets = vault_info
+            .total_assets
+            .multiply_ratio(allocated_bips, state::MAX_ALLOCATION_BIPS);
+
+        Ok(VaultAllocationsResponse {
+            total_assets: vault_info.total_assets,
+            effective_time: state::ALLOCATION_OPERATORS
+                .may_load(deps.storage, &vault_info.operator)?,
+            allocated_bips,
+            allocated_assets,
+            free_assets: vault_info.total_assets - allocated_assets,
+            allocations,
+        })
+    }
+
+    /// Returns the assets under management of the operator, aggregated per asset.
+    /// Without `slashing_request_id`, the current `total_assets` of the operator's whitelisted vaults.
+    /// With `slashing_request_id`, the `total_assets` of the operator's vaults
+    /// recorded when the slashing request (against the operator) was locked.
+    pub fn operator_aum(
+        deps: Deps,
+        operator: Addr,
+        slashing_request_id: Option<SlashingRequestId>,
+    ) -> StdResult<OperatorAumResponse> {
+        let historical = slashing_request_id.is_some();
+        let vaults_aum = match slashing_request_id {
+            Some(id) => {
+                let slashing_request = SLASHING_REQUESTS
+                    .may_load(deps.storage, &id)?
+                    .ok_or(StdError::not_found("slashing request"))?;
+                if slashing_request.request.operator != operator.as_str() {
+                    return Err(StdError::generic_err(
+                        "Slashing request is not against the operator",
+                    ));
+                }
+                if slashing_request.status == SlashingRequestStatus::Pending
+                    || slashing_request.status == SlashingRequestStatus::Canceled
+                    || slashing_request.status == SlashingRequestStatus::Expired
+                {
+                    return Err(StdError::generic_err(
+                        "Slashing request has not been locked",
+                    ));
+                }
+
+                state::SLASH_LOCKED_AUM
+                    .prefix(id)
+                    .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+                    .map(|item| item.map(|(_, aum)| aum))
+                    .collect::<StdResult<Vec<_>>>()?
+            }
+            None => {
+                let vaults = state::OPERATOR_VAULTS
+                    .prefix(&operator)
+                    .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+                    .collect::<StdResult<Vec<_>>>()?;
+
+                let mut vaults_aum = Vec::with_capacity(vaults.len());
+                for vault in vaults {
+                    if is_whitelisted(deps, vault.clone())? {
+                        vaults_aum.push(current_aum(deps, &vault)?);
+                    }
+                }
+                vaults_aum
+            }
+        };
+
+        let mut aggregated: BTreeMap<String, Uint128> = BTreeMap::new();
+        for aum in vaults_aum {
+            let total_assets = aggregated.entry(aum.asset_id).or_default();
+            *total_assets = total_assets.checked_add(aum.total_assets)?;
+        }
+
+        // historical assets under management are not valued, the oracle only has the current price
+        let oracle = if historical {
+            None
+        } else {
+            state::ORACLE.may_load(deps.storage)?
+        };
+        Ok(OperatorAumResponse(
+            aggregated
+                .into_iter()
+                .map(|(asset_id, total_assets)| {
+                    with_usd_value(
+                        deps,
+                        oracle.as_ref(),
+                        AssetAum {
+                            asset_id,
+                            total_assets,
+                            usd_value: None,
+                        },
+                    )
+                })
+                .collect(),
+        ))
+    }
+
+    /// Returns the assets under management of the vault.
+    /// With `slashing_request_id`, the `total_assets` recorded when the slashing request was locked.
+    pub fn vault_aum(
+        deps: Deps,
+        vault: Addr,
+        slashing_request_id: Option<SlashingRequestId>,
+    ) -> StdResult<VaultAumResponse> {
+        match slashing_request_id {
+            // historical assets under management are not valued, the oracle only has the current price
+            Some(id) => Ok(VaultAumResponse(
+                state::SLASH_LOCKED_AUM
+                    .may_load(deps.storage, (id, &vault))?
+                    .ok_or(StdError::not_found("vault aum at slashing request"))?,
+            )),
+            None => {
+                let oracle = state::ORACLE.may_load(deps.storage)?;
+                Ok(VaultAumResponse(with_usd_value(
+                    deps,
+                    oracle.as_ref(),
+                    current_aum(deps, &vault)?,
+                )))
+            }
+        }
+    }
+
+    /// Set the `usd_value` of the assets under management with the current price of the oracle.
+    /// Left as `None` if the oracle is not set, has no price source for the asset,
+    /// or fails to value the asset (e.g. a stale price), so the query itself never fails on the oracle.
+    fn with_usd_value(deps: Deps, oracle: Option<&Addr>, aum: AssetAum) -> AssetAum {
+        let Some(oracle) = oracle else {
+            return aum;
+        };
+        let usd_value = deps
+            .querier
+            .query_wasm_smart::<bvs_oracle::msg::UsdValueResponse>(
+                oracle,
+                &bvs_oracle::msg::QueryMsg::UsdValue {
+                    asset_id: aum.asset_id.clone(),
+                    amount: aum.total_assets,
+                },
+            )
+            .ok()
+            .and_then(|bvs_oracle::msg::UsdValueResponse(usd_value)| usd_value);
+        AssetAum { usd_value, ..aum }
+    }
+
+    /// The current assets under management of the vault, queried from the vault.
+    fn current_aum(deps: Deps, vault: &Addr) -> StdResult<AssetAum> {
+        let vault_info =
+            vault::get_vault_info(deps, vault).map_err(|e| StdError::generic_err(e.to_string()))?;
+        Ok(AssetAum {
+            asset_id: vault_info.asset_id,
+            total_assets: vault_info.total_assets,
+            usd_value: None,
+        })
+    }
+
     pub fn slashing_request_id(
         deps: Deps,
         service: Addr,
@@ -953,6 +2215,94 @@ mod query {
         Ok(SlashingRequestResponse(slashing_request))
     }
 
+    /// Returns the slashing requests filtered by `service`, `operator` and `status`,
+    /// ordered by `(request_time, id)` (descending), the latest requested first.
+    /// The most selective index is used: (service, operator), operator, service, then status.
+    /// The remaining filters (`status` with `service` or `operator`) are applied while iterating over the index,
+    /// so the query reads every request of the index before `start_after` until `limit` requests match:
+    /// up to the whole history of the operator (or service) when few of its requests have the `status`.
+    pub fn list_slashing_requests(
+        deps: Deps,
+        service: Option<Addr>,
+        operator: Option<Addr>,
+        status: Option<SlashingRequestStatus>,
+        limit: u32,
+        start_after: Option<SlashingRequestId>,
+    ) -> StdResult<SlashingRequestListResponse> {
+        // newest first, ordered by (request_time, id)
+        let range_max = match start_after {
+            Some(id) => {
+                let slashing_request = SLASHING_REQUESTS
+                    .may_load(deps.storage, &id)?
+                    .ok_or(StdError::not_found("slashing request"))?;
+                Some(Bound::exclusive((
+                    slashing_request.request_time.seconds(),
+                    id,
+                )))
+            }
+            None => None,
+        };
+        let order = cosmwasm_std::Order::Descending;
+        let idx = &SLASHING_REQUESTS.idx;
+
+        let items: Box<dyn Iterator<Item = StdResult<(SlashingRequestId, SlashingRequest)>>> =
+            match (&operator, &service, status) {
+                (Some(operator), Some(service), _) => Box::new(
+                    idx.service_operator
+                        .sub_prefix((service.clone(), operator.clone()))
+                        .range(deps.storage, None, range_max, order),
+                ),
+                (Some(operator), None, _) => {
+                    Box::new(idx.operator.sub_prefix(operator.clone()).range(
+                        deps.storage,
+                        None,
+                        range_max,
+                        order,
+                    ))
+                }
+                (None, Some(service), _) => {
+                    Box::new(idx.service.sub_prefix(service.clone()).range(
+                        deps.storage,
+                        None,
+                        range_max,
+                        order,
+                    ))
+                }
+                (None, None, Some(status)) => Box::new(idx.status.sub_prefix(status.into()).range(
+                    deps.storage,
+                    None,
+                    range_max,
+                    order,
+                )),
+                (None, None, None) => {
+                    Box::new(idx.request_time.range(deps.storage, None, range_max, order))
+                }
+            };
+
+        let slashing_requests = items
+            .filter(|item| match item {
+                Ok((_, req)) => {
+                    service.as_ref().is_none_or(|s| req.service == s)
+                        && operator
+                            .as_ref()
+                            .is_none_or(|o| req.request.operator == o.as_str())
+                        && status.is_none_or(|s| req.status == s)
+                }
+                Err(_) => true,
+            })
+            .take(limit as usize)
+            .map(|item| {
+                let (id, slashing_request) = item?;
+                Ok(SlashingRequestItem {
+                    id,
+                    slashing_request,
+                })
+            })
+            .collect::<StdResult<_>>()?;
+
+        Ok(SlashingRequestListResponse(slashing_requests))
+    }
+
     pub fn slash_locked(deps: Deps, id: SlashingRequestId) -> StdResult<SlashingLockedResponse> {
         let locked = state::SLASH_LOCKED
             .prefix(id.clone())
@@ -966,29 +2316,68 @@ mod query {
                 .collect::<StdResult<Vec<_>>>()?,
         ))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::vault::{AssetType, VaultInfoQueryMsg, VaultInfoResponse};
-    use super::*;
-    use super::{
-        execute::{set_vault, set_withdrawal_lock_period},
-        query::{get_withdrawal_lock_period, is_validating, is_whitelisted, list_vaults},
-    };
+    /// Returns the total idle slashed collateral held by the router, per asset.
+    pub fn idle_slashed_balances(deps: Deps) -> StdResult<IdleSlashedResponse> {
+        let balances = state::IDLE_SLASHED_TOTALS
+            .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .map(|item| item.map(|(asset_id, amount)| IdleSlashedBalance { asset_id, amount }))
+            .collect::<StdResult<Vec<_>>>()?;
+        Ok(IdleSlashedResponse(balances))
+    }
+
+    /// Returns the idle slashed collateral held by the router for the slashing request, per asset.
+    pub fn idle_slashed(deps: Deps, id: SlashingRequestId) -> StdResult<IdleSlashedResponse> {
+        let balances = state::IDLE_SLASHED
+            .prefix(id)
+            .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .map(|item| {
+                item.map(|(asset_id, held)| IdleSlashedBalance {
+                    asset_id,
+                    amount: held.amount,
+                })
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+        Ok(IdleSlashedResponse(balances))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::vault::{AssetType, VaultInfoQueryMsg, VaultInfoResponse};
+    use super::*;
+    use super::{
+        execute::{
+            set_max_vaults_per_operator, set_min_withdrawal_lock_period,
+            set_operator_withdrawal_lock_period, set_vault, set_withdrawal_lock_period,
+        },
+        query::{
+            get_max_vaults_per_operator, get_min_withdrawal_lock_period,
+            get_operator_withdrawal_lock_period, get_withdrawal_lock_period, is_validating,
+            is_whitelisted, list_vaults, operator_vault_count,
+        },
+    };
     use crate::msg::RequestSlashingPayload;
     use crate::msg::SlashingMetadata;
-    use crate::msg::{InstantiateMsg, SlashingRequestIdResponse, SlashingRequestResponse};
-    use crate::state::{self, SlashingRequest, SlashingRequestStatus, SLASHING_REQUESTS};
+    use crate::msg::{
+        IdleSlashedBalance, IdleSlashedResponse, InstantiateMsg, SlashingRequestIdResponse,
+        SlashingRequestListResponse, SlashingRequestResponse,
+    };
+    use crate::state::{
+        self, SlashingRequest, SlashingRequestIdHasher, SlashingRequestStatus, SLASHING_REQUESTS,
+    };
     use crate::state::{Vault, OPERATOR_VAULTS, REGISTRY, SLASHING_REQUEST_IDS, VAULTS};
     use bvs_library::slashing::SlashingRequestId;
-    use bvs_registry::msg::{IsOperatorActiveResponse, QueryMsg as RegistryQueryMsg};
+    use bvs_registry::msg::{
+        IsOperatorActiveResponse, IsOperatorResponse, QueryMsg as RegistryQueryMsg,
+    };
+    use bvs_registry::{SlashingRecipient, WeightedDestination};
     use cosmwasm_std::testing::{
         message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
     };
     use cosmwasm_std::{
-        from_json, Attribute, ContractResult, Event, HexBinary, OwnedDeps, QuerierResult,
-        SystemError, SystemResult, Uint128, Uint64, WasmQuery,
+        coins, from_json, Attribute, BankMsg, ContractResult, Event, HexBinary, OwnedDeps,
+        QuerierResult, SystemError, SystemResult, Uint128, Uint64, WasmMsg, WasmQuery,
     };
 
     #[test]
@@ -1061,6 +2450,7 @@ mod tests {
                                     router: moved_env.contract.address.clone(),
                                     operator: deps.api.addr_make("operator"),
                                     total_assets: Uint128::zero(),
+                                    asset_id: "cosmos:cosmos-testnet/cw20:vault".to_string(),
                                     asset_type: AssetType::Cw20,
                                     asset_reference: deps.api.addr_make("vault").to_string(),
                                 };
@@ -1193,6 +2583,7 @@ mod tests {
                                         router: vault_contract_addr.clone(),
                                         operator: operator_addr.clone(),
                                         total_assets: Uint128::zero(),
+                                        asset_id: "cosmos:cosmos-testnet/cw20:vault".to_string(),
                                         asset_type: AssetType::Cw20,
                                         asset_reference: deps.api.addr_make("vault").to_string(),
                                     };
@@ -1217,97 +2608,716 @@ mod tests {
                 deps.as_mut(),
                 env.clone(),
                 owner_info.clone(),
-                vault.clone(),
-                true,
-            );
-            assert!(result.is_err());
-
-            let err = result.unwrap_err();
+                vault.clone(),
+                true,
+            );
+            assert!(result.is_err());
+
+            let err = result.unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                ContractError::VaultError {
+                    msg: "Vault must be connected to this router".to_string()
+                }
+                .to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_set_oracle() {
+        let (mut deps, _env, owner_info) = instantiate_contract();
+        let oracle = deps.api.addr_make("oracle");
+
+        let not_owner = message_info(&deps.api.addr_make("not_owner"), &[]);
+        let err = execute::set_oracle(deps.as_mut(), not_owner, Some(oracle.clone())).unwrap_err();
+        assert_eq!(err.to_string(), "Unauthorized");
+
+        let res =
+            execute::set_oracle(deps.as_mut(), owner_info.clone(), Some(oracle.clone())).unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::new("SetOracle")
+                .add_attribute("prev_oracle", "")
+                .add_attribute("new_oracle", oracle.to_string())]
+        );
+        assert_eq!(query::oracle(deps.as_ref()).unwrap(), Some(oracle.clone()));
+
+        let res = execute::set_oracle(deps.as_mut(), owner_info, None).unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::new("SetOracle")
+                .add_attribute("prev_oracle", oracle.to_string())
+                .add_attribute("new_oracle", "")]
+        );
+        assert_eq!(query::oracle(deps.as_ref()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_sweep_and_redistribute_slashed() {
+        let (mut deps, _env, owner_info) = instantiate_contract();
+        let guardrail_info = message_info(&deps.api.addr_make("guardrail"), &[]);
+        let recipient = deps.api.addr_make("recipient");
+        let cw20 = deps.api.addr_make("cw20");
+
+        let id1 = SlashingRequestId::from_hex(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        )
+        .unwrap();
+        let id2 = SlashingRequestId::from_hex(
+            "0000000000000000000000000000000000000000000000000000000000000002",
+        )
+        .unwrap();
+        let bank_asset = "cosmos:cosmoshub-4/bank:denom";
+        let cw20_asset = format!("cosmos:cosmoshub-4/cw20:{cw20}");
+
+        for (id, amount) in [(&id1, 100u128), (&id2, 50u128)] {
+            state::add_idle_slashed(
+                &mut deps.storage,
+                id,
+                bank_asset,
+                AssetType::Bank,
+                "denom".to_string(),
+                Uint128::new(amount),
+            )
+            .unwrap();
+        }
+        state::add_idle_slashed(
+            &mut deps.storage,
+            &id1,
+            &cw20_asset,
+            AssetType::Cw20,
+            cw20.to_string(),
+            Uint128::new(9),
+        )
+        .unwrap();
+
+        let balances = query::idle_slashed_balances(deps.as_ref()).unwrap();
+        assert_eq!(
+            balances,
+            IdleSlashedResponse(vec![
+                IdleSlashedBalance {
+                    asset_id: bank_asset.to_string(),
+                    amount: Uint128::new(150),
+                },
+                IdleSlashedBalance {
+                    asset_id: cw20_asset.clone(),
+                    amount: Uint128::new(9),
+                },
+            ])
+        );
+
+        // only the owner or the guardrail can sweep
+        let not_owner = message_info(&deps.api.addr_make("not_owner"), &[]);
+        let err = execute::sweep_slashed(deps.as_mut(), not_owner, id1.clone(), recipient.clone())
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Unauthorized");
+
+        // owner redistributes id1: 2/3 to the recipient, 1/3 burned
+        let destinations = vec![
+            WeightedDestination {
+                recipient: SlashingRecipient::Address(recipient.clone()),
+                bips: 6667,
+            },
+            WeightedDestination {
+                recipient: SlashingRecipient::Burn,
+                bips: 3333,
+            },
+        ];
+        let res = execute::redistribute_slashed(
+            deps.as_mut(),
+            owner_info.clone(),
+            id1.clone(),
+            destinations,
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages.into_iter().map(|m| m.msg).collect::<Vec<_>>(),
+            vec![
+                BankMsg::Send {
+                    to_address: recipient.to_string(),
+                    amount: coins(67, "denom"),
+                }
+                .into(),
+                BankMsg::Burn {
+                    amount: coins(33, "denom"),
+                }
+                .into(),
+                WasmMsg::Execute {
+                    contract_addr: cw20.to_string(),
+                    msg: to_json_binary(&cw20::Cw20ExecuteMsg::Transfer {
+                        recipient: recipient.to_string(),
+                        amount: Uint128::new(7),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                WasmMsg::Execute {
+                    contract_addr: cw20.to_string(),
+                    msg: to_json_binary(&cw20::Cw20ExecuteMsg::Burn {
+                        amount: Uint128::new(2),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+            ]
+        );
+        assert_eq!(
+            res.events[0],
+            Event::new("RedistributeSlashed")
+                .add_attribute("slashing_request_id", id1.to_string())
+                .add_attribute("asset_id", bank_asset)
+                .add_attribute("recipient", recipient.to_string())
+                .add_attribute("bips", "6667")
+                .add_attribute("amount", "67")
+        );
+        assert_eq!(
+            query::idle_slashed(deps.as_ref(), id1.clone()).unwrap(),
+            IdleSlashedResponse(vec![])
+        );
+        assert_eq!(
+            query::idle_slashed_balances(deps.as_ref()).unwrap(),
+            IdleSlashedResponse(vec![IdleSlashedBalance {
+                asset_id: bank_asset.to_string(),
+                amount: Uint128::new(50),
+            }])
+        );
+
+        // nothing left to sweep for id1
+        let err = execute::sweep_slashed(deps.as_mut(), owner_info.clone(), id1, recipient.clone())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid slashing request: No idle slashed collateral for the slashing request"
+        );
+
+        // invalid destinations
+        let err = execute::redistribute_slashed(
+            deps.as_mut(),
+            owner_info.clone(),
+            id2.clone(),
+            vec![WeightedDestination {
+                recipient: SlashingRecipient::Burn,
+                bips: 5000,
+            }],
+        )
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Destinations bips must add up to 10,000 bips (100%)"));
+
+        // guardrail sweeps id2
+        let res = execute::sweep_slashed(
+            deps.as_mut(),
+            guardrail_info,
+            id2.clone(),
+            recipient.clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages.into_iter().map(|m| m.msg).collect::<Vec<_>>(),
+            vec![BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: coins(50, "denom"),
+            }
+            .into()]
+        );
+        assert_eq!(
+            res.events,
+            vec![Event::new("SweepSlashed")
+                .add_attribute("slashing_request_id", id2.to_string())
+                .add_attribute("asset_id", bank_asset)
+                .add_attribute("recipient", recipient.to_string())
+                .add_attribute("bips", "10000")
+                .add_attribute("amount", "50")]
+        );
+        assert_eq!(
+            query::idle_slashed_balances(deps.as_ref()).unwrap(),
+            IdleSlashedResponse(vec![])
+        );
+    }
+
+    #[test]
+    fn test_max_vaults_per_operator() {
+        let (mut deps, env, owner_info) = instantiate_contract();
+
+        // every vault/{n} is a vault of the operator
+        let moved_env = env.clone();
+        deps.querier
+            .update_wasm(move |req: &WasmQuery| -> QuerierResult {
+                if let WasmQuery::Smart { contract_addr, msg } = req {
+                    let msg: VaultInfoQueryMsg = from_json(msg).unwrap();
+                    match msg {
+                        VaultInfoQueryMsg::VaultInfo {} => {
+                            let response = VaultInfoResponse {
+                                router: moved_env.contract.address.clone(),
+                                operator: MockApi::default().addr_make("operator"),
+                                total_assets: Uint128::zero(),
+                                asset_id: "cosmos:cosmos-testnet/cw20:vault".to_string(),
+                                asset_type: AssetType::Cw20,
+                                asset_reference: contract_addr.to_string(),
+                            };
+                            SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
+                        }
+                    }
+                } else {
+                    SystemResult::Err(SystemError::UnsupportedRequest {
+                        kind: "Unsupported query".to_string(),
+                    })
+                }
+            });
+
+        let operator = deps.api.addr_make("operator");
+        let vaults: Vec<_> = (0..3)
+            .map(|i| deps.api.addr_make(&format!("vault/{i}")))
+            .collect();
+
+        assert_eq!(get_max_vaults_per_operator(deps.as_ref()).unwrap(), 10);
+
+        // only owner can set the max vaults per operator
+        {
+            let not_owner = message_info(&deps.api.addr_make("not_owner"), &[]);
+            let err = set_max_vaults_per_operator(deps.as_mut(), not_owner, 2).unwrap_err();
+            assert_eq!(err.to_string(), "Unauthorized");
+
+            let err =
+                set_max_vaults_per_operator(deps.as_mut(), owner_info.clone(), 0).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                ContractError::VaultError {
+                    msg: "Max vaults per operator must be greater than zero".to_string()
+                }
+                .to_string()
+            );
+        }
+
+        // owner sets the max vaults per operator
+        {
+            let response =
+                set_max_vaults_per_operator(deps.as_mut(), owner_info.clone(), 2).unwrap();
+            assert_eq!(
+                response.events,
+                vec![Event::new("SetMaxVaultsPerOperator")
+                    .add_attribute("prev_max_vaults_per_operator", "10")
+                    .add_attribute("new_max_vaults_per_operator", "2")]
+            );
+            assert_eq!(get_max_vaults_per_operator(deps.as_ref()).unwrap(), 2);
+        }
+
+        // whitelist vaults up to the max
+        for vault in &vaults[..2] {
+            set_vault(
+                deps.as_mut(),
+                env.clone(),
+                owner_info.clone(),
+                vault.clone(),
+                true,
+            )
+            .unwrap();
+        }
+        assert_eq!(
+            operator_vault_count(deps.as_ref(), operator.clone()).unwrap(),
+            2
+        );
+
+        // cannot whitelist a new vault above the max
+        {
+            let err = set_vault(
+                deps.as_mut(),
+                env.clone(),
+                owner_info.clone(),
+                vaults[2].clone(),
+                true,
+            )
+            .unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                ContractError::VaultError {
+                    msg: "Operator has reached the max vaults per operator".to_string()
+                }
+                .to_string()
+            );
+        }
+
+        // an existing vault can be unwhitelisted and whitelisted again at the max
+        {
+            set_vault(
+                deps.as_mut(),
+                env.clone(),
+                owner_info.clone(),
+                vaults[0].clone(),
+                false,
+            )
+            .unwrap();
+            set_vault(
+                deps.as_mut(),
+                env.clone(),
+                owner_info.clone(),
+                vaults[0].clone(),
+                true,
+            )
+            .unwrap();
+            assert_eq!(
+                operator_vault_count(deps.as_ref(), operator.clone()).unwrap(),
+                2
+            );
+        }
+
+        // raising the max allows a new vault
+        {
+            set_max_vaults_per_operator(deps.as_mut(), owner_info.clone(), 3).unwrap();
+            set_vault(
+                deps.as_mut(),
+                env.clone(),
+                owner_info,
+                vaults[2].clone(),
+                true,
+            )
+            .unwrap();
+            assert_eq!(operator_vault_count(deps.as_ref(), operator).unwrap(), 3);
+        }
+    }
+
+    #[test]
+    fn test_set_and_get_withdrawal_lock_period() {
+        let (mut deps, env, owner_info) = instantiate_contract();
+
+        let withdrawal_lock_period = Uint64::new(120);
+
+        // set withdrawal lock period successfully
+        {
+            let result = set_withdrawal_lock_period(
+                deps.as_mut(),
+                env.clone(),
+                owner_info.clone(),
+                withdrawal_lock_period,
+            );
+            assert!(result.is_ok());
+
+            let response = result.unwrap();
+            assert_eq!(response.events.len(), 1);
+            assert_eq!(
+                response.events[0],
+                Event::new("SetWithdrawalLockPeriod")
+                    .add_attribute(
+                        "prev_withdrawal_lock_period",
+                        Uint64::new(604800).to_string()
+                    )
+                    .add_attribute(
+                        "new_withdrawal_lock_period",
+                        withdrawal_lock_period.to_string()
+                    )
+            );
+        }
+
+        let withdrawal_lock_period1 = Uint64::new(150);
+
+        // update withdrawal lock period successfully
+        {
+            let result = set_withdrawal_lock_period(
+                deps.as_mut(),
+                env.clone(),
+                owner_info,
+                withdrawal_lock_period1,
+            );
+            assert!(result.is_ok());
+
+            let response = result.unwrap();
+            assert_eq!(response.events.len(), 1);
+            assert_eq!(
+                response.events[0],
+                Event::new("SetWithdrawalLockPeriod")
+                    .add_attribute(
+                        "prev_withdrawal_lock_period",
+                        withdrawal_lock_period.to_string()
+                    )
+                    .add_attribute(
+                        "new_withdrawal_lock_period",
+                        withdrawal_lock_period1.to_string()
+                    )
+            );
+        }
+
+        // query withdrawal lock period
+        {
+            let result = get_withdrawal_lock_period(deps.as_ref()).unwrap();
+            assert_eq!(result, withdrawal_lock_period1);
+        }
+
+        // wrong permission to update withdrawal lock period successfully
+        {
+            let user_info = MessageInfo {
+                sender: deps.api.addr_make("user"),
+                funds: vec![],
+            };
+            let result =
+                set_withdrawal_lock_period(deps.as_mut(), env, user_info, withdrawal_lock_period1);
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn test_set_min_and_operator_withdrawal_lock_period() {
+        let (mut deps, mut env, owner_info) = instantiate_contract();
+
+        let operator = deps.api.addr_make("operator");
+        let operator_info = message_info(&operator, &[]);
+        let registry = deps.api.addr_make("registry");
+
+        deps.querier
+            .update_wasm(move |req: &WasmQuery| -> QuerierResult {
+                if let WasmQuery::Smart { contract_addr, msg } = req {
+                    if *contract_addr == registry.to_string() {
+                        let msg: RegistryQueryMsg = from_json(msg).unwrap();
+                        match msg {
+                            RegistryQueryMsg::IsOperator(operator) => {
+                                let is_operator =
+                                    operator == MockApi::default().addr_make("operator").as_str();
+                                SystemResult::Ok(ContractResult::Ok(
+                                    to_json_binary(&IsOperatorResponse(is_operator)).unwrap(),
+                                ))
+                            }
+                            _ => SystemResult::Err(SystemError::Unknown {}),
+                        }
+                    } else {
+                        SystemResult::Err(SystemError::NoSuchContract {
+                            addr: contract_addr.to_string(),
+                        })
+                    }
+                } else {
+                    SystemResult::Err(SystemError::UnsupportedRequest {
+                        kind: "Unsupported query".to_string(),
+                    })
+                }
+            });
+
+        // operator without override uses the default withdrawal lock period
+        {
+            let result = get_min_withdrawal_lock_period(deps.as_ref()).unwrap();
+            assert_eq!(result, Uint64::zero());
+
+            let result =
+                get_operator_withdrawal_lock_period(deps.as_ref(), env.clone(), operator.clone());
+            assert_eq!(result.unwrap(), Uint64::new(604800));
+        }
+
+        // operator cannot override until the owner sets the minimum withdrawal lock period
+        {
+            let err = set_operator_withdrawal_lock_period(
+                deps.as_mut(),
+                env.clone(),
+                operator_info.clone(),
+                Some(Uint64::new(1)),
+            )
+            .unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                ContractError::VaultError {
+                    msg: "Minimum withdrawal lock period is not set".to_string()
+                }
+                .to_string()
+            );
+        }
+
+        // only owner can set the minimum withdrawal lock period
+        {
+            let err = set_min_withdrawal_lock_period(
+                deps.as_mut(),
+                operator_info.clone(),
+                Uint64::new(100),
+            )
+            .unwrap_err();
+            assert_eq!(err.to_string(), "Unauthorized");
+        }
+
+        // owner sets the minimum withdrawal lock period
+        {
+            let response =
+                set_min_withdrawal_lock_period(deps.as_mut(), owner_info.clone(), Uint64::new(100))
+                    .unwrap();
+            assert_eq!(
+                response.events,
+                vec![Event::new("SetMinWithdrawalLockPeriod")
+                    .add_attribute("prev_min_withdrawal_lock_period", "0")
+                    .add_attribute("new_min_withdrawal_lock_period", "100")]
+            );
+
+            let result = get_min_withdrawal_lock_period(deps.as_ref()).unwrap();
+            assert_eq!(result, Uint64::new(100));
+        }
+
+        // default withdrawal lock period cannot be set below the minimum
+        {
+            let err = set_withdrawal_lock_period(
+                deps.as_mut(),
+                env.clone(),
+                owner_info.clone(),
+                Uint64::new(99),
+            )
+            .unwrap_err();
             assert_eq!(
                 err.to_string(),
                 ContractError::VaultError {
-                    msg: "Vault must be connected to this router".to_string()
+                    msg: "Withdrawal lock period must not be below the minimum".to_string()
                 }
                 .to_string()
             );
         }
-    }
-
-    #[test]
-    fn test_set_and_get_withdrawal_lock_period() {
-        let (mut deps, env, owner_info) = instantiate_contract();
-
-        let withdrawal_lock_period = Uint64::new(120);
 
-        // set withdrawal lock period successfully
+        // operator withdrawal lock period cannot be set below the minimum
         {
-            let result = set_withdrawal_lock_period(
+            let err = set_operator_withdrawal_lock_period(
                 deps.as_mut(),
                 env.clone(),
-                owner_info.clone(),
-                withdrawal_lock_period,
+                operator_info.clone(),
+                Some(Uint64::new(99)),
+            )
+            .unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                ContractError::VaultError {
+                    msg: "Withdrawal lock period must not be below the minimum".to_string()
+                }
+                .to_string()
             );
-            assert!(result.is_ok());
+        }
 
-            let response = result.unwrap();
-            assert_eq!(response.events.len(), 1);
+        // only registered operator can set its withdrawal lock period
+        {
+            let not_operator = deps.api.addr_make("not_operator");
+            let err = set_operator_withdrawal_lock_period(
+                deps.as_mut(),
+                env.clone(),
+                message_info(&not_operator, &[]),
+                Some(Uint64::new(200)),
+            )
+            .unwrap_err();
             assert_eq!(
-                response.events[0],
-                Event::new("SetWithdrawalLockPeriod")
-                    .add_attribute(
-                        "prev_withdrawal_lock_period",
-                        Uint64::new(604800).to_string()
-                    )
-                    .add_attribute(
-                        "new_withdrawal_lock_period",
-                        withdrawal_lock_period.to_string()
-                    )
+                err.to_string(),
+                ContractError::Unauthorized {
+                    msg: "Sender is not a registered operator".to_string()
+                }
+                .to_string()
             );
         }
 
-        let withdrawal_lock_period1 = Uint64::new(150);
-
-        // update withdrawal lock period successfully
+        // operator overrides its withdrawal lock period,
+        // a decrease is effective after the current withdrawal lock period
         {
-            let result = set_withdrawal_lock_period(
+            let response = set_operator_withdrawal_lock_period(
                 deps.as_mut(),
                 env.clone(),
-                owner_info,
-                withdrawal_lock_period1,
+                operator_info.clone(),
+                Some(Uint64::new(200)),
+            )
+            .unwrap();
+            let effective_time = env.block.time.plus_seconds(604800);
+            assert_eq!(
+                response.events,
+                vec![Event::new("SetOperatorWithdrawalLockPeriod")
+                    .add_attribute("operator", operator.to_string())
+                    .add_attribute("prev_withdrawal_lock_period", "604800")
+                    .add_attribute("new_withdrawal_lock_period", "200")
+                    .add_attribute("effective_time", effective_time.seconds().to_string())]
             );
-            assert!(result.is_ok());
 
-            let response = result.unwrap();
-            assert_eq!(response.events.len(), 1);
+            let result =
+                get_operator_withdrawal_lock_period(deps.as_ref(), env.clone(), operator.clone());
+            assert_eq!(result.unwrap(), Uint64::new(604800));
+
+            env.block.time = effective_time;
+
+            let result =
+                get_operator_withdrawal_lock_period(deps.as_ref(), env.clone(), operator.clone());
+            assert_eq!(result.unwrap(), Uint64::new(200));
+
+            // default withdrawal lock period is not affected
+            let result = get_withdrawal_lock_period(deps.as_ref()).unwrap();
+            assert_eq!(result, Uint64::new(604800));
+        }
+
+        // an increase is effective immediately, removing the pending decrease
+        {
+            let response = set_operator_withdrawal_lock_period(
+                deps.as_mut(),
+                env.clone(),
+                operator_info.clone(),
+                Some(Uint64::new(1000)),
+            )
+            .unwrap();
             assert_eq!(
-                response.events[0],
-                Event::new("SetWithdrawalLockPeriod")
-                    .add_attribute(
-                        "prev_withdrawal_lock_period",
-                        withdrawal_lock_period.to_string()
-                    )
-                    .add_attribute(
-                        "new_withdrawal_lock_period",
-                        withdrawal_lock_period1.to_string()
-                    )
+                response.events,
+                vec![Event::new("SetOperatorWithdrawalLockPeriod")
+                    .add_attribute("operator", operator.to_string())
+                    .add_attribute("prev_withdrawal_lock_period", "200")
+                    .add_attribute("new_withdrawal_lock_period", "1000")
+                    .add_attribute("effective_time", env.block.time.seconds().to_string())]
             );
+
+            set_operator_withdrawal_lock_period(
+                deps.as_mut(),
+                env.clone(),
+                operator_info.clone(),
+                Some(Uint64::new(200)),
+            )
+            .unwrap();
+            set_operator_withdrawal_lock_period(
+                deps.as_mut(),
+                env.clone(),
+                operator_info.clone(),
+                Some(Uint64::new(2000)),
+            )
+            .unwrap();
+
+            let mut later = env.clone();
+            later.block.time = env.block.time.plus_seconds(1000);
+            let result =
+                get_operator_withdrawal_lock_period(deps.as_ref(), later, operator.clone());
+            assert_eq!(result.unwrap(), Uint64::new(2000));
+
+            set_operator_withdrawal_lock_period(
+                deps.as_mut(),
+                env.clone(),
+                operator_info.clone(),
+                Some(Uint64::new(200)),
+            )
+            .unwrap();
+            env.block.time = env.block.time.plus_seconds(2000);
         }
 
-        // query withdrawal lock period
+        // raising the minimum above the override raises the effective period
         {
-            let result = get_withdrawal_lock_period(deps.as_ref()).unwrap();
-            assert_eq!(result, withdrawal_lock_period1);
+            set_min_withdrawal_lock_period(deps.as_mut(), owner_info.clone(), Uint64::new(300))
+                .unwrap();
+
+            let result =
+                get_operator_withdrawal_lock_period(deps.as_ref(), env.clone(), operator.clone());
+            assert_eq!(result.unwrap(), Uint64::new(300));
         }
 
-        // wrong permission to update withdrawal lock period successfully
+        // operator removes its override, back to the default withdrawal lock period
         {
-            let user_info = MessageInfo {
-                sender: deps.api.addr_make("user"),
-                funds: vec![],
-            };
+            let response = set_operator_withdrawal_lock_period(
+                deps.as_mut(),
+                env.clone(),
+                operator_info,
+                None,
+            )
+            .unwrap();
+            assert_eq!(
+                response.events,
+                vec![Event::new("SetOperatorWithdrawalLockPeriod")
+                    .add_attribute("operator", operator.to_string())
+                    .add_attribute("prev_withdrawal_lock_period", "300")
+                    .add_attribute("new_withdrawal_lock_period", "604800")
+                    .add_attribute("effective_time", env.block.time.seconds().to_string())]
+            );
+
             let result =
-                set_withdrawal_lock_period(deps.as_mut(), env, user_info, withdrawal_lock_period1);
-            assert!(result.is_err());
+                get_operator_withdrawal_lock_period(deps.as_ref(), env.clone(), operator.clone());
+            assert_eq!(result.unwrap(), Uint64::new(604800));
         }
     }
 
@@ -1550,6 +3560,7 @@ mod tests {
                                     router: deps.api.addr_make("router"),
                                     operator: operator_addr,
                                     total_assets: Uint128::zero(),
+                                    asset_id: "cosmos:cosmos-testnet/cw20:vault".to_string(),
                                     asset_type: AssetType::Cw20,
                                     asset_reference: contract_addr,
                                 };
@@ -1590,6 +3601,116 @@ mod tests {
 
         assert_eq!(response.0.len(), 1);
         assert_eq!(response.0[0].vault, vault2);
+
+        // vaults no longer whitelisted (before the upgrade) are removed from the operator vaults
+        VAULTS
+            .save(&mut deps.storage, &vault2, &Vault { whitelisted: false })
+            .unwrap();
+        migrate::unindex_non_whitelisted_vaults(deps.as_mut()).unwrap();
+
+        let response =
+            query::list_vaults_by_operator(deps.as_ref(), operator1.clone(), 100, None).unwrap();
+        assert_eq!(response.0.len(), 1);
+
+        let response = query::list_vaults_by_operator(deps.as_ref(), operator2, 100, None).unwrap();
+        assert!(response.0.is_empty());
+    }
+
+    #[test]
+    fn test_backfill_idle_slashed_migration() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let operator = deps.api.addr_make("operator");
+        let vault = deps.api.addr_make("vault");
+        let asset_id = "cosmos:cosmos-testnet/slip44:118".to_string();
+
+        {
+            let moved_vault = vault.to_string();
+            let moved_asset_id = asset_id.clone();
+            deps.querier
+                .update_wasm(move |req: &WasmQuery| -> QuerierResult {
+                    if let WasmQuery::Smart { contract_addr, msg } = req {
+                        let msg: VaultInfoQueryMsg = from_json(msg).unwrap();
+                        assert_eq!(contract_addr, &moved_vault);
+                        match msg {
+                            VaultInfoQueryMsg::VaultInfo {} => {
+                                let response = VaultInfoResponse {
+                                    router: deps.api.addr_make("router"),
+                                    operator: operator.clone(),
+                                    total_assets: Uint128::zero(),
+                                    asset_id: moved_asset_id.clone(),
+                                    asset_type: AssetType::Bank,
+                                    asset_reference: "denom".to_string(),
+                                };
+                                SystemResult::Ok(ContractResult::Ok(
+                                    to_json_binary(&response).unwrap(),
+                                ))
+                            }
+                        }
+                    } else {
+                        SystemResult::Err(SystemError::UnsupportedRequest {
+                            kind: "Unsupported query".to_string(),
+                        })
+                    }
+                });
+
+            VAULTS
+                .save(&mut deps.storage, &vault, &Vault { whitelisted: true })
+                .unwrap();
+        }
+
+        // 300 held by the router: 100 locked for a slashing request, 50 already tracked as idle
+        // and 150 stranded by slashing requests finalized without a destination before the upgrade
+        deps.querier
+            .bank
+            .update_balance(&env.contract.address, coins(300, "denom"));
+        let locked_id = SlashingRequestId::from_hex(
+            "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        )
+        .unwrap();
+        state::SLASH_LOCKED
+            .save(&mut deps.storage, (locked_id, &vault), &Uint128::new(100))
+            .unwrap();
+        let idle_id = SlashingRequestId::from_hex(
+            "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb",
+        )
+        .unwrap();
+        state::add_idle_slashed(
+            &mut deps.storage,
+            &idle_id,
+            &asset_id,
+            AssetType::Bank,
+            "denom".to_string(),
+            Uint128::new(50),
+        )
+        .unwrap();
+
+        migrate::backfill_idle_slashed(deps.as_mut(), &env).unwrap();
+
+        let unattributed = SlashingRequestId::from(state::UNATTRIBUTED_IDLE_SLASHED_ID);
+        let expected = IdleSlashedResponse(vec![IdleSlashedBalance {
+            asset_id: asset_id.clone(),
+            amount: Uint128::new(150),
+        }]);
+        assert_eq!(
+            query::idle_slashed(deps.as_ref(), unattributed.clone()).unwrap(),
+            expected
+        );
+        assert_eq!(
+            query::idle_slashed_balances(deps.as_ref()).unwrap(),
+            IdleSlashedResponse(vec![IdleSlashedBalance {
+                asset_id: asset_id.clone(),
+                amount: Uint128::new(200),
+            }])
+        );
+
+        // running it again does not backfill twice
+        migrate::backfill_idle_slashed(deps.as_mut(), &env).unwrap();
+        assert_eq!(
+            query::idle_slashed(deps.as_ref(), unattributed).unwrap(),
+            expected
+        );
     }
 
     #[test]
@@ -1658,6 +3779,8 @@ mod tests {
                 metadata: SlashingMetadata {
                     reason: "test".to_string(),
                 },
+                category: None,
+                vaults: None,
             },
             request_time: env.block.time,
             request_resolution: env.block.time.plus_seconds(50),
@@ -1691,6 +3814,300 @@ mod tests {
         assert_eq!(res, None);
     }
 
+    #[test]
+    fn test_pending_slashes() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let service1 = deps.api.addr_make("service1");
+        let service2 = deps.api.addr_make("service2");
+        let operator = deps.api.addr_make("operator");
+
+        let new_request = |service: &cosmwasm_std::Addr, status: SlashingRequestStatus| {
+            let slashing_request = SlashingRequest {
+                request: RequestSlashingPayload {
+                    operator: operator.to_string(),
+                    bips: 100,
+                    timestamp: env.block.time,
+                    metadata: SlashingMetadata {
+                        reason: "test".to_string(),
+                    },
+                    category: None,
+                    vaults: None,
+                },
+                request_time: env.block.time,
+                request_resolution: env.block.time.plus_seconds(50),
+                request_expiry: env.block.time.plus_seconds(100),
+                status: status.into(),
+                service: service.clone(),
+            };
+            let id = SlashingRequestId::hash(&slashing_request).unwrap();
+            (id, slashing_request)
+        };
+        let is_pending = |deps: Deps, seconds: u64| {
+            let now = mock_env().block.time.plus_seconds(seconds);
+            !state::list_pending_slashes(deps.storage, &operator, now)
+                .unwrap()
+                .is_empty()
+        };
+
+        assert!(!is_pending(deps.as_ref(), 0));
+
+        // pending until expired
+        let (id1, request1) = new_request(&service1, SlashingRequestStatus::Pending);
+        state::save_slashing_request(&mut deps.storage, &service1, &operator, &request1).unwrap();
+        assert!(is_pending(deps.as_ref(), 0));
+        assert!(is_pending(deps.as_ref(), 100));
+        assert!(!is_pending(deps.as_ref(), 101));
+
+        // pending while any service's slash is pending
+        let (id2, request2) = new_request(&service2, SlashingRequestStatus::Pending);
+        state::save_slashing_request(&mut deps.storage, &service2, &operator, &request2).unwrap();
+        state::update_slashing_request_status(
+            &mut deps.storage,
+            &id1,
+            SlashingRequestStatus::Locked,
+        )
+        .unwrap();
+        assert!(is_pending(deps.as_ref(), 0));
+
+        state::update_slashing_request_status(
+            &mut deps.storage,
+            &id2,
+            SlashingRequestStatus::Canceled,
+        )
+        .unwrap();
+        assert!(!is_pending(deps.as_ref(), 0));
+
+        // pending slashing requests saved before the flag are flagged by the migration
+        let (_, mut request3) = new_request(&service1, SlashingRequestStatus::Pending);
+        request3.request.bips = 200;
+        let id3 = SlashingRequestId::hash(&request3).unwrap();
+        SLASHING_REQUESTS
+            .save(&mut deps.storage, &id3, &request3)
+            .unwrap();
+        assert!(!is_pending(deps.as_ref(), 0));
+        migrate::flag_pending_slashes(deps.as_mut()).unwrap();
+        assert!(is_pending(deps.as_ref(), 0));
+    }
+
+    #[test]
+    fn test_list_slashing_requests() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let service1 = deps.api.addr_make("service1");
+        let service2 = deps.api.addr_make("service2");
+        let operator1 = deps.api.addr_make("operator1");
+        let operator2 = deps.api.addr_make("operator2");
+
+        let new_request = |service: &cosmwasm_std::Addr,
+                           operator: &cosmwasm_std::Addr,
+                           status: SlashingRequestStatus,
+                           request_time: cosmwasm_std::Timestamp| {
+            let slashing_request = SlashingRequest {
+                request: RequestSlashingPayload {
+                    operator: operator.to_string(),
+                    bips: 100,
+                    timestamp: env.block.time,
+                    metadata: SlashingMetadata {
+                        reason: "test".to_string(),
+                    },
+                    category: None,
+                    vaults: None,
+                },
+                request_time,
+                request_resolution: request_time.plus_seconds(50),
+                request_expiry: request_time.plus_seconds(100),
+                status: status.into(),
+                service: service.clone(),
+            };
+            let id = SlashingRequestId::hash(&slashing_request).unwrap();
+            (id, slashing_request)
+        };
+
+        // requested one after another, the ids are not in the requested order
+        let time = env.block.time;
+        let requests = [
+            new_request(&service1, &operator1, SlashingRequestStatus::Pending, time),
+            new_request(
+                &service1,
+                &operator2,
+                SlashingRequestStatus::Locked,
+                time.plus_seconds(1),
+            ),
+            new_request(
+                &service2,
+                &operator1,
+                SlashingRequestStatus::Finalized,
+                time.plus_seconds(2),
+            ),
+            new_request(
+                &service2,
+                &operator2,
+                SlashingRequestStatus::Canceled,
+                time.plus_seconds(3),
+            ),
+        ];
+        let mut by_id: Vec<_> = requests.iter().map(|(id, _)| id.clone()).collect();
+        by_id.sort_by(|a, b| b.as_slice().cmp(a.as_slice()));
+        let by_time: Vec<_> = requests.iter().rev().map(|(id, _)| id.clone()).collect();
+        assert_ne!(by_id, by_time);
+
+        // saved as before the indexes, indexed by the migration
+        let unindexed: cw_storage_plus::Map<&SlashingRequestId, SlashingRequest> =
+            cw_storage_plus::Map::new("slashing_requests");
+        for (id, slashing_request) in &requests {
+            unindexed
+                .save(&mut deps.storage, id, slashing_request)
+                .unwrap();
+        }
+        let response = query::list_slashing_requests(
+            deps.as_ref(),
+            None,
+            Some(operator1.clone()),
+            None,
+            100,
+            None,
+        )
+        .unwrap();
+        assert!(response.0.is_empty());
+
+        migrate::index_slashing_requests(deps.as_mut()).unwrap();
+
+        // ordered by request time (latest first)
+        let ids = |response: SlashingRequestListResponse| -> Vec<SlashingRequestId> {
+            response.0.into_iter().map(|item| item.id).collect()
+        };
+        let expected = |indices: &[usize]| -> Vec<SlashingRequestId> {
+            indices
+                .iter()
+                .rev()
+                .map(|i| requests[*i].0.clone())
+                .collect()
+        };
+
+        // no filter
+        let response =
+            query::list_slashing_requests(deps.as_ref(), None, None, None, 100, None).unwrap();
+        assert_eq!(ids(response), expected(&[0, 1, 2, 3]));
+
+        // by operator
+        let response = query::list_slashing_requests(
+            deps.as_ref(),
+            None,
+            Some(operator1.clone()),
+            None,
+            100,
+            None,
+        )
+        .unwrap();
+        assert_eq!(ids(response), expected(&[0, 2]));
+
+        // by service
+        let response = query::list_slashing_requests(
+            deps.as_ref(),
+            Some(service2.clone()),
+            None,
+            None,
+            100,
+            None,
+        )
+        .unwrap();
+        assert_eq!(ids(response), expected(&[2, 3]));
+
+        // by service and operator
+        let response = query::list_slashing_requests(
+            deps.as_ref(),
+            Some(service1.clone()),
+            Some(operator2.clone()),
+            None,
+            100,
+            None,
+        )
+        .unwrap();
+        assert_eq!(ids(response), expected(&[1]));
+
+        // by status
+        let response = query::list_slashing_requests(
+            deps.as_ref(),
+            None,
+            None,
+            Some(SlashingRequestStatus::Finalized),
+            100,
+            None,
+        )
+        .unwrap();
+        assert_eq!(ids(response), expected(&[2]));
+
+        // by operator and status
+        let response = query::list_slashing_requests(
+            deps.as_ref(),
+            None,
+            Some(operator2.clone()),
+            Some(SlashingRequestStatus::Locked),
+            100,
+            None,
+        )
+        .unwrap();
+        assert_eq!(ids(response), expected(&[1]));
+
+        // status updates are indexed
+        state::update_slashing_request_status(
+            &mut deps.storage,
+            &requests[0].0,
+            SlashingRequestStatus::Locked,
+        )
+        .unwrap();
+        let response = query::list_slashing_requests(
+            deps.as_ref(),
+            None,
+            None,
+            Some(SlashingRequestStatus::Locked),
+            100,
+            None,
+        )
+        .unwrap();
+        assert_eq!(ids(response), expected(&[0, 1]));
+
+        // paginated
+        let page1 =
+            query::list_slashing_requests(deps.as_ref(), None, None, None, 3, None).unwrap();
+        assert_eq!(page1.0.len(), 3);
+        let start_after = page1.0.last().map(|item| item.id.clone());
+        let page2 =
+            query::list_slashing_requests(deps.as_ref(), None, None, None, 3, start_after).unwrap();
+        assert_eq!(page2.0.len(), 1);
+
+        let all: Vec<_> = page1.0.into_iter().chain(page2.0).map(|i| i.id).collect();
+        assert_eq!(all, expected(&[0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_query_vault_aum_at_slashing_request() {
+        let mut deps = mock_dependencies();
+
+        let request_id = SlashingRequestId(
+            HexBinary::from_hex("dff7a6f403eff632636533660ab53ab35e7ae0fe2e5dacb160aa7d876a412f09")
+                .unwrap(),
+        );
+        let vault = deps.api.addr_make("vault");
+        let aum = crate::msg::AssetAum {
+            asset_id: "cosmos:cosmos-testnet/bank:denom".to_string(),
+            total_assets: Uint128::new(1000),
+            usd_value: None,
+        };
+
+        let err =
+            query::vault_aum(deps.as_ref(), vault.clone(), Some(request_id.clone())).unwrap_err();
+        assert_eq!(err.to_string(), "vault aum at slashing request not found");
+
+        state::SLASH_LOCKED_AUM
+            .save(&mut deps.storage, (request_id.clone(), &vault), &aum)
+            .unwrap();
+
+        let res = query::vault_aum(deps.as_ref(), vault, Some(request_id)).unwrap();
+        assert_eq!(res.0, aum);
+    }
+
     #[test]
     fn test_query_slash_locked() {
         let mut deps = mock_dependencies();
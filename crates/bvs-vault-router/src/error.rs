@@ -18,6 +18,9 @@ pub enum ContractError {
     #[error("Invalid slashing request: {msg}")]
     InvalidSlashingRequest { msg: String },
 
+    #[error("Invalid allocation: {msg}")]
+    InvalidAllocation { msg: String },
+
     #[error("Unauthorized: {msg}")]
     Unauthorized { msg: String },
 }
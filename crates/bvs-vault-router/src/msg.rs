@@ -1,7 +1,8 @@
-use crate::state::SlashingRequest;
+use crate::state::{Allocation, SlashingRequest, SlashingRequestStatus};
 use bvs_library::slashing::SlashingRequestId;
+use bvs_registry::WeightedDestination;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{from_json, to_json_binary, Addr, Binary, Timestamp, Uint128, Uint64};
+use cosmwasm_std::{from_json, to_json_binary, Addr, Binary, Decimal, Timestamp, Uint128, Uint64};
 
 #[cw_serde]
 pub struct MigrateMsg {
@@ -27,6 +28,57 @@ pub enum ExecuteMsg {
     /// Only the `owner` can call this message.
     SetWithdrawalLockPeriod(Uint64),
 
+    /// ExecuteMsg SetMinWithdrawalLockPeriod the minimum lock period for withdrawal.
+    /// The default and per-operator withdrawal lock periods cannot be set below it,
+    /// effective withdrawal lock periods below it are raised to it.
+    /// Only the `owner` can call this message.
+    SetMinWithdrawalLockPeriod(Uint64),
+
+    /// ExecuteMsg SetOperatorWithdrawalLockPeriod the lock period for withdrawal
+    /// of the vaults of the operator (info.sender), overriding the default withdrawal lock period.
+    /// Set to `None` to remove the override.
+    /// An increase is effective immediately,
+    /// a decrease is effective after the current withdrawal lock period of the operator.
+    /// Only a registered operator can call this message,
+    /// once the `owner` has set the minimum withdrawal lock period.
+    SetOperatorWithdrawalLockPeriod(Option<Uint64>),
+
+    /// ExecuteMsg SetMaxVaultsPerOperator the max number of (whitelisted) vaults an operator can have.
+    /// Whitelisting a new vault of an operator at the max fails.
+    /// This bounds the number of vaults iterated over when locking a slashing request,
+    /// so it cannot exceed 80 (the lock slashing gas limit, less its fixed cost, over the estimated gas per vault).
+    /// Only the `owner` can call this message.
+    SetMaxVaultsPerOperator(u32),
+
+    /// ExecuteMsg SetOracle the oracle contract (BVS Oracle) used to value the assets under management in USD.
+    /// Set to `None` to remove the oracle.
+    /// Only the `owner` can call this message.
+    SetOracle { oracle: Option<String> },
+
+    /// ExecuteMsg SetAllocationDelay the delay (in seconds) before an allocation increase becomes slashable.
+    /// Only the `owner` can call this message.
+    SetAllocationDelay(Uint64),
+
+    /// ExecuteMsg SetAllocation allocates `bips` of the stake of the `vault` to the `service`,
+    /// the slashable magnitude of the vault for the service.
+    /// The allocations of a vault (including pending deallocations) cannot exceed 10,000 bips (100%).
+    ///
+    /// An allocation increase becomes slashable after the allocation delay,
+    /// and requires an active registration between the operator and the service.
+    /// A deallocation becomes effective after the withdrawal lock period of the operator,
+    /// until then the stake is still slashable at the current allocation.
+    /// Only one allocation change can be pending at a time per (vault, service).
+    ///
+    /// The first allocation of an operator becomes effective after the longer of the allocation delay
+    /// and the withdrawal lock period of the operator.
+    /// Once it is effective, a service can only slash the stake allocated to it (at the incident timestamp).
+    /// Only the operator of the vault (info.sender) can call this message.
+    SetAllocation {
+        vault: String,
+        service: String,
+        bips: u16,
+    },
+
     /// ExecuteMsg TransferOwnership
     /// See [`bvs_library::ownership::transfer_ownership`] for more information on this field
     TransferOwnership { new_owner: String },
@@ -41,7 +93,10 @@ pub enum ExecuteMsg {
     /// - The operator must have opted in to slashing at the specified timestamp
     /// - The timestamp must be within the allowable slashing window (not too old or in the future)
     /// - The service must not have another active slashing request against the same operator
+    /// - The previous slashing request of the service against the operator, if canceled or expired,
+    ///   must have ended at least [`SLASHING_REQUEST_COOLDOWN`](crate::state::SLASHING_REQUEST_COOLDOWN) ago
     /// - The reason provided in metadata must not exceed the maximum allowed length
+    /// - The selected vaults (if provided) must belong to the operator and their assets must be accepted by the service
     ///
     /// When successful, this creates a slashing request with an expiry time based on the
     /// resolution_window parameter and returns a unique slashing request ID.
@@ -61,6 +116,13 @@ pub enum ExecuteMsg {
     /// has resolved the issue. The definition of “resolved” is up to the service to define.
     CancelSlashing(SlashingRequestId),
 
+    /// ExecuteMsg ExpireSlashing moves a pending slashing request past its `request_expiry`
+    /// to the `Expired` status, clearing it as the pending slashing request of the service and operator.
+    ///
+    /// Anyone can call this message, so a stale slashing request doesn't linger as pending
+    /// until the service files a new one.
+    ExpireSlashing(SlashingRequestId),
+
     /// ExecuteMsg FinalizeSlashing moves the slashed collateral from the router to the destination
     /// specified in the slashing parameters that were agreed upon by the service and operator.
     ///
@@ -68,6 +130,25 @@ pub enum ExecuteMsg {
     /// and should only be called after the request has been locked,
     /// and the guardrail proposal has been voted on and passed.
     FinalizeSlashing(SlashingRequestId),
+
+    /// ExecuteMsg SweepSlashed transfers the idle slashed collateral of the slashing request
+    /// (held by the router after it was finalized without a destination) to the `recipient`.
+    /// The collateral stranded before the router tracked it is held under the all-zeros slashing request id.
+    /// Only the `owner` or the `guardrail` can call this message.
+    SweepSlashed {
+        slashing_request_id: SlashingRequestId,
+        recipient: String,
+    },
+
+    /// ExecuteMsg RedistributeSlashed splits the idle slashed collateral of the slashing request
+    /// (held by the router after it was finalized without a destination) between the weighted `destinations`.
+    /// The `bips` of the destinations must add up to 10_000 (100%),
+    /// each share is rounded down and the rounding dust goes to the first destination.
+    /// Only the `owner` or the `guardrail` can call this message.
+    RedistributeSlashed {
+        slashing_request_id: SlashingRequestId,
+        destinations: Vec<WeightedDestination>,
+    },
 }
 
 #[cw_serde]
@@ -98,6 +179,17 @@ pub struct RequestSlashingPayload {
     pub timestamp: Timestamp,
     /// Additional contextual information about the slashing request.
     pub metadata: SlashingMetadata,
+    /// The named slashing category (registered by the service in the registry) of the slashing condition.
+    /// If provided, the slashing parameters and operator opt-in of the category at the timestamp are enforced,
+    /// else the service default slashing parameters and opt-in are enforced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// The vaults of the operator to slash, if provided, else every vault of the operator.
+    /// Each vault must belong to the operator and its asset must be accepted by the service
+    /// (see the `asset_ids` of the slashing parameters at the timestamp).
+    /// Vaults of assets not accepted by the service are never slashed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vaults: Option<Vec<String>>,
 }
 
 #[cw_serde]
@@ -121,6 +213,14 @@ pub enum QueryMsg {
     #[returns(IsValidatingResponse)]
     IsValidating { operator: String },
 
+    /// QueryMsg IsSlashPending: returns true if a slash that would slash the `vault` is pending,
+    /// a slashing request against the vault's operator that is not locked, canceled or expired yet,
+    /// that selects the vault (if it selects vaults), accepts the vault's asset
+    /// and has stake of the vault allocated to its service at the incident timestamp.
+    /// The vault freezes redemptions while a slash is pending.
+    #[returns(IsSlashPendingResponse)]
+    IsSlashPending { vault: String },
+
     /// QueryMsg ListVaults: returns a list of vaults.
     /// You can provide `limit` and `start_after` to paginate the results.
     /// The max `limit` is 100.
@@ -140,10 +240,69 @@ pub enum QueryMsg {
         start_after: Option<String>,
     },
 
-    /// QueryMsg WithdrawalLockPeriod: returns the withdrawal lock period.
+    /// QueryMsg WithdrawalLockPeriod: returns the default withdrawal lock period,
+    /// for operators without an override.
     #[returns(WithdrawalLockPeriodResponse)]
     WithdrawalLockPeriod {},
 
+    /// QueryMsg MinWithdrawalLockPeriod: returns the minimum withdrawal lock period.
+    #[returns(WithdrawalLockPeriodResponse)]
+    MinWithdrawalLockPeriod {},
+
+    /// QueryMsg OperatorWithdrawalLockPeriod: returns the effective withdrawal lock period of the operator.
+    /// Called by the vaults when queuing withdrawals,
+    /// The period in effect at the incident is the max slashable delay of slashing requests against the operator.
+    #[returns(WithdrawalLockPeriodResponse)]
+    OperatorWithdrawalLockPeriod { operator: String },
+
+    /// QueryMsg MaxVaultsPerOperator: returns the max number of vaults an operator can have.
+    #[returns(MaxVaultsPerOperatorResponse)]
+    MaxVaultsPerOperator {},
+
+    /// QueryMsg OperatorVaultCount: returns the number of (whitelisted) vaults of the operator,
+    /// counted towards the max vaults per operator.
+    #[returns(OperatorVaultCountResponse)]
+    OperatorVaultCount { operator: String },
+
+    /// QueryMsg Oracle: returns the oracle contract, `None` if not set.
+    #[returns(OracleResponse)]
+    Oracle {},
+
+    /// QueryMsg OperatorAum: returns the assets under management of the operator,
+    /// the `total_assets` of its whitelisted vaults aggregated per asset.
+    /// If `slashing_request_id` is provided, returns the assets under management of the operator
+    /// at the moment the slashing request was locked instead.
+    /// If the oracle is set, each current asset is valued in USD at the current price.
+    #[returns(OperatorAumResponse)]
+    OperatorAum {
+        operator: String,
+        slashing_request_id: Option<SlashingRequestId>,
+    },
+
+    /// QueryMsg VaultAum: returns the assets under management of the vault.
+    /// If `slashing_request_id` is provided, returns the assets under management of the vault
+    /// at the moment the slashing request was locked instead.
+    /// If the oracle is set, the current asset is valued in USD at the current price.
+    #[returns(VaultAumResponse)]
+    VaultAum {
+        vault: String,
+        slashing_request_id: Option<SlashingRequestId>,
+    },
+
+    /// QueryMsg AllocationDelay: returns the delay (in seconds) before an allocation increase becomes slashable.
+    #[returns(AllocationDelayResponse)]
+    AllocationDelay {},
+
+    /// QueryMsg Allocation: returns the current allocation of the `vault` to the `service`,
+    /// with the pending allocation change if any.
+    #[returns(AllocationResponse)]
+    Allocation { vault: String, service: String },
+
+    /// QueryMsg VaultAllocations: returns the allocations of the `vault` to services,
+    /// with the stake allocated (including pending deallocations) and free to allocate.
+    #[returns(VaultAllocationsResponse)]
+    VaultAllocations { vault: String },
+
     #[returns(SlashingRequestIdResponse)]
     SlashingRequestId { service: String, operator: String },
 
@@ -154,6 +313,32 @@ pub enum QueryMsg {
     SlashingLocked {
         slashing_request_id: SlashingRequestId,
     },
+
+    /// QueryMsg ListSlashingRequests: returns a list of slashing requests (of any status, including history),
+    /// filtered by `service`, `operator` and `status` if provided.
+    /// Ordered from the latest to the earliest requested (by `request_time`, then by id).
+    /// A `status` filter combined with `service` or `operator` is applied while iterating over their requests,
+    /// it can read the whole history of the operator (or service) to fill a page.
+    /// You can provide `limit` and `start_after` to paginate the results.
+    /// The max `limit` is 100.
+    #[returns(SlashingRequestListResponse)]
+    ListSlashingRequests {
+        service: Option<String>,
+        operator: Option<String>,
+        status: Option<SlashingRequestStatus>,
+        limit: Option<u32>,
+        start_after: Option<SlashingRequestId>,
+    },
+
+    /// QueryMsg IdleSlashedBalances: returns the idle slashed collateral held by the router
+    /// (of slashing requests finalized without a destination), per asset.
+    #[returns(IdleSlashedResponse)]
+    IdleSlashedBalances {},
+
+    /// QueryMsg IdleSlashed: returns the idle slashed collateral held by the router
+    /// for the slashing request, per asset.
+    #[returns(IdleSlashedResponse)]
+    IdleSlashed(SlashingRequestId),
 }
 
 /// The response to the `IsWhitelisted` query.
@@ -168,6 +353,12 @@ struct IsWhitelistedResponse(bool);
 #[cw_serde]
 struct IsValidatingResponse(bool);
 
+/// The response to the `IsSlashPending` query.
+/// Not exported.
+/// This is just a wrapper around `bool`, so that the schema can be generated.
+#[cw_serde]
+struct IsSlashPendingResponse(bool);
+
 /// The response to the `ListVaults` query.
 /// For pagination, the `start_after` field is the last `vault` from the previous page.
 #[cw_serde]
@@ -185,12 +376,87 @@ pub struct Vault {
 #[cw_serde]
 struct WithdrawalLockPeriodResponse(Uint64);
 
+/// The response to the `MaxVaultsPerOperator` query.
+/// Not exported.
+/// This is just a wrapper around `u32`, so that the schema can be generated.
+#[cw_serde]
+struct MaxVaultsPerOperatorResponse(u32);
+
+/// The response to the `OperatorVaultCount` query.
+/// Not exported.
+/// This is just a wrapper around `u32`, so that the schema can be generated.
+#[cw_serde]
+struct OperatorVaultCountResponse(u32);
+
+/// The response to the `Oracle` query.
+/// Not exported.
+/// This is just a wrapper around `Option<Addr>`, so that the schema can be generated.
+#[cw_serde]
+struct OracleResponse(Option<Addr>);
+
+/// The response to the `AllocationDelay` query.
+/// Not exported.
+/// This is just a wrapper around `Uint64`, so that the schema can be generated.
+#[cw_serde]
+struct AllocationDelayResponse(Uint64);
+
+#[cw_serde]
+pub struct AllocationResponse(pub Allocation);
+
+/// The response to the `VaultAllocations` query.
+#[cw_serde]
+pub struct VaultAllocationsResponse {
+    /// Total assets in the vault
+    pub total_assets: Uint128,
+    /// The timestamp the allocations of the vault's operator are effective from,
+    /// `None` if the operator never allocated.
+    /// Before it, every service of the operator can slash the full stake of the vault.
+    pub effective_time: Option<Timestamp>,
+    /// The bips of the vault's stake allocated to services, including pending deallocations.
+    pub allocated_bips: u16,
+    /// The assets allocated to services, `total_assets * allocated_bips / 10,000` rounded down.
+    pub allocated_assets: Uint128,
+    /// The assets free to allocate, `total_assets - allocated_assets`.
+    pub free_assets: Uint128,
+    /// The allocations of the vault, ordered by `service`.
+    pub allocations: Vec<VaultAllocation>,
+}
+
+#[cw_serde]
+pub struct VaultAllocation {
+    pub service: Addr,
+    pub allocation: Allocation,
+}
+
 #[cw_serde]
 pub struct SlashingRequestIdResponse(pub Option<SlashingRequestId>);
 
 #[cw_serde]
 pub struct SlashingRequestResponse(pub Option<SlashingRequest>);
 
+/// The response to the `ListSlashingRequests` query.
+/// For pagination, the `start_after` field is the last `id` from the previous page.
+#[cw_serde]
+pub struct SlashingRequestListResponse(pub Vec<SlashingRequestItem>);
+
+#[cw_serde]
+pub struct SlashingRequestItem {
+    pub id: SlashingRequestId,
+    pub slashing_request: SlashingRequest,
+}
+
+/// The response to the `IdleSlashedBalances` and `IdleSlashed` queries.
+/// One item per asset, ordered by `asset_id`.
+#[cw_serde]
+pub struct IdleSlashedResponse(pub Vec<IdleSlashedBalance>);
+
+#[cw_serde]
+pub struct IdleSlashedBalance {
+    /// Asset identifier, using the CAIP-19 format.
+    pub asset_id: String,
+    pub amount: Uint128,
+}
+
 #[cw_serde]
 pub struct SlashingLockedResponse(pub Vec<SlashingLockedResponseItem>);
 
@@ -199,3 +465,26 @@ pub struct SlashingLockedResponseItem {
     pub vault: Addr,
     pub amount: Uint128,
 }
+
+/// The assets under management of a single asset.
+#[cw_serde]
+pub struct AssetAum {
+    /// Asset identifier, using the CAIP-19 format.
+    pub asset_id: String,
+    /// The total assets under management.
+    pub total_assets: Uint128,
+    /// The USD value of the `total_assets`, at the current price of the oracle.
+    /// `None` if the oracle is not set, has no (valid) price for the asset,
+    /// or for assets under management at a slashing request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usd_value: Option<Decimal>,
+}
+
+/// The response to the `OperatorAum` query.
+/// One item per asset, ordered by `asset_id`.
+#[cw_serde]
+pub struct OperatorAumResponse(pub Vec<AssetAum>);
+
+/// The response to the `VaultAum` query.
+#[cw_serde]
+pub struct VaultAumResponse(pub AssetAum);
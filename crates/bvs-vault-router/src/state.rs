@@ -1,10 +1,11 @@
-use crate::msg::RequestSlashingPayload;
+use crate::contract::vault::AssetType;
+use crate::msg::{AssetAum, RequestSlashingPayload};
 use bvs_library::addr::{Operator, Service};
 use bvs_library::slashing::SlashingRequestId;
 use bvs_library::time::DAYS;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{to_json_vec, Addr, StdError, StdResult, Storage, Timestamp, Uint128, Uint64};
-use cw_storage_plus::{Item, Map};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 use sha3::Digest;
 
 /// Mapping of vault's Addr to Vault
@@ -16,6 +17,9 @@ pub(crate) const REGISTRY: Item<Addr> = Item::new("registry");
 /// Storage for the guardrail contract address
 pub(crate) const GUARDRAIL: Item<Addr> = Item::new("guardrail");
 
+/// Storage for the (optional) oracle contract address, used to value the assets under management in USD.
+pub(crate) const ORACLE: Item<Addr> = Item::new("oracle");
+
 #[cw_serde]
 pub struct Vault {
     pub whitelisted: bool,
@@ -43,12 +47,254 @@ pub(crate) const WITHDRAWAL_LOCK_PERIOD: Item<Uint64> = Item::new("withdrawal_lo
 /// The default value is 7 days.
 pub const DEFAULT_WITHDRAWAL_LOCK_PERIOD: Uint64 = Uint64::new(604800);
 
-/// Operator to its managed vaults. Key = (OperatorAddr, VaultAddr)
+/// Store the minimum withdrawal lock period in seconds, enforced by the `owner`.
+/// The default and per-operator withdrawal lock periods cannot be set below it.
+/// Operators cannot override their withdrawal lock period until it is set.
+pub(crate) const MIN_WITHDRAWAL_LOCK_PERIOD: Item<Uint64> = Item::new("min_withdrawal_lock_period");
+
+/// (Operator, effective timestamp in seconds) to the withdrawal lock period (in seconds)
+/// overriding [`WITHDRAWAL_LOCK_PERIOD`] from that timestamp, `None` if the override is removed.
+/// The history of the operator's overrides, to get the period at the incident timestamp of a slashing request.
+pub(crate) const OPERATOR_WITHDRAWAL_LOCK_PERIODS: Map<(&Operator, u64), Option<Uint64>> =
+    Map::new("operator_withdrawal_lock_periods");
+
+/// Get the minimum withdrawal lock period, zero if not set.
+pub(crate) fn get_min_withdrawal_lock_period(storage: &dyn Storage) -> StdResult<Uint64> {
+    Ok(MIN_WITHDRAWAL_LOCK_PERIOD
+        .may_load(storage)?
+        .unwrap_or_default())
+}
+
+/// Get the default withdrawal lock period (for operators without an override),
+/// [`DEFAULT_WITHDRAWAL_LOCK_PERIOD`] if not set.
+/// Never lower than the minimum withdrawal lock period.
+pub(crate) fn get_withdrawal_lock_period(storage: &dyn Storage) -> StdResult<Uint64> {
+    let value = WITHDRAWAL_LOCK_PERIOD
+        .may_load(storage)?
+        .unwrap_or(DEFAULT_WITHDRAWAL_LOCK_PERIOD);
+    Ok(value.max(get_min_withdrawal_lock_period(storage)?))
+}
+
+/// Get the effective withdrawal lock period of the operator at the `timestamp`:
+/// the operator override effective at the `timestamp` if set, else the default withdrawal lock period.
+/// Never lower than the minimum withdrawal lock period,
+/// so an override set before the minimum was raised is lifted to it.
+pub(crate) fn get_operator_withdrawal_lock_period_at(
+    storage: &dyn Storage,
+    operator: &Operator,
+    timestamp: Timestamp,
+) -> StdResult<Uint64> {
+    let checkpoint = OPERATOR_WITHDRAWAL_LOCK_PERIODS
+        .prefix(operator)
+        .range(
+            storage,
+            None,
+            Some(Bound::inclusive(timestamp.seconds())),
+            cosmwasm_std::Order::Descending,
+        )
+        .next()
+        .transpose()?;
+    match checkpoint.and_then(|(_, value)| value) {
+        Some(value) => Ok(value.max(get_min_withdrawal_lock_period(storage)?)),
+        None => get_withdrawal_lock_period(storage),
+    }
+}
+
+/// Operator to its managed (whitelisted) vaults. Key = (OperatorAddr, VaultAddr)
+/// A vault is removed when it is no longer whitelisted.
 pub(crate) const OPERATOR_VAULTS: Map<(&Addr, &Addr), ()> = Map::new("operator_vaults");
 
+/// Store the max number of vaults per operator in [`OPERATOR_VAULTS`].
+pub(crate) const MAX_VAULTS_PER_OPERATOR: Item<u32> = Item::new("max_vaults_per_operator");
+
+/// This is used when the max vaults per operator is not set.
+/// The default value is 10.
+pub const DEFAULT_MAX_VAULTS_PER_OPERATOR: u32 = 10;
+
+/// Estimated gas used by `LockSlashing` per vault of the operator:
+/// - ~100,000 to query the vault info (60,000 wasmd contract instance cost, execution and storage reads),
+/// - ~20,000 to read the allocation checkpoint and write [`SLASH_LOCKED`] and [`SLASH_LOCKED_AUM`],
+/// - ~300,000 for the `SlashLocked` sub-message moving the slashed assets to the router
+///   (a CW20 vault calls the token contract, a bank vault sends a bank message).
+///
+/// Rounded up to 500,000 for margin.
+pub const LOCK_SLASHING_GAS_PER_VAULT: u64 = 500_000;
+
+/// The gas `LockSlashing` must fit in,
+/// the gas limit used to execute contracts by the deployment tooling, below the block gas limit.
+pub const LOCK_SLASHING_GAS_LIMIT: u64 = 50_000_000;
+
+/// Gas reserved in [`LOCK_SLASHING_GAS_LIMIT`] for the cost of `LockSlashing` independent of the vaults:
+/// - ~1,000,000 for the transaction itself (signature verification, tx size and the wasm instance),
+/// - ~100,000 to load and update the slashing request and list the vaults of the operator,
+/// - ~200,000 to query the slashing parameters from the registry.
+///
+/// Rounded up to 10,000,000 (20% of the limit),
+/// the margin for the per vault estimate being off.
+pub const LOCK_SLASHING_GAS_FIXED: u64 = 10_000_000;
+
+/// The upper bound of the max vaults per operator (80),
+/// so that `LockSlashing` over every vault of the operator fits in [`LOCK_SLASHING_GAS_LIMIT`]
+/// after the [`LOCK_SLASHING_GAS_FIXED`] cost.
+pub const MAX_MAX_VAULTS_PER_OPERATOR: u32 =
+    ((LOCK_SLASHING_GAS_LIMIT - LOCK_SLASHING_GAS_FIXED) / LOCK_SLASHING_GAS_PER_VAULT) as u32;
+
+/// Get the max number of vaults per operator, [`DEFAULT_MAX_VAULTS_PER_OPERATOR`] if not set.
+pub(crate) fn get_max_vaults_per_operator(storage: &dyn Storage) -> StdResult<u32> {
+    Ok(MAX_VAULTS_PER_OPERATOR
+        .may_load(storage)?
+        .unwrap_or(DEFAULT_MAX_VAULTS_PER_OPERATOR))
+}
+
+/// Count the (whitelisted) vaults of the operator in [`OPERATOR_VAULTS`].
+/// Bounded by the max vaults per operator (unless lowered after the vaults were set).
+pub(crate) fn count_operator_vaults(storage: &dyn Storage, operator: &Operator) -> StdResult<u32> {
+    let count = OPERATOR_VAULTS
+        .prefix(operator)
+        .keys_raw(storage, None, None, cosmwasm_std::Order::Ascending)
+        .count();
+    Ok(count as u32)
+}
+
+/// Max allocation (100% of the vault's stake), in bips.
+pub const MAX_ALLOCATION_BIPS: u16 = 10_000;
+
+/// Store the allocation delay in seconds, enforced by the `owner`.
+/// An allocation increase only becomes slashable after the allocation delay.
+pub(crate) const ALLOCATION_DELAY: Item<Uint64> = Item::new("allocation_delay");
+
+/// This is used when the allocation delay is not set.
+/// The default value is 1 day.
+pub const DEFAULT_ALLOCATION_DELAY: Uint64 = Uint64::new(DAYS);
+
+/// Get the allocation delay, [`DEFAULT_ALLOCATION_DELAY`] if not set.
+pub(crate) fn get_allocation_delay(storage: &dyn Storage) -> StdResult<Uint64> {
+    Ok(ALLOCATION_DELAY
+        .may_load(storage)?
+        .unwrap_or(DEFAULT_ALLOCATION_DELAY))
+}
+
+#[cw_serde]
+pub struct Allocation {
+    /// The allocated magnitude (bips of the vault's stake) slashable by the service.
+    pub bips: u16,
+    /// The allocation change pending until its `effective_time`.
+    pub pending: Option<PendingAllocation>,
+}
+
+#[cw_serde]
+pub struct PendingAllocation {
+    pub bips: u16,
+    pub effective_time: Timestamp,
+}
+
+impl Allocation {
+    /// The allocation at `now`, with the pending change applied if it is effective.
+    pub fn at(&self, now: Timestamp) -> Allocation {
+        match &self.pending {
+            Some(pending) if pending.effective_time <= now => Allocation {
+                bips: pending.bips,
+                pending: None,
+            },
+            _ => self.clone(),
+        }
+    }
+
+    /// The bips of the vault's stake encumbered by the allocation:
+    /// a pending deallocation is still slashable, so it is encumbered until it is effective.
+    pub fn encumbered_bips(&self) -> u16 {
+        match &self.pending {
+            Some(pending) => self.bips.max(pending.bips),
+            None => self.bips,
+        }
+    }
+}
+
+/// (Vault, Service) to the latest allocation of the vault's stake to the service.
+pub(crate) const ALLOCATIONS: Map<(&Addr, &Service), Allocation> = Map::new("allocations");
+
+/// (Vault, Service, effective timestamp in seconds) to the allocated bips from that timestamp.
+/// The history of [`ALLOCATIONS`], to get the allocation at the incident timestamp of a slashing request.
+pub(crate) const ALLOCATION_CHECKPOINTS: Map<(&Addr, &Service, u64), u16> =
+    Map::new("allocation_checkpoints");
+
+/// Operator to the timestamp its stake allocations are effective from.
+/// Before the operator's first allocation is effective (or if the operator never allocated),
+/// every service of the operator can slash the full stake of its vaults.
+pub(crate) const ALLOCATION_OPERATORS: Map<&Operator, Timestamp> = Map::new("allocation_operators");
+
+/// Get the bips of the vault's stake allocated (slashable) to the service at the `timestamp`.
+/// [`MAX_ALLOCATION_BIPS`] if the operator's allocations are not effective at the `timestamp`.
+pub(crate) fn get_allocation_bips_at(
+    storage: &dyn Storage,
+    operator: &Operator,
+    vault: &Addr,
+    service: &Service,
+    timestamp: Timestamp,
+) -> StdResult<u16> {
+    match ALLOCATION_OPERATORS.may_load(storage, operator)? {
+        Some(effective_time) if effective_time <= timestamp => {}
+        _ => return Ok(MAX_ALLOCATION_BIPS),
+    }
+
+    let checkpoint = ALLOCATION_CHECKPOINTS
+        .prefix((vault, service))
+        .range(
+            storage,
+            None,
+            Some(Bound::inclusive(timestamp.seconds())),
+            cosmwasm_std::Order::Descending,
+        )
+        .next()
+        .transpose()?;
+    Ok(checkpoint.map(|(_, bips)| bips).unwrap_or_default())
+}
+
+/// Sum the bips of the vault's stake encumbered by all its allocations at `now`,
+/// excluding the allocation to `exclude_service`.
+pub(crate) fn sum_encumbered_bips(
+    storage: &dyn Storage,
+    vault: &Addr,
+    exclude_service: &Service,
+    now: Timestamp,
+) -> StdResult<u32> {
+    ALLOCATIONS
+        .prefix(vault)
+        .range(storage, None, None, cosmwasm_std::Order::Ascending)
+        .filter(|item| !matches!(item, Ok((service, _)) if service == exclude_service))
+        .map(|item| item.map(|(_, allocation)| allocation.at(now).encumbered_bips() as u32))
+        .sum()
+}
+
 /// Stores the expiry window for slashing requests in seconds. Used in `request_expiry`
 pub const SLASHING_REQUEST_EXPIRY_WINDOW: Uint64 = Uint64::new(7 * DAYS);
 
+/// The cooldown in seconds before a service can request slashing an operator again,
+/// after its previous slashing request ended without being locked (canceled or expired).
+/// Redemptions from the operator's vaults are frozen while a slash is pending,
+/// the cooldown bounds how long back-to-back requests can keep them frozen.
+pub const SLASHING_REQUEST_COOLDOWN: Uint64 = Uint64::new(DAYS);
+
+/// (Service, Operator) to the timestamp until which the service cannot request slashing the operator,
+/// set when a slashing request ends without being locked. See [`SLASHING_REQUEST_COOLDOWN`].
+pub(crate) const SLASHING_REQUEST_COOLDOWNS: Map<(&Service, &Operator), Timestamp> =
+    Map::new("slashing_request_cooldowns");
+
+/// Start the [`SLASHING_REQUEST_COOLDOWN`] of the service against the operator,
+/// from the time (`ended_at`) its slashing request ended without being locked.
+pub(crate) fn start_slashing_request_cooldown(
+    store: &mut dyn Storage,
+    service: &Service,
+    operator: &Operator,
+    ended_at: Timestamp,
+) -> StdResult<()> {
+    SLASHING_REQUEST_COOLDOWNS.save(
+        store,
+        (service, operator),
+        &ended_at.plus_seconds(SLASHING_REQUEST_COOLDOWN.u64()),
+    )
+}
+
 #[cw_serde]
 pub struct SlashingRequest {
     /// The core slashing request data including operator, bips, timestamp, and metadata.
@@ -78,6 +324,8 @@ pub enum SlashingRequestStatus {
     Finalized = 2,
     /// The slashing request has been canceled.
     Canceled = 3,
+    /// The slashing request has expired without being locked.
+    Expired = 4,
 }
 
 impl From<SlashingRequestStatus> for u8 {
@@ -95,6 +343,7 @@ impl TryFrom<u8> for SlashingRequestStatus {
             1 => Ok(SlashingRequestStatus::Locked),
             2 => Ok(SlashingRequestStatus::Finalized),
             3 => Ok(SlashingRequestStatus::Canceled),
+            4 => Ok(SlashingRequestStatus::Expired),
             _ => Err(StdError::generic_err("SlashingRequestStatus out of range")),
         }
     }
@@ -131,12 +380,81 @@ impl SlashingRequestIdHasher for SlashingRequestId {}
 pub(crate) const SLASHING_REQUEST_IDS: Map<(&Service, &Operator), SlashingRequestId> =
     Map::new("slashing_request_ids");
 
+/// Indexes of [`SLASHING_REQUESTS`] by service, operator and status,
+/// for listing slashing requests with [`crate::msg::QueryMsg::ListSlashingRequests`].
+pub(crate) struct SlashingRequestIndexes<'a> {
+    pub service: MultiIndex<'a, (Addr, u64), SlashingRequest, SlashingRequestId>,
+    pub operator: MultiIndex<'a, (Addr, u64), SlashingRequest, SlashingRequestId>,
+    pub service_operator: MultiIndex<'a, (Addr, Addr, u64), SlashingRequest, SlashingRequestId>,
+    pub status: MultiIndex<'a, (u8, u64), SlashingRequest, SlashingRequestId>,
+    pub request_time: MultiIndex<'a, u64, SlashingRequest, SlashingRequestId>,
+}
+
+impl IndexList<SlashingRequest> for SlashingRequestIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<SlashingRequest>> + '_> {
+        let v: Vec<&dyn Index<SlashingRequest>> = vec![
+            &self.service,
+            &self.operator,
+            &self.service_operator,
+            &self.status,
+            &self.request_time,
+        ];
+        Box::new(v.into_iter())
+    }
+}
+
 /// Stores the slashing request data for a given slashing request id.
 ///
 /// Slashing request won't be removed,
 /// hence this map will store all slashing requests.
-pub(crate) const SLASHING_REQUESTS: Map<&SlashingRequestId, SlashingRequest> =
-    Map::new("slashing_requests");
+/// Indexed by service, operator, (service, operator) and status, see [`SlashingRequestIndexes`].
+/// Every index is suffixed by the `request_time` (in seconds),
+/// to list the slashing requests in the order they were requested (and not by id).
+pub(crate) const SLASHING_REQUESTS: IndexedMap<
+    &SlashingRequestId,
+    SlashingRequest,
+    SlashingRequestIndexes,
+> = IndexedMap::new(
+    "slashing_requests",
+    SlashingRequestIndexes {
+        service: MultiIndex::new(
+            |_, req| (req.service.clone(), req.request_time.seconds()),
+            "slashing_requests",
+            "slashing_requests__service",
+        ),
+        operator: MultiIndex::new(
+            |_, req| {
+                (
+                    Addr::unchecked(&req.request.operator),
+                    req.request_time.seconds(),
+                )
+            },
+            "slashing_requests",
+            "slashing_requests__operator",
+        ),
+        service_operator: MultiIndex::new(
+            |_, req| {
+                (
+                    req.service.clone(),
+                    Addr::unchecked(&req.request.operator),
+                    req.request_time.seconds(),
+                )
+            },
+            "slashing_requests",
+            "slashing_requests__service_operator",
+        ),
+        status: MultiIndex::new(
+            |_, req| (req.status, req.request_time.seconds()),
+            "slashing_requests",
+            "slashing_requests__status",
+        ),
+        request_time: MultiIndex::new(
+            |_, req| req.request_time.seconds(),
+            "slashing_requests",
+            "slashing_requests__request_time",
+        ),
+    },
+);
 
 /// Will return Some() when:
 /// 1. Slash is sitting idle in a pending state and beyond the expiry date.
@@ -182,6 +500,10 @@ pub(crate) fn save_slashing_request(
     // save slashing request
     SLASHING_REQUESTS.save(store, &slashing_id, data)?;
 
+    if data.status == SlashingRequestStatus::Pending {
+        PENDING_SLASHES.save(store, (operator, service), &data.request_expiry)?;
+    }
+
     Ok(slashing_id)
 }
 
@@ -190,17 +512,44 @@ pub(crate) fn update_slashing_request_status(
     slashing_request_id: &SlashingRequestId,
     status: SlashingRequestStatus,
 ) -> StdResult<SlashingRequest> {
-    SLASHING_REQUESTS.update(
-        store,
-        slashing_request_id,
-        |slashing_request| match slashing_request {
-            Some(mut slashing_request) => {
-                slashing_request.status = status.into();
-                Ok(slashing_request)
+    let slashing_request =
+        SLASHING_REQUESTS.update(store, slashing_request_id, |slashing_request| {
+            match slashing_request {
+                Some(mut slashing_request) => {
+                    slashing_request.status = status.into();
+                    Ok(slashing_request)
+                }
+                None => Err(StdError::not_found("Slashing request id not found")),
             }
-            None => Err(StdError::not_found("Slashing request id not found")),
-        },
-    )
+        })?;
+
+    if status != SlashingRequestStatus::Pending {
+        let operator = Addr::unchecked(&slashing_request.request.operator);
+        PENDING_SLASHES.remove(store, (&operator, &slashing_request.service));
+    }
+
+    Ok(slashing_request)
+}
+
+/// (Operator, Service) to the expiry of the pending slashing request of the service against the operator.
+/// Set when the slashing is requested, removed when the slashing request is locked or canceled.
+/// While a slash is pending (until it expires), the vaults it would slash freeze redemptions,
+/// so the `total_assets` of the vaults can't shrink before the slash is locked.
+pub(crate) const PENDING_SLASHES: Map<(&Operator, &Service), Timestamp> =
+    Map::new("pending_slashes");
+
+/// The services with a slash (that has not expired at `now`) pending against the operator.
+pub(crate) fn list_pending_slashes(
+    store: &dyn Storage,
+    operator: &Operator,
+    now: Timestamp,
+) -> StdResult<Vec<Service>> {
+    PENDING_SLASHES
+        .prefix(operator)
+        .range(store, None, None, cosmwasm_std::Order::Ascending)
+        .filter(|item| !matches!(item, Ok((_, request_expiry)) if *request_expiry < now))
+        .map(|item| item.map(|(service, _)| service))
+        .collect()
 }
 
 pub(crate) fn remove_slashing_request_id(
@@ -219,6 +568,89 @@ pub(crate) fn remove_slashing_request_id(
 /// slash request entry.
 pub(crate) const SLASH_LOCKED: Map<(SlashingRequestId, &Addr), Uint128> = Map::new("slash_locked");
 
+/// Stores the assets under management of each vault at the moment the slashing request was locked.
+/// Mapped slash request id and vault address to the vault's asset and total assets,
+/// kept after the slashing request is finalized so past slashing can be audited.
+pub(crate) const SLASH_LOCKED_AUM: Map<(SlashingRequestId, &Addr), AssetAum> =
+    Map::new("slash_locked_aum");
+
+/// Stores the slashed collateral held (idle) by the router,
+/// after a slashing request is finalized without a destination.
+/// Mapped slash request id and asset id to the held collateral,
+/// until it is moved out with `SweepSlashed` or `RedistributeSlashed`.
+pub(crate) const IDLE_SLASHED: Map<(SlashingRequestId, &str), IdleSlashed> =
+    Map::new("idle_slashed");
+
+/// The slashing request id (all zeros) the idle slashed collateral stranded before the upgrade is held under.
+/// Slashing requests finalized without a destination before [`IDLE_SLASHED`] left their collateral
+/// in the router untracked, it cannot be attributed back to the slashing requests.
+pub const UNATTRIBUTED_IDLE_SLASHED_ID: [u8; 32] = [0; 32];
+
+/// Stores the total idle slashed collateral held by the router per asset id,
+/// the sum of [`IDLE_SLASHED`] over all slashing requests.
+pub(crate) const IDLE_SLASHED_TOTALS: Map<&str, Uint128> = Map::new("idle_slashed_totals");
+
+#[cw_serde]
+pub struct IdleSlashed {
+    pub asset_type: AssetType,
+    pub asset_reference: String,
+    pub amount: Uint128,
+}
+
+/// Add the slashed `amount` of the asset held by the router for the slashing request.
+pub(crate) fn add_idle_slashed(
+    store: &mut dyn Storage,
+    slashing_request_id: &SlashingRequestId,
+    asset_id: &str,
+    asset_type: AssetType,
+    asset_reference: String,
+    amount: Uint128,
+) -> StdResult<()> {
+    IDLE_SLASHED.update(
+        store,
+        (slashing_request_id.clone(), asset_id),
+        |held| -> StdResult<_> {
+            Ok(IdleSlashed {
+                asset_type,
+                asset_reference,
+                amount: held
+                    .map(|held| held.amount)
+                    .unwrap_or_default()
+                    .checked_add(amount)?,
+            })
+        },
+    )?;
+    IDLE_SLASHED_TOTALS.update(store, asset_id, |total| -> StdResult<_> {
+        Ok(total.unwrap_or_default().checked_add(amount)?)
+    })?;
+    Ok(())
+}
+
+/// Remove and return all the idle slashed collateral (per asset id) held for the slashing request.
+pub(crate) fn take_idle_slashed(
+    store: &mut dyn Storage,
+    slashing_request_id: &SlashingRequestId,
+) -> StdResult<Vec<(String, IdleSlashed)>> {
+    let held = IDLE_SLASHED
+        .prefix(slashing_request_id.clone())
+        .range(store, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (asset_id, idle_slashed) in &held {
+        IDLE_SLASHED.remove(store, (slashing_request_id.clone(), asset_id));
+        let total = IDLE_SLASHED_TOTALS
+            .load(store, asset_id)?
+            .checked_sub(idle_slashed.amount)?;
+        if total.is_zero() {
+            IDLE_SLASHED_TOTALS.remove(store, asset_id);
+        } else {
+            IDLE_SLASHED_TOTALS.save(store, asset_id, &total)?;
+        }
+    }
+
+    Ok(held)
+}
+
 pub(crate) fn remove_all_slash_locked_by_id(
     store: &mut dyn Storage,
     slashing_request_id: SlashingRequestId,
@@ -246,6 +678,8 @@ mod test {
             metadata: SlashingMetadata {
                 reason: "test".to_string(),
             },
+            category: None,
+            vaults: None,
         };
         let slashing_request = SlashingRequest {
             request: data.clone(),
@@ -290,6 +724,7 @@ mod test {
         assert_eq!(1u8, SlashingRequestStatus::Locked);
         assert_eq!(2u8, SlashingRequestStatus::Finalized);
         assert_eq!(3u8, SlashingRequestStatus::Canceled);
+        assert_eq!(4u8, SlashingRequestStatus::Expired);
 
         // Test inequality
         assert_ne!(SlashingRequestStatus::Pending, 1u8);
@@ -309,6 +744,8 @@ mod test {
             metadata: SlashingMetadata {
                 reason: "test".to_string(),
             },
+            category: None,
+            vaults: None,
         };
         let slashing_request = SlashingRequest {
             request: data.clone(),
@@ -339,6 +776,88 @@ mod test {
         assert!(SLASHING_REQUEST_IDS.is_empty(&deps.storage));
     }
 
+    #[test]
+    fn test_get_allocation_bips_at() {
+        let mut deps = mock_dependencies();
+        let now = mock_env().block.time;
+        let operator = deps.api.addr_make("operator");
+        let vault = deps.api.addr_make("vault");
+        let service = deps.api.addr_make("service");
+
+        // operator never allocated: full stake
+        let bips = get_allocation_bips_at(&deps.storage, &operator, &vault, &service, now).unwrap();
+        assert_eq!(bips, MAX_ALLOCATION_BIPS);
+
+        // allocated 5000 bips from now + 100, deallocated to 1000 bips from now + 1000
+        ALLOCATION_OPERATORS
+            .save(&mut deps.storage, &operator, &now.plus_seconds(100))
+            .unwrap();
+        ALLOCATION_CHECKPOINTS
+            .save(
+                &mut deps.storage,
+                (&vault, &service, now.plus_seconds(100).seconds()),
+                &5000,
+            )
+            .unwrap();
+        ALLOCATION_CHECKPOINTS
+            .save(
+                &mut deps.storage,
+                (&vault, &service, now.plus_seconds(1000).seconds()),
+                &1000,
+            )
+            .unwrap();
+
+        let at = |seconds: u64| {
+            get_allocation_bips_at(
+                &deps.storage,
+                &operator,
+                &vault,
+                &service,
+                now.plus_seconds(seconds),
+            )
+            .unwrap()
+        };
+        assert_eq!(at(99), MAX_ALLOCATION_BIPS);
+        assert_eq!(at(100), 5000);
+        assert_eq!(at(999), 5000);
+        assert_eq!(at(1000), 1000);
+        assert_eq!(at(5000), 1000);
+
+        // other services (or vaults) of the operator have nothing allocated
+        let other = deps.api.addr_make("other");
+        let bips = get_allocation_bips_at(
+            &deps.storage,
+            &operator,
+            &vault,
+            &other,
+            now.plus_seconds(100),
+        )
+        .unwrap();
+        assert_eq!(bips, 0);
+    }
+
+    #[test]
+    fn test_allocation_at() {
+        let now = mock_env().block.time;
+        let allocation = Allocation {
+            bips: 3000,
+            pending: Some(PendingAllocation {
+                bips: 1000,
+                effective_time: now.plus_seconds(10),
+            }),
+        };
+        assert_eq!(allocation.at(now), allocation);
+        assert_eq!(allocation.at(now).encumbered_bips(), 3000);
+        assert_eq!(
+            allocation.at(now.plus_seconds(10)),
+            Allocation {
+                bips: 1000,
+                pending: None,
+            }
+        );
+        assert_eq!(allocation.at(now.plus_seconds(10)).encumbered_bips(), 1000);
+    }
+
     #[test]
     fn test_remove_all_slash_locked_by_id() {
         let mut deps = mock_dependencies();
@@ -5,25 +5,34 @@ use bvs_library::{
     ownership::OwnershipError,
     testing::{Cw20TokenContract, TestingContract},
 };
+use bvs_oracle::msg::{PriceFeed, PriceSource};
+use bvs_oracle::testing::OracleContract;
 use bvs_pauser::testing::PauserContract;
 use bvs_registry::msg::Metadata;
 use bvs_registry::testing::RegistryContract;
-use bvs_registry::SlashingParameters;
+use bvs_registry::{SlashingParameters, SlashingRecipient, WeightedDestination};
 use bvs_vault_bank::testing::VaultBankContract;
 use bvs_vault_base::msg::RecipientAmount;
 use bvs_vault_cw20::testing::VaultCw20Contract;
 use bvs_vault_router::msg::{
-    RequestSlashingPayload, RequestSlashingResponse, SlashingMetadata, SlashingRequestIdResponse,
-    SlashingRequestResponse, Vault,
+    AllocationResponse, AssetAum, IdleSlashedBalance, IdleSlashedResponse, OperatorAumResponse,
+    RequestSlashingPayload, RequestSlashingResponse, SlashingLockedResponse,
+    SlashingLockedResponseItem, SlashingMetadata, SlashingRequestIdResponse, SlashingRequestItem,
+    SlashingRequestListResponse, SlashingRequestResponse, Vault, VaultAllocation,
+    VaultAllocationsResponse, VaultAumResponse,
+};
+use bvs_vault_router::state::{
+    Allocation, PendingAllocation, SlashingRequest, SlashingRequestStatus,
+    DEFAULT_MAX_VAULTS_PER_OPERATOR, LOCK_SLASHING_GAS_FIXED, LOCK_SLASHING_GAS_LIMIT,
+    LOCK_SLASHING_GAS_PER_VAULT, MAX_MAX_VAULTS_PER_OPERATOR,
 };
-use bvs_vault_router::state::{SlashingRequest, SlashingRequestStatus};
 use bvs_vault_router::{
     msg::{ExecuteMsg, QueryMsg, VaultListResponse},
     testing::VaultRouterContract,
     ContractError,
 };
 use cosmwasm_std::{coin, coins, BalanceResponse, BankQuery, Decimal, QueryRequest, Uint128};
-use cosmwasm_std::{testing::mock_env, Event, HexBinary, Uint64};
+use cosmwasm_std::{testing::mock_env, Addr, Event, HexBinary, Uint64};
 use cw_multi_test::{App, Executor};
 use cw_utils::Threshold;
 
@@ -317,6 +326,247 @@ fn set_withdrawal_lock_period() {
     assert_eq!(result, withdrawal_lock_period2);
 }
 
+#[test]
+fn operator_withdrawal_lock_period() {
+    let (mut app, tc) = TestContracts::init();
+    let owner = app.api().addr_make("owner");
+    let operator = app.api().addr_make("operator");
+    let service = app.api().addr_make("service");
+
+    tc.registry
+        .execute(
+            &mut app,
+            &operator,
+            &bvs_registry::msg::ExecuteMsg::RegisterAsOperator {
+                metadata: Metadata {
+                    name: Some("operator".to_string()),
+                    uri: None,
+                },
+            },
+        )
+        .unwrap();
+
+    // owner sets the minimum withdrawal lock period
+    {
+        let msg = &ExecuteMsg::SetMinWithdrawalLockPeriod(Uint64::new(DAYS));
+        tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+
+        let msg = QueryMsg::MinWithdrawalLockPeriod {};
+        let result: Uint64 = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(result, Uint64::new(DAYS));
+    }
+
+    // operator cannot override below the minimum
+    {
+        let msg = &ExecuteMsg::SetOperatorWithdrawalLockPeriod(Some(Uint64::new(DAYS - 1)));
+        let err = tc
+            .vault_router
+            .execute(&mut app, &operator, msg)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::VaultError {
+                msg: "Withdrawal lock period must not be below the minimum".to_string()
+            }
+            .to_string()
+        );
+    }
+
+    // operator overrides its withdrawal lock period to 14 days
+    {
+        let msg = &ExecuteMsg::SetOperatorWithdrawalLockPeriod(Some(Uint64::new(14 * DAYS)));
+        let response = tc.vault_router.execute(&mut app, &operator, msg).unwrap();
+        assert_eq!(
+            response.events,
+            vec![
+                Event::new("execute")
+                    .add_attribute("_contract_address", tc.vault_router.addr.as_str()),
+                Event::new("wasm-SetOperatorWithdrawalLockPeriod")
+                    .add_attribute("_contract_address", tc.vault_router.addr.as_str())
+                    .add_attribute("operator", operator.to_string())
+                    .add_attribute("prev_withdrawal_lock_period", (7 * DAYS).to_string())
+                    .add_attribute("new_withdrawal_lock_period", (14 * DAYS).to_string())
+                    .add_attribute(
+                        "effective_time",
+                        app.block_info().time.seconds().to_string()
+                    ),
+            ]
+        );
+
+        let msg = QueryMsg::OperatorWithdrawalLockPeriod {
+            operator: operator.to_string(),
+        };
+        let result: Uint64 = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(result, Uint64::new(14 * DAYS));
+
+        let msg = QueryMsg::WithdrawalLockPeriod {};
+        let result: Uint64 = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(result, Uint64::new(7 * DAYS));
+    }
+
+    // the slashable look-back window is the operator withdrawal lock period at the incident:
+    // 8 days ago (after the override) is within the window (fails later on the registration status)
+    {
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(14 * DAYS);
+            block.height += 1;
+        });
+
+        let msg = &ExecuteMsg::RequestSlashing(RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time.minus_seconds(8 * DAYS),
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
+        });
+        let err = tc
+            .vault_router
+            .execute(&mut app, &service, msg)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Service and Operator must be active at the specified timestamp".to_string()
+            }
+            .to_string()
+        );
+    }
+
+    // more than 14 days ago (before the override, under the 7 days default) is outside the window
+    {
+        let msg = &ExecuteMsg::RequestSlashing(RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app
+                .block_info()
+                .time
+                .minus_seconds(14 * DAYS)
+                .minus_seconds(1),
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
+        });
+        let err = tc
+            .vault_router
+            .execute(&mut app, &service, msg)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Slash timestamp must be within the allowable slash period".to_string()
+            }
+            .to_string()
+        );
+    }
+
+    // lowering the withdrawal lock period is only effective after the current 14 days,
+    // so the incident 8 days ago remains slashable
+    {
+        let msg = &ExecuteMsg::SetOperatorWithdrawalLockPeriod(Some(Uint64::new(DAYS)));
+        tc.vault_router.execute(&mut app, &operator, msg).unwrap();
+
+        let msg = QueryMsg::OperatorWithdrawalLockPeriod {
+            operator: operator.to_string(),
+        };
+        let result: Uint64 = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(result, Uint64::new(14 * DAYS));
+
+        let msg = &ExecuteMsg::RequestSlashing(RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time.minus_seconds(8 * DAYS),
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
+        });
+        let err = tc
+            .vault_router
+            .execute(&mut app, &service, msg)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Service and Operator must be active at the specified timestamp".to_string()
+            }
+            .to_string()
+        );
+    }
+
+    // once effective, the look-back window is the withdrawal lock period at the incident:
+    // 2 days ago (under the 14 days period) is within the window
+    {
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(14 * DAYS);
+            block.height += 1;
+        });
+
+        let msg = QueryMsg::OperatorWithdrawalLockPeriod {
+            operator: operator.to_string(),
+        };
+        let result: Uint64 = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(result, Uint64::new(DAYS));
+
+        let msg = &ExecuteMsg::RequestSlashing(RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time.minus_seconds(2 * DAYS),
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
+        });
+        let err = tc
+            .vault_router
+            .execute(&mut app, &service, msg)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Service and Operator must be active at the specified timestamp".to_string()
+            }
+            .to_string()
+        );
+    }
+
+    // 2 days ago (under the 1 day period) is outside the window
+    {
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(2 * DAYS);
+            block.height += 1;
+        });
+
+        let msg = &ExecuteMsg::RequestSlashing(RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time.minus_seconds(2 * DAYS),
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
+        });
+        let err = tc
+            .vault_router
+            .execute(&mut app, &service, msg)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Slash timestamp must be within the allowable slash period".to_string()
+            }
+            .to_string()
+        );
+    }
+}
+
 #[test]
 fn transfer_ownership_successfully() {
     let (mut app, tc) = TestContracts::init();
@@ -407,6 +657,8 @@ fn request_slashing_successful() {
                 destination: Some(service.clone()),
                 max_slashing_bips: 5000,
                 resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
             },
         };
         tc.registry
@@ -430,6 +682,7 @@ fn request_slashing_successful() {
 
         let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
             service: service.to_string(),
+            operator: None,
         };
         tc.registry
             .execute(&mut app, &operator, msg)
@@ -449,6 +702,8 @@ fn request_slashing_successful() {
         metadata: SlashingMetadata {
             reason: "test".to_string(),
         },
+        category: None,
+        vaults: None,
     };
 
     let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
@@ -514,11 +769,8 @@ fn request_slashing_successful() {
     );
 }
 
-/// This test is
-/// to check all negative cases for slashing request and finally a success case in the end.
-/// To find a simpler success case, look at the `request_slashing_successful` test.
 #[test]
-fn request_slashing_lifecycle() {
+fn request_slashing_exiting_operator() {
     let (mut app, tc) = TestContracts::init();
 
     let operator = app.api().addr_make("operator");
@@ -553,140 +805,148 @@ fn request_slashing_lifecycle() {
             .expect("failed to register service");
     }
 
+    // service enable slashing
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::EnableSlashing {
+            slashing_parameters: SlashingParameters {
+                destination: Some(service.clone()),
+                max_slashing_bips: 5000,
+                resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        };
+        tc.registry
+            .execute(&mut app, &service, msg)
+            .expect("failed to enable slashing");
+    }
+
     app.update_block(|block| {
         block.height += 1;
         block.time = block.time.plus_seconds(10);
     });
 
-    // service request slashing with reason string over limit
+    // register operator to service for active status
     {
-        let slashing_request_payload = RequestSlashingPayload {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterOperatorToService {
             operator: operator.to_string(),
-            bips: 100,
-            timestamp: app.block_info().time,
-            metadata: SlashingMetadata {
-                reason: "this is a reason to slash the operator that is too long to be allowed in the \
-                metadata of this test, and we need to make it even longer to reach exactly 251 bytes total. \
-                The purpose of this test is to verify that the system properly rejects long text. ".to_string(),
-            },
         };
-
-        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
-        let err = tc
-            .vault_router
+        tc.registry
             .execute(&mut app, &service, msg)
-            .unwrap_err();
-        assert_eq!(
-            err.root_cause().to_string(),
-            ContractError::InvalidSlashingRequest {
-                msg: "Reason exceeds maximum allowed length".to_string()
-            }
-            .to_string()
-        )
+            .expect("failed to register operator to service");
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
+            service: service.to_string(),
+            operator: None,
+        };
+        tc.registry
+            .execute(&mut app, &operator, msg)
+            .expect("failed to register service to operator");
     }
 
-    // service request slashing with date too far in the past (over max_slashable_delay)
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    // operator deregisters right before the service requests slashing
     {
-        let slashing_request_payload = RequestSlashingPayload {
-            operator: operator.to_string(),
-            bips: 100,
-            timestamp: app
-                .block_info()
-                .time
-                .minus_seconds(7 * DAYS) // default is 7 days
-                .minus_seconds(1),
-            metadata: SlashingMetadata {
-                reason: "test".to_string(),
-            },
+        let msg = &bvs_registry::msg::ExecuteMsg::DeregisterServiceFromOperator {
+            service: service.to_string(),
+            operator: None,
         };
-
-        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
-        let err = tc
-            .vault_router
-            .execute(&mut app, &service, msg)
-            .unwrap_err();
-        assert_eq!(
-            err.root_cause().to_string(),
-            ContractError::InvalidSlashingRequest {
-                msg: "Slash timestamp must be within the allowable slash period".to_string()
-            }
-            .to_string()
-        )
+        tc.registry
+            .execute(&mut app, &operator, msg)
+            .expect("failed to deregister service from operator");
     }
 
-    // service request slashing with date in the future
-    {
-        let slashing_request_payload = RequestSlashingPayload {
-            operator: operator.to_string(),
-            bips: 100,
-            timestamp: app.block_info().time.plus_seconds(1),
-            metadata: SlashingMetadata {
-                reason: "test".to_string(),
-            },
-        };
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
 
-        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
-        let err = tc
-            .vault_router
-            .execute(&mut app, &service, msg)
-            .unwrap_err();
-        assert_eq!(
-            err.root_cause().to_string(),
-            ContractError::InvalidSlashingRequest {
-                msg: "Slash timestamp must be within the allowable slash period".to_string()
-            }
-            .to_string()
-        )
-    }
+    // exiting operator is still slashable for incidents during the exit
+    let slashing_request_payload = RequestSlashingPayload {
+        operator: operator.to_string(),
+        bips: 100,
+        timestamp: app.block_info().time,
+        metadata: SlashingMetadata {
+            reason: "test".to_string(),
+        },
+        category: None,
+        vaults: None,
+    };
 
-    // service request slashing when operator and service not in ACTIVE status
+    let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload);
+    tc.vault_router
+        .execute(&mut app, &service, msg)
+        .expect("failed to request slashing for exiting operator");
+}
+
+#[test]
+fn request_slashing_with_category() {
+    let (mut app, tc) = TestContracts::init();
+
+    let operator = app.api().addr_make("operator");
+    let service = app.api().addr_make("service");
+
+    // register operator + service
     {
-        // register Service to Operator for OperatorRegistered status
-        let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
-            service: service.to_string(),
-        };
         tc.registry
-            .execute(&mut app, &operator, msg)
-            .expect("failed to register service to operator");
+            .execute(
+                &mut app,
+                &operator,
+                &bvs_registry::msg::ExecuteMsg::RegisterAsOperator {
+                    metadata: Metadata {
+                        name: Some("operator".to_string()),
+                        uri: None,
+                    },
+                },
+            )
+            .expect("failed to register operator");
 
-        app.update_block(|block| {
-            block.height += 1;
-            block.time = block.time.plus_seconds(10);
-        });
+        tc.registry
+            .execute(
+                &mut app,
+                &service,
+                &bvs_registry::msg::ExecuteMsg::RegisterAsService {
+                    metadata: Metadata {
+                        name: Some("service".to_string()),
+                        uri: None,
+                    },
+                },
+            )
+            .expect("failed to register service");
+    }
 
-        // service request slashing
-        let slashing_request_payload = RequestSlashingPayload {
-            operator: operator.to_string(),
-            bips: 9999,
-            timestamp: app.block_info().time,
-            metadata: SlashingMetadata {
-                reason: "test".to_string(),
+    // service enable default slashing and an equivocation category
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::EnableSlashing {
+            slashing_parameters: SlashingParameters {
+                destination: Some(service.clone()),
+                max_slashing_bips: 100,
+                resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
             },
         };
-
-        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
-        let err = tc
-            .vault_router
+        tc.registry
             .execute(&mut app, &service, msg)
-            .unwrap_err();
-        assert_eq!(
-            err.root_cause().to_string(),
-            ContractError::InvalidSlashingRequest {
-                msg: "Service and Operator must be active at the specified timestamp".to_string()
-            }
-            .to_string()
-        )
-    }
+            .expect("failed to enable slashing");
 
-    // update registration status to ACTIVE
-    {
-        // register operator to service for ACTIVE status
-        let msg = &bvs_registry::msg::ExecuteMsg::RegisterOperatorToService {
-            operator: operator.to_string(),
+        let msg = &bvs_registry::msg::ExecuteMsg::SetSlashingCategory {
+            category: "equivocation".to_string(),
+            slashing_parameters: SlashingParameters {
+                destination: Some(service.clone()),
+                max_slashing_bips: 5000,
+                resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
         };
         tc.registry
             .execute(&mut app, &service, msg)
-            .expect("failed to register operator to service");
+            .expect("failed to set slashing category");
     }
 
     app.update_block(|block| {
@@ -694,43 +954,22 @@ fn request_slashing_lifecycle() {
         block.time = block.time.plus_seconds(10);
     });
 
-    // service request slashing before enabling slashing
+    // register operator to service for active status
     {
-        let slashing_request_payload = RequestSlashingPayload {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterOperatorToService {
             operator: operator.to_string(),
-            bips: 100,
-            timestamp: app.block_info().time,
-            metadata: SlashingMetadata {
-                reason: "test".to_string(),
-            },
         };
-
-        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
-        let err = tc
-            .vault_router
+        tc.registry
             .execute(&mut app, &service, msg)
-            .unwrap_err();
-        assert_eq!(
-            err.root_cause().to_string(),
-            ContractError::InvalidSlashingRequest {
-                msg: "Service must have slashing enabled at the specified timestamp".to_string()
-            }
-            .to_string()
-        )
-    }
+            .expect("failed to register operator to service");
 
-    // service enable slashing
-    {
-        let msg = &bvs_registry::msg::ExecuteMsg::EnableSlashing {
-            slashing_parameters: SlashingParameters {
-                destination: Some(service.clone()),
-                max_slashing_bips: 5000,
-                resolution_window: 100,
-            },
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
+            service: service.to_string(),
+            operator: None,
         };
         tc.registry
-            .execute(&mut app, &service, msg)
-            .expect("failed to enable slashing");
+            .execute(&mut app, &operator, msg)
+            .expect("failed to register service to operator");
     }
 
     app.update_block(|block| {
@@ -738,17 +977,35 @@ fn request_slashing_lifecycle() {
         block.time = block.time.plus_seconds(10);
     });
 
-    // service request slashing with bips over max_slashing_bips
+    // operator only opts in to the default slashing
     {
-        let slashing_request_payload = RequestSlashingPayload {
-            operator: operator.to_string(),
-            bips: 9999,
-            timestamp: app.block_info().time,
-            metadata: SlashingMetadata {
-                reason: "test".to_string(),
-            },
+        let msg = &bvs_registry::msg::ExecuteMsg::OperatorOptInToSlashing {
+            service: service.to_string(),
+            operator: None,
         };
+        tc.registry
+            .execute(&mut app, &operator, msg)
+            .expect("failed to opt in to slashing");
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    let mut slashing_request_payload = RequestSlashingPayload {
+        operator: operator.to_string(),
+        bips: 1000,
+        timestamp: app.block_info().time,
+        metadata: SlashingMetadata {
+            reason: "equivocation".to_string(),
+        },
+        category: None,
+        vaults: None,
+    };
 
+    // default slashing parameters max_slashing_bips is exceeded
+    {
         let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
         let err = tc
             .vault_router
@@ -760,20 +1017,12 @@ fn request_slashing_lifecycle() {
                 msg: "Slashing bips exceeds the maximum allowed by service".to_string()
             }
             .to_string()
-        )
+        );
     }
 
-    // service request slashing with bips == 0
+    // category not enabled
     {
-        let slashing_request_payload = RequestSlashingPayload {
-            operator: operator.to_string(),
-            bips: 0,
-            timestamp: app.block_info().time,
-            metadata: SlashingMetadata {
-                reason: "test".to_string(),
-            },
-        };
-
+        slashing_request_payload.category = Some("liveness".to_string());
         let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
         let err = tc
             .vault_router
@@ -782,23 +1031,16 @@ fn request_slashing_lifecycle() {
         assert_eq!(
             err.root_cause().to_string(),
             ContractError::InvalidSlashingRequest {
-                msg: "Slashing bips must be greater than zero".to_string()
+                msg: "Service must have the slashing category enabled at the specified timestamp"
+                    .to_string()
             }
             .to_string()
-        )
+        );
     }
 
-    // service request slashing before operator opt-in to slashing
+    // operator not opted in to the category
     {
-        let slashing_request_payload = RequestSlashingPayload {
-            operator: operator.to_string(),
-            bips: 100,
-            timestamp: app.block_info().time,
-            metadata: SlashingMetadata {
-                reason: "test".to_string(),
-            },
-        };
-
+        slashing_request_payload.category = Some("equivocation".to_string());
         let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
         let err = tc
             .vault_router
@@ -810,17 +1052,19 @@ fn request_slashing_lifecycle() {
                 msg: "Operator must be opted-in to slashing at the specified timestamp".to_string()
             }
             .to_string()
-        )
+        );
     }
 
-    // operator opt-in to slashing
+    // operator opts in to the category
     {
-        let msg = &bvs_registry::msg::ExecuteMsg::OperatorOptInToSlashing {
+        let msg = &bvs_registry::msg::ExecuteMsg::OperatorOptInToSlashingCategory {
             service: service.to_string(),
+            category: "equivocation".to_string(),
+            operator: None,
         };
         tc.registry
             .execute(&mut app, &operator, msg)
-            .expect("failed to opt-in to slashing");
+            .expect("failed to opt in to slashing category");
     }
 
     app.update_block(|block| {
@@ -828,119 +1072,43 @@ fn request_slashing_lifecycle() {
         block.time = block.time.plus_seconds(10);
     });
 
-    // service request slashing for older timestamp before operator opted-in
+    // category max_slashing_bips is enforced instead of the default
     {
-        let slashing_request_payload = RequestSlashingPayload {
-            operator: operator.to_string(),
-            bips: 100,
-            timestamp: app.block_info().time.minus_seconds(10),
-            metadata: SlashingMetadata {
-                reason: "test".to_string(),
-            },
-        };
-
+        slashing_request_payload.timestamp = app.block_info().time;
         let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
-        let err = tc
-            .vault_router
+        tc.vault_router
             .execute(&mut app, &service, msg)
-            .unwrap_err();
-        assert_eq!(
-            err.root_cause().to_string(),
-            ContractError::InvalidSlashingRequest {
-                msg: "Operator must be opted-in to slashing at the specified timestamp".to_string()
-            }
-            .to_string()
-        )
+            .expect("failed to request slashing with category");
     }
 
-    // service successfully request slashing
-    {
-        let slashing_request_payload = RequestSlashingPayload {
-            operator: operator.to_string(),
-            bips: 100,
-            timestamp: app.block_info().time,
-            metadata: SlashingMetadata {
-                reason: "test".to_string(),
+    let slashing_request_id: SlashingRequestIdResponse = tc
+        .vault_router
+        .query(
+            &app,
+            &QueryMsg::SlashingRequestId {
+                service: service.to_string(),
+                operator: operator.to_string(),
             },
-        };
-
-        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
-        tc.vault_router
-            .execute(&mut app, &service, msg)
-            .expect("failed to request slashing");
-    }
-
-    app.update_block(|block| {
-        block.height += 1;
-        block.time = block.time.plus_seconds(10);
-    });
-
-    // service request a second slashing to the same operator while there is an pending request
-    {
-        let slashing_request_payload = RequestSlashingPayload {
-            operator: operator.to_string(),
-            bips: 100,
-            timestamp: app.block_info().time,
-            metadata: SlashingMetadata {
-                reason: "test".to_string(),
-            },
-        };
-
-        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
-        let err = tc
-            .vault_router
-            .execute(&mut app, &service, msg)
-            .unwrap_err();
-        assert_eq!(
-            err.root_cause().to_string(),
-            ContractError::InvalidSlashingRequest {
-                msg: "Cannot process new request while previous slashing request is pending"
-                    .to_string()
-            }
-            .to_string()
-        )
-    }
-
-    // move blockchain after slashing request expiry
-    app.update_block(|block| {
-        block.height += 20;
-        block.time = block.time.plus_seconds(100).plus_seconds(7 * DAYS); // resolution_window + 7 days
-    });
-
-    // service successfully request slashing after slashing request expiry
-    {
-        let slashing_request_payload = RequestSlashingPayload {
-            operator: operator.to_string(),
-            bips: 100,
-            timestamp: app.block_info().time,
-            metadata: SlashingMetadata {
-                reason: "test2".to_string(),
-            },
-        };
-
-        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
-        let response = tc.vault_router.execute(&mut app, &service, msg).unwrap();
-        assert_eq!(
-            response.events,
-            vec![
-                Event::new("execute")
-                    .add_attribute("_contract_address", tc.vault_router.addr.as_str()),
-                Event::new("wasm-RequestSlashing")
-                    .add_attribute("_contract_address", tc.vault_router.addr.as_str())
-                    .add_attribute("service", service.to_string())
-                    .add_attribute("operator", operator.to_string())
-                    .add_attribute(
-                        "slashing_request_id",
-                        "5ba8f0118fefaed4b4ad086e34cd2c85ddc17a1d2c17c5f66e129abf2c3ac4ea"
-                    )
-                    .add_attribute("reason", "test2"),
-            ]
-        );
-    }
-}
+        )
+        .unwrap();
+    let slashing_request: SlashingRequestResponse = tc
+        .vault_router
+        .query(
+            &app,
+            &QueryMsg::SlashingRequest(slashing_request_id.0.unwrap()),
+        )
+        .unwrap();
+    assert_eq!(
+        slashing_request.0.unwrap().request.category,
+        Some("equivocation".to_string())
+    );
+}
 
+/// This test is
+/// to check all negative cases for slashing request and finally a success case in the end.
+/// To find a simpler success case, look at the `request_slashing_successful` test.
 #[test]
-fn test_slash_locking() {
+fn request_slashing_lifecycle() {
     let (mut app, tc) = TestContracts::init();
 
     let operator = app.api().addr_make("operator");
@@ -973,105 +1141,150 @@ fn test_slash_locking() {
                 },
             )
             .expect("failed to register service");
+    }
 
-        let owner = app.api().addr_make("owner");
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
 
-        let msg = &ExecuteMsg::SetVault {
-            vault: tc.bank_vault.addr().to_string(),
-            whitelisted: true,
+    // service request slashing with reason string over limit
+    {
+        let slashing_request_payload = RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time,
+            metadata: SlashingMetadata {
+                reason: "this is a reason to slash the operator that is too long to be allowed in the \
+                metadata of this test, and we need to make it even longer to reach exactly 251 bytes total. \
+                The purpose of this test is to verify that the system properly rejects long text. ".to_string(),
+            },
+            category: None, vaults: None,
         };
 
-        tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
+        let err = tc
+            .vault_router
+            .execute(&mut app, &service, msg)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Reason exceeds maximum allowed length".to_string()
+            }
+            .to_string()
+        )
+    }
 
-        let msg = &ExecuteMsg::SetVault {
-            vault: tc.cw20_vault.addr().to_string(),
-            whitelisted: true,
+    // service request slashing with date too far in the past (over max_slashable_delay)
+    {
+        let slashing_request_payload = RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app
+                .block_info()
+                .time
+                .minus_seconds(7 * DAYS) // default is 7 days
+                .minus_seconds(1),
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
         };
 
-        tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
+        let err = tc
+            .vault_router
+            .execute(&mut app, &service, msg)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Slash timestamp must be within the allowable slash period".to_string()
+            }
+            .to_string()
+        )
     }
 
-    // stake funds
+    // service request slashing with date in the future
     {
-        let owner = app.api().addr_make("owner");
-        let denom = "denom";
+        let slashing_request_payload = RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time.plus_seconds(1),
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
+        };
 
-        let staker = app.api().addr_make("staker");
-        let msg = bvs_vault_cw20::msg::ExecuteMsg::DepositFor(RecipientAmount {
-            recipient: staker.clone(),
-            amount: Uint128::new(300_u128),
-        });
-        tc.cw20
-            .increase_allowance(&mut app, &staker, tc.cw20_vault.addr(), 300_u128);
-        tc.cw20.fund(&mut app, &staker, 300_u128);
-        tc.cw20_vault.execute(&mut app, &staker, &msg).unwrap();
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
+        let err = tc
+            .vault_router
+            .execute(&mut app, &service, msg)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Slash timestamp must be within the allowable slash period".to_string()
+            }
+            .to_string()
+        )
+    }
 
-        // Fund the staker with some initial tokens
-        app.send_tokens(owner.clone(), staker.clone(), &coins(1_000_000_000, denom))
-            .unwrap();
+    // service request slashing when operator and service not in ACTIVE status
+    {
+        // register Service to Operator for OperatorRegistered status
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
+            service: service.to_string(),
+            operator: None,
+        };
+        tc.registry
+            .execute(&mut app, &operator, msg)
+            .expect("failed to register service to operator");
 
-        // Deposit 115_687_654 tokens from staker to Vault
-        let msg = bvs_vault_bank::msg::ExecuteMsg::DepositFor(RecipientAmount {
-            recipient: staker.clone(),
-            amount: Uint128::new(200),
+        app.update_block(|block| {
+            block.height += 1;
+            block.time = block.time.plus_seconds(10);
         });
-        tc.bank_vault
-            .execute_with_funds(&mut app, &staker, &msg, coins(200, denom))
-            .unwrap();
-
-        let bank_vault_info = tc
-            .bank_vault
-            .query::<bvs_vault_base::msg::VaultInfoResponse>(
-                &mut app,
-                &bvs_vault_bank::msg::QueryMsg::VaultInfo {},
-            )
-            .unwrap();
-        let cw20_vault_info = tc
-            .cw20_vault
-            .query::<bvs_vault_base::msg::VaultInfoResponse>(
-                &mut app,
-                &bvs_vault_bank::msg::QueryMsg::VaultInfo {},
-            )
-            .unwrap();
-
-        assert_eq!(bank_vault_info.total_assets, Uint128::new(200));
-        assert_eq!(cw20_vault_info.total_assets, Uint128::new(300));
-    }
 
-    // service enable slashing
-    {
-        let msg = &bvs_registry::msg::ExecuteMsg::EnableSlashing {
-            slashing_parameters: SlashingParameters {
-                destination: Some(service.clone()),
-                max_slashing_bips: 5000,
-                resolution_window: 100,
+        // service request slashing
+        let slashing_request_payload = RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 9999,
+            timestamp: app.block_info().time,
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
             },
+            category: None,
+            vaults: None,
         };
-        tc.registry
+
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
+        let err = tc
+            .vault_router
             .execute(&mut app, &service, msg)
-            .expect("failed to enable slashing");
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Service and Operator must be active at the specified timestamp".to_string()
+            }
+            .to_string()
+        )
     }
 
-    app.update_block(|block| {
-        block.height += 1;
-        block.time = block.time.plus_seconds(10);
-    });
-
-    // register operator to service for active status
+    // update registration status to ACTIVE
     {
+        // register operator to service for ACTIVE status
         let msg = &bvs_registry::msg::ExecuteMsg::RegisterOperatorToService {
             operator: operator.to_string(),
         };
         tc.registry
             .execute(&mut app, &service, msg)
             .expect("failed to register operator to service");
-
-        let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
-            service: service.to_string(),
-        };
-        tc.registry
-            .execute(&mut app, &operator, msg)
-            .expect("failed to register service to operator");
     }
 
     app.update_block(|block| {
@@ -1079,267 +1292,2509 @@ fn test_slash_locking() {
         block.time = block.time.plus_seconds(10);
     });
 
-    // Pending slashing requests that are idle beyond expiry
-    // should not block new slashing requests
-    // and will be transitioned implicitly to cancel status
-    let active_slashing_id;
+    // service request slashing before enabling slashing
     {
-        let slashing_request_payload_1 = RequestSlashingPayload {
+        let slashing_request_payload = RequestSlashingPayload {
             operator: operator.to_string(),
             bips: 100,
             timestamp: app.block_info().time,
             metadata: SlashingMetadata {
                 reason: "test".to_string(),
             },
+            category: None,
+            vaults: None,
         };
-        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload_1.clone());
-        tc.vault_router
-            .execute(&mut app, &service, msg)
-            .expect("failed to request slashing");
 
-        let slash_req_1_id = tc
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
+        let err = tc
             .vault_router
-            .query::<SlashingRequestIdResponse>(
-                &mut app,
-                &QueryMsg::SlashingRequestId {
-                    service: service.to_string(),
-                    operator: operator.to_string(),
-                },
-            )
-            .unwrap();
+            .execute(&mut app, &service, msg)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Service must have slashing enabled at the specified timestamp".to_string()
+            }
+            .to_string()
+        )
+    }
 
-        app.update_block(|block| {
-            block.height += 60;
-            block.time = block.time.plus_seconds(100).plus_seconds(7 * DAYS);
-        });
+    // service enable slashing
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::EnableSlashing {
+            slashing_parameters: SlashingParameters {
+                destination: Some(service.clone()),
+                max_slashing_bips: 5000,
+                resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        };
+        tc.registry
+            .execute(&mut app, &service, msg)
+            .expect("failed to enable slashing");
+    }
 
-        let slashing_request_payload_2 = RequestSlashingPayload {
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    // service request slashing with bips over max_slashing_bips
+    {
+        let slashing_request_payload = RequestSlashingPayload {
             operator: operator.to_string(),
-            bips: 100,
+            bips: 9999,
             timestamp: app.block_info().time,
             metadata: SlashingMetadata {
                 reason: "test".to_string(),
             },
+            category: None,
+            vaults: None,
         };
-        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload_2.clone());
-        tc.vault_router
+
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
+        let err = tc
+            .vault_router
             .execute(&mut app, &service, msg)
-            .expect("failed to request slashing");
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Slashing bips exceeds the maximum allowed by service".to_string()
+            }
+            .to_string()
+        )
+    }
 
-        app.update_block(|block| {
-            block.height += 1;
-            block.time = block.time.plus_seconds(10);
-        });
+    // service request slashing with bips == 0
+    {
+        let slashing_request_payload = RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 0,
+            timestamp: app.block_info().time,
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
+        };
 
-        let slash_request_1_status = tc
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
+        let err = tc
             .vault_router
-            .query::<SlashingRequestResponse>(
-                &mut app,
-                &QueryMsg::SlashingRequest(slash_req_1_id.0.unwrap()),
-            )
-            .unwrap();
-
-        let slash_request_1_status = slash_request_1_status.0.unwrap().status;
+            .execute(&mut app, &service, msg)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Slashing bips must be greater than zero".to_string()
+            }
+            .to_string()
+        )
+    }
 
-        // The previous slash request should be canceled implicitly
-        // by the slash_request handler
-        assert_eq!(slash_request_1_status, SlashingRequestStatus::Canceled);
+    // service request slashing before operator opt-in to slashing
+    {
+        let slashing_request_payload = RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time,
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
+        };
 
-        let slash_req_2_id = tc
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
+        let err = tc
             .vault_router
-            .query::<SlashingRequestIdResponse>(
-                &mut app,
-                &QueryMsg::SlashingRequestId {
-                    service: service.to_string(),
-                    operator: operator.to_string(),
-                },
-            )
-            .unwrap();
-
-        // The second slashing request id is now the current active one
-        // using this for later slash_locking tests
-        active_slashing_id = slash_req_2_id.0.unwrap();
+            .execute(&mut app, &service, msg)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Operator must be opted-in to slashing at the specified timestamp".to_string()
+            }
+            .to_string()
+        )
     }
 
+    // operator opt-in to slashing
     {
-        // pass the resolution window
-        app.update_block(|block| {
-            block.height += 10;
-            block.time = block.time.plus_seconds(100);
-        });
+        let msg = &bvs_registry::msg::ExecuteMsg::OperatorOptInToSlashing {
+            service: service.to_string(),
+            operator: None,
+        };
+        tc.registry
+            .execute(&mut app, &operator, msg)
+            .expect("failed to opt-in to slashing");
+    }
 
-        let msg = ExecuteMsg::LockSlashing(active_slashing_id.clone());
-        let res = tc.vault_router.execute(&mut app, &service, &msg).unwrap();
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
 
-        assert_eq!(
-            res.events,
-            vec![
-                Event::new("execute")
-                    .add_attribute("_contract_address", tc.vault_router.addr.as_str()),
-                Event::new("wasm-LockSlashing")
-                    .add_attribute("_contract_address", tc.vault_router.addr.as_str())
-                    .add_attribute("service", service.to_string())
-                    .add_attribute("operator", operator.to_string())
-                    .add_attribute("slashing_request_id", active_slashing_id.to_string())
-                    .add_attribute("bips", "100")
-                    .add_attribute("affected_vaults", "2"),
-                Event::new("execute")
-                    .add_attribute("_contract_address", tc.bank_vault.addr().to_string()),
-                Event::new("wasm-SlashLocked")
-                    .add_attribute("_contract_address", tc.bank_vault.addr().to_string())
-                    .add_attribute("sender", tc.vault_router.addr().to_string())
-                    .add_attribute("amount", "2")
-                    .add_attribute("denom", "denom"),
-                Event::new("transfer")
-                    .add_attribute("recipient", tc.vault_router.addr().to_string(),)
-                    .add_attribute("sender", tc.bank_vault.addr().to_string(),)
-                    .add_attribute("amount", "2denom"),
-                Event::new("execute")
-                    .add_attribute("_contract_address", tc.cw20_vault.addr().to_string()),
-                Event::new("wasm-SlashLocked")
-                    .add_attribute("_contract_address", tc.cw20_vault.addr().to_string())
-                    .add_attribute("sender", tc.vault_router.addr().to_string())
-                    .add_attribute("amount", "3")
-                    .add_attribute("token", tc.cw20.addr().to_string()),
-                Event::new("execute")
-                    .add_attribute("_contract_address", tc.cw20.addr().to_string(),),
-                Event::new("wasm")
-                    .add_attribute("_contract_address", tc.cw20.addr().to_string())
-                    .add_attribute("action", "transfer")
-                    .add_attribute("from", tc.cw20_vault.addr().to_string(),)
-                    .add_attribute("to", tc.vault_router.addr().to_string(),)
-                    .add_attribute("amount", "3"),
-            ]
-        );
+    // service request slashing for older timestamp before operator opted-in
+    {
+        let slashing_request_payload = RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time.minus_seconds(10),
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
+        };
 
-        let bank_vault_info = tc
-            .bank_vault
-            .query::<bvs_vault_base::msg::VaultInfoResponse>(
-                &mut app,
-                &bvs_vault_bank::msg::QueryMsg::VaultInfo {},
-            )
-            .unwrap();
-        let cw20_vault_info = tc
-            .cw20_vault
-            .query::<bvs_vault_base::msg::VaultInfoResponse>(
-                &mut app,
-                &bvs_vault_bank::msg::QueryMsg::VaultInfo {},
-            )
-            .unwrap();
-        assert_eq!(bank_vault_info.total_assets, Uint128::from(198_u128));
-        assert_eq!(cw20_vault_info.total_assets, Uint128::from(297_u128));
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
+        let err = tc
+            .vault_router
+            .execute(&mut app, &service, msg)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Operator must be opted-in to slashing at the specified timestamp".to_string()
+            }
+            .to_string()
+        )
+    }
 
-        let query = BankQuery::Balance {
-            address: tc.vault_router.addr().to_string(),
-            denom: "denom".to_string(),
+    // service successfully request slashing
+    {
+        let slashing_request_payload = RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time,
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
         };
 
-        let router_bank_balance: BalanceResponse =
-            app.wrap().query(&QueryRequest::Bank(query)).unwrap();
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
+        tc.vault_router
+            .execute(&mut app, &service, msg)
+            .expect("failed to request slashing");
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    // service request a second slashing to the same operator while there is an pending request
+    {
+        let slashing_request_payload = RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time,
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
+        };
+
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
+        let err = tc
+            .vault_router
+            .execute(&mut app, &service, msg)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Cannot process new request while previous slashing request is pending"
+                    .to_string()
+            }
+            .to_string()
+        )
+    }
+
+    // move blockchain after slashing request expiry
+    app.update_block(|block| {
+        block.height += 20;
+        block.time = block.time.plus_seconds(100).plus_seconds(7 * DAYS); // resolution_window + 7 days
+    });
+
+    // the expired slashing request starts the cooldown of the service against the operator
+    {
+        let slashing_request_payload = RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time,
+            metadata: SlashingMetadata {
+                reason: "test2".to_string(),
+            },
+            category: None,
+            vaults: None,
+        };
+
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload);
+        let err = tc
+            .vault_router
+            .execute(&mut app, &service, msg)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Cannot process new request during the cooldown of the previous slashing request"
+                    .to_string()
+            }
+            .to_string()
+        )
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(DAYS);
+    });
+
+    // service successfully request slashing after slashing request expiry and the cooldown
+    {
+        let slashing_request_payload = RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time,
+            metadata: SlashingMetadata {
+                reason: "test2".to_string(),
+            },
+            category: None,
+            vaults: None,
+        };
+
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
+        let response = tc.vault_router.execute(&mut app, &service, msg).unwrap();
+        assert_eq!(
+            response.events,
+            vec![
+                Event::new("execute")
+                    .add_attribute("_contract_address", tc.vault_router.addr.as_str()),
+                Event::new("wasm-RequestSlashing")
+                    .add_attribute("_contract_address", tc.vault_router.addr.as_str())
+                    .add_attribute("service", service.to_string())
+                    .add_attribute("operator", operator.to_string())
+                    .add_attribute(
+                        "slashing_request_id",
+                        "c1da35743b440e0ba489d4bc5c5f7058b25b9701e4dc6d00a1446e3d977e492e"
+                    )
+                    .add_attribute("reason", "test2"),
+            ]
+        );
+    }
+}
+
+#[test]
+fn test_slash_locking() {
+    let (mut app, tc) = TestContracts::init();
+
+    let operator = app.api().addr_make("operator");
+    let service = app.api().addr_make("service");
+
+    // register operator + service
+    {
+        tc.registry
+            .execute(
+                &mut app,
+                &operator,
+                &bvs_registry::msg::ExecuteMsg::RegisterAsOperator {
+                    metadata: Metadata {
+                        name: Some("operator".to_string()),
+                        uri: None,
+                    },
+                },
+            )
+            .expect("failed to register operator");
+
+        tc.registry
+            .execute(
+                &mut app,
+                &service,
+                &bvs_registry::msg::ExecuteMsg::RegisterAsService {
+                    metadata: Metadata {
+                        name: Some("service".to_string()),
+                        uri: None,
+                    },
+                },
+            )
+            .expect("failed to register service");
+
+        let owner = app.api().addr_make("owner");
+
+        let msg = &ExecuteMsg::SetVault {
+            vault: tc.bank_vault.addr().to_string(),
+            whitelisted: true,
+        };
+
+        tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+
+        let msg = &ExecuteMsg::SetVault {
+            vault: tc.cw20_vault.addr().to_string(),
+            whitelisted: true,
+        };
+
+        tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+    }
+
+    // stake funds
+    {
+        let owner = app.api().addr_make("owner");
+        let denom = "denom";
+
+        let staker = app.api().addr_make("staker");
+        let msg = bvs_vault_cw20::msg::ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(300_u128),
+        });
+        tc.cw20
+            .increase_allowance(&mut app, &staker, tc.cw20_vault.addr(), 300_u128);
+        tc.cw20.fund(&mut app, &staker, 300_u128);
+        tc.cw20_vault.execute(&mut app, &staker, &msg).unwrap();
+
+        // Fund the staker with some initial tokens
+        app.send_tokens(owner.clone(), staker.clone(), &coins(1_000_000_000, denom))
+            .unwrap();
+
+        // Deposit 115_687_654 tokens from staker to Vault
+        let msg = bvs_vault_bank::msg::ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(200),
+        });
+        tc.bank_vault
+            .execute_with_funds(&mut app, &staker, &msg, coins(200, denom))
+            .unwrap();
+
+        let bank_vault_info = tc
+            .bank_vault
+            .query::<bvs_vault_base::msg::VaultInfoResponse>(
+                &mut app,
+                &bvs_vault_bank::msg::QueryMsg::VaultInfo {},
+            )
+            .unwrap();
+        let cw20_vault_info = tc
+            .cw20_vault
+            .query::<bvs_vault_base::msg::VaultInfoResponse>(
+                &mut app,
+                &bvs_vault_bank::msg::QueryMsg::VaultInfo {},
+            )
+            .unwrap();
+
+        assert_eq!(bank_vault_info.total_assets, Uint128::new(200));
+        assert_eq!(cw20_vault_info.total_assets, Uint128::new(300));
+    }
+
+    // service enable slashing
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::EnableSlashing {
+            slashing_parameters: SlashingParameters {
+                destination: Some(service.clone()),
+                max_slashing_bips: 5000,
+                resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        };
+        tc.registry
+            .execute(&mut app, &service, msg)
+            .expect("failed to enable slashing");
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    // register operator to service for active status
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterOperatorToService {
+            operator: operator.to_string(),
+        };
+        tc.registry
+            .execute(&mut app, &service, msg)
+            .expect("failed to register operator to service");
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
+            service: service.to_string(),
+            operator: None,
+        };
+        tc.registry
+            .execute(&mut app, &operator, msg)
+            .expect("failed to register service to operator");
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    // Pending slashing requests that are idle beyond expiry
+    // should not block new slashing requests
+    // and will be transitioned implicitly to cancel status
+    let active_slashing_id;
+    {
+        let slashing_request_payload_1 = RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time,
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
+        };
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload_1.clone());
+        tc.vault_router
+            .execute(&mut app, &service, msg)
+            .expect("failed to request slashing");
+
+        let slash_req_1_id = tc
+            .vault_router
+            .query::<SlashingRequestIdResponse>(
+                &mut app,
+                &QueryMsg::SlashingRequestId {
+                    service: service.to_string(),
+                    operator: operator.to_string(),
+                },
+            )
+            .unwrap();
+
+        // at the request expiry, the previous slash can still be locked and is not replaced
+        app.update_block(|block| {
+            block.height += 60;
+            block.time = block.time.plus_seconds(100).plus_seconds(7 * DAYS);
+        });
+
+        let slashing_request_payload_2 = RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time,
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
+        };
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload_2.clone());
+        let err = tc
+            .vault_router
+            .execute(&mut app, &service, msg)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: "Cannot process new request while previous slashing request is pending"
+                    .to_string()
+            }
+            .to_string()
+        );
+
+        // past the request expiry and the cooldown of the service against the operator
+        app.update_block(|block| {
+            block.height += 1;
+            block.time = block.time.plus_seconds(DAYS);
+        });
+
+        let slashing_request_payload_2 = RequestSlashingPayload {
+            timestamp: app.block_info().time,
+            ..slashing_request_payload_2
+        };
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload_2.clone());
+        tc.vault_router
+            .execute(&mut app, &service, msg)
+            .expect("failed to request slashing");
+
+        app.update_block(|block| {
+            block.height += 1;
+            block.time = block.time.plus_seconds(10);
+        });
+
+        let slash_request_1_status = tc
+            .vault_router
+            .query::<SlashingRequestResponse>(
+                &mut app,
+                &QueryMsg::SlashingRequest(slash_req_1_id.0.clone().unwrap()),
+            )
+            .unwrap();
+
+        let slash_request_1_status = slash_request_1_status.0.unwrap().status;
+
+        // The previous slash request (expired without being locked) should be expired implicitly
+        // by the request_slashing handler
+        assert_eq!(slash_request_1_status, SlashingRequestStatus::Expired);
+
+        // and listed as expired, not canceled
+        let list = |app: &App, status: SlashingRequestStatus| {
+            tc.vault_router
+                .query::<SlashingRequestListResponse>(
+                    app,
+                    &QueryMsg::ListSlashingRequests {
+                        service: Some(service.to_string()),
+                        operator: Some(operator.to_string()),
+                        status: Some(status),
+                        limit: None,
+                        start_after: None,
+                    },
+                )
+                .unwrap()
+                .0
+                .into_iter()
+                .map(|item| item.id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            list(&app, SlashingRequestStatus::Expired),
+            vec![slash_req_1_id.0.unwrap()]
+        );
+        assert!(list(&app, SlashingRequestStatus::Canceled).is_empty());
+
+        let slash_req_2_id = tc
+            .vault_router
+            .query::<SlashingRequestIdResponse>(
+                &mut app,
+                &QueryMsg::SlashingRequestId {
+                    service: service.to_string(),
+                    operator: operator.to_string(),
+                },
+            )
+            .unwrap();
+
+        // The second slashing request id is now the current active one
+        // using this for later slash_locking tests
+        active_slashing_id = slash_req_2_id.0.unwrap();
+    }
+
+    {
+        // pass the resolution window
+        app.update_block(|block| {
+            block.height += 10;
+            block.time = block.time.plus_seconds(100);
+        });
+
+        let msg = ExecuteMsg::LockSlashing(active_slashing_id.clone());
+        let res = tc.vault_router.execute(&mut app, &service, &msg).unwrap();
+
+        assert_eq!(
+            res.events,
+            vec![
+                Event::new("execute")
+                    .add_attribute("_contract_address", tc.vault_router.addr.as_str()),
+                Event::new("wasm-LockSlashing")
+                    .add_attribute("_contract_address", tc.vault_router.addr.as_str())
+                    .add_attribute("service", service.to_string())
+                    .add_attribute("operator", operator.to_string())
+                    .add_attribute("slashing_request_id", active_slashing_id.to_string())
+                    .add_attribute("bips", "100")
+                    .add_attribute("affected_vaults", "2"),
+                Event::new("execute")
+                    .add_attribute("_contract_address", tc.bank_vault.addr().to_string()),
+                Event::new("wasm-SlashLocked")
+                    .add_attribute("_contract_address", tc.bank_vault.addr().to_string())
+                    .add_attribute("sender", tc.vault_router.addr().to_string())
+                    .add_attribute("amount", "2")
+                    .add_attribute("denom", "denom"),
+                Event::new("transfer")
+                    .add_attribute("recipient", tc.vault_router.addr().to_string(),)
+                    .add_attribute("sender", tc.bank_vault.addr().to_string(),)
+                    .add_attribute("amount", "2denom"),
+                Event::new("execute")
+                    .add_attribute("_contract_address", tc.cw20_vault.addr().to_string()),
+                Event::new("wasm-SlashLocked")
+                    .add_attribute("_contract_address", tc.cw20_vault.addr().to_string())
+                    .add_attribute("sender", tc.vault_router.addr().to_string())
+                    .add_attribute("amount", "3")
+                    .add_attribute("token", tc.cw20.addr().to_string()),
+                Event::new("execute")
+                    .add_attribute("_contract_address", tc.cw20.addr().to_string(),),
+                Event::new("wasm")
+                    .add_attribute("_contract_address", tc.cw20.addr().to_string())
+                    .add_attribute("action", "transfer")
+                    .add_attribute("from", tc.cw20_vault.addr().to_string(),)
+                    .add_attribute("to", tc.vault_router.addr().to_string(),)
+                    .add_attribute("amount", "3"),
+            ]
+        );
+
+        let bank_vault_info = tc
+            .bank_vault
+            .query::<bvs_vault_base::msg::VaultInfoResponse>(
+                &mut app,
+                &bvs_vault_bank::msg::QueryMsg::VaultInfo {},
+            )
+            .unwrap();
+        let cw20_vault_info = tc
+            .cw20_vault
+            .query::<bvs_vault_base::msg::VaultInfoResponse>(
+                &mut app,
+                &bvs_vault_bank::msg::QueryMsg::VaultInfo {},
+            )
+            .unwrap();
+        assert_eq!(bank_vault_info.total_assets, Uint128::from(198_u128));
+        assert_eq!(cw20_vault_info.total_assets, Uint128::from(297_u128));
+
+        let query = BankQuery::Balance {
+            address: tc.vault_router.addr().to_string(),
+            denom: "denom".to_string(),
+        };
+
+        let router_bank_balance: BalanceResponse =
+            app.wrap().query(&QueryRequest::Bank(query)).unwrap();
+
+        assert_eq!(router_bank_balance.amount, coin(2, "denom")); // 1% of 200
+
+        let router_cw20_balance = tc.cw20.balance(&app, tc.vault_router.addr());
+
+        // due to the decimal
+        assert_eq!(router_cw20_balance, 3_u128); // 1% of 300
+    }
+}
+
+#[test]
+fn lock_slashing_max_vaults_per_operator() {
+    let (mut app, tc) = TestContracts::init();
+    let env = mock_env();
+    let owner = app.api().addr_make("owner");
+    let operator = app.api().addr_make("operator");
+    let service = app.api().addr_make("service");
+    let staker = app.api().addr_make("staker");
+
+    // register operator + service, enable slashing and register each other
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterAsOperator {
+            metadata: Metadata {
+                name: None,
+                uri: None,
+            },
+        };
+        tc.registry.execute(&mut app, &operator, msg).unwrap();
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterAsService {
+            metadata: Metadata {
+                name: None,
+                uri: None,
+            },
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        let msg = &bvs_registry::msg::ExecuteMsg::EnableSlashing {
+            slashing_parameters: SlashingParameters {
+                destination: Some(service.clone()),
+                max_slashing_bips: 5000,
+                resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        app.update_block(|block| {
+            block.height += 1;
+            block.time = block.time.plus_seconds(10);
+        });
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterOperatorToService {
+            operator: operator.to_string(),
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
+            service: service.to_string(),
+            operator: None,
+        };
+        tc.registry.execute(&mut app, &operator, msg).unwrap();
+    }
+
+    // whitelist vaults of the operator up to the default max (10)
+    let mut bank_vaults = vec![tc.bank_vault.clone()];
+    {
+        for _ in 0..8 {
+            bank_vaults.push(VaultBankContract::new(&mut app, &env, None));
+        }
+
+        let vaults = bank_vaults
+            .iter()
+            .map(|v| v.addr.clone())
+            .chain([tc.cw20_vault.addr.clone()]);
+        for vault in vaults {
+            let msg = &ExecuteMsg::SetVault {
+                vault: vault.to_string(),
+                whitelisted: true,
+            };
+            tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+        }
+
+        let msg = QueryMsg::OperatorVaultCount {
+            operator: operator.to_string(),
+        };
+        let count: u32 = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(count, 10);
+    }
+
+    // the 11th vault of the operator cannot be whitelisted
+    let vault = VaultBankContract::new(&mut app, &env, None);
+    {
+        let msg = &ExecuteMsg::SetVault {
+            vault: vault.addr.to_string(),
+            whitelisted: true,
+        };
+        let err = tc.vault_router.execute(&mut app, &owner, msg).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::VaultError {
+                msg: "Operator has reached the max vaults per operator".to_string()
+            }
+            .to_string()
+        );
+    }
+
+    // a vault no longer whitelisted frees its slot for the 11th vault
+    {
+        let msg = &ExecuteMsg::SetVault {
+            vault: bank_vaults[8].addr.to_string(),
+            whitelisted: false,
+        };
+        tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+
+        let msg = QueryMsg::OperatorVaultCount {
+            operator: operator.to_string(),
+        };
+        let count: u32 = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(count, 9);
+
+        let msg = &ExecuteMsg::SetVault {
+            vault: vault.addr.to_string(),
+            whitelisted: true,
+        };
+        tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+        bank_vaults[8] = vault;
+
+        let msg = QueryMsg::OperatorVaultCount {
+            operator: operator.to_string(),
+        };
+        let count: u32 = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(count, 10);
+    }
+
+    // the max cannot exceed the lock slashing gas limit, less its fixed cost, at the estimated gas per vault
+    {
+        assert_eq!(MAX_MAX_VAULTS_PER_OPERATOR, 80);
+        assert!(
+            LOCK_SLASHING_GAS_FIXED
+                + u64::from(MAX_MAX_VAULTS_PER_OPERATOR) * LOCK_SLASHING_GAS_PER_VAULT
+                <= LOCK_SLASHING_GAS_LIMIT
+        );
+
+        let msg = &ExecuteMsg::SetMaxVaultsPerOperator(MAX_MAX_VAULTS_PER_OPERATOR + 1);
+        let err = tc.vault_router.execute(&mut app, &owner, msg).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::VaultError {
+                msg: "Max vaults per operator must not exceed the lock slashing gas limit"
+                    .to_string()
+            }
+            .to_string()
+        );
+
+        let msg = &ExecuteMsg::SetMaxVaultsPerOperator(MAX_MAX_VAULTS_PER_OPERATOR);
+        tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+    }
+
+    // whitelist vaults of the operator up to the max
+    {
+        for _ in DEFAULT_MAX_VAULTS_PER_OPERATOR..MAX_MAX_VAULTS_PER_OPERATOR {
+            let vault = VaultBankContract::new(&mut app, &env, None);
+            let msg = &ExecuteMsg::SetVault {
+                vault: vault.addr.to_string(),
+                whitelisted: true,
+            };
+            tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+            bank_vaults.push(vault);
+        }
+
+        let msg = QueryMsg::OperatorVaultCount {
+            operator: operator.to_string(),
+        };
+        let count: u32 = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(count, MAX_MAX_VAULTS_PER_OPERATOR);
+    }
+
+    // stake in every vault
+    {
+        let total = 1_000 * bank_vaults.len() as u128;
+        app.send_tokens(owner.clone(), staker.clone(), &coins(total, "denom"))
+            .unwrap();
+        for vault in &bank_vaults {
+            let msg = bvs_vault_bank::msg::ExecuteMsg::DepositFor(RecipientAmount {
+                recipient: staker.clone(),
+                amount: Uint128::new(1_000),
+            });
+            vault
+                .execute_with_funds(&mut app, &staker, &msg, coins(1_000, "denom"))
+                .unwrap();
+        }
+
+        tc.cw20.fund(&mut app, &staker, 1_000_u128);
+        tc.cw20
+            .increase_allowance(&mut app, &staker, tc.cw20_vault.addr(), 1_000_u128);
+        let msg = bvs_vault_cw20::msg::ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(1_000),
+        });
+        tc.cw20_vault.execute(&mut app, &staker, &msg).unwrap();
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    // all the vaults at the max are locked in a single LockSlashing
+    {
+        let msg = &ExecuteMsg::RequestSlashing(RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time,
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
+        });
+        let response = tc.vault_router.execute(&mut app, &service, msg).unwrap();
+        let id: RequestSlashingResponse = response.data.unwrap().into();
+
+        app.update_block(|block| {
+            block.height += 10;
+            block.time = block.time.plus_seconds(100);
+        });
+
+        let msg = &ExecuteMsg::LockSlashing(id.0.clone());
+        let response = tc.vault_router.execute(&mut app, &service, msg).unwrap();
+        let lock_slashing = response
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm-LockSlashing")
+            .unwrap();
+        assert!(lock_slashing
+            .attributes
+            .iter()
+            .any(|a| a.key == "affected_vaults"
+                && a.value == MAX_MAX_VAULTS_PER_OPERATOR.to_string()));
+
+        let msg = QueryMsg::SlashingLocked {
+            slashing_request_id: id.0,
+        };
+        let locked: SlashingLockedResponse = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(locked.0.len(), MAX_MAX_VAULTS_PER_OPERATOR as usize);
+        assert!(locked.0.iter().all(|l| l.amount == Uint128::new(10)));
+    }
+}
+
+#[test]
+fn operator_and_vault_aum() {
+    let (mut app, tc) = TestContracts::init();
+    let env = mock_env();
+    let owner = app.api().addr_make("owner");
+    let operator = app.api().addr_make("operator");
+    let service = app.api().addr_make("service");
+    let staker = app.api().addr_make("staker");
+
+    // register operator + service, enable slashing and register each other
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterAsOperator {
+            metadata: Metadata {
+                name: None,
+                uri: None,
+            },
+        };
+        tc.registry.execute(&mut app, &operator, msg).unwrap();
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterAsService {
+            metadata: Metadata {
+                name: None,
+                uri: None,
+            },
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        let msg = &bvs_registry::msg::ExecuteMsg::EnableSlashing {
+            slashing_parameters: SlashingParameters {
+                destination: Some(service.clone()),
+                max_slashing_bips: 5000,
+                resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        app.update_block(|block| {
+            block.height += 1;
+            block.time = block.time.plus_seconds(10);
+        });
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterOperatorToService {
+            operator: operator.to_string(),
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
+            service: service.to_string(),
+            operator: None,
+        };
+        tc.registry.execute(&mut app, &operator, msg).unwrap();
+    }
+
+    // two bank vaults (same asset) and a cw20 vault of the operator
+    let bank_vault2 = VaultBankContract::new(&mut app, &env, None);
+    {
+        for vault in [&tc.bank_vault.addr, &bank_vault2.addr, &tc.cw20_vault.addr] {
+            let msg = &ExecuteMsg::SetVault {
+                vault: vault.to_string(),
+                whitelisted: true,
+            };
+            tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+        }
+
+        app.send_tokens(owner.clone(), staker.clone(), &coins(600, "denom"))
+            .unwrap();
+        for (vault, amount) in [(&tc.bank_vault, 200), (&bank_vault2, 300)] {
+            let msg = bvs_vault_bank::msg::ExecuteMsg::DepositFor(RecipientAmount {
+                recipient: staker.clone(),
+                amount: Uint128::new(amount),
+            });
+            vault
+                .execute_with_funds(&mut app, &staker, &msg, coins(amount, "denom"))
+                .unwrap();
+        }
+
+        tc.cw20.fund(&mut app, &staker, 500_u128);
+        tc.cw20
+            .increase_allowance(&mut app, &staker, tc.cw20_vault.addr(), 500_u128);
+        let msg = bvs_vault_cw20::msg::ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(500),
+        });
+        tc.cw20_vault.execute(&mut app, &staker, &msg).unwrap();
+    }
+
+    let bank_asset_id = tc
+        .bank_vault
+        .query::<bvs_vault_base::msg::VaultInfoResponse>(
+            &app,
+            &bvs_vault_bank::msg::QueryMsg::VaultInfo {},
+        )
+        .unwrap()
+        .asset_id;
+    let cw20_asset_id = tc
+        .cw20_vault
+        .query::<bvs_vault_base::msg::VaultInfoResponse>(
+            &app,
+            &bvs_vault_cw20::msg::QueryMsg::VaultInfo {},
+        )
+        .unwrap()
+        .asset_id;
+    let mut expected = vec![
+        AssetAum {
+            asset_id: bank_asset_id.clone(),
+            total_assets: Uint128::new(500),
+            usd_value: None,
+        },
+        AssetAum {
+            asset_id: cw20_asset_id.clone(),
+            total_assets: Uint128::new(500),
+            usd_value: None,
+        },
+    ];
+    expected.sort_by(|a, b| a.asset_id.cmp(&b.asset_id));
+
+    // current aum is aggregated per asset
+    {
+        let msg = QueryMsg::OperatorAum {
+            operator: operator.to_string(),
+            slashing_request_id: None,
+        };
+        let response: OperatorAumResponse = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(response, OperatorAumResponse(expected.clone()));
+
+        let msg = QueryMsg::VaultAum {
+            vault: tc.bank_vault.addr.to_string(),
+            slashing_request_id: None,
+        };
+        let response: VaultAumResponse = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(
+            response,
+            VaultAumResponse(AssetAum {
+                asset_id: bank_asset_id.clone(),
+                total_assets: Uint128::new(200),
+                usd_value: None,
+            })
+        );
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    let msg = &ExecuteMsg::RequestSlashing(RequestSlashingPayload {
+        operator: operator.to_string(),
+        bips: 1000,
+        timestamp: app.block_info().time,
+        metadata: SlashingMetadata {
+            reason: "test".to_string(),
+        },
+        category: None,
+        vaults: None,
+    });
+    let response = tc.vault_router.execute(&mut app, &service, msg).unwrap();
+    let RequestSlashingResponse(id) = response.data.unwrap().into();
+
+    // aum at a slashing request that has not been locked
+    {
+        let msg = QueryMsg::OperatorAum {
+            operator: operator.to_string(),
+            slashing_request_id: Some(id.clone()),
+        };
+        let err = tc
+            .vault_router
+            .query::<OperatorAumResponse>(&app, &msg)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Slashing request has not been locked"));
+    }
+
+    app.update_block(|block| {
+        block.height += 10;
+        block.time = block.time.plus_seconds(100);
+    });
+
+    let msg = &ExecuteMsg::LockSlashing(id.clone());
+    tc.vault_router.execute(&mut app, &service, msg).unwrap();
+
+    // 10% is slashed from the current aum
+    {
+        let msg = QueryMsg::OperatorAum {
+            operator: operator.to_string(),
+            slashing_request_id: None,
+        };
+        let response: OperatorAumResponse = tc.vault_router.query(&app, &msg).unwrap();
+        assert!(response
+            .0
+            .iter()
+            .all(|aum| aum.total_assets == Uint128::new(450)));
+    }
+
+    // aum at the moment the slashing request was locked
+    {
+        let msg = QueryMsg::OperatorAum {
+            operator: operator.to_string(),
+            slashing_request_id: Some(id.clone()),
+        };
+        let response: OperatorAumResponse = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(response, OperatorAumResponse(expected));
+
+        let msg = QueryMsg::VaultAum {
+            vault: bank_vault2.addr.to_string(),
+            slashing_request_id: Some(id.clone()),
+        };
+        let response: VaultAumResponse = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(
+            response,
+            VaultAumResponse(AssetAum {
+                asset_id: bank_asset_id.clone(),
+                total_assets: Uint128::new(300),
+                usd_value: None,
+            })
+        );
+
+        let msg = QueryMsg::OperatorAum {
+            operator: service.to_string(),
+            slashing_request_id: Some(id.clone()),
+        };
+        let err = tc
+            .vault_router
+            .query::<OperatorAumResponse>(&app, &msg)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Slashing request is not against the operator"));
+    }
+
+    // vaults no longer whitelisted are not part of the current aum
+    {
+        let msg = &ExecuteMsg::SetVault {
+            vault: tc.cw20_vault.addr.to_string(),
+            whitelisted: false,
+        };
+        tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+
+        let msg = QueryMsg::OperatorAum {
+            operator: operator.to_string(),
+            slashing_request_id: None,
+        };
+        let response: OperatorAumResponse = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(
+            response,
+            OperatorAumResponse(vec![AssetAum {
+                asset_id: bank_asset_id.clone(),
+                total_assets: Uint128::new(450),
+                usd_value: None,
+            }])
+        );
+    }
+
+    // with the oracle set, assets with a price source are valued in USD at the current price
+    {
+        let oracle = OracleContract::new(&mut app, &env, None);
+        let msg = &bvs_oracle::msg::ExecuteMsg::SetPriceSource {
+            asset_id: bank_asset_id.clone(),
+            source: PriceSource {
+                decimals: 2,
+                feed: PriceFeed::Push,
+            },
+        };
+        oracle.execute(&mut app, &owner, msg).unwrap();
+        let msg = &bvs_oracle::msg::ExecuteMsg::PushPrice {
+            asset_id: bank_asset_id.clone(),
+            price: Decimal::percent(200),
+        };
+        oracle.execute(&mut app, &owner, msg).unwrap();
+
+        let msg = &ExecuteMsg::SetOracle {
+            oracle: Some(oracle.addr.to_string()),
+        };
+        tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+
+        // 180 at 2 decimals = 1.8 units at 2 USD
+        let msg = QueryMsg::VaultAum {
+            vault: tc.bank_vault.addr.to_string(),
+            slashing_request_id: None,
+        };
+        let response: VaultAumResponse = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(response.0.usd_value, Some(Decimal::percent(360)));
+
+        // 450 at 2 decimals = 4.5 units at 2 USD
+        let msg = QueryMsg::OperatorAum {
+            operator: operator.to_string(),
+            slashing_request_id: None,
+        };
+        let response: OperatorAumResponse = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(response.0[0].usd_value, Some(Decimal::percent(900)));
+
+        // assets under management at a slashing request are not valued at the current price
+        let msg = QueryMsg::OperatorAum {
+            operator: operator.to_string(),
+            slashing_request_id: Some(id.clone()),
+        };
+        let response: OperatorAumResponse = tc.vault_router.query(&app, &msg).unwrap();
+        assert!(response.0.iter().all(|aum| aum.usd_value.is_none()));
+
+        let msg = QueryMsg::VaultAum {
+            vault: tc.bank_vault.addr.to_string(),
+            slashing_request_id: Some(id),
+        };
+        let response: VaultAumResponse = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(response.0.usd_value, None);
+
+        // an oracle failing to value the asset does not fail the query
+        let msg = &bvs_oracle::msg::ExecuteMsg::SetPriceSource {
+            asset_id: bank_asset_id.clone(),
+            source: PriceSource {
+                decimals: 2,
+                feed: PriceFeed::Pyth {
+                    contract: app.api().addr_make("not_pyth"),
+                    price_id: HexBinary::from(vec![1; 32]),
+                },
+            },
+        };
+        oracle.execute(&mut app, &owner, msg).unwrap();
+
+        let msg = QueryMsg::VaultAum {
+            vault: tc.bank_vault.addr.to_string(),
+            slashing_request_id: None,
+        };
+        let response: VaultAumResponse = tc.vault_router.query(&app, &msg).unwrap();
+        assert_eq!(
+            response,
+            VaultAumResponse(AssetAum {
+                asset_id: bank_asset_id.clone(),
+                total_assets: Uint128::new(180),
+                usd_value: None,
+            })
+        );
+    }
+}
+
+#[test]
+fn test_finalize_slashing() {
+    let (mut app, tc) = TestContracts::init();
+
+    let operator = app.api().addr_make("operator");
+    let service = app.api().addr_make("service");
+
+    // register operator + service
+    {
+        tc.registry
+            .execute(
+                &mut app,
+                &operator,
+                &bvs_registry::msg::ExecuteMsg::RegisterAsOperator {
+                    metadata: Metadata {
+                        name: Some("operator".to_string()),
+                        uri: None,
+                    },
+                },
+            )
+            .expect("failed to register operator");
+
+        tc.registry
+            .execute(
+                &mut app,
+                &service,
+                &bvs_registry::msg::ExecuteMsg::RegisterAsService {
+                    metadata: Metadata {
+                        name: Some("service".to_string()),
+                        uri: None,
+                    },
+                },
+            )
+            .expect("failed to register service");
+
+        let owner = app.api().addr_make("owner");
+
+        let msg = &ExecuteMsg::SetVault {
+            vault: tc.bank_vault.addr().to_string(),
+            whitelisted: true,
+        };
+
+        tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+
+        let msg = &ExecuteMsg::SetVault {
+            vault: tc.cw20_vault.addr().to_string(),
+            whitelisted: true,
+        };
+
+        tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+    }
+
+    // stake funds
+    {
+        let owner = app.api().addr_make("owner");
+        let denom = "denom";
+
+        let staker = app.api().addr_make("staker");
+        tc.cw20
+            .increase_allowance(&mut app, &staker, tc.cw20_vault.addr(), 300_u128);
+        tc.cw20.fund(&mut app, &staker, 300_u128);
+        // staker stake 300 cw20 tokens
+        let msg = bvs_vault_cw20::msg::ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(300_u128),
+        });
+        tc.cw20_vault.execute(&mut app, &staker, &msg).unwrap();
+
+        // fund the staker with some initial bank tokens
+        app.send_tokens(owner.clone(), staker.clone(), &coins(1_000_000_000, denom))
+            .unwrap();
+
+        // staker stake 200 bank token
+        let msg = bvs_vault_bank::msg::ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(200),
+        });
+        tc.bank_vault
+            .execute_with_funds(&mut app, &staker, &msg, coins(200, denom))
+            .unwrap();
+    }
+
+    // service enable slashing
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::EnableSlashing {
+            slashing_parameters: SlashingParameters {
+                destination: Some(service.clone()),
+                max_slashing_bips: 5000,
+                resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        };
+        tc.registry
+            .execute(&mut app, &service, msg)
+            .expect("failed to enable slashing");
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    // register operator to service and vice versa for active status
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterOperatorToService {
+            operator: operator.to_string(),
+        };
+        tc.registry
+            .execute(&mut app, &service, msg)
+            .expect("failed to register operator to service");
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
+            service: service.to_string(),
+            operator: None,
+        };
+        tc.registry
+            .execute(&mut app, &operator, msg)
+            .expect("failed to register service to operator");
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    // service request slashing
+    let slashing_request_payload = RequestSlashingPayload {
+        operator: operator.to_string(),
+        bips: 100,
+        timestamp: app.block_info().time,
+        metadata: SlashingMetadata {
+            reason: "test".to_string(),
+        },
+        category: None,
+        vaults: None,
+    };
+
+    let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
+    tc.vault_router.execute(&mut app, &service, msg).unwrap();
+
+    // query slashing request id
+    let msg = QueryMsg::SlashingRequestId {
+        service: service.to_string(),
+        operator: operator.to_string(),
+    };
+    let SlashingRequestIdResponse(slashing_request_id) =
+        tc.vault_router.query(&mut app, &msg).unwrap();
+    let slashing_request_id = slashing_request_id.unwrap();
+
+    // pass the resolution window
+    app.update_block(|block| {
+        block.height += 10;
+        block.time = block.time.plus_seconds(100);
+    });
+
+    // Lock slashing
+    let msg = ExecuteMsg::LockSlashing(slashing_request_id.clone());
+    tc.vault_router.execute(&mut app, &service, &msg).unwrap();
+
+    let owner = app.api().addr_make("owner");
+
+    // Create a proposal for the slashing request by owner
+    let proposal_msg = bvs_guardrail::msg::ExecuteMsg::Propose {
+        slashing_request_id: slashing_request_id.clone(),
+        reason: "test slashing".to_string(),
+    };
+    tc.guardrail
+        .execute(&mut app, &owner, &proposal_msg)
+        .unwrap();
+
+    // Have voters vote on the proposal
+    let voter1 = app.api().addr_make("voter1");
+    let voter2 = app.api().addr_make("voter2");
+    let voter3 = app.api().addr_make("voter3");
+    let voter4 = app.api().addr_make("voter4");
+
+    // Voter1 votes "yes"
+    let vote_yes_msg = bvs_guardrail::msg::ExecuteMsg::Vote {
+        slashing_request_id: slashing_request_id.clone(),
+        vote: cw3::Vote::Yes,
+    };
+    tc.guardrail
+        .execute(&mut app, &voter1, &vote_yes_msg)
+        .unwrap();
+
+    // Voter2 votes "yes"
+    tc.guardrail
+        .execute(&mut app, &voter2, &vote_yes_msg)
+        .unwrap();
+
+    // Voter3 votes "no"
+    let vote_no_msg = bvs_guardrail::msg::ExecuteMsg::Vote {
+        slashing_request_id: slashing_request_id.clone(),
+        vote: cw3::Vote::No,
+    };
+    tc.guardrail
+        .execute(&mut app, &voter3, &vote_no_msg)
+        .unwrap();
+
+    // Voter4 votes "yes" to pass the proposal
+    let vote_msg = bvs_guardrail::msg::ExecuteMsg::Vote {
+        slashing_request_id: slashing_request_id.clone(),
+        vote: cw3::Vote::Yes,
+    };
+    tc.guardrail.execute(&mut app, &voter4, &vote_msg).unwrap();
+
+    // Check the proposal status is Passed
+    let proposal_status = bvs_guardrail::msg::QueryMsg::ProposalBySlashingRequestId {
+        slashing_request_id: slashing_request_id.clone(),
+    };
+    let proposal: cw3::ProposalResponse = tc.guardrail.query(&app, &proposal_status).unwrap();
+    assert_eq!(proposal.status, cw3::Status::Passed);
+
+    // Finalize slashing
+    let msg = ExecuteMsg::FinalizeSlashing(slashing_request_id.clone());
+    let res = tc.vault_router.execute(&mut app, &service, &msg).unwrap();
+
+    // Verify the slashing is finalized
+    // Check events
+    assert_eq!(
+        res.events,
+        vec![
+            Event::new("execute").add_attribute("_contract_address", tc.vault_router.addr.as_str()),
+            Event::new("wasm-FinalizeSlashing")
+                .add_attribute("_contract_address", tc.vault_router.addr.as_str())
+                .add_attribute("service", service.to_string())
+                .add_attribute("operator", operator.to_string())
+                .add_attribute("slashing_request_id", slashing_request_id.to_string())
+                .add_attribute("destination", service.to_string())
+                .add_attribute("affected_vaults", 2.to_string()),
+            Event::new("transfer")
+                .add_attribute("recipient", service.to_string())
+                .add_attribute("sender", tc.vault_router.addr().to_string())
+                .add_attribute("amount", "2denom"),
+            Event::new("execute").add_attribute("_contract_address", tc.cw20.addr().to_string()),
+            Event::new("wasm")
+                .add_attribute("_contract_address", tc.cw20.addr().to_string())
+                .add_attribute("action", "transfer")
+                .add_attribute("from", tc.vault_router.addr().to_string())
+                .add_attribute("to", service.to_string())
+                .add_attribute("amount", "3"),
+        ]
+    );
+
+    // Check that the slashed assets are transferred to the destination
+    let query = BankQuery::Balance {
+        address: service.to_string(),
+        denom: "denom".to_string(),
+    };
+    let service_bank_balance: BalanceResponse =
+        app.wrap().query(&QueryRequest::Bank(query)).unwrap();
+    assert_eq!(service_bank_balance.amount, coin(2, "denom")); // 1% of 200
+
+    let service_cw20_balance = tc.cw20.balance(&app, &service);
+    assert_eq!(service_cw20_balance, 3_u128); // 1% of 300
+
+    // Check that the router no longer has the slashed assets
+    let query = BankQuery::Balance {
+        address: tc.vault_router.addr().to_string(),
+        denom: "denom".to_string(),
+    };
+    let router_bank_balance: BalanceResponse =
+        app.wrap().query(&QueryRequest::Bank(query)).unwrap();
+    assert_eq!(router_bank_balance.amount, coin(0, "denom"));
+
+    let router_cw20_balance = tc.cw20.balance(&app, tc.vault_router.addr());
+    assert_eq!(router_cw20_balance, 0_u128);
+
+    // Check that the slashing request is marked as finalized
+    let msg = QueryMsg::SlashingRequest(slashing_request_id.clone());
+    let SlashingRequestResponse(slashing_request) = tc.vault_router.query(&mut app, &msg).unwrap();
+    let slashing_request = slashing_request.unwrap();
+
+    assert_eq!(slashing_request.status, SlashingRequestStatus::Finalized);
+
+    // The finalized slashing request is still listed, no longer pending
+    let msg = QueryMsg::ListSlashingRequests {
+        service: Some(service.to_string()),
+        operator: Some(operator.to_string()),
+        status: None,
+        limit: None,
+        start_after: None,
+    };
+    let response: SlashingRequestListResponse = tc.vault_router.query(&app, &msg).unwrap();
+    assert_eq!(
+        response,
+        SlashingRequestListResponse(vec![SlashingRequestItem {
+            id: slashing_request_id.clone(),
+            slashing_request: slashing_request.clone(),
+        }])
+    );
+
+    let msg = QueryMsg::ListSlashingRequests {
+        service: None,
+        operator: Some(operator.to_string()),
+        status: Some(SlashingRequestStatus::Pending),
+        limit: None,
+        start_after: None,
+    };
+    let response: SlashingRequestListResponse = tc.vault_router.query(&app, &msg).unwrap();
+    assert_eq!(response, SlashingRequestListResponse(vec![]));
+}
+
+#[test]
+fn test_finalize_slashing_weighted_destinations() {
+    let (mut app, tc) = TestContracts::init();
+
+    let owner = app.api().addr_make("owner");
+    let operator = app.api().addr_make("operator");
+    let service = app.api().addr_make("service");
+    let staker = app.api().addr_make("staker");
+    let users = app.api().addr_make("users");
+    let insurance = app.api().addr_make("insurance");
+
+    // register operator + service, whitelist vaults and stake funds
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterAsOperator {
+            metadata: Metadata {
+                name: None,
+                uri: None,
+            },
+        };
+        tc.registry.execute(&mut app, &operator, msg).unwrap();
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterAsService {
+            metadata: Metadata {
+                name: None,
+                uri: None,
+            },
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        for vault in [tc.bank_vault.addr(), tc.cw20_vault.addr()] {
+            let msg = &ExecuteMsg::SetVault {
+                vault: vault.to_string(),
+                whitelisted: true,
+            };
+            tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+        }
+
+        tc.cw20
+            .increase_allowance(&mut app, &staker, tc.cw20_vault.addr(), 300_u128);
+        tc.cw20.fund(&mut app, &staker, 300_u128);
+        let msg = bvs_vault_cw20::msg::ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(300),
+        });
+        tc.cw20_vault.execute(&mut app, &staker, &msg).unwrap();
+
+        app.send_tokens(owner.clone(), staker.clone(), &coins(333, "denom"))
+            .unwrap();
+        let msg = bvs_vault_bank::msg::ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(333),
+        });
+        tc.bank_vault
+            .execute_with_funds(&mut app, &staker, &msg, coins(333, "denom"))
+            .unwrap();
+    }
+
+    // 70% to users, 20% to insurance, 10% burned
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::EnableSlashing {
+            slashing_parameters: SlashingParameters {
+                destination: None,
+                max_slashing_bips: 5000,
+                resolution_window: 100,
+                destinations: vec![
+                    WeightedDestination {
+                        recipient: SlashingRecipient::Address(users.clone()),
+                        bips: 7000,
+                    },
+                    WeightedDestination {
+                        recipient: SlashingRecipient::Address(insurance.clone()),
+                        bips: 2000,
+                    },
+                    WeightedDestination {
+                        recipient: SlashingRecipient::Burn,
+                        bips: 1000,
+                    },
+                ],
+                asset_ids: vec![],
+            },
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterOperatorToService {
+            operator: operator.to_string(),
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
+            service: service.to_string(),
+            operator: None,
+        };
+        tc.registry.execute(&mut app, &operator, msg).unwrap();
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    // slash 10%: 33 of the bank vault, 30 of the cw20 vault
+    let msg = &ExecuteMsg::RequestSlashing(RequestSlashingPayload {
+        operator: operator.to_string(),
+        bips: 1000,
+        timestamp: app.block_info().time,
+        metadata: SlashingMetadata {
+            reason: "test".to_string(),
+        },
+        category: None,
+        vaults: None,
+    });
+    let res = tc.vault_router.execute(&mut app, &service, msg).unwrap();
+    let RequestSlashingResponse(slashing_request_id) = res.data.unwrap().into();
+
+    app.update_block(|block| {
+        block.height += 10;
+        block.time = block.time.plus_seconds(100);
+    });
+
+    let msg = ExecuteMsg::LockSlashing(slashing_request_id.clone());
+    tc.vault_router.execute(&mut app, &service, &msg).unwrap();
+
+    // pass the guardrail proposal
+    {
+        let msg = bvs_guardrail::msg::ExecuteMsg::Propose {
+            slashing_request_id: slashing_request_id.clone(),
+            reason: "test slashing".to_string(),
+        };
+        tc.guardrail.execute(&mut app, &owner, &msg).unwrap();
+
+        let msg = bvs_guardrail::msg::ExecuteMsg::Vote {
+            slashing_request_id: slashing_request_id.clone(),
+            vote: cw3::Vote::Yes,
+        };
+        for voter in ["voter1", "voter2", "voter3"] {
+            let voter = app.api().addr_make(voter);
+            tc.guardrail.execute(&mut app, &voter, &msg).unwrap();
+        }
+    }
+
+    let msg = ExecuteMsg::FinalizeSlashing(slashing_request_id.clone());
+    let res = tc.vault_router.execute(&mut app, &service, &msg).unwrap();
+
+    let distributed = |vault: &str, recipient: &str, bips: &str, amount: &str| {
+        Event::new("wasm-SlashingDistributed")
+            .add_attribute("_contract_address", tc.vault_router.addr.as_str())
+            .add_attribute("slashing_request_id", slashing_request_id.to_string())
+            .add_attribute("vault", vault)
+            .add_attribute("recipient", recipient)
+            .add_attribute("bips", bips)
+            .add_attribute("amount", amount)
+    };
+    let bank_vault = tc.bank_vault.addr.as_str();
+    let cw20_vault = tc.cw20_vault.addr.as_str();
+    let mut expected = vec![
+        // the rounding dust of 33 (23.1 + 6.6 + 3.3) goes to the first destination
+        distributed(bank_vault, users.as_str(), "7000", "24"),
+        distributed(bank_vault, insurance.as_str(), "2000", "6"),
+        distributed(bank_vault, "burn", "1000", "3"),
+        distributed(cw20_vault, users.as_str(), "7000", "21"),
+        distributed(cw20_vault, insurance.as_str(), "2000", "6"),
+        distributed(cw20_vault, "burn", "1000", "3"),
+    ];
+    // events are ordered by vault address
+    if cw20_vault < bank_vault {
+        expected.rotate_left(3);
+    }
+    let events: Vec<_> = res
+        .events
+        .into_iter()
+        .filter(|e| e.ty == "wasm-SlashingDistributed")
+        .collect();
+    assert_eq!(events, expected);
+
+    let balance =
+        |app: &App, address: &Addr| app.wrap().query_balance(address, "denom").unwrap().amount;
+    assert_eq!(balance(&app, &users), Uint128::new(24));
+    assert_eq!(balance(&app, &insurance), Uint128::new(6));
+    assert_eq!(balance(&app, tc.vault_router.addr()), Uint128::zero());
+
+    assert_eq!(tc.cw20.balance(&app, &users), 21_u128);
+    assert_eq!(tc.cw20.balance(&app, &insurance), 6_u128);
+    assert_eq!(tc.cw20.balance(&app, tc.vault_router.addr()), 0_u128);
+}
+
+#[test]
+fn test_finalize_slashing_idle_sweep_slashed() {
+    let (mut app, tc) = TestContracts::init();
+
+    let owner = app.api().addr_make("owner");
+    let operator = app.api().addr_make("operator");
+    let service = app.api().addr_make("service");
+    let staker = app.api().addr_make("staker");
+    let recipient = app.api().addr_make("recipient");
+
+    // register operator + service, whitelist vaults and stake funds
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterAsOperator {
+            metadata: Metadata {
+                name: None,
+                uri: None,
+            },
+        };
+        tc.registry.execute(&mut app, &operator, msg).unwrap();
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterAsService {
+            metadata: Metadata {
+                name: None,
+                uri: None,
+            },
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        for vault in [tc.bank_vault.addr(), tc.cw20_vault.addr()] {
+            let msg = &ExecuteMsg::SetVault {
+                vault: vault.to_string(),
+                whitelisted: true,
+            };
+            tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+        }
+
+        tc.cw20
+            .increase_allowance(&mut app, &staker, tc.cw20_vault.addr(), 300_u128);
+        tc.cw20.fund(&mut app, &staker, 300_u128);
+        let msg = bvs_vault_cw20::msg::ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(300),
+        });
+        tc.cw20_vault.execute(&mut app, &staker, &msg).unwrap();
+
+        app.send_tokens(owner.clone(), staker.clone(), &coins(333, "denom"))
+            .unwrap();
+        let msg = bvs_vault_bank::msg::ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(333),
+        });
+        tc.bank_vault
+            .execute_with_funds(&mut app, &staker, &msg, coins(333, "denom"))
+            .unwrap();
+    }
+
+    // no destination: the slashed collateral is held (idle) by the router
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::EnableSlashing {
+            slashing_parameters: SlashingParameters {
+                destination: None,
+                max_slashing_bips: 5000,
+                resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterOperatorToService {
+            operator: operator.to_string(),
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
+            service: service.to_string(),
+            operator: None,
+        };
+        tc.registry.execute(&mut app, &operator, msg).unwrap();
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    // slash 10%: 33 of the bank vault, 30 of the cw20 vault
+    let msg = &ExecuteMsg::RequestSlashing(RequestSlashingPayload {
+        operator: operator.to_string(),
+        bips: 1000,
+        timestamp: app.block_info().time,
+        metadata: SlashingMetadata {
+            reason: "test".to_string(),
+        },
+        category: None,
+        vaults: None,
+    });
+    let res = tc.vault_router.execute(&mut app, &service, msg).unwrap();
+    let RequestSlashingResponse(slashing_request_id) = res.data.unwrap().into();
+
+    app.update_block(|block| {
+        block.height += 10;
+        block.time = block.time.plus_seconds(100);
+    });
+
+    let msg = ExecuteMsg::LockSlashing(slashing_request_id.clone());
+    tc.vault_router.execute(&mut app, &service, &msg).unwrap();
+
+    // pass the guardrail proposal
+    {
+        let msg = bvs_guardrail::msg::ExecuteMsg::Propose {
+            slashing_request_id: slashing_request_id.clone(),
+            reason: "test slashing".to_string(),
+        };
+        tc.guardrail.execute(&mut app, &owner, &msg).unwrap();
+
+        let msg = bvs_guardrail::msg::ExecuteMsg::Vote {
+            slashing_request_id: slashing_request_id.clone(),
+            vote: cw3::Vote::Yes,
+        };
+        for voter in ["voter1", "voter2", "voter3"] {
+            let voter = app.api().addr_make(voter);
+            tc.guardrail.execute(&mut app, &voter, &msg).unwrap();
+        }
+    }
+
+    let msg = ExecuteMsg::FinalizeSlashing(slashing_request_id.clone());
+    let res = tc.vault_router.execute(&mut app, &service, &msg).unwrap();
+
+    let idle_events: Vec<_> = res
+        .events
+        .into_iter()
+        .filter(|e| e.ty == "wasm-IdleSlashed")
+        .collect();
+    assert_eq!(idle_events.len(), 2);
+
+    assert_eq!(
+        app.wrap()
+            .query_balance(tc.vault_router.addr(), "denom")
+            .unwrap()
+            .amount,
+        Uint128::new(33)
+    );
+    assert_eq!(tc.cw20.balance(&app, tc.vault_router.addr()), 30_u128);
+
+    let bank_asset_id = tc
+        .bank_vault
+        .query::<bvs_vault_base::msg::VaultInfoResponse>(
+            &app,
+            &bvs_vault_bank::msg::QueryMsg::VaultInfo {},
+        )
+        .unwrap()
+        .asset_id;
+    let cw20_asset_id = tc
+        .cw20_vault
+        .query::<bvs_vault_base::msg::VaultInfoResponse>(
+            &app,
+            &bvs_vault_cw20::msg::QueryMsg::VaultInfo {},
+        )
+        .unwrap()
+        .asset_id;
+    let mut expected = vec![
+        IdleSlashedBalance {
+            asset_id: bank_asset_id,
+            amount: Uint128::new(33),
+        },
+        IdleSlashedBalance {
+            asset_id: cw20_asset_id,
+            amount: Uint128::new(30),
+        },
+    ];
+    expected.sort_by(|a, b| a.asset_id.cmp(&b.asset_id));
+
+    let res: IdleSlashedResponse = tc
+        .vault_router
+        .query(&app, &QueryMsg::IdleSlashedBalances {})
+        .unwrap();
+    assert_eq!(res, IdleSlashedResponse(expected.clone()));
+    let res: IdleSlashedResponse = tc
+        .vault_router
+        .query(&app, &QueryMsg::IdleSlashed(slashing_request_id.clone()))
+        .unwrap();
+    assert_eq!(res, IdleSlashedResponse(expected));
+
+    // only the owner or the guardrail can sweep
+    let msg = ExecuteMsg::SweepSlashed {
+        slashing_request_id: slashing_request_id.clone(),
+        recipient: recipient.to_string(),
+    };
+    let err = tc
+        .vault_router
+        .execute(&mut app, &service, &msg)
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::Ownership(OwnershipError::Unauthorized).to_string()
+    );
+
+    let res = tc
+        .vault_router
+        .execute(&mut app, tc.guardrail.addr(), &msg)
+        .unwrap();
+    let swept: Vec<_> = res
+        .events
+        .into_iter()
+        .filter(|e| e.ty == "wasm-SweepSlashed")
+        .collect();
+    assert_eq!(swept.len(), 2);
+
+    assert_eq!(
+        app.wrap()
+            .query_balance(&recipient, "denom")
+            .unwrap()
+            .amount,
+        Uint128::new(33)
+    );
+    assert_eq!(tc.cw20.balance(&app, &recipient), 30_u128);
+    assert_eq!(
+        app.wrap()
+            .query_balance(tc.vault_router.addr(), "denom")
+            .unwrap()
+            .amount,
+        Uint128::zero()
+    );
+    assert_eq!(tc.cw20.balance(&app, tc.vault_router.addr()), 0_u128);
+
+    let res: IdleSlashedResponse = tc
+        .vault_router
+        .query(&app, &QueryMsg::IdleSlashedBalances {})
+        .unwrap();
+    assert_eq!(res, IdleSlashedResponse(vec![]));
+
+    // nothing left to sweep
+    tc.vault_router.execute(&mut app, &owner, &msg).unwrap_err();
+}
+
+#[test]
+fn stake_allocation_lock_slashing() {
+    let (mut app, tc) = TestContracts::init();
+
+    let owner = app.api().addr_make("owner");
+    let operator = app.api().addr_make("operator");
+    let service = app.api().addr_make("service");
+    let staker = app.api().addr_make("staker");
+
+    // register operator + service, whitelist vaults and stake funds
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterAsOperator {
+            metadata: Metadata {
+                name: None,
+                uri: None,
+            },
+        };
+        tc.registry.execute(&mut app, &operator, msg).unwrap();
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterAsService {
+            metadata: Metadata {
+                name: None,
+                uri: None,
+            },
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        for vault in [tc.bank_vault.addr(), tc.cw20_vault.addr()] {
+            let msg = &ExecuteMsg::SetVault {
+                vault: vault.to_string(),
+                whitelisted: true,
+            };
+            tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+        }
+
+        tc.cw20
+            .increase_allowance(&mut app, &staker, tc.cw20_vault.addr(), 300_u128);
+        tc.cw20.fund(&mut app, &staker, 300_u128);
+        let msg = bvs_vault_cw20::msg::ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(300),
+        });
+        tc.cw20_vault.execute(&mut app, &staker, &msg).unwrap();
+
+        app.send_tokens(owner.clone(), staker.clone(), &coins(333, "denom"))
+            .unwrap();
+        let msg = bvs_vault_bank::msg::ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(333),
+        });
+        tc.bank_vault
+            .execute_with_funds(&mut app, &staker, &msg, coins(333, "denom"))
+            .unwrap();
+    }
+
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::EnableSlashing {
+            slashing_parameters: SlashingParameters {
+                destination: None,
+                max_slashing_bips: 5000,
+                resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterOperatorToService {
+            operator: operator.to_string(),
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
+            service: service.to_string(),
+            operator: None,
+        };
+        tc.registry.execute(&mut app, &operator, msg).unwrap();
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    // only the operator of the vault can allocate
+    let msg = &ExecuteMsg::SetAllocation {
+        vault: tc.bank_vault.addr().to_string(),
+        service: service.to_string(),
+        bips: 5000,
+    };
+    let err = tc
+        .vault_router
+        .execute(&mut app, &service, msg)
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::Unauthorized {
+            msg: "Only the operator of the vault can allocate its stake".to_string()
+        }
+        .to_string()
+    );
+
+    // only to an active service
+    let other_service = app.api().addr_make("other_service");
+    let err = tc
+        .vault_router
+        .execute(
+            &mut app,
+            &operator,
+            &ExecuteMsg::SetAllocation {
+                vault: tc.bank_vault.addr().to_string(),
+                service: other_service.to_string(),
+                bips: 5000,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidAllocation {
+            msg: "Service and Operator must be active to allocate".to_string()
+        }
+        .to_string()
+    );
+
+    // allocate 50% of the bank vault to the service, nothing of the cw20 vault
+    // the first allocation of the operator is effective after its withdrawal lock period (7 days),
+    // longer than the allocation delay (1 day)
+    let res = tc.vault_router.execute(&mut app, &operator, msg).unwrap();
+    let effective_time = app.block_info().time.plus_seconds(7 * DAYS);
+    assert_eq!(
+        res.events[1],
+        Event::new("wasm-SetAllocation")
+            .add_attribute("_contract_address", tc.vault_router.addr.as_str())
+            .add_attribute("operator", operator.as_str())
+            .add_attribute("vault", tc.bank_vault.addr.as_str())
+            .add_attribute("service", service.as_str())
+            .add_attribute("prev_bips", "0")
+            .add_attribute("new_bips", "5000")
+            .add_attribute("effective_time", effective_time.seconds().to_string())
+    );
+
+    let res: AllocationResponse = tc
+        .vault_router
+        .query(
+            &app,
+            &QueryMsg::Allocation {
+                vault: tc.bank_vault.addr().to_string(),
+                service: service.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        AllocationResponse(Allocation {
+            bips: 0,
+            pending: Some(PendingAllocation {
+                bips: 5000,
+                effective_time,
+            }),
+        })
+    );
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(7 * DAYS);
+    });
+
+    let res: VaultAllocationsResponse = tc
+        .vault_router
+        .query(
+            &app,
+            &QueryMsg::VaultAllocations {
+                vault: tc.bank_vault.addr().to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        VaultAllocationsResponse {
+            total_assets: Uint128::new(333),
+            effective_time: Some(effective_time),
+            allocated_bips: 5000,
+            allocated_assets: Uint128::new(166),
+            free_assets: Uint128::new(167),
+            allocations: vec![VaultAllocation {
+                service: service.clone(),
+                allocation: Allocation {
+                    bips: 5000,
+                    pending: None,
+                },
+            }],
+        }
+    );
+
+    // slash 10% of the allocated stake: 16 (of 166.5) of the bank vault, 0 of the cw20 vault
+    let msg = &ExecuteMsg::RequestSlashing(RequestSlashingPayload {
+        operator: operator.to_string(),
+        bips: 1000,
+        timestamp: app.block_info().time,
+        metadata: SlashingMetadata {
+            reason: "test".to_string(),
+        },
+        category: None,
+        vaults: None,
+    });
+    let res = tc.vault_router.execute(&mut app, &service, msg).unwrap();
+    let RequestSlashingResponse(slashing_request_id) = res.data.unwrap().into();
+
+    // only the redemptions of the vaults with stake allocated to the service are frozen
+    let is_slash_pending = |app: &App, vault: &Addr| -> bool {
+        tc.vault_router
+            .query(
+                app,
+                &QueryMsg::IsSlashPending {
+                    vault: vault.to_string(),
+                },
+            )
+            .unwrap()
+    };
+    assert!(is_slash_pending(&app, tc.bank_vault.addr()));
+    assert!(!is_slash_pending(&app, tc.cw20_vault.addr()));
+
+    // deallocate, still slashable (encumbered) until the withdrawal lock period
+    let msg = &ExecuteMsg::SetAllocation {
+        vault: tc.bank_vault.addr().to_string(),
+        service: service.to_string(),
+        bips: 0,
+    };
+    tc.vault_router.execute(&mut app, &operator, msg).unwrap();
+    let err = tc
+        .vault_router
+        .execute(&mut app, &operator, msg)
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidAllocation {
+            msg: "Pending allocation must be effective before it can be changed".to_string()
+        }
+        .to_string()
+    );
+    let res: VaultAllocationsResponse = tc
+        .vault_router
+        .query(
+            &app,
+            &QueryMsg::VaultAllocations {
+                vault: tc.bank_vault.addr().to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.allocated_bips, 5000);
+
+    app.update_block(|block| {
+        block.height += 10;
+        block.time = block.time.plus_seconds(100);
+    });
+
+    // the allocation at the incident timestamp is slashed
+    let msg = ExecuteMsg::LockSlashing(slashing_request_id.clone());
+    tc.vault_router.execute(&mut app, &service, &msg).unwrap();
+
+    let msg = QueryMsg::SlashingLocked {
+        slashing_request_id: slashing_request_id.clone(),
+    };
+    let locked: SlashingLockedResponse = tc.vault_router.query(&app, &msg).unwrap();
+    let locked_amount = |vault: &Addr| {
+        locked
+            .0
+            .iter()
+            .find(|l| l.vault == vault)
+            .map(|l| l.amount)
+            .unwrap()
+    };
+    assert_eq!(locked_amount(tc.bank_vault.addr()), Uint128::new(16));
+    assert_eq!(locked_amount(tc.cw20_vault.addr()), Uint128::zero());
+}
+
+#[test]
+fn stake_allocation_first_allocation_effective_time() {
+    let (mut app, tc) = TestContracts::init();
+
+    let owner = app.api().addr_make("owner");
+    let operator = app.api().addr_make("operator");
+    let service = app.api().addr_make("service");
+    let staker = app.api().addr_make("staker");
+
+    // register operator + service, whitelist vaults and stake funds
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterAsOperator {
+            metadata: Metadata {
+                name: None,
+                uri: None,
+            },
+        };
+        tc.registry.execute(&mut app, &operator, msg).unwrap();
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterAsService {
+            metadata: Metadata {
+                name: None,
+                uri: None,
+            },
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        for vault in [tc.bank_vault.addr(), tc.cw20_vault.addr()] {
+            let msg = &ExecuteMsg::SetVault {
+                vault: vault.to_string(),
+                whitelisted: true,
+            };
+            tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+        }
+
+        tc.cw20
+            .increase_allowance(&mut app, &staker, tc.cw20_vault.addr(), 300_u128);
+        tc.cw20.fund(&mut app, &staker, 300_u128);
+        let msg = bvs_vault_cw20::msg::ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(300),
+        });
+        tc.cw20_vault.execute(&mut app, &staker, &msg).unwrap();
+
+        app.send_tokens(owner.clone(), staker.clone(), &coins(333, "denom"))
+            .unwrap();
+        let msg = bvs_vault_bank::msg::ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(333),
+        });
+        tc.bank_vault
+            .execute_with_funds(&mut app, &staker, &msg, coins(333, "denom"))
+            .unwrap();
+    }
+
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::EnableSlashing {
+            slashing_parameters: SlashingParameters {
+                destination: None,
+                max_slashing_bips: 5000,
+                resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterOperatorToService {
+            operator: operator.to_string(),
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
+            service: service.to_string(),
+            operator: None,
+        };
+        tc.registry.execute(&mut app, &operator, msg).unwrap();
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    // the first allocation of the operator: 50% of the bank vault,
+    // effective after the longer of the allocation delay (1 day) and its withdrawal lock period (7 days)
+    let first_time = app.block_info().time;
+    let msg = &ExecuteMsg::SetAllocation {
+        vault: tc.bank_vault.addr().to_string(),
+        service: service.to_string(),
+        bips: 5000,
+    };
+    tc.vault_router.execute(&mut app, &operator, msg).unwrap();
+
+    // the next allocations are effective after the allocation delay
+    let msg = &ExecuteMsg::SetAllocation {
+        vault: tc.cw20_vault.addr().to_string(),
+        service: service.to_string(),
+        bips: 1000,
+    };
+    let res = tc.vault_router.execute(&mut app, &operator, msg).unwrap();
+    let effective_time = app.block_info().time.plus_seconds(DAYS);
+    assert_eq!(
+        res.events[1],
+        Event::new("wasm-SetAllocation")
+            .add_attribute("_contract_address", tc.vault_router.addr.as_str())
+            .add_attribute("operator", operator.as_str())
+            .add_attribute("vault", tc.cw20_vault.addr.as_str())
+            .add_attribute("service", service.as_str())
+            .add_attribute("prev_bips", "0")
+            .add_attribute("new_bips", "1000")
+            .add_attribute("effective_time", effective_time.seconds().to_string())
+    );
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(2 * DAYS);
+    });
+
+    let res: VaultAllocationsResponse = tc
+        .vault_router
+        .query(
+            &app,
+            &QueryMsg::VaultAllocations {
+                vault: tc.cw20_vault.addr().to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.effective_time, Some(first_time.plus_seconds(7 * DAYS)));
+    assert_eq!(res.allocated_bips, 1000);
+
+    // the operator's allocations are not effective yet, the full stake is slashable
+    let msg = &ExecuteMsg::RequestSlashing(RequestSlashingPayload {
+        operator: operator.to_string(),
+        bips: 1000,
+        timestamp: app.block_info().time,
+        metadata: SlashingMetadata {
+            reason: "test".to_string(),
+        },
+        category: None,
+        vaults: None,
+    });
+    let res = tc.vault_router.execute(&mut app, &service, msg).unwrap();
+    let RequestSlashingResponse(slashing_request_id) = res.data.unwrap().into();
 
-        assert_eq!(router_bank_balance.amount, coin(2, "denom")); // 1% of 200
+    app.update_block(|block| {
+        block.height += 10;
+        block.time = block.time.plus_seconds(100);
+    });
 
-        let router_cw20_balance = tc.cw20.balance(&app, tc.vault_router.addr());
+    let msg = ExecuteMsg::LockSlashing(slashing_request_id.clone());
+    tc.vault_router.execute(&mut app, &service, &msg).unwrap();
 
-        // due to the decimal
-        assert_eq!(router_cw20_balance, 3_u128); // 1% of 300
-    }
+    let msg = QueryMsg::SlashingLocked {
+        slashing_request_id: slashing_request_id.clone(),
+    };
+    let locked: SlashingLockedResponse = tc.vault_router.query(&app, &msg).unwrap();
+    let locked_amount = |vault: &Addr| {
+        locked
+            .0
+            .iter()
+            .find(|l| l.vault == vault)
+            .map(|l| l.amount)
+            .unwrap()
+    };
+    assert_eq!(locked_amount(tc.bank_vault.addr()), Uint128::new(33));
+    assert_eq!(locked_amount(tc.cw20_vault.addr()), Uint128::new(30));
 }
 
 #[test]
-fn test_finalize_slashing() {
+fn slash_pending_selected_vaults_and_cooldown() {
     let (mut app, tc) = TestContracts::init();
 
+    let owner = app.api().addr_make("owner");
     let operator = app.api().addr_make("operator");
     let service = app.api().addr_make("service");
+    let staker = app.api().addr_make("staker");
 
-    // register operator + service
+    // register operator + service, whitelist vaults and stake funds
     {
-        tc.registry
-            .execute(
-                &mut app,
-                &operator,
-                &bvs_registry::msg::ExecuteMsg::RegisterAsOperator {
-                    metadata: Metadata {
-                        name: Some("operator".to_string()),
-                        uri: None,
-                    },
-                },
-            )
-            .expect("failed to register operator");
-
-        tc.registry
-            .execute(
-                &mut app,
-                &service,
-                &bvs_registry::msg::ExecuteMsg::RegisterAsService {
-                    metadata: Metadata {
-                        name: Some("service".to_string()),
-                        uri: None,
-                    },
-                },
-            )
-            .expect("failed to register service");
-
-        let owner = app.api().addr_make("owner");
-
-        let msg = &ExecuteMsg::SetVault {
-            vault: tc.bank_vault.addr().to_string(),
-            whitelisted: true,
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterAsOperator {
+            metadata: Metadata {
+                name: None,
+                uri: None,
+            },
         };
+        tc.registry.execute(&mut app, &operator, msg).unwrap();
 
-        tc.vault_router.execute(&mut app, &owner, msg).unwrap();
-
-        let msg = &ExecuteMsg::SetVault {
-            vault: tc.cw20_vault.addr().to_string(),
-            whitelisted: true,
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterAsService {
+            metadata: Metadata {
+                name: None,
+                uri: None,
+            },
         };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        for vault in [tc.bank_vault.addr(), tc.cw20_vault.addr()] {
+            let msg = &ExecuteMsg::SetVault {
+                vault: vault.to_string(),
+                whitelisted: true,
+            };
+            tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+        }
 
-        tc.vault_router.execute(&mut app, &owner, msg).unwrap();
-    }
-
-    // stake funds
-    {
-        let owner = app.api().addr_make("owner");
-        let denom = "denom";
-
-        let staker = app.api().addr_make("staker");
         tc.cw20
             .increase_allowance(&mut app, &staker, tc.cw20_vault.addr(), 300_u128);
         tc.cw20.fund(&mut app, &staker, 300_u128);
-        // staker stake 300 cw20 tokens
         let msg = bvs_vault_cw20::msg::ExecuteMsg::DepositFor(RecipientAmount {
             recipient: staker.clone(),
-            amount: Uint128::new(300_u128),
+            amount: Uint128::new(300),
         });
         tc.cw20_vault.execute(&mut app, &staker, &msg).unwrap();
 
-        // fund the staker with some initial bank tokens
-        app.send_tokens(owner.clone(), staker.clone(), &coins(1_000_000_000, denom))
+        app.send_tokens(owner.clone(), staker.clone(), &coins(333, "denom"))
             .unwrap();
-
-        // staker stake 200 bank token
         let msg = bvs_vault_bank::msg::ExecuteMsg::DepositFor(RecipientAmount {
             recipient: staker.clone(),
-            amount: Uint128::new(200),
+            amount: Uint128::new(333),
         });
         tc.bank_vault
-            .execute_with_funds(&mut app, &staker, &msg, coins(200, denom))
+            .execute_with_funds(&mut app, &staker, &msg, coins(333, "denom"))
             .unwrap();
     }
 
-    // service enable slashing
     {
         let msg = &bvs_registry::msg::ExecuteMsg::EnableSlashing {
             slashing_parameters: SlashingParameters {
-                destination: Some(service.clone()),
+                destination: None,
                 max_slashing_bips: 5000,
                 resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
             },
         };
-        tc.registry
-            .execute(&mut app, &service, msg)
-            .expect("failed to enable slashing");
+        tc.registry.execute(&mut app, &service, msg).unwrap();
     }
 
     app.update_block(|block| {
@@ -1347,21 +3802,17 @@ fn test_finalize_slashing() {
         block.time = block.time.plus_seconds(10);
     });
 
-    // register operator to service and vice versa for active status
     {
         let msg = &bvs_registry::msg::ExecuteMsg::RegisterOperatorToService {
             operator: operator.to_string(),
         };
-        tc.registry
-            .execute(&mut app, &service, msg)
-            .expect("failed to register operator to service");
+        tc.registry.execute(&mut app, &service, msg).unwrap();
 
         let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
             service: service.to_string(),
+            operator: None,
         };
-        tc.registry
-            .execute(&mut app, &operator, msg)
-            .expect("failed to register service to operator");
+        tc.registry.execute(&mut app, &operator, msg).unwrap();
     }
 
     app.update_block(|block| {
@@ -1369,153 +3820,282 @@ fn test_finalize_slashing() {
         block.time = block.time.plus_seconds(10);
     });
 
-    // service request slashing
-    let slashing_request_payload = RequestSlashingPayload {
-        operator: operator.to_string(),
-        bips: 100,
-        timestamp: app.block_info().time,
-        metadata: SlashingMetadata {
-            reason: "test".to_string(),
-        },
+    let timestamp = app.block_info().time;
+    let request = |vaults: Option<Vec<&Addr>>| {
+        ExecuteMsg::RequestSlashing(RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 1000,
+            timestamp,
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: vaults.map(|vaults| vaults.into_iter().map(Addr::to_string).collect()),
+        })
+    };
+    let is_slash_pending = |app: &App, vault: &Addr| -> bool {
+        tc.vault_router
+            .query(
+                app,
+                &QueryMsg::IsSlashPending {
+                    vault: vault.to_string(),
+                },
+            )
+            .unwrap()
     };
 
-    let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
-    tc.vault_router.execute(&mut app, &service, msg).unwrap();
+    // only the redemptions of the selected vaults are frozen
+    let msg = request(Some(vec![tc.cw20_vault.addr()]));
+    let res = tc.vault_router.execute(&mut app, &service, &msg).unwrap();
+    let RequestSlashingResponse(slashing_request_id) = res.data.unwrap().into();
+    assert!(!is_slash_pending(&app, tc.bank_vault.addr()));
+    assert!(is_slash_pending(&app, tc.cw20_vault.addr()));
+
+    tc.vault_router
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::CancelSlashing(slashing_request_id),
+        )
+        .unwrap();
+    assert!(!is_slash_pending(&app, tc.cw20_vault.addr()));
 
-    // query slashing request id
-    let msg = QueryMsg::SlashingRequestId {
-        service: service.to_string(),
-        operator: operator.to_string(),
-    };
-    let SlashingRequestIdResponse(slashing_request_id) =
-        tc.vault_router.query(&mut app, &msg).unwrap();
-    let slashing_request_id = slashing_request_id.unwrap();
+    // the canceled slashing request starts the cooldown of the service against the operator
+    let msg = request(None);
+    let err = tc
+        .vault_router
+        .execute(&mut app, &service, &msg)
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidSlashingRequest {
+            msg: "Cannot process new request during the cooldown of the previous slashing request"
+                .to_string()
+        }
+        .to_string()
+    );
 
-    // pass the resolution window
     app.update_block(|block| {
-        block.height += 10;
-        block.time = block.time.plus_seconds(100);
+        block.height += 1;
+        block.time = block.time.plus_seconds(DAYS);
     });
 
-    // Lock slashing
-    let msg = ExecuteMsg::LockSlashing(slashing_request_id.clone());
+    // without selected vaults, every vault of the operator is frozen
+    let msg = request(None);
     tc.vault_router.execute(&mut app, &service, &msg).unwrap();
+    assert!(is_slash_pending(&app, tc.bank_vault.addr()));
+    assert!(is_slash_pending(&app, tc.cw20_vault.addr()));
+}
+
+#[test]
+fn asset_selective_slashing() {
+    let (mut app, tc) = TestContracts::init();
 
     let owner = app.api().addr_make("owner");
+    let operator = app.api().addr_make("operator");
+    let service = app.api().addr_make("service");
+    let staker = app.api().addr_make("staker");
 
-    // Create a proposal for the slashing request by owner
-    let proposal_msg = bvs_guardrail::msg::ExecuteMsg::Propose {
-        slashing_request_id: slashing_request_id.clone(),
-        reason: "test slashing".to_string(),
-    };
-    tc.guardrail
-        .execute(&mut app, &owner, &proposal_msg)
-        .unwrap();
+    // register operator + service, whitelist vaults and stake funds
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterAsOperator {
+            metadata: Metadata {
+                name: None,
+                uri: None,
+            },
+        };
+        tc.registry.execute(&mut app, &operator, msg).unwrap();
 
-    // Have voters vote on the proposal
-    let voter1 = app.api().addr_make("voter1");
-    let voter2 = app.api().addr_make("voter2");
-    let voter3 = app.api().addr_make("voter3");
-    let voter4 = app.api().addr_make("voter4");
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterAsService {
+            metadata: Metadata {
+                name: None,
+                uri: None,
+            },
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        for vault in [tc.bank_vault.addr(), tc.cw20_vault.addr()] {
+            let msg = &ExecuteMsg::SetVault {
+                vault: vault.to_string(),
+                whitelisted: true,
+            };
+            tc.vault_router.execute(&mut app, &owner, msg).unwrap();
+        }
 
-    // Voter1 votes "yes"
-    let vote_yes_msg = bvs_guardrail::msg::ExecuteMsg::Vote {
-        slashing_request_id: slashing_request_id.clone(),
-        vote: cw3::Vote::Yes,
-    };
-    tc.guardrail
-        .execute(&mut app, &voter1, &vote_yes_msg)
-        .unwrap();
+        tc.cw20
+            .increase_allowance(&mut app, &staker, tc.cw20_vault.addr(), 300_u128);
+        tc.cw20.fund(&mut app, &staker, 300_u128);
+        let msg = bvs_vault_cw20::msg::ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(300),
+        });
+        tc.cw20_vault.execute(&mut app, &staker, &msg).unwrap();
 
-    // Voter2 votes "yes"
-    tc.guardrail
-        .execute(&mut app, &voter2, &vote_yes_msg)
-        .unwrap();
+        app.send_tokens(owner.clone(), staker.clone(), &coins(333, "denom"))
+            .unwrap();
+        let msg = bvs_vault_bank::msg::ExecuteMsg::DepositFor(RecipientAmount {
+            recipient: staker.clone(),
+            amount: Uint128::new(333),
+        });
+        tc.bank_vault
+            .execute_with_funds(&mut app, &staker, &msg, coins(333, "denom"))
+            .unwrap();
+    }
 
-    // Voter3 votes "no"
-    let vote_no_msg = bvs_guardrail::msg::ExecuteMsg::Vote {
-        slashing_request_id: slashing_request_id.clone(),
-        vote: cw3::Vote::No,
-    };
-    tc.guardrail
-        .execute(&mut app, &voter3, &vote_no_msg)
-        .unwrap();
+    // the service only accepts the bank vault asset
+    let bank_asset_id = tc
+        .bank_vault
+        .query::<bvs_vault_base::msg::VaultInfoResponse>(
+            &app,
+            &bvs_vault_bank::msg::QueryMsg::VaultInfo {},
+        )
+        .unwrap()
+        .asset_id;
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::EnableSlashing {
+            slashing_parameters: SlashingParameters {
+                destination: None,
+                max_slashing_bips: 5000,
+                resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![bank_asset_id],
+            },
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+    }
 
-    // Voter4 votes "yes" to pass the proposal
-    let vote_msg = bvs_guardrail::msg::ExecuteMsg::Vote {
-        slashing_request_id: slashing_request_id.clone(),
-        vote: cw3::Vote::Yes,
-    };
-    tc.guardrail.execute(&mut app, &voter4, &vote_msg).unwrap();
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
 
-    // Check the proposal status is Passed
-    let proposal_status = bvs_guardrail::msg::QueryMsg::ProposalBySlashingRequestId {
-        slashing_request_id: slashing_request_id.clone(),
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterOperatorToService {
+            operator: operator.to_string(),
+        };
+        tc.registry.execute(&mut app, &service, msg).unwrap();
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
+            service: service.to_string(),
+            operator: None,
+        };
+        tc.registry.execute(&mut app, &operator, msg).unwrap();
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    let timestamp = app.block_info().time;
+    let request = |vaults: Option<Vec<&Addr>>| {
+        ExecuteMsg::RequestSlashing(RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 1000,
+            timestamp,
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: vaults.map(|vaults| vaults.into_iter().map(Addr::to_string).collect()),
+        })
     };
-    let proposal: cw3::ProposalResponse = tc.guardrail.query(&app, &proposal_status).unwrap();
-    assert_eq!(proposal.status, cw3::Status::Passed);
+    let not_operator_vault = app.api().addr_make("not_operator_vault");
+    for (vaults, msg) in [
+        (vec![], "Vaults must not be empty"),
+        (
+            vec![tc.bank_vault.addr(), tc.bank_vault.addr()],
+            "Duplicate vault",
+        ),
+        (
+            vec![&not_operator_vault],
+            "Vault does not belong to the operator",
+        ),
+        (
+            vec![tc.cw20_vault.addr()],
+            "Vault asset is not accepted by the service",
+        ),
+    ] {
+        let err = tc
+            .vault_router
+            .execute(&mut app, &service, &request(Some(vaults)))
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidSlashingRequest {
+                msg: msg.to_string()
+            }
+            .to_string()
+        );
+    }
 
-    // Finalize slashing
-    let msg = ExecuteMsg::FinalizeSlashing(slashing_request_id.clone());
+    // the selected vaults are part of the request
+    let msg = request(Some(vec![tc.bank_vault.addr()]));
     let res = tc.vault_router.execute(&mut app, &service, &msg).unwrap();
-
-    // Verify the slashing is finalized
-    // Check events
+    let RequestSlashingResponse(slashing_request_id) = res.data.unwrap().into();
+    let res: SlashingRequestResponse = tc
+        .vault_router
+        .query(
+            &app,
+            &QueryMsg::SlashingRequest(slashing_request_id.clone()),
+        )
+        .unwrap();
     assert_eq!(
-        res.events,
-        vec![
-            Event::new("execute").add_attribute("_contract_address", tc.vault_router.addr.as_str()),
-            Event::new("wasm-FinalizeSlashing")
-                .add_attribute("_contract_address", tc.vault_router.addr.as_str())
-                .add_attribute("service", service.to_string())
-                .add_attribute("operator", operator)
-                .add_attribute("slashing_request_id", slashing_request_id.to_string())
-                .add_attribute("destination", service.to_string())
-                .add_attribute("affected_vaults", 2.to_string()),
-            Event::new("transfer")
-                .add_attribute("recipient", service.to_string())
-                .add_attribute("sender", tc.vault_router.addr().to_string())
-                .add_attribute("amount", "2denom"),
-            Event::new("execute").add_attribute("_contract_address", tc.cw20.addr().to_string()),
-            Event::new("wasm")
-                .add_attribute("_contract_address", tc.cw20.addr().to_string())
-                .add_attribute("action", "transfer")
-                .add_attribute("from", tc.vault_router.addr().to_string())
-                .add_attribute("to", service.to_string())
-                .add_attribute("amount", "3"),
-        ]
+        res.0.unwrap().request.vaults,
+        Some(vec![tc.bank_vault.addr.to_string()])
     );
+    tc.vault_router
+        .execute(
+            &mut app,
+            &service,
+            &ExecuteMsg::CancelSlashing(slashing_request_id),
+        )
+        .unwrap();
 
-    // Check that the slashed assets are transferred to the destination
-    let query = BankQuery::Balance {
-        address: service.to_string(),
-        denom: "denom".to_string(),
-    };
-    let service_bank_balance: BalanceResponse =
-        app.wrap().query(&QueryRequest::Bank(query)).unwrap();
-    assert_eq!(service_bank_balance.amount, coin(2, "denom")); // 1% of 200
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(DAYS);
+    });
 
-    let service_cw20_balance = tc.cw20.balance(&app, &service);
-    assert_eq!(service_cw20_balance, 3_u128); // 1% of 300
+    // without selected vaults, only the vaults of the accepted assets are slashed
+    let msg = request(None);
+    let res = tc.vault_router.execute(&mut app, &service, &msg).unwrap();
+    let RequestSlashingResponse(slashing_request_id) = res.data.unwrap().into();
 
-    // Check that the router no longer has the slashed assets
-    let query = BankQuery::Balance {
-        address: tc.vault_router.addr().to_string(),
-        denom: "denom".to_string(),
+    // and only their redemptions are frozen
+    let is_slash_pending = |app: &App, vault: &Addr| -> bool {
+        tc.vault_router
+            .query(
+                app,
+                &QueryMsg::IsSlashPending {
+                    vault: vault.to_string(),
+                },
+            )
+            .unwrap()
     };
-    let router_bank_balance: BalanceResponse =
-        app.wrap().query(&QueryRequest::Bank(query)).unwrap();
-    assert_eq!(router_bank_balance.amount, coin(0, "denom"));
+    assert!(is_slash_pending(&app, tc.bank_vault.addr()));
+    assert!(!is_slash_pending(&app, tc.cw20_vault.addr()));
 
-    let router_cw20_balance = tc.cw20.balance(&app, tc.vault_router.addr());
-    assert_eq!(router_cw20_balance, 0_u128);
+    app.update_block(|block| {
+        block.height += 10;
+        block.time = block.time.plus_seconds(100);
+    });
 
-    // Check that the slashing request is marked as finalized
-    let msg = QueryMsg::SlashingRequest(slashing_request_id.clone());
-    let SlashingRequestResponse(slashing_request) = tc.vault_router.query(&mut app, &msg).unwrap();
-    let slashing_request = slashing_request.unwrap();
+    let msg = ExecuteMsg::LockSlashing(slashing_request_id.clone());
+    tc.vault_router.execute(&mut app, &service, &msg).unwrap();
 
-    assert_eq!(slashing_request.status, SlashingRequestStatus::Finalized);
+    let msg = QueryMsg::SlashingLocked {
+        slashing_request_id,
+    };
+    let locked: SlashingLockedResponse = tc.vault_router.query(&app, &msg).unwrap();
+    assert_eq!(
+        locked,
+        SlashingLockedResponse(vec![SlashingLockedResponseItem {
+            vault: tc.bank_vault.addr.clone(),
+            amount: Uint128::new(33),
+        }])
+    );
+    assert_eq!(tc.cw20.balance(&app, tc.vault_router.addr()), 0_u128);
 }
 
 #[test]
@@ -1608,6 +4188,8 @@ fn test_finalize_slashing_negative() {
                 destination: Some(service.clone()),
                 max_slashing_bips: 5000,
                 resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
             },
         };
         tc.registry
@@ -1631,6 +4213,7 @@ fn test_finalize_slashing_negative() {
 
         let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
             service: service.to_string(),
+            operator: None,
         };
         tc.registry
             .execute(&mut app, &operator, msg)
@@ -1650,6 +4233,8 @@ fn test_finalize_slashing_negative() {
         metadata: SlashingMetadata {
             reason: "test".to_string(),
         },
+        category: None,
+        vaults: None,
     };
 
     let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
@@ -1927,6 +4512,8 @@ fn test_slash_locking_negative() {
                 destination: Some(service.clone()),
                 max_slashing_bips: 5000,
                 resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
             },
         };
         tc.registry
@@ -1950,6 +4537,7 @@ fn test_slash_locking_negative() {
 
         let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
             service: service.to_string(),
+            operator: None,
         };
         tc.registry
             .execute(&mut app, &operator, msg)
@@ -1970,6 +4558,8 @@ fn test_slash_locking_negative() {
             metadata: SlashingMetadata {
                 reason: "expired test".to_string(),
             },
+            category: None,
+            vaults: None,
         };
 
         let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
@@ -2002,9 +4592,10 @@ fn test_slash_locking_negative() {
         );
     }
 
+    // past the cooldown of the service against the operator after the expired slash
     app.update_block(|block| {
         block.height += 1;
-        block.time = block.time.plus_seconds(10);
+        block.time = block.time.plus_seconds(DAYS);
     });
 
     // service request slashing
@@ -2015,6 +4606,8 @@ fn test_slash_locking_negative() {
         metadata: SlashingMetadata {
             reason: "test".to_string(),
         },
+        category: None,
+        vaults: None,
     };
 
     let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
@@ -2136,6 +4729,8 @@ fn test_slash_locking_negative() {
             metadata: SlashingMetadata {
                 reason: "test".to_string(),
             },
+            category: None,
+            vaults: None,
         };
 
         let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
@@ -2154,6 +4749,178 @@ fn test_slash_locking_negative() {
     }
 }
 
+#[test]
+fn expire_slashing_successful() {
+    let (mut app, tc) = TestContracts::init();
+
+    let operator = app.api().addr_make("operator");
+    let service = app.api().addr_make("service");
+
+    // register operator + service
+    {
+        tc.registry
+            .execute(
+                &mut app,
+                &operator,
+                &bvs_registry::msg::ExecuteMsg::RegisterAsOperator {
+                    metadata: Metadata {
+                        name: Some("operator".to_string()),
+                        uri: None,
+                    },
+                },
+            )
+            .expect("failed to register operator");
+
+        tc.registry
+            .execute(
+                &mut app,
+                &service,
+                &bvs_registry::msg::ExecuteMsg::RegisterAsService {
+                    metadata: Metadata {
+                        name: Some("service".to_string()),
+                        uri: None,
+                    },
+                },
+            )
+            .expect("failed to register service");
+    }
+
+    // service enable slashing
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::EnableSlashing {
+            slashing_parameters: SlashingParameters {
+                destination: Some(service.clone()),
+                max_slashing_bips: 5000,
+                resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
+            },
+        };
+        tc.registry
+            .execute(&mut app, &service, msg)
+            .expect("failed to enable slashing");
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    // register operator to service for active status
+    {
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterOperatorToService {
+            operator: operator.to_string(),
+        };
+        tc.registry
+            .execute(&mut app, &service, msg)
+            .expect("failed to register operator to service");
+
+        let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
+            service: service.to_string(),
+            operator: None,
+        };
+        tc.registry
+            .execute(&mut app, &operator, msg)
+            .expect("failed to register service to operator");
+    }
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(10);
+    });
+
+    // request slashing
+    {
+        // service request slashing
+        let slashing_request_payload = RequestSlashingPayload {
+            operator: operator.to_string(),
+            bips: 100,
+            timestamp: app.block_info().time,
+            metadata: SlashingMetadata {
+                reason: "test".to_string(),
+            },
+            category: None,
+            vaults: None,
+        };
+
+        let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
+        tc.vault_router
+            .execute(&mut app, &service, msg)
+            .expect("failed to request slashing");
+    }
+
+    // query slashing request id
+    let msg = QueryMsg::SlashingRequestId {
+        service: service.to_string(),
+        operator: operator.to_string(),
+    };
+    let slashing_request_id_response: SlashingRequestIdResponse =
+        tc.vault_router.query(&app, &msg).unwrap();
+    let slashing_request_id = slashing_request_id_response.0.unwrap();
+
+    // cannot expire before the request expiry
+    let anyone = app.api().addr_make("anyone");
+    let msg = &ExecuteMsg::ExpireSlashing(slashing_request_id.clone());
+    let err = tc.vault_router.execute(&mut app, &anyone, msg).unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidSlashingRequest {
+            msg: "Slashing request has not expired yet".to_string(),
+        }
+        .to_string()
+    );
+
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(100).plus_seconds(7 * DAYS + 1);
+    });
+
+    // anyone can expire the slashing request
+    let response = tc.vault_router.execute(&mut app, &anyone, msg).unwrap();
+    assert_eq!(
+        response.events,
+        vec![
+            Event::new("execute").add_attribute("_contract_address", tc.vault_router.addr.as_str()),
+            Event::new("wasm-ExpireSlashing")
+                .add_attribute("_contract_address", tc.vault_router.addr.as_str())
+                .add_attribute("sender", anyone.to_string())
+                .add_attribute("service", service.to_string())
+                .add_attribute("operator", operator.to_string())
+                .add_attribute("slashing_request_id", slashing_request_id.to_string())
+        ]
+    );
+
+    // query slashing request
+    let msg = QueryMsg::SlashingRequest(slashing_request_id.clone());
+    let slashing_request_response: SlashingRequestResponse =
+        tc.vault_router.query(&app, &msg).unwrap();
+    let slashing_request = slashing_request_response.0.unwrap();
+    assert_eq!(slashing_request.status, SlashingRequestStatus::Expired);
+
+    // the pending slashing request id is cleared
+    let msg = QueryMsg::SlashingRequestId {
+        service: service.to_string(),
+        operator: operator.to_string(),
+    };
+    let slashing_request_id_response: SlashingRequestIdResponse =
+        tc.vault_router.query(&app, &msg).unwrap();
+    assert_eq!(
+        slashing_request_id_response,
+        SlashingRequestIdResponse(None)
+    );
+
+    // cannot expire twice
+    let msg = &ExecuteMsg::ExpireSlashing(slashing_request_id);
+    let err = tc.vault_router.execute(&mut app, &anyone, msg).unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidSlashingRequest {
+            msg: "Only slashing requests in pending status can be expired".to_string(),
+        }
+        .to_string()
+    );
+}
+
 #[test]
 fn cancel_slashing_successful() {
     let (mut app, tc) = TestContracts::init();
@@ -2197,6 +4964,8 @@ fn cancel_slashing_successful() {
                 destination: Some(service.clone()),
                 max_slashing_bips: 5000,
                 resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
             },
         };
         tc.registry
@@ -2220,6 +4989,7 @@ fn cancel_slashing_successful() {
 
         let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
             service: service.to_string(),
+            operator: None,
         };
         tc.registry
             .execute(&mut app, &operator, msg)
@@ -2241,6 +5011,8 @@ fn cancel_slashing_successful() {
             metadata: SlashingMetadata {
                 reason: "test".to_string(),
             },
+            category: None,
+            vaults: None,
         };
 
         let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
@@ -2326,6 +5098,8 @@ fn cancel_slashing_error_cases() {
                 destination: Some(service.clone()),
                 max_slashing_bips: 5000,
                 resolution_window: 100,
+                destinations: vec![],
+                asset_ids: vec![],
             },
         };
         tc.registry
@@ -2349,6 +5123,7 @@ fn cancel_slashing_error_cases() {
 
         let msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
             service: service.to_string(),
+            operator: None,
         };
         tc.registry
             .execute(&mut app, &operator, msg)
@@ -2391,6 +5166,8 @@ fn cancel_slashing_error_cases() {
             metadata: SlashingMetadata {
                 reason: "test".to_string(),
             },
+            category: None,
+            vaults: None,
         };
 
         let msg = &ExecuteMsg::RequestSlashing(slashing_request_payload.clone());
@@ -2428,3 +5205,126 @@ fn cancel_slashing_error_cases() {
         );
     }
 }
+
+#[test]
+fn registry_admission_min_stake() {
+    let (mut app, tc) = TestContracts::init();
+    let owner = app.api().addr_make("owner");
+    let operator = app.api().addr_make("operator");
+    let service = app.api().addr_make("service");
+    let staker = app.api().addr_make("staker");
+
+    tc.vault_router
+        .execute(
+            &mut app,
+            &owner,
+            &ExecuteMsg::SetVault {
+                vault: tc.bank_vault.addr().to_string(),
+                whitelisted: true,
+            },
+        )
+        .unwrap();
+    tc.registry
+        .execute(
+            &mut app,
+            &owner,
+            &bvs_registry::msg::ExecuteMsg::SetRouter {
+                router: tc.vault_router.addr().to_string(),
+            },
+        )
+        .unwrap();
+
+    tc.registry
+        .execute(
+            &mut app,
+            &service,
+            &bvs_registry::msg::ExecuteMsg::RegisterAsService {
+                metadata: Metadata {
+                    name: None,
+                    uri: None,
+                },
+            },
+        )
+        .unwrap();
+    tc.registry
+        .execute(
+            &mut app,
+            &operator,
+            &bvs_registry::msg::ExecuteMsg::RegisterAsOperator {
+                metadata: Metadata {
+                    name: None,
+                    uri: None,
+                },
+            },
+        )
+        .unwrap();
+
+    let vault_info: bvs_vault_base::msg::VaultInfoResponse = tc
+        .bank_vault
+        .query(&app, &bvs_vault_bank::msg::QueryMsg::VaultInfo {})
+        .unwrap();
+    tc.registry
+        .execute(
+            &mut app,
+            &service,
+            &bvs_registry::msg::ExecuteMsg::SetAdmissionPolicy {
+                policy: bvs_registry::AdmissionPolicy::MinStake {
+                    asset_id: vault_info.asset_id,
+                    amount: Uint128::new(200),
+                },
+            },
+        )
+        .unwrap();
+
+    let register_msg = &bvs_registry::msg::ExecuteMsg::RegisterServiceToOperator {
+        service: service.to_string(),
+        operator: None,
+    };
+    let status_msg = &bvs_registry::msg::QueryMsg::Status {
+        service: service.to_string(),
+        operator: operator.to_string(),
+        timestamp: None,
+    };
+
+    // below the min stake, the registration is pending the manual approval of the service
+    tc.registry
+        .execute(&mut app, &operator, register_msg)
+        .unwrap();
+    let status: bvs_registry::msg::StatusResponse = tc.registry.query(&app, status_msg).unwrap();
+    assert_eq!(
+        status,
+        bvs_registry::RegistrationStatus::OperatorRegistered.into()
+    );
+    tc.registry
+        .execute(
+            &mut app,
+            &operator,
+            &bvs_registry::msg::ExecuteMsg::DeregisterServiceFromOperator {
+                service: service.to_string(),
+                operator: None,
+            },
+        )
+        .unwrap();
+
+    // staker deposits into the operator vault to reach the min stake
+    app.send_tokens(owner.clone(), staker.clone(), &coins(200, "denom"))
+        .unwrap();
+    tc.bank_vault
+        .execute_with_funds(
+            &mut app,
+            &staker,
+            &bvs_vault_bank::msg::ExecuteMsg::DepositFor(RecipientAmount {
+                recipient: staker.clone(),
+                amount: Uint128::new(200),
+            }),
+            coins(200, "denom"),
+        )
+        .unwrap();
+
+    tc.registry
+        .execute(&mut app, &operator, register_msg)
+        .unwrap();
+
+    let status: bvs_registry::msg::StatusResponse = tc.registry.query(&app, status_msg).unwrap();
+    assert_eq!(status, bvs_registry::RegistrationStatus::Active.into());
+}
@@ -0,0 +1,131 @@
+// This file was automatically generated from oracle/schema.json.
+// DO NOT MODIFY IT BY HAND.
+
+package oracle
+
+type InstantiateMsg struct {
+	Owner string `json:"owner"`
+}
+
+// ExecuteMsg SetPriceSource sets (or replaces) the price source of the asset. Any
+// previously pushed price of the asset is removed. Only the `owner` can call this message.
+//
+// ExecuteMsg RemovePriceSource removes the price source (and pushed price) of the asset.
+// Only the `owner` can call this message.
+//
+// ExecuteMsg PushPrice pushes the USD price of one whole unit of the asset, published at
+// the current block time. The asset must have a [`PriceFeed::Push`] price source. Only the
+// `owner` can call this message.
+//
+// ExecuteMsg TransferOwnership See [`bvs_library::ownership::transfer_ownership`] for more
+// information on this field
+type ExecuteMsg struct {
+	SetPriceSource    *SetPriceSource    `json:"set_price_source,omitempty"`
+	RemovePriceSource *RemovePriceSource `json:"remove_price_source,omitempty"`
+	PushPrice         *PushPrice         `json:"push_price,omitempty"`
+	TransferOwnership *TransferOwnership `json:"transfer_ownership,omitempty"`
+}
+
+type PushPrice struct {
+	AssetID string `json:"asset_id"`
+	Price   string `json:"price"`
+}
+
+type RemovePriceSource struct {
+	AssetID string `json:"asset_id"`
+}
+
+type SetPriceSource struct {
+	// Asset identifier, using the CAIP-19 format.
+	AssetID string                    `json:"asset_id"`
+	Source  SetPriceSourcePriceSource `json:"source"`
+}
+
+// The price source of an asset.
+type SetPriceSourcePriceSource struct {
+	// The number of decimals of the asset, used to convert an amount (in the smallest unit) to
+	// whole units. Max 18.
+	Decimals int64 `json:"decimals"`
+	// Where the price is read from.
+	Feed PriceFeed `json:"feed"`
+}
+
+// The price is queried from a Pyth-style price feed contract, see [`crate::pyth`] for the
+// interface.
+type PriceFeedClass struct {
+	Pyth FeedPyth `json:"pyth"`
+}
+
+type FeedPyth struct {
+	Contract string `json:"contract"`
+	// The price feed id (32 bytes).
+	PriceID string `json:"price_id"`
+}
+
+type TransferOwnership struct {
+	NewOwner string `json:"new_owner"`
+}
+
+// QueryMsg PriceSource: returns the price source of the asset.
+//
+// QueryMsg Price: returns the USD price of one whole unit of the asset. Errors if the asset
+// has no price source, or the price is older than the max price age.
+//
+// QueryMsg UsdValue: returns the USD value of the `amount` (in the smallest unit) of the
+// asset. Returns `None` if the asset has no price source, errors if the price is older than
+// the max price age.
+type QueryMsg struct {
+	PriceSource *string   `json:"price_source,omitempty"`
+	Price       *string   `json:"price,omitempty"`
+	USDValue    *USDValue `json:"usd_value,omitempty"`
+}
+
+type USDValue struct {
+	Amount  string `json:"amount"`
+	AssetID string `json:"asset_id"`
+}
+
+type PriceResponse struct {
+	// The USD price of one whole unit of the asset.
+	Price string `json:"price"`
+	// The time the price was published at.
+	PublishTime string `json:"publish_time"`
+}
+
+// The price source of an asset.
+type PriceSource struct {
+	// The number of decimals of the asset, used to convert an amount (in the smallest unit) to
+	// whole units. Max 18.
+	Decimals int64 `json:"decimals"`
+	// Where the price is read from.
+	Feed PurplePriceFeed `json:"feed"`
+}
+
+// The price is queried from a Pyth-style price feed contract, see [`crate::pyth`] for the
+// interface.
+type PurplePriceFeedClass struct {
+	Pyth PurplePyth `json:"pyth"`
+}
+
+type PurplePyth struct {
+	Contract string `json:"contract"`
+	// The price feed id (32 bytes).
+	PriceID string `json:"price_id"`
+}
+
+// The price is pushed by the `owner` with [`ExecuteMsg::PushPrice`].
+type PriceFeedEnum string
+
+const (
+	Push PriceFeedEnum = "push"
+)
+
+type PriceFeed struct {
+	PriceFeedClass *PriceFeedClass
+	Enum           *PriceFeedEnum
+}
+
+type PurplePriceFeed struct {
+	PurplePriceFeedClass *PurplePriceFeedClass
+	Enum                 *PriceFeedEnum
+}
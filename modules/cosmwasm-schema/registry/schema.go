@@ -3,14 +3,48 @@
 
 package registry
 
+type ActiveCountResponse int64
+
+type CommissionResponse int64
+
+type CommissionChangeDelayResponse int64
+
+type DeregistrationDelayResponse int64
+
 type IsOperatorResponse bool
 
 type IsOperatorActiveResponse bool
 
+type IsOperatorAllowlistedResponse bool
+
 type IsOperatorOptedInToSlashingResponse bool
 
 type IsServiceResponse bool
 
+type OperatorListResponse []string
+
+type RegistrationListResponse []Registration
+
+type PendingExitListResponse []PendingExit
+
+type ServiceListResponse []string
+
+type SlashingCategoryListResponse []SlashingCategory
+
+type MaxActiveRelationshipsResponse int64
+
+type OperatorManagerListResponse []OperatorManager
+
+type RegistrationHistoryResponse []RegistrationStatusChange
+
+type ServiceHooksEnabledResponse bool
+
+type SlashingOptInHistoryResponse []SlashingOptIn
+
+type SlashingParametersHistoryResponse []SlashingParametersChange
+
+type SlashingParametersNoticePeriodResponse int64
+
 type StatusResponse int64
 
 type InstantiateMsg struct {
@@ -18,40 +52,285 @@ type InstantiateMsg struct {
 	Pauser string `json:"pauser"`
 }
 
+// ExecuteMsg UpdateOperatorMetadataAsManager updates the metadata of the `operator`,
+// info.sender must be a manager of the operator with [`ManagerPermission::Metadata`].
+//
+// ExecuteMsg RegisterServiceToOperator registers the service to the operator. The
+// `operator` defaults to info.sender, else info.sender must be a manager of the operator
+// with [`ManagerPermission::Registration`]. A manager without
+// [`ManagerPermission::SlashingOptIn`] activating the registration doesn't opt in the
+// operator to the slashing of the service.
+//
+// ExecuteMsg DeregisterServiceFromOperator deregisters the service from the operator. The
+// `operator` defaults to info.sender, else info.sender must be a manager of the operator
+// with [`ManagerPermission::Registration`].
+//
+// ExecuteMsg RegisterOperatorsToService registers multiple operators to the service
+// (info.sender) atomically, as if [`ExecuteMsg::RegisterOperatorToService`] was sent for
+// each operator. The batch size is bounded by
+// [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+//
+// ExecuteMsg DeregisterOperatorsFromService deregisters multiple operators from the service
+// (info.sender) atomically, as if [`ExecuteMsg::DeregisterOperatorFromService`] was sent
+// for each operator. The batch size is bounded by
+// [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+//
+// ExecuteMsg RegisterServicesToOperator registers multiple services to the operator
+// atomically, as if [`ExecuteMsg::RegisterServiceToOperator`] was sent for each service.
+// The batch size is bounded by [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+//
+// ExecuteMsg DeregisterServicesFromOperator deregisters multiple services from the operator
+// atomically, as if [`ExecuteMsg::DeregisterServiceFromOperator`] was sent for each
+// service. The batch size is bounded by [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+//
+// ExecuteMsg FinalizeDeregistration finalizes an [`RegistrationStatus::Exiting`]
+// registration after the deregistration delay has passed, setting it to
+// [`RegistrationStatus::Inactive`]. Can be called by either the operator, a manager of the
+// operator with [`ManagerPermission::Registration`], or the service.
+//
+// ExecuteMsg DeregisterAsService retires the service (info.sender) and deregisters up to
+// `limit` of its relationships, must be called until the sunset is completed. On the first
+// call, the service is retired and its slashing (including categories) is disabled. Active
+// registrations become [`RegistrationStatus::Exiting`], pending registrations become
+// [`RegistrationStatus::Inactive`]. The `limit` must be greater than zero, the max `limit`
+// is [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+//
+// ExecuteMsg DeregisterAsOperator retires the operator (info.sender) and deregisters up to
+// `limit` of its relationships, must be called until the sunset is completed. Active
+// registrations become [`RegistrationStatus::Exiting`], pending registrations become
+// [`RegistrationStatus::Inactive`]. The `limit` must be greater than zero, the max `limit`
+// is [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+//
+// ExecuteMsg EnableSlashing enables slashing for the service (info.sender) immediately. Can
+// only be called when slashing is not enabled, use
+// [`ExecuteMsg::ProposeSlashingParameters`] to update enabled slashing parameters.
+//
+// ExecuteMsg ProposeSlashingParameters proposes new slashing parameters for the service
+// (info.sender), replacing any pending slashing parameters. The new slashing parameters can
+// be activated with [`ExecuteMsg::ActivateSlashingParameters`] after the slashing
+// parameters notice period.
+//
+// ExecuteMsg ActivateSlashingParameters activates the pending slashing parameters of the
+// service after the notice period has passed. Can be called by anyone. If the new slashing
+// parameters are stricter (higher `max_slashing_bips` or shorter `resolution_window`),
+// operators have to opt in again, else their opt-in is kept.
+//
+// ExecuteMsg SetSlashingCategory adds a named slashing category for the service
+// (info.sender) with its own slashing parameters, effective immediately. Operators must opt
+// in to each category with [`ExecuteMsg::OperatorOptInToSlashingCategory`]. Can only be
+// called when the category is not enabled, use [`ExecuteMsg::ProposeSlashingCategory`] to
+// update an enabled category.
+//
+// ExecuteMsg ProposeSlashingCategory proposes new slashing parameters for the enabled named
+// slashing category of the service (info.sender), replacing any pending slashing
+// parameters. The new slashing parameters can be activated with
+// [`ExecuteMsg::ActivateSlashingCategory`] after the slashing parameters notice period.
+//
+// ExecuteMsg ActivateSlashingCategory activates the pending slashing parameters of the
+// named slashing category of the service after the notice period has passed. Can be called
+// by anyone. If the new slashing parameters are stricter, operators have to opt in to the
+// category again, else their opt-in is kept.
+//
+// ExecuteMsg RemoveSlashingCategory removes the named slashing category of the service
+// (info.sender), resetting the category opt-in of all operators.
+//
+// ExecuteMsg OperatorOptInToSlashingCategory opts in the operator to the named slashing
+// category of the service. The operator and service must have an active registration. The
+// `operator` defaults to info.sender, else info.sender must be a manager of the operator
+// with [`ManagerPermission::SlashingOptIn`].
+//
+// ExecuteMsg OperatorOptInToSlashing opts in the operator to the slashing of the service.
+// The `operator` defaults to info.sender, else info.sender must be a manager of the
+// operator with [`ManagerPermission::SlashingOptIn`].
+//
+// ExecuteMsg SetMaxActiveRelationships sets the global default max number of active
+// relationships an operator (to services) and a service (to operators) can have. Existing
+// active relationships above the new max are not affected, but no new relationship can
+// become active until the count is below the max. Only the `owner` can call this message.
+//
+// ExecuteMsg SetMaxActiveRelationshipsForOperator overrides the global default for the
+// operator. Set `max` to `None` to remove the override. Only the `owner` can call this
+// message.
+//
+// ExecuteMsg SetMaxActiveRelationshipsForService overrides the global default for the
+// service. Set `max` to `None` to remove the override. Only the `owner` can call this
+// message.
+//
+// ExecuteMsg SetDeregistrationDelay sets the delay (in seconds) an exiting registration
+// must wait before it can be finalized with [`ExecuteMsg::FinalizeDeregistration`]. When
+// the router is set (see [`ExecuteMsg::SetRouter`]), an operator whose withdrawal lock
+// period in the `vault-router` is longer waits for its lock period instead, so it stays
+// slashable for the whole look-back of the router. Registrations that are already exiting
+// keep their exit timestamp. Only the `owner` can call this message.
+//
+// ExecuteMsg SetSlashingParametersNoticePeriod sets the notice period (in seconds) between
+// proposing new slashing parameters and activating them. Slashing parameters that are
+// already pending keep their effective timestamp. Only the `owner` can call this message.
+//
+// ExecuteMsg ProposeCommission proposes the commission (in bips) the operator (info.sender)
+// takes from the rewards of the service, replacing any pending commission. The commission
+// can be activated with [`ExecuteMsg::ActivateCommission`] after the commission change
+// delay. The operator and service must be registered.
+//
+// ExecuteMsg ActivateCommission activates the pending commission of the operator for the
+// service after the commission change delay has passed. Can be called by anyone.
+//
+// ExecuteMsg SetCommissionChangeDelay sets the delay (in seconds) between proposing a new
+// commission and activating it. Commissions that are already pending keep their effective
+// timestamp. Only the `owner` can call this message.
+//
+// ExecuteMsg SetRouter sets the `vault-router` contract address, used to query the vaults
+// of an operator for [`AdmissionPolicy::MinStake`] and the withdrawal lock period of an
+// exiting operator (see [`ExecuteMsg::SetDeregistrationDelay`]). Only the `owner` can call
+// this message.
+//
+// ExecuteMsg SetAdmissionPolicy sets the [`AdmissionPolicy`] of the service (info.sender).
+// When an admitted operator registers the service with
+// [`ExecuteMsg::RegisterServiceToOperator`], the registration becomes
+// [`RegistrationStatus::Active`] immediately. With [`AdmissionPolicy::Manual`] (default),
+// or for an operator that isn't admitted, the service must register the operator.
+//
+// ExecuteMsg SetAdmissionAllowlist adds (`allowed: true`) or removes (`allowed: false`) the
+// operators to the admission allowlist of the service (info.sender), used by
+// [`AdmissionPolicy::Allowlist`]. The batch size is bounded by
+// [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+//
+// ExecuteMsg SetOperatorKey sets (or rotates) the public signing key of the operator
+// (info.sender) for the service, one key per `key_type`. The service can verify
+// attestations signed by the operator with the key valid at the time of the attestation,
+// see [`QueryMsg::OperatorKey`]. The operator and service must be registered.
+//
+// The `signature` proves possession of the key: the signature made with the key of
+// `sha256(service || operator || key_type || public_key)`, the addresses as UTF-8 strings
+// and the key type as a single byte (`Secp256k1` = 1, `Ed25519` = 2). 64 bytes (r || s) for
+// `Secp256k1`, 64 bytes for `Ed25519`.
+//
+// ExecuteMsg RemoveOperatorKey removes the public signing key of the operator (info.sender)
+// of the `key_type` for the service.
+//
+// ExecuteMsg SetOperatorManager authorizes the `manager` to act on behalf of the operator
+// (info.sender) with the `permissions`, replacing any previous permissions. Only the
+// operator can set its managers.
+//
+// ExecuteMsg RevokeOperatorManager revokes all permissions of the `manager` of the operator
+// (info.sender).
+//
+// ExecuteMsg EnableServiceHooks opts in the service (info.sender) to receive
+// [`RegistryHookExecuteMsg`] hooks from the registry whenever a relationship of the service
+// changes. The service must be a contract that handles [`RegistryHookExecuteMsg`].
+//
+// ExecuteMsg DisableServiceHooks stops the hooks to the service (info.sender).
 type ExecuteMsg struct {
-	RegisterAsService             *RegisterAsService             `json:"register_as_service,omitempty"`
-	UpdateServiceMetadata         *Metadata                      `json:"update_service_metadata,omitempty"`
-	RegisterAsOperator            *RegisterAsOperator            `json:"register_as_operator,omitempty"`
-	UpdateOperatorMetadata        *Metadata                      `json:"update_operator_metadata,omitempty"`
-	RegisterOperatorToService     *RegisterOperatorToService     `json:"register_operator_to_service,omitempty"`
-	DeregisterOperatorFromService *DeregisterOperatorFromService `json:"deregister_operator_from_service,omitempty"`
-	RegisterServiceToOperator     *RegisterServiceToOperator     `json:"register_service_to_operator,omitempty"`
-	DeregisterServiceFromOperator *DeregisterServiceFromOperator `json:"deregister_service_from_operator,omitempty"`
-	EnableSlashing                *EnableSlashing                `json:"enable_slashing,omitempty"`
-	DisableSlashing               *DisableSlashing               `json:"disable_slashing,omitempty"`
-	OperatorOptInToSlashing       *OperatorOptInToSlashing       `json:"operator_opt_in_to_slashing,omitempty"`
-	TransferOwnership             *TransferOwnership             `json:"transfer_ownership,omitempty"`
+	RegisterAsService                    *RegisterAsService                    `json:"register_as_service,omitempty"`
+	UpdateServiceMetadata                *RegisterAsServiceMetadata            `json:"update_service_metadata,omitempty"`
+	RegisterAsOperator                   *RegisterAsOperator                   `json:"register_as_operator,omitempty"`
+	UpdateOperatorMetadata               *RegisterAsServiceMetadata            `json:"update_operator_metadata,omitempty"`
+	UpdateOperatorMetadataAsManager      *UpdateOperatorMetadataAsManager      `json:"update_operator_metadata_as_manager,omitempty"`
+	RegisterOperatorToService            *RegisterOperatorToService            `json:"register_operator_to_service,omitempty"`
+	DeregisterOperatorFromService        *DeregisterOperatorFromService        `json:"deregister_operator_from_service,omitempty"`
+	RegisterServiceToOperator            *RegisterServiceToOperator            `json:"register_service_to_operator,omitempty"`
+	DeregisterServiceFromOperator        *DeregisterServiceFromOperator        `json:"deregister_service_from_operator,omitempty"`
+	RegisterOperatorsToService           *RegisterOperatorsToService           `json:"register_operators_to_service,omitempty"`
+	DeregisterOperatorsFromService       *DeregisterOperatorsFromService       `json:"deregister_operators_from_service,omitempty"`
+	RegisterServicesToOperator           *RegisterServicesToOperator           `json:"register_services_to_operator,omitempty"`
+	DeregisterServicesFromOperator       *DeregisterServicesFromOperator       `json:"deregister_services_from_operator,omitempty"`
+	FinalizeDeregistration               *FinalizeDeregistration               `json:"finalize_deregistration,omitempty"`
+	DeregisterAsService                  *DeregisterAsService                  `json:"deregister_as_service,omitempty"`
+	DeregisterAsOperator                 *DeregisterAsOperator                 `json:"deregister_as_operator,omitempty"`
+	EnableSlashing                       *EnableSlashing                       `json:"enable_slashing,omitempty"`
+	DisableSlashing                      *DisableSlashing                      `json:"disable_slashing,omitempty"`
+	ProposeSlashingParameters            *ProposeSlashingParameters            `json:"propose_slashing_parameters,omitempty"`
+	ActivateSlashingParameters           *ActivateSlashingParameters           `json:"activate_slashing_parameters,omitempty"`
+	SetSlashingCategory                  *SetSlashingCategory                  `json:"set_slashing_category,omitempty"`
+	ProposeSlashingCategory              *ProposeSlashingCategory              `json:"propose_slashing_category,omitempty"`
+	ActivateSlashingCategory             *ActivateSlashingCategory             `json:"activate_slashing_category,omitempty"`
+	RemoveSlashingCategory               *RemoveSlashingCategory               `json:"remove_slashing_category,omitempty"`
+	OperatorOptInToSlashingCategory      *OperatorOptInToSlashingCategory      `json:"operator_opt_in_to_slashing_category,omitempty"`
+	OperatorOptInToSlashing              *OperatorOptInToSlashing              `json:"operator_opt_in_to_slashing,omitempty"`
+	TransferOwnership                    *TransferOwnership                    `json:"transfer_ownership,omitempty"`
+	SetMaxActiveRelationships            *SetMaxActiveRelationships            `json:"set_max_active_relationships,omitempty"`
+	SetMaxActiveRelationshipsForOperator *SetMaxActiveRelationshipsForOperator `json:"set_max_active_relationships_for_operator,omitempty"`
+	SetMaxActiveRelationshipsForService  *SetMaxActiveRelationshipsForService  `json:"set_max_active_relationships_for_service,omitempty"`
+	SetDeregistrationDelay               *SetDeregistrationDelay               `json:"set_deregistration_delay,omitempty"`
+	SetSlashingParametersNoticePeriod    *SetSlashingParametersNoticePeriod    `json:"set_slashing_parameters_notice_period,omitempty"`
+	ProposeCommission                    *ProposeCommission                    `json:"propose_commission,omitempty"`
+	ActivateCommission                   *ActivateCommission                   `json:"activate_commission,omitempty"`
+	SetCommissionChangeDelay             *SetCommissionChangeDelay             `json:"set_commission_change_delay,omitempty"`
+	SetRouter                            *SetRouter                            `json:"set_router,omitempty"`
+	SetAdmissionPolicy                   *SetAdmissionPolicy                   `json:"set_admission_policy,omitempty"`
+	SetAdmissionAllowlist                *SetAdmissionAllowlist                `json:"set_admission_allowlist,omitempty"`
+	SetOperatorKey                       *SetOperatorKey                       `json:"set_operator_key,omitempty"`
+	RemoveOperatorKey                    *RemoveOperatorKey                    `json:"remove_operator_key,omitempty"`
+	SetOperatorManager                   *SetOperatorManager                   `json:"set_operator_manager,omitempty"`
+	RevokeOperatorManager                *RevokeOperatorManager                `json:"revoke_operator_manager,omitempty"`
+	EnableServiceHooks                   *EnableServiceHooks                   `json:"enable_service_hooks,omitempty"`
+	DisableServiceHooks                  *DisableServiceHooks                  `json:"disable_service_hooks,omitempty"`
+}
+
+type ActivateCommission struct {
+	Operator string `json:"operator"`
+	Service  string `json:"service"`
+}
+
+type ActivateSlashingCategory struct {
+	Category string `json:"category"`
+	Service  string `json:"service"`
+}
+
+type ActivateSlashingParameters struct {
+	Service string `json:"service"`
+}
+
+type DeregisterAsOperator struct {
+	Limit *int64 `json:"limit"`
+}
+
+type DeregisterAsService struct {
+	Limit *int64 `json:"limit"`
 }
 
 type DeregisterOperatorFromService struct {
 	Operator string `json:"operator"`
 }
 
+type DeregisterOperatorsFromService struct {
+	Operators []string `json:"operators"`
+}
+
 type DeregisterServiceFromOperator struct {
-	Service string `json:"service"`
+	Operator *string `json:"operator"`
+	Service  string  `json:"service"`
+}
+
+type DeregisterServicesFromOperator struct {
+	Operator *string  `json:"operator"`
+	Services []string `json:"services"`
+}
+
+type DisableServiceHooks struct {
 }
 
 type DisableSlashing struct {
 }
 
+type EnableServiceHooks struct {
+}
+
 type EnableSlashing struct {
 	SlashingParameters EnableSlashingSlashingParameters `json:"slashing_parameters"`
 }
 
 type EnableSlashingSlashingParameters struct {
+	// The asset ids (CAIP-19) of the vaults the service accepts as security and can slash.
+	// Empty, indicates that the vaults of every asset can be slashed. Max 10 asset ids.
+	AssetIds []string `json:"asset_ids,omitempty"`
 	// The address to which the slashed funds will be sent after the slashing is finalized.
 	// None, indicates that the slashed funds will be burned.
 	Destination *string `json:"destination"`
+	// The weighted destinations the slashed funds are split between after the slashing is
+	// finalized. If not empty, `destination` must be `None`, and the `bips` of the destinations
+	// must add up to 10_000 (100%). Max 10 destinations.
+	Destinations []WeightedDestination `json:"destinations,omitempty"`
 	// The maximum percentage of the operator's total stake that can be slashed. The value is
 	// represented in bips (basis points), where 100 bips = 1%. And the value must be between 0
 	// and 10_000 (inclusive).
@@ -63,37 +342,173 @@ type EnableSlashingSlashingParameters struct {
 	ResolutionWindow int64 `json:"resolution_window"`
 }
 
+type WeightedDestination struct {
+	// The share of the slashed funds in bips (basis points), where 100 bips = 1%. Must be
+	// greater than zero.
+	Bips      int64             `json:"bips"`
+	Recipient SlashingRecipient `json:"recipient"`
+}
+
+// The slashed funds are sent to the address.
+type SlashingRecipientClass struct {
+	Address string `json:"address"`
+}
+
+type FinalizeDeregistration struct {
+	Operator string `json:"operator"`
+	Service  string `json:"service"`
+}
+
 type OperatorOptInToSlashing struct {
-	Service string `json:"service"`
+	Operator *string `json:"operator"`
+	Service  string  `json:"service"`
+}
+
+type OperatorOptInToSlashingCategory struct {
+	Category string  `json:"category"`
+	Operator *string `json:"operator"`
+	Service  string  `json:"service"`
+}
+
+type ProposeCommission struct {
+	CommissionBips int64  `json:"commission_bips"`
+	Service        string `json:"service"`
+}
+
+type ProposeSlashingCategory struct {
+	Category           string                           `json:"category"`
+	SlashingParameters EnableSlashingSlashingParameters `json:"slashing_parameters"`
+}
+
+type ProposeSlashingParameters struct {
+	SlashingParameters EnableSlashingSlashingParameters `json:"slashing_parameters"`
 }
 
 type RegisterAsOperator struct {
-	Metadata Metadata `json:"metadata"`
+	Metadata RegisterAsServiceMetadata `json:"metadata"`
 }
 
-// metadata is emitted as events and not stored on-chain.
-type Metadata struct {
+// Metadata of a service or operator. The latest metadata is stored on-chain (with history)
+// and also emitted as `MetadataUpdated` events. When updating the metadata, fields that are
+// `None` are not updated.
+type RegisterAsServiceMetadata struct {
 	Name *string `json:"name"`
 	URI  *string `json:"uri"`
 }
 
 type RegisterAsService struct {
-	Metadata Metadata `json:"metadata"`
+	Metadata RegisterAsServiceMetadata `json:"metadata"`
 }
 
 type RegisterOperatorToService struct {
 	Operator string `json:"operator"`
 }
 
+type RegisterOperatorsToService struct {
+	Operators []string `json:"operators"`
+}
+
 type RegisterServiceToOperator struct {
+	Operator *string `json:"operator"`
+	Service  string  `json:"service"`
+}
+
+type RegisterServicesToOperator struct {
+	Operator *string  `json:"operator"`
+	Services []string `json:"services"`
+}
+
+type RemoveOperatorKey struct {
+	KeyType KeyType `json:"key_type"`
+	Service string  `json:"service"`
+}
+
+type RemoveSlashingCategory struct {
+	Category string `json:"category"`
+}
+
+type RevokeOperatorManager struct {
+	Manager string `json:"manager"`
+}
+
+type SetAdmissionAllowlist struct {
+	Allowed   bool     `json:"allowed"`
+	Operators []string `json:"operators"`
+}
+
+type SetAdmissionPolicy struct {
+	Policy AdmissionPolicy `json:"policy"`
+}
+
+// Only operators with at least `amount` of the `asset_id` (CAIP-19) in their whitelisted
+// vaults of the `vault-router` are admitted.
+type AdmissionPolicyClass struct {
+	MinStake PolicyMinStake `json:"min_stake"`
+}
+
+type PolicyMinStake struct {
+	Amount  string `json:"amount"`
+	AssetID string `json:"asset_id"`
+}
+
+type SetCommissionChangeDelay struct {
+	Delay int64 `json:"delay"`
+}
+
+type SetDeregistrationDelay struct {
+	Delay int64 `json:"delay"`
+}
+
+type SetMaxActiveRelationships struct {
+	PerOperator int64 `json:"per_operator"`
+	PerService  int64 `json:"per_service"`
+}
+
+type SetMaxActiveRelationshipsForOperator struct {
+	Max      *int64 `json:"max"`
+	Operator string `json:"operator"`
+}
+
+type SetMaxActiveRelationshipsForService struct {
+	Max     *int64 `json:"max"`
 	Service string `json:"service"`
 }
 
+type SetOperatorKey struct {
+	KeyType   KeyType `json:"key_type"`
+	PublicKey string  `json:"public_key"`
+	Service   string  `json:"service"`
+	Signature string  `json:"signature"`
+}
+
+type SetOperatorManager struct {
+	Manager     string              `json:"manager"`
+	Permissions []ManagerPermission `json:"permissions"`
+}
+
+type SetRouter struct {
+	Router string `json:"router"`
+}
+
+type SetSlashingCategory struct {
+	Category           string                           `json:"category"`
+	SlashingParameters EnableSlashingSlashingParameters `json:"slashing_parameters"`
+}
+
+type SetSlashingParametersNoticePeriod struct {
+	NoticePeriod int64 `json:"notice_period"`
+}
+
 type TransferOwnership struct {
 	// See [`bvs_library::ownership::transfer_ownership`] for more information on this field
 	NewOwner string `json:"new_owner"`
 }
 
+type UpdateOperatorMetadataAsManager struct {
+	Metadata RegisterAsServiceMetadata `json:"metadata"`
+	Operator string                    `json:"operator"`
+}
+
 // QueryMsg Status: Returns the registration status of an operator to a service The response
 // is a StatusResponse that contains a u8 value that maps to a RegistrationStatus:
 //
@@ -108,13 +523,180 @@ type TransferOwnership struct {
 //
 // - 3: ServiceRegistered: State when only the Service has registered but the Operator
 // hasn't yet, indicating a pending registration from the Operator side
+//
+// QueryMsg RegistrationHistory: returns every registration status change between the
+// service and operator, in ascending order of timestamp. You can provide `limit` and
+// `start_after` (timestamp) to paginate the results. The max `limit` is 100.
+//
+// QueryMsg SlashingOptInHistory: returns every slashing opt-in change of the operator to
+// the service, in ascending order of timestamp. You can provide `limit` and `start_after`
+// (timestamp) to paginate the results. The max `limit` is 100.
+//
+// QueryMsg SlashingParametersHistory: returns every slashing parameters change of the
+// service, in ascending order of timestamp. You can provide `limit` and `start_after`
+// (timestamp) to paginate the results. The max `limit` is 100.
+//
+// QueryMsg ServiceMetadata: returns the metadata of the service at the given `timestamp`
+// (or current if `None`), `None` if no metadata is stored.
+//
+// QueryMsg OperatorMetadata: returns the metadata of the operator at the given `timestamp`
+// (or current if `None`), `None` if no metadata is stored.
+//
+// QueryMsg ActiveOperatorCount: returns the number of operators actively registered to the
+// service.
+//
+// QueryMsg ActiveServiceCount: returns the number of services the operator is actively
+// registered to.
+//
+// QueryMsg MaxActiveRelationshipsForOperator: returns the effective max active
+// relationships of the operator.
+//
+// QueryMsg MaxActiveRelationshipsForService: returns the effective max active relationships
+// of the service.
+//
+// QueryMsg ListOperatorsByService: returns the operators that have a registration with the
+// service. Filtered by `status` if provided, else all registrations that are not
+// [`RegistrationStatus::Inactive`] are returned. If `timestamp` is provided, the status at
+// that timestamp is used for filtering. You can provide `limit` and `start_after`
+// (operator) to paginate the results. The max `limit` is 100.
+//
+// QueryMsg ListServicesByOperator: returns the services that have a registration with the
+// operator. Filtered by `status` if provided, else all registrations that are not
+// [`RegistrationStatus::Inactive`] are returned. If `timestamp` is provided, the status at
+// that timestamp is used for filtering. You can provide `limit` and `start_after` (service)
+// to paginate the results. The max `limit` is 100.
+//
+// QueryMsg SlashingCategory: returns the slashing parameters of the named slashing category
+// of the service at the given `timestamp` (or current if `None`), `None` if the category
+// isn't enabled.
+//
+// QueryMsg ListSlashingCategories: returns the current slashing categories of the service.
+// You can provide `limit` and `start_after` (category) to paginate the results. The max
+// `limit` is 100.
+//
+// QueryMsg IsOperatorOptedInToSlashingCategory: returns whether the operator has opted in
+// to the named slashing category of the service at the given `timestamp` (or current if
+// `None`).
+//
+// QueryMsg PendingSlashingParameters: returns the proposed slashing parameters of the
+// service pending activation, `None` if there are none.
+//
+// QueryMsg PendingSlashingCategory: returns the proposed slashing parameters of the named
+// slashing category of the service pending activation, `None` if there are none.
+//
+// QueryMsg SlashingParametersNoticePeriod: returns the notice period (in seconds) between
+// proposing new slashing parameters and activating them.
+//
+// QueryMsg Commission: returns the commission (in bips) the operator takes from the rewards
+// of the service at the given `timestamp` (or current if `None`), 0 if not set.
+//
+// QueryMsg PendingCommission: returns the proposed commission of the operator for the
+// service pending activation, `None` if there is none.
+//
+// QueryMsg CommissionChangeDelay: returns the delay (in seconds) between proposing a new
+// commission and activating it.
+//
+// QueryMsg Router: returns the `vault-router` contract address, `None` if not set.
+//
+// QueryMsg AdmissionPolicy: returns the [`AdmissionPolicy`] of the service.
+//
+// QueryMsg IsOperatorAllowlisted: returns whether the operator is in the admission
+// allowlist of the service.
+//
+// QueryMsg DeregistrationDelay: returns the delay (in seconds) between deregistering an
+// active registration and finalizing it.
+//
+// QueryMsg PendingExit: returns the timestamp (in seconds) at which the exiting
+// registration can be finalized, `None` if the registration is not exiting.
+//
+// QueryMsg ListPendingExitsByOperator: returns the exiting registrations of the operator.
+// You can provide `limit` and `start_after` (service) to paginate the results. The max
+// `limit` is 100.
+//
+// QueryMsg ListPendingExitsByService: returns the exiting registrations of the service. You
+// can provide `limit` and `start_after` (operator) to paginate the results. The max `limit`
+// is 100.
+//
+// QueryMsg ListServices: returns all the registered services, excluding the retired
+// services. You can provide `limit` and `start_after` to paginate the results. The max
+// `limit` is 100.
+//
+// QueryMsg ListOperators: returns all the registered operators, excluding the retired
+// operators. You can provide `limit` and `start_after` to paginate the results. The max
+// `limit` is 100.
+//
+// QueryMsg ServiceSunset: returns the sunset progress of the service, `None` if not
+// retired.
+//
+// QueryMsg OperatorSunset: returns the sunset progress of the operator, `None` if not
+// retired.
+//
+// QueryMsg ServiceHooksEnabled: returns whether the service has enabled registry hooks.
+//
+// QueryMsg OperatorManagers: returns the managers of the operator and their permissions.
+// You can provide `limit` and `start_after` (manager) to paginate the results. The max
+// `limit` is 100.
+//
+// QueryMsg OperatorKey: returns the public signing key of the operator of the `key_type`
+// for the service at the given `timestamp` (or current if `None`), `None` if not set.
 type QueryMsg struct {
-	Status                      *Status                      `json:"status,omitempty"`
-	IsService                   *string                      `json:"is_service,omitempty"`
-	IsOperator                  *string                      `json:"is_operator,omitempty"`
-	IsOperatorActive            *string                      `json:"is_operator_active,omitempty"`
-	SlashingParameters          *QueryMsgSlashingParameters  `json:"slashing_parameters,omitempty"`
-	IsOperatorOptedInToSlashing *IsOperatorOptedInToSlashing `json:"is_operator_opted_in_to_slashing,omitempty"`
+	Status                              *Status                              `json:"status,omitempty"`
+	RegistrationHistory                 *RegistrationHistory                 `json:"registration_history,omitempty"`
+	SlashingOptInHistory                *SlashingOptInHistory                `json:"slashing_opt_in_history,omitempty"`
+	SlashingParametersHistory           *SlashingParametersHistory           `json:"slashing_parameters_history,omitempty"`
+	IsService                           *string                              `json:"is_service,omitempty"`
+	IsOperator                          *string                              `json:"is_operator,omitempty"`
+	IsOperatorActive                    *string                              `json:"is_operator_active,omitempty"`
+	ServiceMetadata                     *ServiceMetadata                     `json:"service_metadata,omitempty"`
+	OperatorMetadata                    *OperatorMetadata                    `json:"operator_metadata,omitempty"`
+	SlashingParameters                  *QueryMsgSlashingParameters          `json:"slashing_parameters,omitempty"`
+	IsOperatorOptedInToSlashing         *IsOperatorOptedInToSlashing         `json:"is_operator_opted_in_to_slashing,omitempty"`
+	ActiveOperatorCount                 *string                              `json:"active_operator_count,omitempty"`
+	ActiveServiceCount                  *string                              `json:"active_service_count,omitempty"`
+	MaxActiveRelationshipsForOperator   *string                              `json:"max_active_relationships_for_operator,omitempty"`
+	MaxActiveRelationshipsForService    *string                              `json:"max_active_relationships_for_service,omitempty"`
+	ListOperatorsByService              *ListOperatorsByService              `json:"list_operators_by_service,omitempty"`
+	ListServicesByOperator              *ListServicesByOperator              `json:"list_services_by_operator,omitempty"`
+	SlashingCategory                    *SlashingCategoryClass               `json:"slashing_category,omitempty"`
+	ListSlashingCategories              *ListSlashingCategories              `json:"list_slashing_categories,omitempty"`
+	IsOperatorOptedInToSlashingCategory *IsOperatorOptedInToSlashingCategory `json:"is_operator_opted_in_to_slashing_category,omitempty"`
+	PendingSlashingParameters           *PendingSlashingParametersClass      `json:"pending_slashing_parameters,omitempty"`
+	PendingSlashingCategory             *PendingSlashingCategory             `json:"pending_slashing_category,omitempty"`
+	SlashingParametersNoticePeriod      *SlashingParametersNoticePeriod      `json:"slashing_parameters_notice_period,omitempty"`
+	Commission                          *Commission                          `json:"commission,omitempty"`
+	PendingCommission                   *PendingCommissionClass              `json:"pending_commission,omitempty"`
+	CommissionChangeDelay               *CommissionChangeDelay               `json:"commission_change_delay,omitempty"`
+	Router                              *Router                              `json:"router,omitempty"`
+	AdmissionPolicy                     *string                              `json:"admission_policy,omitempty"`
+	IsOperatorAllowlisted               *IsOperatorAllowlisted               `json:"is_operator_allowlisted,omitempty"`
+	DeregistrationDelay                 *DeregistrationDelay                 `json:"deregistration_delay,omitempty"`
+	PendingExit                         *PendingExitClass                    `json:"pending_exit,omitempty"`
+	ListPendingExitsByOperator          *ListPendingExitsByOperator          `json:"list_pending_exits_by_operator,omitempty"`
+	ListPendingExitsByService           *ListPendingExitsByService           `json:"list_pending_exits_by_service,omitempty"`
+	ListServices                        *ListServices                        `json:"list_services,omitempty"`
+	ListOperators                       *ListOperators                       `json:"list_operators,omitempty"`
+	ServiceSunset                       *string                              `json:"service_sunset,omitempty"`
+	OperatorSunset                      *string                              `json:"operator_sunset,omitempty"`
+	ServiceHooksEnabled                 *string                              `json:"service_hooks_enabled,omitempty"`
+	OperatorManagers                    *OperatorManagers                    `json:"operator_managers,omitempty"`
+	OperatorKey                         *OperatorKey                         `json:"operator_key,omitempty"`
+}
+
+type Commission struct {
+	Operator  string `json:"operator"`
+	Service   string `json:"service"`
+	Timestamp *int64 `json:"timestamp"`
+}
+
+type CommissionChangeDelay struct {
+}
+
+type DeregistrationDelay struct {
+}
+
+type IsOperatorAllowlisted struct {
+	Operator string `json:"operator"`
+	Service  string `json:"service"`
 }
 
 type IsOperatorOptedInToSlashing struct {
@@ -123,21 +705,302 @@ type IsOperatorOptedInToSlashing struct {
 	Timestamp *int64 `json:"timestamp"`
 }
 
+type IsOperatorOptedInToSlashingCategory struct {
+	Category  string `json:"category"`
+	Operator  string `json:"operator"`
+	Service   string `json:"service"`
+	Timestamp *int64 `json:"timestamp"`
+}
+
+type ListOperators struct {
+	Limit      *int64  `json:"limit"`
+	StartAfter *string `json:"start_after"`
+}
+
+type ListOperatorsByService struct {
+	Limit      *int64              `json:"limit"`
+	Service    string              `json:"service"`
+	StartAfter *string             `json:"start_after"`
+	Status     *RegistrationStatus `json:"status"`
+	Timestamp  *int64              `json:"timestamp"`
+}
+
+type ListPendingExitsByOperator struct {
+	Limit      *int64  `json:"limit"`
+	Operator   string  `json:"operator"`
+	StartAfter *string `json:"start_after"`
+}
+
+type ListPendingExitsByService struct {
+	Limit      *int64  `json:"limit"`
+	Service    string  `json:"service"`
+	StartAfter *string `json:"start_after"`
+}
+
+type ListServices struct {
+	Limit      *int64  `json:"limit"`
+	StartAfter *string `json:"start_after"`
+}
+
+type ListServicesByOperator struct {
+	Limit      *int64              `json:"limit"`
+	Operator   string              `json:"operator"`
+	StartAfter *string             `json:"start_after"`
+	Status     *RegistrationStatus `json:"status"`
+	Timestamp  *int64              `json:"timestamp"`
+}
+
+type ListSlashingCategories struct {
+	Limit      *int64  `json:"limit"`
+	Service    string  `json:"service"`
+	StartAfter *string `json:"start_after"`
+}
+
+type OperatorKey struct {
+	KeyType   KeyType `json:"key_type"`
+	Operator  string  `json:"operator"`
+	Service   string  `json:"service"`
+	Timestamp *int64  `json:"timestamp"`
+}
+
+type OperatorManagers struct {
+	Limit      *int64  `json:"limit"`
+	Operator   string  `json:"operator"`
+	StartAfter *string `json:"start_after"`
+}
+
+type OperatorMetadata struct {
+	Operator  string `json:"operator"`
+	Timestamp *int64 `json:"timestamp"`
+}
+
+type PendingCommissionClass struct {
+	Operator string `json:"operator"`
+	Service  string `json:"service"`
+}
+
+type PendingExitClass struct {
+	Operator string `json:"operator"`
+	Service  string `json:"service"`
+}
+
+type PendingSlashingCategory struct {
+	Category string `json:"category"`
+	Service  string `json:"service"`
+}
+
+type PendingSlashingParametersClass struct {
+	Service string `json:"service"`
+}
+
+type RegistrationHistory struct {
+	Limit      *int64 `json:"limit"`
+	Operator   string `json:"operator"`
+	Service    string `json:"service"`
+	StartAfter *int64 `json:"start_after"`
+}
+
+type Router struct {
+}
+
+type ServiceMetadata struct {
+	Service   string `json:"service"`
+	Timestamp *int64 `json:"timestamp"`
+}
+
+type SlashingCategoryClass struct {
+	Category  string `json:"category"`
+	Service   string `json:"service"`
+	Timestamp *int64 `json:"timestamp"`
+}
+
+type SlashingOptInHistory struct {
+	Limit      *int64 `json:"limit"`
+	Operator   string `json:"operator"`
+	Service    string `json:"service"`
+	StartAfter *int64 `json:"start_after"`
+}
+
 type QueryMsgSlashingParameters struct {
 	Service   string `json:"service"`
 	Timestamp *int64 `json:"timestamp"`
 }
 
+type SlashingParametersHistory struct {
+	Limit      *int64 `json:"limit"`
+	Service    string `json:"service"`
+	StartAfter *int64 `json:"start_after"`
+}
+
+type SlashingParametersNoticePeriod struct {
+}
+
 type Status struct {
 	Operator  string `json:"operator"`
 	Service   string `json:"service"`
 	Timestamp *int64 `json:"timestamp"`
 }
 
+// Only operators with at least `amount` of the `asset_id` (CAIP-19) in their whitelisted
+// vaults of the `vault-router` are admitted.
+type PurpleAdmissionPolicyClass struct {
+	MinStake PurpleMinStake `json:"min_stake"`
+}
+
+type PurpleMinStake struct {
+	Amount  string `json:"amount"`
+	AssetID string `json:"asset_id"`
+}
+
+// The response to the `ListOperatorsByService` and `ListServicesByOperator` queries. For
+// pagination, the `start_after` field is the last `operator` or `service` from the previous
+// page.
+type Registration struct {
+	Operator string `json:"operator"`
+	Service  string `json:"service"`
+	// The [`RegistrationStatus`] as u8, see [`StatusResponse`].
+	Status int64 `json:"status"`
+}
+
+// The response to the `ListPendingExitsByOperator` and `ListPendingExitsByService` queries.
+type PendingExit struct {
+	// Timestamp (in seconds) at which the deregistration can be finalized
+	ExitTimestamp int64  `json:"exit_timestamp"`
+	Operator      string `json:"operator"`
+	Service       string `json:"service"`
+}
+
+// The response to the `ListSlashingCategories` query.
+type SlashingCategory struct {
+	Category           string                             `json:"category"`
+	SlashingParameters SlashingCategorySlashingParameters `json:"slashing_parameters"`
+}
+
+type SlashingCategorySlashingParameters struct {
+	// The asset ids (CAIP-19) of the vaults the service accepts as security and can slash.
+	// Empty, indicates that the vaults of every asset can be slashed. Max 10 asset ids.
+	AssetIds []string `json:"asset_ids,omitempty"`
+	// The address to which the slashed funds will be sent after the slashing is finalized.
+	// None, indicates that the slashed funds will be burned.
+	Destination *string `json:"destination"`
+	// The weighted destinations the slashed funds are split between after the slashing is
+	// finalized. If not empty, `destination` must be `None`, and the `bips` of the destinations
+	// must add up to 10_000 (100%). Max 10 destinations.
+	Destinations []DestinationElement `json:"destinations,omitempty"`
+	// The maximum percentage of the operator's total stake that can be slashed. The value is
+	// represented in bips (basis points), where 100 bips = 1%. And the value must be between 0
+	// and 10_000 (inclusive).
+	MaxSlashingBips int64 `json:"max_slashing_bips"`
+	// The minimum amount of time (in seconds) that the slashing can be delayed before it is
+	// executed and finalized. Setting this value to a duration less than the queued withdrawal
+	// delay is recommended. To prevent restaker's early withdrawal of their assets from the
+	// vault due to the impending slash, defeating the purpose of shared security.
+	ResolutionWindow int64 `json:"resolution_window"`
+}
+
+type DestinationElement struct {
+	// The share of the slashed funds in bips (basis points), where 100 bips = 1%. Must be
+	// greater than zero.
+	Bips      int64                   `json:"bips"`
+	Recipient PurpleSlashingRecipient `json:"recipient"`
+}
+
+// The slashed funds are sent to the address.
+type PurpleSlashingRecipientClass struct {
+	Address string `json:"address"`
+}
+
+type OperatorManager struct {
+	Manager     string              `json:"manager"`
+	Permissions []ManagerPermission `json:"permissions"`
+}
+
+// Metadata of a service or operator. The latest metadata is stored on-chain (with history)
+// and also emitted as `MetadataUpdated` events. When updating the metadata, fields that are
+// `None` are not updated.
+type Metadata struct {
+	Name *string `json:"name"`
+	URI  *string `json:"uri"`
+}
+
+// Progress of the sunset of a retired service or operator, see
+// [`ExecuteMsg::DeregisterAsService`](crate::msg::ExecuteMsg::DeregisterAsService) and
+// [`ExecuteMsg::DeregisterAsOperator`](crate::msg::ExecuteMsg::DeregisterAsOperator).
+type Sunset struct {
+	// Whether all relationships have been walked and deregistered
+	Completed bool `json:"completed"`
+	// The counterparty of the last walked relationship, relationships are walked in ascending
+	// order of the counterparty address.
+	Last *string `json:"last"`
+}
+
+type PendingCommission struct {
+	// The proposed commission (in bips).
+	CommissionBips int64 `json:"commission_bips"`
+	// The timestamp (in seconds) at which the proposed commission can be activated.
+	EffectiveTimestamp int64 `json:"effective_timestamp"`
+}
+
+type PendingSlashingParameters struct {
+	// The timestamp (in seconds) at which the proposed slashing parameters can be activated.
+	EffectiveTimestamp int64 `json:"effective_timestamp"`
+	// The proposed slashing parameters.
+	SlashingParameters PendingSlashingParametersSlashingParameters `json:"slashing_parameters"`
+}
+
+// The proposed slashing parameters.
+type PendingSlashingParametersSlashingParameters struct {
+	// The asset ids (CAIP-19) of the vaults the service accepts as security and can slash.
+	// Empty, indicates that the vaults of every asset can be slashed. Max 10 asset ids.
+	AssetIds []string `json:"asset_ids,omitempty"`
+	// The address to which the slashed funds will be sent after the slashing is finalized.
+	// None, indicates that the slashed funds will be burned.
+	Destination *string `json:"destination"`
+	// The weighted destinations the slashed funds are split between after the slashing is
+	// finalized. If not empty, `destination` must be `None`, and the `bips` of the destinations
+	// must add up to 10_000 (100%). Max 10 destinations.
+	Destinations []SlashingParametersWeightedDestination `json:"destinations,omitempty"`
+	// The maximum percentage of the operator's total stake that can be slashed. The value is
+	// represented in bips (basis points), where 100 bips = 1%. And the value must be between 0
+	// and 10_000 (inclusive).
+	MaxSlashingBips int64 `json:"max_slashing_bips"`
+	// The minimum amount of time (in seconds) that the slashing can be delayed before it is
+	// executed and finalized. Setting this value to a duration less than the queued withdrawal
+	// delay is recommended. To prevent restaker's early withdrawal of their assets from the
+	// vault due to the impending slash, defeating the purpose of shared security.
+	ResolutionWindow int64 `json:"resolution_window"`
+}
+
+type SlashingParametersWeightedDestination struct {
+	// The share of the slashed funds in bips (basis points), where 100 bips = 1%. Must be
+	// greater than zero.
+	Bips      int64                   `json:"bips"`
+	Recipient FluffySlashingRecipient `json:"recipient"`
+}
+
+// The slashed funds are sent to the address.
+type FluffySlashingRecipientClass struct {
+	Address string `json:"address"`
+}
+
+// A registration status change at `timestamp` (in seconds), the new status is effective
+// from `timestamp + 1` in timestamp queries.
+type RegistrationStatusChange struct {
+	Status    int64 `json:"status"`
+	Timestamp int64 `json:"timestamp"`
+}
+
 type SlashingParameters struct {
+	// The asset ids (CAIP-19) of the vaults the service accepts as security and can slash.
+	// Empty, indicates that the vaults of every asset can be slashed. Max 10 asset ids.
+	AssetIds []string `json:"asset_ids,omitempty"`
 	// The address to which the slashed funds will be sent after the slashing is finalized.
 	// None, indicates that the slashed funds will be burned.
 	Destination *string `json:"destination"`
+	// The weighted destinations the slashed funds are split between after the slashing is
+	// finalized. If not empty, `destination` must be `None`, and the `bips` of the destinations
+	// must add up to 10_000 (100%). Max 10 destinations.
+	Destinations []PurpleWeightedDestination `json:"destinations,omitempty"`
 	// The maximum percentage of the operator's total stake that can be slashed. The value is
 	// represented in bips (basis points), where 100 bips = 1%. And the value must be between 0
 	// and 10_000 (inclusive).
@@ -148,3 +1011,177 @@ type SlashingParameters struct {
 	// vault due to the impending slash, defeating the purpose of shared security.
 	ResolutionWindow int64 `json:"resolution_window"`
 }
+
+type PurpleWeightedDestination struct {
+	// The share of the slashed funds in bips (basis points), where 100 bips = 1%. Must be
+	// greater than zero.
+	Bips      int64                      `json:"bips"`
+	Recipient TentacledSlashingRecipient `json:"recipient"`
+}
+
+// The slashed funds are sent to the address.
+type TentacledSlashingRecipientClass struct {
+	Address string `json:"address"`
+}
+
+// A slashing opt-in change at `timestamp` (in seconds), `opted_in` is `false` if the opt-in
+// was reset.
+type SlashingOptIn struct {
+	OptedIn   bool  `json:"opted_in"`
+	Timestamp int64 `json:"timestamp"`
+}
+
+// A slashing parameters change at `timestamp` (in seconds), `slashing_parameters` is `None`
+// if slashing was disabled.
+type SlashingParametersChange struct {
+	SlashingParameters *SlashingParametersChangeSlashingParameters `json:"slashing_parameters"`
+	Timestamp          int64                                       `json:"timestamp"`
+}
+
+type SlashingParametersChangeSlashingParameters struct {
+	// The asset ids (CAIP-19) of the vaults the service accepts as security and can slash.
+	// Empty, indicates that the vaults of every asset can be slashed. Max 10 asset ids.
+	AssetIds []string `json:"asset_ids,omitempty"`
+	// The address to which the slashed funds will be sent after the slashing is finalized.
+	// None, indicates that the slashed funds will be burned.
+	Destination *string `json:"destination"`
+	// The weighted destinations the slashed funds are split between after the slashing is
+	// finalized. If not empty, `destination` must be `None`, and the `bips` of the destinations
+	// must add up to 10_000 (100%). Max 10 destinations.
+	Destinations []FluffyWeightedDestination `json:"destinations,omitempty"`
+	// The maximum percentage of the operator's total stake that can be slashed. The value is
+	// represented in bips (basis points), where 100 bips = 1%. And the value must be between 0
+	// and 10_000 (inclusive).
+	MaxSlashingBips int64 `json:"max_slashing_bips"`
+	// The minimum amount of time (in seconds) that the slashing can be delayed before it is
+	// executed and finalized. Setting this value to a duration less than the queued withdrawal
+	// delay is recommended. To prevent restaker's early withdrawal of their assets from the
+	// vault due to the impending slash, defeating the purpose of shared security.
+	ResolutionWindow int64 `json:"resolution_window"`
+}
+
+type FluffyWeightedDestination struct {
+	// The share of the slashed funds in bips (basis points), where 100 bips = 1%. Must be
+	// greater than zero.
+	Bips      int64                   `json:"bips"`
+	Recipient StickySlashingRecipient `json:"recipient"`
+}
+
+// The slashed funds are sent to the address.
+type StickySlashingRecipientClass struct {
+	Address string `json:"address"`
+}
+
+// The slashed funds are burned.
+type SlashingRecipientEnum string
+
+const (
+	Burn SlashingRecipientEnum = "burn"
+)
+
+// secp256k1 public key, 33 bytes compressed or 65 bytes uncompressed SEC1 encoding
+//
+// ed25519 public key, 32 bytes
+//
+// Type of the operator signing key. BLS12-381 keys are out of scope, their proof of
+// possession can't be verified without the `cosmwasm_2_1` capability, which the target
+// chains don't support.
+type KeyType string
+
+const (
+	Ed25519   KeyType = "ed25519"
+	Secp256K1 KeyType = "secp256k1"
+)
+
+// The service must register each operator, the operator registration is pending until
+// then.
+//
+// Any registered operator is admitted.
+//
+// Only operators in the service admission allowlist are admitted.
+type AdmissionPolicyEnum string
+
+const (
+	Allowlist AdmissionPolicyEnum = "allowlist"
+	Manual    AdmissionPolicyEnum = "manual"
+	Open      AdmissionPolicyEnum = "open"
+)
+
+// Register, deregister and finalize deregistration of services to the operator. Without
+// [`ManagerPermission::SlashingOptIn`], an activated registration doesn't opt in the
+// operator to the slashing of the service.
+//
+// Opt in to slashing and slashing categories of services
+//
+// Update the operator metadata
+//
+// Permission of an operator manager to act on behalf of the operator.
+type ManagerPermission string
+
+const (
+	ManagerPermissionMetadata      ManagerPermission = "metadata"
+	ManagerPermissionRegistration  ManagerPermission = "registration"
+	ManagerPermissionSlashingOptIn ManagerPermission = "slashing_opt_in"
+)
+
+// Default state when neither the Operator nor the Service has registered, or when either
+// the Operator or Service has unregistered
+//
+// State when both the Operator and Service have registered with each other, indicating a
+// fully established relationship
+//
+// State when only the Operator has registered but the Service hasn't yet registered,
+// indicating a pending registration from the Service side This is Operator-initiated
+// registration, waiting for Service to finalize
+//
+// State when only the Service has registered but the Operator hasn't yet registered,
+// indicating a pending registration from the Operator side This is Service-initiated
+// registration, waiting for Operator to finalize
+//
+// State when either the Operator or Service has deregistered an Active registration, and
+// the deregistration delay has not been finalized. The Operator is still considered
+// validating and slashable for the Service in this state.
+//
+// Registered status of the Operator to Service Can be initiated by the Operator or the
+// Service Becomes Active when the Operator and Service both have registered Becomes Exiting
+// when the Operator or Service have deregistered an Active registration Becomes Inactive
+// when the Operator or Service have unregistered (default state)
+type RegistrationStatus string
+
+const (
+	Active             RegistrationStatus = "active"
+	Exiting            RegistrationStatus = "exiting"
+	Inactive           RegistrationStatus = "inactive"
+	OperatorRegistered RegistrationStatus = "operator_registered"
+	ServiceRegistered  RegistrationStatus = "service_registered"
+)
+
+type SlashingRecipient struct {
+	SlashingRecipientClass *SlashingRecipientClass
+	Enum                   *SlashingRecipientEnum
+}
+
+type AdmissionPolicy struct {
+	AdmissionPolicyClass *AdmissionPolicyClass
+	Enum                 *AdmissionPolicyEnum
+}
+
+type PurpleSlashingRecipient struct {
+	PurpleSlashingRecipientClass *PurpleSlashingRecipientClass
+	Enum                         *SlashingRecipientEnum
+}
+
+type FluffySlashingRecipient struct {
+	FluffySlashingRecipientClass *FluffySlashingRecipientClass
+	Enum                         *SlashingRecipientEnum
+}
+
+type TentacledSlashingRecipient struct {
+	TentacledSlashingRecipientClass *TentacledSlashingRecipientClass
+	Enum                            *SlashingRecipientEnum
+}
+
+type StickySlashingRecipient struct {
+	StickySlashingRecipientClass *StickySlashingRecipientClass
+	Enum                         *SlashingRecipientEnum
+}
@@ -3,16 +3,30 @@
 
 package vaultrouter
 
+type AllocationDelayResponse string
+
+type IdleSlashedResponse []IdleSlashed
+
+type IsSlashPendingResponse bool
+
 type IsValidatingResponse bool
 
 type IsWhitelistedResponse bool
 
+type SlashingRequestListResponse []SlashingRequestItem
+
 type VaultListResponse []Vault
 
-type SlashingLockedResponse []SlashingLockedResponseItem
+type MaxVaultsPerOperatorResponse int64
 
 type WithdrawalLockPeriodResponse string
 
+type OperatorAumResponse []AssetAum
+
+type OperatorVaultCountResponse int64
+
+type SlashingLockedResponse []SlashingLockedResponseItem
+
 type InstantiateMsg struct {
 	Guardrail string `json:"guardrail"`
 	Owner     string `json:"owner"`
@@ -26,6 +40,45 @@ type InstantiateMsg struct {
 // ExecuteMsg SetWithdrawalLockPeriod the lock period for withdrawal. Only the `owner` can
 // call this message.
 //
+// ExecuteMsg SetMinWithdrawalLockPeriod the minimum lock period for withdrawal. The default
+// and per-operator withdrawal lock periods cannot be set below it, effective withdrawal
+// lock periods below it are raised to it. Only the `owner` can call this message.
+//
+// ExecuteMsg SetOperatorWithdrawalLockPeriod the lock period for withdrawal of the vaults
+// of the operator (info.sender), overriding the default withdrawal lock period. Set to
+// `None` to remove the override. An increase is effective immediately, a decrease is
+// effective after the current withdrawal lock period of the operator. Only a registered
+// operator can call this message, once the `owner` has set the minimum withdrawal lock
+// period.
+//
+// ExecuteMsg SetMaxVaultsPerOperator the max number of (whitelisted) vaults an operator can
+// have. Whitelisting a new vault of an operator at the max fails. This bounds the number of
+// vaults iterated over when locking a slashing request, so it cannot exceed 80 (the lock
+// slashing gas limit, less its fixed cost, over the estimated gas per vault). Only the
+// `owner` can call this message.
+//
+// ExecuteMsg SetOracle the oracle contract (BVS Oracle) used to value the assets under
+// management in USD. Set to `None` to remove the oracle. Only the `owner` can call this
+// message.
+//
+// ExecuteMsg SetAllocationDelay the delay (in seconds) before an allocation increase
+// becomes slashable. Only the `owner` can call this message.
+//
+// ExecuteMsg SetAllocation allocates `bips` of the stake of the `vault` to the `service`,
+// the slashable magnitude of the vault for the service. The allocations of a vault
+// (including pending deallocations) cannot exceed 10,000 bips (100%).
+//
+// An allocation increase becomes slashable after the allocation delay, and requires an
+// active registration between the operator and the service. A deallocation becomes
+// effective after the withdrawal lock period of the operator, until then the stake is still
+// slashable at the current allocation. Only one allocation change can be pending at a time
+// per (vault, service).
+//
+// The first allocation of an operator becomes effective after the longer of the allocation
+// delay and the withdrawal lock period of the operator. Once it is effective, a service can
+// only slash the stake allocated to it (at the incident timestamp). Only the operator of
+// the vault (info.sender) can call this message.
+//
 // ExecuteMsg TransferOwnership See [`bvs_library::ownership::transfer_ownership`] for more
 // information on this field
 //
@@ -40,8 +93,12 @@ type InstantiateMsg struct {
 // The slashing amount (in bips) must not exceed the max_slashing_bips set by the service -
 // The operator must have opted in to slashing at the specified timestamp - The timestamp
 // must be within the allowable slashing window (not too old or in the future) - The service
-// must not have another active slashing request against the same operator - The reason
-// provided in metadata must not exceed the maximum allowed length
+// must not have another active slashing request against the same operator - The previous
+// slashing request of the service against the operator, if canceled or expired, must have
+// ended at least [`SLASHING_REQUEST_COOLDOWN`](crate::state::SLASHING_REQUEST_COOLDOWN) ago
+// - The reason provided in metadata must not exceed the maximum allowed length - The
+// selected vaults (if provided) must belong to the operator and their assets must be
+// accepted by the service
 //
 // When successful, this creates a slashing request with an expiry time based on the
 // resolution_window parameter and returns a unique slashing request ID.
@@ -57,20 +114,64 @@ type InstantiateMsg struct {
 // The service (slash initiator) should cancel the slashing process if the operator has
 // resolved the issue. The definition of “resolved” is up to the service to define.
 //
+// ExecuteMsg ExpireSlashing moves a pending slashing request past its `request_expiry` to
+// the `Expired` status, clearing it as the pending slashing request of the service and
+// operator.
+//
+// Anyone can call this message, so a stale slashing request doesn't linger as pending until
+// the service files a new one.
+//
 // ExecuteMsg FinalizeSlashing moves the slashed collateral from the router to the
 // destination specified in the slashing parameters that were agreed upon by the service and
 // operator.
 //
 // This is the final step in the slashing process and should only be called after the
 // request has been locked, and the guardrail proposal has been voted on and passed.
+//
+// ExecuteMsg SweepSlashed transfers the idle slashed collateral of the slashing request
+// (held by the router after it was finalized without a destination) to the `recipient`. The
+// collateral stranded before the router tracked it is held under the all-zeros slashing
+// request id. Only the `owner` or the `guardrail` can call this message.
+//
+// ExecuteMsg RedistributeSlashed splits the idle slashed collateral of the slashing request
+// (held by the router after it was finalized without a destination) between the weighted
+// `destinations`. The `bips` of the destinations must add up to 10_000 (100%), each share
+// is rounded down and the rounding dust goes to the first destination. Only the `owner` or
+// the `guardrail` can call this message.
 type ExecuteMsg struct {
-	SetVault                *SetVault             `json:"set_vault,omitempty"`
-	SetWithdrawalLockPeriod *string               `json:"set_withdrawal_lock_period,omitempty"`
-	TransferOwnership       *TransferOwnership    `json:"transfer_ownership,omitempty"`
-	RequestSlashing         *RequestSlashingClass `json:"request_slashing,omitempty"`
-	LockSlashing            *string               `json:"lock_slashing,omitempty"`
-	CancelSlashing          *string               `json:"cancel_slashing,omitempty"`
-	FinalizeSlashing        *string               `json:"finalize_slashing,omitempty"`
+	SetVault                        *SetVault             `json:"set_vault,omitempty"`
+	SetWithdrawalLockPeriod         *string               `json:"set_withdrawal_lock_period,omitempty"`
+	SetMinWithdrawalLockPeriod      *string               `json:"set_min_withdrawal_lock_period,omitempty"`
+	SetOperatorWithdrawalLockPeriod *string               `json:"set_operator_withdrawal_lock_period"`
+	SetMaxVaultsPerOperator         *int64                `json:"set_max_vaults_per_operator,omitempty"`
+	SetOracle                       *SetOracle            `json:"set_oracle,omitempty"`
+	SetAllocationDelay              *string               `json:"set_allocation_delay,omitempty"`
+	SetAllocation                   *SetAllocation        `json:"set_allocation,omitempty"`
+	TransferOwnership               *TransferOwnership    `json:"transfer_ownership,omitempty"`
+	RequestSlashing                 *RequestSlashingClass `json:"request_slashing,omitempty"`
+	LockSlashing                    *string               `json:"lock_slashing,omitempty"`
+	CancelSlashing                  *string               `json:"cancel_slashing,omitempty"`
+	ExpireSlashing                  *string               `json:"expire_slashing,omitempty"`
+	FinalizeSlashing                *string               `json:"finalize_slashing,omitempty"`
+	SweepSlashed                    *SweepSlashed         `json:"sweep_slashed,omitempty"`
+	RedistributeSlashed             *RedistributeSlashed  `json:"redistribute_slashed,omitempty"`
+}
+
+type RedistributeSlashed struct {
+	Destinations      []WeightedDestination `json:"destinations"`
+	SlashingRequestID string                `json:"slashing_request_id"`
+}
+
+type WeightedDestination struct {
+	// The share of the slashed funds in bips (basis points), where 100 bips = 1%. Must be
+	// greater than zero.
+	Bips      int64             `json:"bips"`
+	Recipient SlashingRecipient `json:"recipient"`
+}
+
+// The slashed funds are sent to the address.
+type SlashingRecipientClass struct {
+	Address string `json:"address"`
 }
 
 type RequestSlashingClass struct {
@@ -78,6 +179,11 @@ type RequestSlashingClass struct {
 	// slash is set by the service slashing parameters at the timestamp and the operator must
 	// have opted in.
 	Bips int64 `json:"bips"`
+	// The named slashing category (registered by the service in the registry) of the slashing
+	// condition. If provided, the slashing parameters and operator opt-in of the category at
+	// the timestamp are enforced, else the service default slashing parameters and opt-in are
+	// enforced.
+	Category *string `json:"category"`
 	// Additional contextual information about the slashing request.
 	Metadata RequestSlashingMetadata `json:"metadata"`
 	// The operator address to slash. (service, operator) must have active registration at the
@@ -85,6 +191,11 @@ type RequestSlashingClass struct {
 	Operator string `json:"operator"`
 	// The timestamp at which the slashing condition occurred.
 	Timestamp string `json:"timestamp"`
+	// The vaults of the operator to slash, if provided, else every vault of the operator. Each
+	// vault must belong to the operator and its asset must be accepted by the service (see the
+	// `asset_ids` of the slashing parameters at the timestamp). Vaults of assets not accepted
+	// by the service are never slashed.
+	Vaults []string `json:"vaults"`
 }
 
 // Additional contextual information about the slashing request.
@@ -94,11 +205,26 @@ type RequestSlashingMetadata struct {
 	Reason string `json:"reason"`
 }
 
+type SetAllocation struct {
+	Bips    int64  `json:"bips"`
+	Service string `json:"service"`
+	Vault   string `json:"vault"`
+}
+
+type SetOracle struct {
+	Oracle *string `json:"oracle"`
+}
+
 type SetVault struct {
 	Vault       string `json:"vault"`
 	Whitelisted bool   `json:"whitelisted"`
 }
 
+type SweepSlashed struct {
+	Recipient         string `json:"recipient"`
+	SlashingRequestID string `json:"slashing_request_id"`
+}
+
 type TransferOwnership struct {
 	NewOwner string `json:"new_owner"`
 }
@@ -109,22 +235,104 @@ type TransferOwnership struct {
 // QueryMsg IsValidating: returns true if the operator is validating services. See BVS
 // Registry for more information.
 //
+// QueryMsg IsSlashPending: returns true if a slash that would slash the `vault` is pending,
+// a slashing request against the vault's operator that is not locked, canceled or expired
+// yet, that selects the vault (if it selects vaults), accepts the vault's asset and has
+// stake of the vault allocated to its service at the incident timestamp. The vault freezes
+// redemptions while a slash is pending.
+//
 // QueryMsg ListVaults: returns a list of vaults. You can provide `limit` and `start_after`
 // to paginate the results. The max `limit` is 100.
 //
 // QueryMsg ListVaultsByOperator: returns a list of vaults managed by given operator. You
 // can provide `limit` and `start_after` to paginate the results. The max `limit` is 100.
 //
-// QueryMsg WithdrawalLockPeriod: returns the withdrawal lock period.
+// QueryMsg WithdrawalLockPeriod: returns the default withdrawal lock period, for operators
+// without an override.
+//
+// QueryMsg MinWithdrawalLockPeriod: returns the minimum withdrawal lock period.
+//
+// QueryMsg OperatorWithdrawalLockPeriod: returns the effective withdrawal lock period of
+// the operator. Called by the vaults when queuing withdrawals, The period in effect at the
+// incident is the max slashable delay of slashing requests against the operator.
+//
+// QueryMsg MaxVaultsPerOperator: returns the max number of vaults an operator can have.
+//
+// QueryMsg OperatorVaultCount: returns the number of (whitelisted) vaults of the operator,
+// counted towards the max vaults per operator.
+//
+// QueryMsg Oracle: returns the oracle contract, `None` if not set.
+//
+// QueryMsg OperatorAum: returns the assets under management of the operator, the
+// `total_assets` of its whitelisted vaults aggregated per asset. If `slashing_request_id`
+// is provided, returns the assets under management of the operator at the moment the
+// slashing request was locked instead. If the oracle is set, each current asset is valued
+// in USD at the current price.
+//
+// QueryMsg VaultAum: returns the assets under management of the vault. If
+// `slashing_request_id` is provided, returns the assets under management of the vault at
+// the moment the slashing request was locked instead. If the oracle is set, the current
+// asset is valued in USD at the current price.
+//
+// QueryMsg AllocationDelay: returns the delay (in seconds) before an allocation increase
+// becomes slashable.
+//
+// QueryMsg Allocation: returns the current allocation of the `vault` to the `service`, with
+// the pending allocation change if any.
+//
+// QueryMsg VaultAllocations: returns the allocations of the `vault` to services, with the
+// stake allocated (including pending deallocations) and free to allocate.
+//
+// QueryMsg ListSlashingRequests: returns a list of slashing requests (of any status,
+// including history), filtered by `service`, `operator` and `status` if provided. Ordered
+// from the latest to the earliest requested (by `request_time`, then by id). A `status`
+// filter combined with `service` or `operator` is applied while iterating over their
+// requests, it can read the whole history of the operator (or service) to fill a page. You
+// can provide `limit` and `start_after` to paginate the results. The max `limit` is 100.
+//
+// QueryMsg IdleSlashedBalances: returns the idle slashed collateral held by the router (of
+// slashing requests finalized without a destination), per asset.
+//
+// QueryMsg IdleSlashed: returns the idle slashed collateral held by the router for the
+// slashing request, per asset.
 type QueryMsg struct {
-	IsWhitelisted        *IsWhitelisted        `json:"is_whitelisted,omitempty"`
-	IsValidating         *IsValidating         `json:"is_validating,omitempty"`
-	ListVaults           *ListVaults           `json:"list_vaults,omitempty"`
-	ListVaultsByOperator *ListVaultsByOperator `json:"list_vaults_by_operator,omitempty"`
-	WithdrawalLockPeriod *WithdrawalLockPeriod `json:"withdrawal_lock_period,omitempty"`
-	SlashingRequestID    *SlashingRequestID    `json:"slashing_request_id,omitempty"`
-	SlashingRequest      *string               `json:"slashing_request,omitempty"`
-	SlashingLocked       *SlashingLocked       `json:"slashing_locked,omitempty"`
+	IsWhitelisted                *IsWhitelisted                `json:"is_whitelisted,omitempty"`
+	IsValidating                 *IsValidating                 `json:"is_validating,omitempty"`
+	IsSlashPending               *IsSlashPending               `json:"is_slash_pending,omitempty"`
+	ListVaults                   *ListVaults                   `json:"list_vaults,omitempty"`
+	ListVaultsByOperator         *ListVaultsByOperator         `json:"list_vaults_by_operator,omitempty"`
+	WithdrawalLockPeriod         *WithdrawalLockPeriod         `json:"withdrawal_lock_period,omitempty"`
+	MinWithdrawalLockPeriod      *MinWithdrawalLockPeriod      `json:"min_withdrawal_lock_period,omitempty"`
+	OperatorWithdrawalLockPeriod *OperatorWithdrawalLockPeriod `json:"operator_withdrawal_lock_period,omitempty"`
+	MaxVaultsPerOperator         *MaxVaultsPerOperator         `json:"max_vaults_per_operator,omitempty"`
+	OperatorVaultCount           *OperatorVaultCount           `json:"operator_vault_count,omitempty"`
+	Oracle                       *Oracle                       `json:"oracle,omitempty"`
+	OperatorAum                  *OperatorAum                  `json:"operator_aum,omitempty"`
+	VaultAum                     *VaultAum                     `json:"vault_aum,omitempty"`
+	AllocationDelay              *AllocationDelay              `json:"allocation_delay,omitempty"`
+	Allocation                   *AllocationClass              `json:"allocation,omitempty"`
+	VaultAllocations             *VaultAllocations             `json:"vault_allocations,omitempty"`
+	SlashingRequestID            *SlashingRequestID            `json:"slashing_request_id,omitempty"`
+	SlashingRequest              *string                       `json:"slashing_request,omitempty"`
+	SlashingLocked               *SlashingLocked               `json:"slashing_locked,omitempty"`
+	ListSlashingRequests         *ListSlashingRequests         `json:"list_slashing_requests,omitempty"`
+	IdleSlashedBalances          *IdleSlashedBalances          `json:"idle_slashed_balances,omitempty"`
+	IdleSlashed                  *string                       `json:"idle_slashed,omitempty"`
+}
+
+type AllocationClass struct {
+	Service string `json:"service"`
+	Vault   string `json:"vault"`
+}
+
+type AllocationDelay struct {
+}
+
+type IdleSlashedBalances struct {
+}
+
+type IsSlashPending struct {
+	Vault string `json:"vault"`
 }
 
 type IsValidating struct {
@@ -135,6 +343,14 @@ type IsWhitelisted struct {
 	Vault string `json:"vault"`
 }
 
+type ListSlashingRequests struct {
+	Limit      *int64                 `json:"limit"`
+	Operator   *string                `json:"operator"`
+	Service    *string                `json:"service"`
+	StartAfter *string                `json:"start_after"`
+	Status     *SlashingRequestStatus `json:"status"`
+}
+
 type ListVaults struct {
 	Limit      *int64  `json:"limit"`
 	StartAfter *string `json:"start_after"`
@@ -146,6 +362,28 @@ type ListVaultsByOperator struct {
 	StartAfter *string `json:"start_after"`
 }
 
+type MaxVaultsPerOperator struct {
+}
+
+type MinWithdrawalLockPeriod struct {
+}
+
+type OperatorAum struct {
+	Operator          string  `json:"operator"`
+	SlashingRequestID *string `json:"slashing_request_id"`
+}
+
+type OperatorVaultCount struct {
+	Operator string `json:"operator"`
+}
+
+type OperatorWithdrawalLockPeriod struct {
+	Operator string `json:"operator"`
+}
+
+type Oracle struct {
+}
+
 type SlashingLocked struct {
 	SlashingRequestID string `json:"slashing_request_id"`
 }
@@ -155,9 +393,94 @@ type SlashingRequestID struct {
 	Service  string `json:"service"`
 }
 
+type VaultAllocations struct {
+	Vault string `json:"vault"`
+}
+
+type VaultAum struct {
+	SlashingRequestID *string `json:"slashing_request_id"`
+	Vault             string  `json:"vault"`
+}
+
 type WithdrawalLockPeriod struct {
 }
 
+type AllocationResponse struct {
+	// The allocated magnitude (bips of the vault's stake) slashable by the service.
+	Bips int64 `json:"bips"`
+	// The allocation change pending until its `effective_time`.
+	Pending *PendingAllocation `json:"pending"`
+}
+
+type PendingAllocation struct {
+	Bips          int64  `json:"bips"`
+	EffectiveTime string `json:"effective_time"`
+}
+
+// The response to the `IdleSlashedBalances` and `IdleSlashed` queries. One item per asset,
+// ordered by `asset_id`.
+type IdleSlashed struct {
+	Amount string `json:"amount"`
+	// Asset identifier, using the CAIP-19 format.
+	AssetID string `json:"asset_id"`
+}
+
+// The response to the `ListSlashingRequests` query. For pagination, the `start_after` field
+// is the last `id` from the previous page.
+type SlashingRequestItem struct {
+	ID              string                             `json:"id"`
+	SlashingRequest SlashingRequestItemSlashingRequest `json:"slashing_request"`
+}
+
+type SlashingRequestItemSlashingRequest struct {
+	// The core slashing request data including operator, bips, timestamp, and metadata.
+	Request RequestSlashingPayload `json:"request"`
+	// The timestamp after which the request is no longer valid. This will be `request_time` +
+	// `resolution_window` + `SLASHING_REQUEST_EXPIRY_WINDOW`
+	RequestExpiry string `json:"request_expiry"`
+	// The timestamp when the request resolution window will end and becomes eligible for
+	// locking. This will be `request_time` + `resolution_window`.
+	RequestResolution string `json:"request_resolution"`
+	// The timestamp when the request was submitted.
+	RequestTime string `json:"request_time"`
+	// The service that initiated the slashing request.
+	Service string `json:"service"`
+	// The status of the slashing request.
+	Status int64 `json:"status"`
+}
+
+// The core slashing request data including operator, bips, timestamp, and metadata.
+type RequestSlashingPayload struct {
+	// The percentage of tokens to slash in basis points (1/100th of a percent). Max bips to
+	// slash is set by the service slashing parameters at the timestamp and the operator must
+	// have opted in.
+	Bips int64 `json:"bips"`
+	// The named slashing category (registered by the service in the registry) of the slashing
+	// condition. If provided, the slashing parameters and operator opt-in of the category at
+	// the timestamp are enforced, else the service default slashing parameters and opt-in are
+	// enforced.
+	Category *string `json:"category"`
+	// Additional contextual information about the slashing request.
+	Metadata SlashingMetadata `json:"metadata"`
+	// The operator address to slash. (service, operator) must have active registration at the
+	// timestamp.
+	Operator string `json:"operator"`
+	// The timestamp at which the slashing condition occurred.
+	Timestamp string `json:"timestamp"`
+	// The vaults of the operator to slash, if provided, else every vault of the operator. Each
+	// vault must belong to the operator and its asset must be accepted by the service (see the
+	// `asset_ids` of the slashing parameters at the timestamp). Vaults of assets not accepted
+	// by the service are never slashed.
+	Vaults []string `json:"vaults"`
+}
+
+// Additional contextual information about the slashing request.
+type SlashingMetadata struct {
+	// The reason for the slashing request. Must contain human-readable string. Max length of
+	// 250 characters, empty string is allowed but not recommended.
+	Reason string `json:"reason"`
+}
+
 // The response to the `ListVaults` query. For pagination, the `start_after` field is the
 // last `vault` from the previous page.
 type Vault struct {
@@ -165,6 +488,20 @@ type Vault struct {
 	Whitelisted bool   `json:"whitelisted"`
 }
 
+// The assets under management of a single asset.
+//
+// The response to the `OperatorAum` query. One item per asset, ordered by `asset_id`.
+type AssetAum struct {
+	// Asset identifier, using the CAIP-19 format.
+	AssetID string `json:"asset_id"`
+	// The total assets under management.
+	TotalAssets string `json:"total_assets"`
+	// The USD value of the `total_assets`, at the current price of the oracle. `None` if the
+	// oracle is not set, has no (valid) price for the asset, or for assets under management at
+	// a slashing request.
+	USDValue *string `json:"usd_value"`
+}
+
 type SlashingLockedResponseItem struct {
 	Amount string `json:"amount"`
 	Vault  string `json:"vault"`
@@ -193,6 +530,11 @@ type RequestClass struct {
 	// slash is set by the service slashing parameters at the timestamp and the operator must
 	// have opted in.
 	Bips int64 `json:"bips"`
+	// The named slashing category (registered by the service in the registry) of the slashing
+	// condition. If provided, the slashing parameters and operator opt-in of the category at
+	// the timestamp are enforced, else the service default slashing parameters and opt-in are
+	// enforced.
+	Category *string `json:"category"`
 	// Additional contextual information about the slashing request.
 	Metadata RequestMetadata `json:"metadata"`
 	// The operator address to slash. (service, operator) must have active registration at the
@@ -200,6 +542,11 @@ type RequestClass struct {
 	Operator string `json:"operator"`
 	// The timestamp at which the slashing condition occurred.
 	Timestamp string `json:"timestamp"`
+	// The vaults of the operator to slash, if provided, else every vault of the operator. Each
+	// vault must belong to the operator and its asset must be accepted by the service (see the
+	// `asset_ids` of the slashing parameters at the timestamp). Vaults of assets not accepted
+	// by the service are never slashed.
+	Vaults []string `json:"vaults"`
 }
 
 // Additional contextual information about the slashing request.
@@ -208,3 +555,83 @@ type RequestMetadata struct {
 	// 250 characters, empty string is allowed but not recommended.
 	Reason string `json:"reason"`
 }
+
+// The response to the `VaultAllocations` query.
+type VaultAllocationsResponse struct {
+	// The assets allocated to services, `total_assets * allocated_bips / 10,000` rounded down.
+	AllocatedAssets string `json:"allocated_assets"`
+	// The bips of the vault's stake allocated to services, including pending deallocations.
+	AllocatedBips int64 `json:"allocated_bips"`
+	// The allocations of the vault, ordered by `service`.
+	Allocations []VaultAllocation `json:"allocations"`
+	// The timestamp the allocations of the vault's operator are effective from, `None` if the
+	// operator never allocated. Before it, every service of the operator can slash the full
+	// stake of the vault.
+	EffectiveTime *string `json:"effective_time"`
+	// The assets free to allocate, `total_assets - allocated_assets`.
+	FreeAssets string `json:"free_assets"`
+	// Total assets in the vault
+	TotalAssets string `json:"total_assets"`
+}
+
+type VaultAllocation struct {
+	Allocation Allocation `json:"allocation"`
+	Service    string     `json:"service"`
+}
+
+type Allocation struct {
+	// The allocated magnitude (bips of the vault's stake) slashable by the service.
+	Bips int64 `json:"bips"`
+	// The allocation change pending until its `effective_time`.
+	Pending *AllocationPendingAllocation `json:"pending"`
+}
+
+type AllocationPendingAllocation struct {
+	Bips          int64  `json:"bips"`
+	EffectiveTime string `json:"effective_time"`
+}
+
+// The response to the `VaultAum` query.
+//
+// The assets under management of a single asset.
+type VaultAumResponse struct {
+	// Asset identifier, using the CAIP-19 format.
+	AssetID string `json:"asset_id"`
+	// The total assets under management.
+	TotalAssets string `json:"total_assets"`
+	// The USD value of the `total_assets`, at the current price of the oracle. `None` if the
+	// oracle is not set, has no (valid) price for the asset, or for assets under management at
+	// a slashing request.
+	USDValue *string `json:"usd_value"`
+}
+
+// The slashed funds are burned.
+type SlashingRecipientEnum string
+
+const (
+	Burn SlashingRecipientEnum = "burn"
+)
+
+// The slashing request is pending and has not been processed yet.
+//
+// The slashing request has been executed and funds are locked.
+//
+// The slashing request has been finalized.
+//
+// The slashing request has been canceled.
+//
+// The slashing request has expired without being locked.
+type SlashingRequestStatus string
+
+const (
+	Canceled  SlashingRequestStatus = "canceled"
+	Expired   SlashingRequestStatus = "expired"
+	Finalized SlashingRequestStatus = "finalized"
+	Locked    SlashingRequestStatus = "locked"
+	Pending   SlashingRequestStatus = "pending"
+)
+
+type SlashingRecipient struct {
+	SlashingRecipientClass *SlashingRecipientClass
+	Enum                   *SlashingRecipientEnum
+}
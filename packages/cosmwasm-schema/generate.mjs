@@ -50,6 +50,7 @@ const packages = [
   "@satlayer/bvs-vault-bank-tokenized",
   "@satlayer/bvs-vault-factory",
   "@satlayer/bvs-rewards",
+  "@satlayer/bvs-oracle",
 ];
 
 for (const schema of packages) {
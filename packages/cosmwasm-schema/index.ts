@@ -14,3 +14,4 @@ export {
 } from "./vault-bank-tokenized";
 export { ExecuteMsg as VaultFactoryExecuteMsg, QueryMsg as VaultFactoryQueryMsg } from "./vault-factory";
 export { ExecuteMsg as RewardsExecuteMsg, QueryMsg as RewardsQueryMsg } from "./rewards";
+export { ExecuteMsg as OracleExecuteMsg, QueryMsg as OracleQueryMsg } from "./oracle";
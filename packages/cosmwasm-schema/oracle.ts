@@ -0,0 +1,149 @@
+// This file was automatically generated from oracle/schema.json.
+// DO NOT MODIFY IT BY HAND.
+
+export interface InstantiateMsg {
+  owner: string;
+}
+
+/**
+ * ExecuteMsg SetPriceSource sets (or replaces) the price source of the asset. Any
+ * previously pushed price of the asset is removed. Only the `owner` can call this message.
+ *
+ * ExecuteMsg RemovePriceSource removes the price source (and pushed price) of the asset.
+ * Only the `owner` can call this message.
+ *
+ * ExecuteMsg PushPrice pushes the USD price of one whole unit of the asset, published at
+ * the current block time. The asset must have a [`PriceFeed::Push`] price source. Only the
+ * `owner` can call this message.
+ *
+ * ExecuteMsg TransferOwnership See [`bvs_library::ownership::transfer_ownership`] for more
+ * information on this field
+ */
+export interface ExecuteMsg {
+  set_price_source?: SetPriceSource;
+  remove_price_source?: RemovePriceSource;
+  push_price?: PushPrice;
+  transfer_ownership?: TransferOwnership;
+}
+
+export interface PushPrice {
+  asset_id: string;
+  price: string;
+}
+
+export interface RemovePriceSource {
+  asset_id: string;
+}
+
+export interface SetPriceSource {
+  /**
+   * Asset identifier, using the CAIP-19 format.
+   */
+  asset_id: string;
+  source: PriceSource;
+}
+
+/**
+ * The price source of an asset.
+ */
+export interface PriceSource {
+  /**
+   * The number of decimals of the asset, used to convert an amount (in the smallest unit) to
+   * whole units. Max 18.
+   */
+  decimals: number;
+  /**
+   * Where the price is read from.
+   */
+  feed: PriceFeedClass | PriceFeedEnum;
+}
+
+/**
+ * The price is queried from a Pyth-style price feed contract, see [`crate::pyth`] for the
+ * interface.
+ */
+export interface PriceFeedClass {
+  pyth: FeedPyth;
+}
+
+export interface FeedPyth {
+  contract: string;
+  /**
+   * The price feed id (32 bytes).
+   */
+  price_id: string;
+}
+
+/**
+ * The price is pushed by the `owner` with [`ExecuteMsg::PushPrice`].
+ */
+export enum PriceFeedEnum {
+  Push = "push",
+}
+
+export interface TransferOwnership {
+  new_owner: string;
+}
+
+/**
+ * QueryMsg PriceSource: returns the price source of the asset.
+ *
+ * QueryMsg Price: returns the USD price of one whole unit of the asset. Errors if the asset
+ * has no price source, or the price is older than the max price age.
+ *
+ * QueryMsg UsdValue: returns the USD value of the `amount` (in the smallest unit) of the
+ * asset. Returns `None` if the asset has no price source, errors if the price is older than
+ * the max price age.
+ */
+export interface QueryMsg {
+  price_source?: string;
+  price?: string;
+  usd_value?: USDValue;
+}
+
+export interface USDValue {
+  amount: string;
+  asset_id: string;
+}
+
+export interface PriceResponse {
+  /**
+   * The USD price of one whole unit of the asset.
+   */
+  price: string;
+  /**
+   * The time the price was published at.
+   */
+  publish_time: string;
+}
+
+/**
+ * The price source of an asset.
+ */
+export interface PriceSourceResponse {
+  /**
+   * The number of decimals of the asset, used to convert an amount (in the smallest unit) to
+   * whole units. Max 18.
+   */
+  decimals: number;
+  /**
+   * Where the price is read from.
+   */
+  feed: PurplePriceFeedClass | PriceFeedEnum;
+}
+
+/**
+ * The price is queried from a Pyth-style price feed contract, see [`crate::pyth`] for the
+ * interface.
+ */
+export interface PurplePriceFeedClass {
+  pyth: PurplePyth;
+}
+
+export interface PurplePyth {
+  contract: string;
+  /**
+   * The price feed id (32 bytes).
+   */
+  price_id: string;
+}
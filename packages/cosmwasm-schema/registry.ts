@@ -1,14 +1,30 @@
 // This file was automatically generated from registry/schema.json.
 // DO NOT MODIFY IT BY HAND.
 
+type ActiveCountResponse = number;
+
+type CommissionResponse = number;
+
+type CommissionChangeDelayResponse = number;
+
+type DeregistrationDelayResponse = number;
+
 type IsOperatorResponse = boolean;
 
 type IsOperatorActiveResponse = boolean;
 
+type IsOperatorAllowlistedResponse = boolean;
+
 type IsOperatorOptedInToSlashingResponse = boolean;
 
 type IsServiceResponse = boolean;
 
+type MaxActiveRelationshipsResponse = number;
+
+type ServiceHooksEnabledResponse = boolean;
+
+type SlashingParametersNoticePeriodResponse = number;
+
 type StatusResponse = number;
 
 export interface InstantiateMsg {
@@ -16,41 +32,290 @@ export interface InstantiateMsg {
   pauser: string;
 }
 
+/**
+ * ExecuteMsg UpdateOperatorMetadataAsManager updates the metadata of the `operator`,
+ * info.sender must be a manager of the operator with [`ManagerPermission::Metadata`].
+ *
+ * ExecuteMsg RegisterServiceToOperator registers the service to the operator. The
+ * `operator` defaults to info.sender, else info.sender must be a manager of the operator
+ * with [`ManagerPermission::Registration`]. A manager without
+ * [`ManagerPermission::SlashingOptIn`] activating the registration doesn't opt in the
+ * operator to the slashing of the service.
+ *
+ * ExecuteMsg DeregisterServiceFromOperator deregisters the service from the operator. The
+ * `operator` defaults to info.sender, else info.sender must be a manager of the operator
+ * with [`ManagerPermission::Registration`].
+ *
+ * ExecuteMsg RegisterOperatorsToService registers multiple operators to the service
+ * (info.sender) atomically, as if [`ExecuteMsg::RegisterOperatorToService`] was sent for
+ * each operator. The batch size is bounded by
+ * [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+ *
+ * ExecuteMsg DeregisterOperatorsFromService deregisters multiple operators from the service
+ * (info.sender) atomically, as if [`ExecuteMsg::DeregisterOperatorFromService`] was sent
+ * for each operator. The batch size is bounded by
+ * [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+ *
+ * ExecuteMsg RegisterServicesToOperator registers multiple services to the operator
+ * atomically, as if [`ExecuteMsg::RegisterServiceToOperator`] was sent for each service.
+ * The batch size is bounded by [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+ *
+ * ExecuteMsg DeregisterServicesFromOperator deregisters multiple services from the operator
+ * atomically, as if [`ExecuteMsg::DeregisterServiceFromOperator`] was sent for each
+ * service. The batch size is bounded by [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+ *
+ * ExecuteMsg FinalizeDeregistration finalizes an [`RegistrationStatus::Exiting`]
+ * registration after the deregistration delay has passed, setting it to
+ * [`RegistrationStatus::Inactive`]. Can be called by either the operator, a manager of the
+ * operator with [`ManagerPermission::Registration`], or the service.
+ *
+ * ExecuteMsg DeregisterAsService retires the service (info.sender) and deregisters up to
+ * `limit` of its relationships, must be called until the sunset is completed. On the first
+ * call, the service is retired and its slashing (including categories) is disabled. Active
+ * registrations become [`RegistrationStatus::Exiting`], pending registrations become
+ * [`RegistrationStatus::Inactive`]. The `limit` must be greater than zero, the max `limit`
+ * is [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+ *
+ * ExecuteMsg DeregisterAsOperator retires the operator (info.sender) and deregisters up to
+ * `limit` of its relationships, must be called until the sunset is completed. Active
+ * registrations become [`RegistrationStatus::Exiting`], pending registrations become
+ * [`RegistrationStatus::Inactive`]. The `limit` must be greater than zero, the max `limit`
+ * is [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+ *
+ * ExecuteMsg EnableSlashing enables slashing for the service (info.sender) immediately. Can
+ * only be called when slashing is not enabled, use
+ * [`ExecuteMsg::ProposeSlashingParameters`] to update enabled slashing parameters.
+ *
+ * ExecuteMsg ProposeSlashingParameters proposes new slashing parameters for the service
+ * (info.sender), replacing any pending slashing parameters. The new slashing parameters can
+ * be activated with [`ExecuteMsg::ActivateSlashingParameters`] after the slashing
+ * parameters notice period.
+ *
+ * ExecuteMsg ActivateSlashingParameters activates the pending slashing parameters of the
+ * service after the notice period has passed. Can be called by anyone. If the new slashing
+ * parameters are stricter (higher `max_slashing_bips` or shorter `resolution_window`),
+ * operators have to opt in again, else their opt-in is kept.
+ *
+ * ExecuteMsg SetSlashingCategory adds a named slashing category for the service
+ * (info.sender) with its own slashing parameters, effective immediately. Operators must opt
+ * in to each category with [`ExecuteMsg::OperatorOptInToSlashingCategory`]. Can only be
+ * called when the category is not enabled, use [`ExecuteMsg::ProposeSlashingCategory`] to
+ * update an enabled category.
+ *
+ * ExecuteMsg ProposeSlashingCategory proposes new slashing parameters for the enabled named
+ * slashing category of the service (info.sender), replacing any pending slashing
+ * parameters. The new slashing parameters can be activated with
+ * [`ExecuteMsg::ActivateSlashingCategory`] after the slashing parameters notice period.
+ *
+ * ExecuteMsg ActivateSlashingCategory activates the pending slashing parameters of the
+ * named slashing category of the service after the notice period has passed. Can be called
+ * by anyone. If the new slashing parameters are stricter, operators have to opt in to the
+ * category again, else their opt-in is kept.
+ *
+ * ExecuteMsg RemoveSlashingCategory removes the named slashing category of the service
+ * (info.sender), resetting the category opt-in of all operators.
+ *
+ * ExecuteMsg OperatorOptInToSlashingCategory opts in the operator to the named slashing
+ * category of the service. The operator and service must have an active registration. The
+ * `operator` defaults to info.sender, else info.sender must be a manager of the operator
+ * with [`ManagerPermission::SlashingOptIn`].
+ *
+ * ExecuteMsg OperatorOptInToSlashing opts in the operator to the slashing of the service.
+ * The `operator` defaults to info.sender, else info.sender must be a manager of the
+ * operator with [`ManagerPermission::SlashingOptIn`].
+ *
+ * ExecuteMsg SetMaxActiveRelationships sets the global default max number of active
+ * relationships an operator (to services) and a service (to operators) can have. Existing
+ * active relationships above the new max are not affected, but no new relationship can
+ * become active until the count is below the max. Only the `owner` can call this message.
+ *
+ * ExecuteMsg SetMaxActiveRelationshipsForOperator overrides the global default for the
+ * operator. Set `max` to `None` to remove the override. Only the `owner` can call this
+ * message.
+ *
+ * ExecuteMsg SetMaxActiveRelationshipsForService overrides the global default for the
+ * service. Set `max` to `None` to remove the override. Only the `owner` can call this
+ * message.
+ *
+ * ExecuteMsg SetDeregistrationDelay sets the delay (in seconds) an exiting registration
+ * must wait before it can be finalized with [`ExecuteMsg::FinalizeDeregistration`]. When
+ * the router is set (see [`ExecuteMsg::SetRouter`]), an operator whose withdrawal lock
+ * period in the `vault-router` is longer waits for its lock period instead, so it stays
+ * slashable for the whole look-back of the router. Registrations that are already exiting
+ * keep their exit timestamp. Only the `owner` can call this message.
+ *
+ * ExecuteMsg SetSlashingParametersNoticePeriod sets the notice period (in seconds) between
+ * proposing new slashing parameters and activating them. Slashing parameters that are
+ * already pending keep their effective timestamp. Only the `owner` can call this message.
+ *
+ * ExecuteMsg ProposeCommission proposes the commission (in bips) the operator (info.sender)
+ * takes from the rewards of the service, replacing any pending commission. The commission
+ * can be activated with [`ExecuteMsg::ActivateCommission`] after the commission change
+ * delay. The operator and service must be registered.
+ *
+ * ExecuteMsg ActivateCommission activates the pending commission of the operator for the
+ * service after the commission change delay has passed. Can be called by anyone.
+ *
+ * ExecuteMsg SetCommissionChangeDelay sets the delay (in seconds) between proposing a new
+ * commission and activating it. Commissions that are already pending keep their effective
+ * timestamp. Only the `owner` can call this message.
+ *
+ * ExecuteMsg SetRouter sets the `vault-router` contract address, used to query the vaults
+ * of an operator for [`AdmissionPolicy::MinStake`] and the withdrawal lock period of an
+ * exiting operator (see [`ExecuteMsg::SetDeregistrationDelay`]). Only the `owner` can call
+ * this message.
+ *
+ * ExecuteMsg SetAdmissionPolicy sets the [`AdmissionPolicy`] of the service (info.sender).
+ * When an admitted operator registers the service with
+ * [`ExecuteMsg::RegisterServiceToOperator`], the registration becomes
+ * [`RegistrationStatus::Active`] immediately. With [`AdmissionPolicy::Manual`] (default),
+ * or for an operator that isn't admitted, the service must register the operator.
+ *
+ * ExecuteMsg SetAdmissionAllowlist adds (`allowed: true`) or removes (`allowed: false`) the
+ * operators to the admission allowlist of the service (info.sender), used by
+ * [`AdmissionPolicy::Allowlist`]. The batch size is bounded by
+ * [`MAX_BATCH_SIZE`](crate::state::MAX_BATCH_SIZE).
+ *
+ * ExecuteMsg SetOperatorKey sets (or rotates) the public signing key of the operator
+ * (info.sender) for the service, one key per `key_type`. The service can verify
+ * attestations signed by the operator with the key valid at the time of the attestation,
+ * see [`QueryMsg::OperatorKey`]. The operator and service must be registered.
+ *
+ * The `signature` proves possession of the key: the signature made with the key of
+ * `sha256(service || operator || key_type || public_key)`, the addresses as UTF-8 strings
+ * and the key type as a single byte (`Secp256k1` = 1, `Ed25519` = 2). 64 bytes (r || s) for
+ * `Secp256k1`, 64 bytes for `Ed25519`.
+ *
+ * ExecuteMsg RemoveOperatorKey removes the public signing key of the operator (info.sender)
+ * of the `key_type` for the service.
+ *
+ * ExecuteMsg SetOperatorManager authorizes the `manager` to act on behalf of the operator
+ * (info.sender) with the `permissions`, replacing any previous permissions. Only the
+ * operator can set its managers.
+ *
+ * ExecuteMsg RevokeOperatorManager revokes all permissions of the `manager` of the operator
+ * (info.sender).
+ *
+ * ExecuteMsg EnableServiceHooks opts in the service (info.sender) to receive
+ * [`RegistryHookExecuteMsg`] hooks from the registry whenever a relationship of the service
+ * changes. The service must be a contract that handles [`RegistryHookExecuteMsg`].
+ *
+ * ExecuteMsg DisableServiceHooks stops the hooks to the service (info.sender).
+ */
 export interface ExecuteMsg {
   register_as_service?: RegisterAsService;
   update_service_metadata?: Metadata;
   register_as_operator?: RegisterAsOperator;
   update_operator_metadata?: Metadata;
+  update_operator_metadata_as_manager?: UpdateOperatorMetadataAsManager;
   register_operator_to_service?: RegisterOperatorToService;
   deregister_operator_from_service?: DeregisterOperatorFromService;
   register_service_to_operator?: RegisterServiceToOperator;
   deregister_service_from_operator?: DeregisterServiceFromOperator;
+  register_operators_to_service?: RegisterOperatorsToService;
+  deregister_operators_from_service?: DeregisterOperatorsFromService;
+  register_services_to_operator?: RegisterServicesToOperator;
+  deregister_services_from_operator?: DeregisterServicesFromOperator;
+  finalize_deregistration?: FinalizeDeregistration;
+  deregister_as_service?: DeregisterAsService;
+  deregister_as_operator?: DeregisterAsOperator;
   enable_slashing?: EnableSlashing;
   disable_slashing?: DisableSlashing;
+  propose_slashing_parameters?: ProposeSlashingParameters;
+  activate_slashing_parameters?: ActivateSlashingParameters;
+  set_slashing_category?: SetSlashingCategory;
+  propose_slashing_category?: ProposeSlashingCategory;
+  activate_slashing_category?: ActivateSlashingCategory;
+  remove_slashing_category?: RemoveSlashingCategory;
+  operator_opt_in_to_slashing_category?: OperatorOptInToSlashingCategory;
   operator_opt_in_to_slashing?: OperatorOptInToSlashing;
   transfer_ownership?: TransferOwnership;
+  set_max_active_relationships?: SetMaxActiveRelationships;
+  set_max_active_relationships_for_operator?: SetMaxActiveRelationshipsForOperator;
+  set_max_active_relationships_for_service?: SetMaxActiveRelationshipsForService;
+  set_deregistration_delay?: SetDeregistrationDelay;
+  set_slashing_parameters_notice_period?: SetSlashingParametersNoticePeriod;
+  propose_commission?: ProposeCommission;
+  activate_commission?: ActivateCommission;
+  set_commission_change_delay?: SetCommissionChangeDelay;
+  set_router?: SetRouter;
+  set_admission_policy?: SetAdmissionPolicy;
+  set_admission_allowlist?: SetAdmissionAllowlist;
+  set_operator_key?: SetOperatorKey;
+  remove_operator_key?: RemoveOperatorKey;
+  set_operator_manager?: SetOperatorManager;
+  revoke_operator_manager?: RevokeOperatorManager;
+  enable_service_hooks?: EnableServiceHooks;
+  disable_service_hooks?: DisableServiceHooks;
+}
+
+export interface ActivateCommission {
+  operator: string;
+  service: string;
+}
+
+export interface ActivateSlashingCategory {
+  category: string;
+  service: string;
+}
+
+export interface ActivateSlashingParameters {
+  service: string;
+}
+
+export interface DeregisterAsOperator {
+  limit?: number | null;
+}
+
+export interface DeregisterAsService {
+  limit?: number | null;
 }
 
 export interface DeregisterOperatorFromService {
   operator: string;
 }
 
+export interface DeregisterOperatorsFromService {
+  operators: string[];
+}
+
 export interface DeregisterServiceFromOperator {
+  operator?: null | string;
   service: string;
 }
 
+export interface DeregisterServicesFromOperator {
+  operator?: null | string;
+  services: string[];
+}
+
+export interface DisableServiceHooks {}
+
 export interface DisableSlashing {}
 
+export interface EnableServiceHooks {}
+
 export interface EnableSlashing {
   slashing_parameters: SlashingParameters;
 }
 
 export interface SlashingParameters {
+  /**
+   * The asset ids (CAIP-19) of the vaults the service accepts as security and can slash.
+   * Empty, indicates that the vaults of every asset can be slashed. Max 10 asset ids.
+   */
+  asset_ids?: string[];
   /**
    * The address to which the slashed funds will be sent after the slashing is finalized.
    * None, indicates that the slashed funds will be burned.
    */
   destination?: null | string;
+  /**
+   * The weighted destinations the slashed funds are split between after the slashing is
+   * finalized. If not empty, `destination` must be `None`, and the `bips` of the destinations
+   * must add up to 10_000 (100%). Max 10 destinations.
+   */
+  destinations?: WeightedDestination[];
   /**
    * The maximum percentage of the operator's total stake that can be slashed. The value is
    * represented in bips (basis points), where 100 bips = 1%. And the value must be between 0
@@ -66,16 +331,67 @@ export interface SlashingParameters {
   resolution_window: number;
 }
 
+export interface WeightedDestination {
+  /**
+   * The share of the slashed funds in bips (basis points), where 100 bips = 1%. Must be
+   * greater than zero.
+   */
+  bips: number;
+  recipient: SlashingRecipientClass | SlashingRecipientEnum;
+}
+
+/**
+ * The slashed funds are sent to the address.
+ */
+export interface SlashingRecipientClass {
+  address: string;
+}
+
+/**
+ * The slashed funds are burned.
+ */
+export enum SlashingRecipientEnum {
+  Burn = "burn",
+}
+
+export interface FinalizeDeregistration {
+  operator: string;
+  service: string;
+}
+
 export interface OperatorOptInToSlashing {
+  operator?: null | string;
+  service: string;
+}
+
+export interface OperatorOptInToSlashingCategory {
+  category: string;
+  operator?: null | string;
   service: string;
 }
 
+export interface ProposeCommission {
+  commission_bips: number;
+  service: string;
+}
+
+export interface ProposeSlashingCategory {
+  category: string;
+  slashing_parameters: SlashingParameters;
+}
+
+export interface ProposeSlashingParameters {
+  slashing_parameters: SlashingParameters;
+}
+
 export interface RegisterAsOperator {
   metadata: Metadata;
 }
 
 /**
- * metadata is emitted as events and not stored on-chain.
+ * Metadata of a service or operator. The latest metadata is stored on-chain (with history)
+ * and also emitted as `MetadataUpdated` events. When updating the metadata, fields that are
+ * `None` are not updated.
  */
 export interface Metadata {
   name?: null | string;
@@ -90,10 +406,148 @@ export interface RegisterOperatorToService {
   operator: string;
 }
 
+export interface RegisterOperatorsToService {
+  operators: string[];
+}
+
 export interface RegisterServiceToOperator {
+  operator?: null | string;
   service: string;
 }
 
+export interface RegisterServicesToOperator {
+  operator?: null | string;
+  services: string[];
+}
+
+export interface RemoveOperatorKey {
+  key_type: KeyType;
+  service: string;
+}
+
+/**
+ * secp256k1 public key, 33 bytes compressed or 65 bytes uncompressed SEC1 encoding
+ *
+ * ed25519 public key, 32 bytes
+ *
+ * Type of the operator signing key. BLS12-381 keys are out of scope, their proof of
+ * possession can't be verified without the `cosmwasm_2_1` capability, which the target
+ * chains don't support.
+ */
+export enum KeyType {
+  Ed25519 = "ed25519",
+  Secp256K1 = "secp256k1",
+}
+
+export interface RemoveSlashingCategory {
+  category: string;
+}
+
+export interface RevokeOperatorManager {
+  manager: string;
+}
+
+export interface SetAdmissionAllowlist {
+  allowed: boolean;
+  operators: string[];
+}
+
+export interface SetAdmissionPolicy {
+  policy: AdmissionPolicyClass | AdmissionPolicyEnum;
+}
+
+/**
+ * Only operators with at least `amount` of the `asset_id` (CAIP-19) in their whitelisted
+ * vaults of the `vault-router` are admitted.
+ */
+export interface AdmissionPolicyClass {
+  min_stake: PolicyMinStake;
+}
+
+export interface PolicyMinStake {
+  amount: string;
+  asset_id: string;
+}
+
+/**
+ * The service must register each operator, the operator registration is pending until
+ * then.
+ *
+ * Any registered operator is admitted.
+ *
+ * Only operators in the service admission allowlist are admitted.
+ */
+export enum AdmissionPolicyEnum {
+  Allowlist = "allowlist",
+  Manual = "manual",
+  Open = "open",
+}
+
+export interface SetCommissionChangeDelay {
+  delay: number;
+}
+
+export interface SetDeregistrationDelay {
+  delay: number;
+}
+
+export interface SetMaxActiveRelationships {
+  per_operator: number;
+  per_service: number;
+}
+
+export interface SetMaxActiveRelationshipsForOperator {
+  max?: number | null;
+  operator: string;
+}
+
+export interface SetMaxActiveRelationshipsForService {
+  max?: number | null;
+  service: string;
+}
+
+export interface SetOperatorKey {
+  key_type: KeyType;
+  public_key: string;
+  service: string;
+  signature: string;
+}
+
+export interface SetOperatorManager {
+  manager: string;
+  permissions: ManagerPermission[];
+}
+
+/**
+ * Register, deregister and finalize deregistration of services to the operator. Without
+ * [`ManagerPermission::SlashingOptIn`], an activated registration doesn't opt in the
+ * operator to the slashing of the service.
+ *
+ * Opt in to slashing and slashing categories of services
+ *
+ * Update the operator metadata
+ *
+ * Permission of an operator manager to act on behalf of the operator.
+ */
+export enum ManagerPermission {
+  Metadata = "metadata",
+  Registration = "registration",
+  SlashingOptIn = "slashing_opt_in",
+}
+
+export interface SetRouter {
+  router: string;
+}
+
+export interface SetSlashingCategory {
+  category: string;
+  slashing_parameters: SlashingParameters;
+}
+
+export interface SetSlashingParametersNoticePeriod {
+  notice_period: number;
+}
+
 export interface TransferOwnership {
   /**
    * See [`bvs_library::ownership::transfer_ownership`] for more information on this field
@@ -101,6 +555,11 @@ export interface TransferOwnership {
   new_owner: string;
 }
 
+export interface UpdateOperatorMetadataAsManager {
+  metadata: Metadata;
+  operator: string;
+}
+
 /**
  * QueryMsg Status: Returns the registration status of an operator to a service The response
  * is a StatusResponse that contains a u8 value that maps to a RegistrationStatus:
@@ -116,14 +575,179 @@ export interface TransferOwnership {
  *
  * - 3: ServiceRegistered: State when only the Service has registered but the Operator
  * hasn't yet, indicating a pending registration from the Operator side
+ *
+ * QueryMsg RegistrationHistory: returns every registration status change between the
+ * service and operator, in ascending order of timestamp. You can provide `limit` and
+ * `start_after` (timestamp) to paginate the results. The max `limit` is 100.
+ *
+ * QueryMsg SlashingOptInHistory: returns every slashing opt-in change of the operator to
+ * the service, in ascending order of timestamp. You can provide `limit` and `start_after`
+ * (timestamp) to paginate the results. The max `limit` is 100.
+ *
+ * QueryMsg SlashingParametersHistory: returns every slashing parameters change of the
+ * service, in ascending order of timestamp. You can provide `limit` and `start_after`
+ * (timestamp) to paginate the results. The max `limit` is 100.
+ *
+ * QueryMsg ServiceMetadata: returns the metadata of the service at the given `timestamp`
+ * (or current if `None`), `None` if no metadata is stored.
+ *
+ * QueryMsg OperatorMetadata: returns the metadata of the operator at the given `timestamp`
+ * (or current if `None`), `None` if no metadata is stored.
+ *
+ * QueryMsg ActiveOperatorCount: returns the number of operators actively registered to the
+ * service.
+ *
+ * QueryMsg ActiveServiceCount: returns the number of services the operator is actively
+ * registered to.
+ *
+ * QueryMsg MaxActiveRelationshipsForOperator: returns the effective max active
+ * relationships of the operator.
+ *
+ * QueryMsg MaxActiveRelationshipsForService: returns the effective max active relationships
+ * of the service.
+ *
+ * QueryMsg ListOperatorsByService: returns the operators that have a registration with the
+ * service. Filtered by `status` if provided, else all registrations that are not
+ * [`RegistrationStatus::Inactive`] are returned. If `timestamp` is provided, the status at
+ * that timestamp is used for filtering. You can provide `limit` and `start_after`
+ * (operator) to paginate the results. The max `limit` is 100.
+ *
+ * QueryMsg ListServicesByOperator: returns the services that have a registration with the
+ * operator. Filtered by `status` if provided, else all registrations that are not
+ * [`RegistrationStatus::Inactive`] are returned. If `timestamp` is provided, the status at
+ * that timestamp is used for filtering. You can provide `limit` and `start_after` (service)
+ * to paginate the results. The max `limit` is 100.
+ *
+ * QueryMsg SlashingCategory: returns the slashing parameters of the named slashing category
+ * of the service at the given `timestamp` (or current if `None`), `None` if the category
+ * isn't enabled.
+ *
+ * QueryMsg ListSlashingCategories: returns the current slashing categories of the service.
+ * You can provide `limit` and `start_after` (category) to paginate the results. The max
+ * `limit` is 100.
+ *
+ * QueryMsg IsOperatorOptedInToSlashingCategory: returns whether the operator has opted in
+ * to the named slashing category of the service at the given `timestamp` (or current if
+ * `None`).
+ *
+ * QueryMsg PendingSlashingParameters: returns the proposed slashing parameters of the
+ * service pending activation, `None` if there are none.
+ *
+ * QueryMsg PendingSlashingCategory: returns the proposed slashing parameters of the named
+ * slashing category of the service pending activation, `None` if there are none.
+ *
+ * QueryMsg SlashingParametersNoticePeriod: returns the notice period (in seconds) between
+ * proposing new slashing parameters and activating them.
+ *
+ * QueryMsg Commission: returns the commission (in bips) the operator takes from the rewards
+ * of the service at the given `timestamp` (or current if `None`), 0 if not set.
+ *
+ * QueryMsg PendingCommission: returns the proposed commission of the operator for the
+ * service pending activation, `None` if there is none.
+ *
+ * QueryMsg CommissionChangeDelay: returns the delay (in seconds) between proposing a new
+ * commission and activating it.
+ *
+ * QueryMsg Router: returns the `vault-router` contract address, `None` if not set.
+ *
+ * QueryMsg AdmissionPolicy: returns the [`AdmissionPolicy`] of the service.
+ *
+ * QueryMsg IsOperatorAllowlisted: returns whether the operator is in the admission
+ * allowlist of the service.
+ *
+ * QueryMsg DeregistrationDelay: returns the delay (in seconds) between deregistering an
+ * active registration and finalizing it.
+ *
+ * QueryMsg PendingExit: returns the timestamp (in seconds) at which the exiting
+ * registration can be finalized, `None` if the registration is not exiting.
+ *
+ * QueryMsg ListPendingExitsByOperator: returns the exiting registrations of the operator.
+ * You can provide `limit` and `start_after` (service) to paginate the results. The max
+ * `limit` is 100.
+ *
+ * QueryMsg ListPendingExitsByService: returns the exiting registrations of the service. You
+ * can provide `limit` and `start_after` (operator) to paginate the results. The max `limit`
+ * is 100.
+ *
+ * QueryMsg ListServices: returns all the registered services, excluding the retired
+ * services. You can provide `limit` and `start_after` to paginate the results. The max
+ * `limit` is 100.
+ *
+ * QueryMsg ListOperators: returns all the registered operators, excluding the retired
+ * operators. You can provide `limit` and `start_after` to paginate the results. The max
+ * `limit` is 100.
+ *
+ * QueryMsg ServiceSunset: returns the sunset progress of the service, `None` if not
+ * retired.
+ *
+ * QueryMsg OperatorSunset: returns the sunset progress of the operator, `None` if not
+ * retired.
+ *
+ * QueryMsg ServiceHooksEnabled: returns whether the service has enabled registry hooks.
+ *
+ * QueryMsg OperatorManagers: returns the managers of the operator and their permissions.
+ * You can provide `limit` and `start_after` (manager) to paginate the results. The max
+ * `limit` is 100.
+ *
+ * QueryMsg OperatorKey: returns the public signing key of the operator of the `key_type`
+ * for the service at the given `timestamp` (or current if `None`), `None` if not set.
  */
 export interface QueryMsg {
   status?: Status;
+  registration_history?: RegistrationHistory;
+  slashing_opt_in_history?: SlashingOptInHistory;
+  slashing_parameters_history?: SlashingParametersHistory;
   is_service?: string;
   is_operator?: string;
   is_operator_active?: string;
+  service_metadata?: ServiceMetadata;
+  operator_metadata?: OperatorMetadata;
   slashing_parameters?: QueryMsgSlashingParameters;
   is_operator_opted_in_to_slashing?: IsOperatorOptedInToSlashing;
+  active_operator_count?: string;
+  active_service_count?: string;
+  max_active_relationships_for_operator?: string;
+  max_active_relationships_for_service?: string;
+  list_operators_by_service?: ListOperatorsByService;
+  list_services_by_operator?: ListServicesByOperator;
+  slashing_category?: SlashingCategory;
+  list_slashing_categories?: ListSlashingCategories;
+  is_operator_opted_in_to_slashing_category?: IsOperatorOptedInToSlashingCategory;
+  pending_slashing_parameters?: PendingSlashingParameters;
+  pending_slashing_category?: PendingSlashingCategory;
+  slashing_parameters_notice_period?: SlashingParametersNoticePeriod;
+  commission?: Commission;
+  pending_commission?: PendingCommission;
+  commission_change_delay?: CommissionChangeDelay;
+  router?: Router;
+  admission_policy?: string;
+  is_operator_allowlisted?: IsOperatorAllowlisted;
+  deregistration_delay?: DeregistrationDelay;
+  pending_exit?: PendingExit;
+  list_pending_exits_by_operator?: ListPendingExitsByOperator;
+  list_pending_exits_by_service?: ListPendingExitsByService;
+  list_services?: ListServices;
+  list_operators?: ListOperators;
+  service_sunset?: string;
+  operator_sunset?: string;
+  service_hooks_enabled?: string;
+  operator_managers?: OperatorManagers;
+  operator_key?: OperatorKey;
+}
+
+export interface Commission {
+  operator: string;
+  service: string;
+  timestamp?: number | null;
+}
+
+export interface CommissionChangeDelay {}
+
+export interface DeregistrationDelay {}
+
+export interface IsOperatorAllowlisted {
+  operator: string;
+  service: string;
 }
 
 export interface IsOperatorOptedInToSlashing {
@@ -132,23 +756,464 @@ export interface IsOperatorOptedInToSlashing {
   timestamp?: number | null;
 }
 
+export interface IsOperatorOptedInToSlashingCategory {
+  category: string;
+  operator: string;
+  service: string;
+  timestamp?: number | null;
+}
+
+export interface ListOperators {
+  limit?: number | null;
+  start_after?: null | string;
+}
+
+export interface ListOperatorsByService {
+  limit?: number | null;
+  service: string;
+  start_after?: null | string;
+  status?: RegistrationStatus | null;
+  timestamp?: number | null;
+}
+
+/**
+ * Default state when neither the Operator nor the Service has registered, or when either
+ * the Operator or Service has unregistered
+ *
+ * State when both the Operator and Service have registered with each other, indicating a
+ * fully established relationship
+ *
+ * State when only the Operator has registered but the Service hasn't yet registered,
+ * indicating a pending registration from the Service side This is Operator-initiated
+ * registration, waiting for Service to finalize
+ *
+ * State when only the Service has registered but the Operator hasn't yet registered,
+ * indicating a pending registration from the Operator side This is Service-initiated
+ * registration, waiting for Operator to finalize
+ *
+ * State when either the Operator or Service has deregistered an Active registration, and
+ * the deregistration delay has not been finalized. The Operator is still considered
+ * validating and slashable for the Service in this state.
+ *
+ * Registered status of the Operator to Service Can be initiated by the Operator or the
+ * Service Becomes Active when the Operator and Service both have registered Becomes Exiting
+ * when the Operator or Service have deregistered an Active registration Becomes Inactive
+ * when the Operator or Service have unregistered (default state)
+ */
+export enum RegistrationStatus {
+  Active = "active",
+  Exiting = "exiting",
+  Inactive = "inactive",
+  OperatorRegistered = "operator_registered",
+  ServiceRegistered = "service_registered",
+}
+
+export interface ListPendingExitsByOperator {
+  limit?: number | null;
+  operator: string;
+  start_after?: null | string;
+}
+
+export interface ListPendingExitsByService {
+  limit?: number | null;
+  service: string;
+  start_after?: null | string;
+}
+
+export interface ListServices {
+  limit?: number | null;
+  start_after?: null | string;
+}
+
+export interface ListServicesByOperator {
+  limit?: number | null;
+  operator: string;
+  start_after?: null | string;
+  status?: RegistrationStatus | null;
+  timestamp?: number | null;
+}
+
+export interface ListSlashingCategories {
+  limit?: number | null;
+  service: string;
+  start_after?: null | string;
+}
+
+export interface OperatorKey {
+  key_type: KeyType;
+  operator: string;
+  service: string;
+  timestamp?: number | null;
+}
+
+export interface OperatorManagers {
+  limit?: number | null;
+  operator: string;
+  start_after?: null | string;
+}
+
+export interface OperatorMetadata {
+  operator: string;
+  timestamp?: number | null;
+}
+
+export interface PendingCommission {
+  operator: string;
+  service: string;
+}
+
+export interface PendingExit {
+  operator: string;
+  service: string;
+}
+
+export interface PendingSlashingCategory {
+  category: string;
+  service: string;
+}
+
+export interface PendingSlashingParameters {
+  service: string;
+}
+
+export interface RegistrationHistory {
+  limit?: number | null;
+  operator: string;
+  service: string;
+  start_after?: number | null;
+}
+
+export interface Router {}
+
+export interface ServiceMetadata {
+  service: string;
+  timestamp?: number | null;
+}
+
+export interface SlashingCategory {
+  category: string;
+  service: string;
+  timestamp?: number | null;
+}
+
+export interface SlashingOptInHistory {
+  limit?: number | null;
+  operator: string;
+  service: string;
+  start_after?: number | null;
+}
+
 export interface QueryMsgSlashingParameters {
   service: string;
   timestamp?: number | null;
 }
 
+export interface SlashingParametersHistory {
+  limit?: number | null;
+  service: string;
+  start_after?: number | null;
+}
+
+export interface SlashingParametersNoticePeriod {}
+
 export interface Status {
   operator: string;
   service: string;
   timestamp?: number | null;
 }
 
+/**
+ * Only operators with at least `amount` of the `asset_id` (CAIP-19) in their whitelisted
+ * vaults of the `vault-router` are admitted.
+ */
+export interface PurpleAdmissionPolicyClass {
+  min_stake: PurpleMinStake;
+}
+
+export interface PurpleMinStake {
+  amount: string;
+  asset_id: string;
+}
+
+/**
+ * The response to the `ListOperatorsByService` and `ListServicesByOperator` queries. For
+ * pagination, the `start_after` field is the last `operator` or `service` from the previous
+ * page.
+ */
+export interface RegistrationListResponse {
+  operator: string;
+  service: string;
+  /**
+   * The [`RegistrationStatus`] as u8, see [`StatusResponse`].
+   */
+  status: number;
+}
+
+/**
+ * The response to the `ListPendingExitsByOperator` and `ListPendingExitsByService` queries.
+ */
+export interface PendingExitListResponse {
+  /**
+   * Timestamp (in seconds) at which the deregistration can be finalized
+   */
+  exit_timestamp: number;
+  operator: string;
+  service: string;
+}
+
+/**
+ * The response to the `ListSlashingCategories` query.
+ */
+export interface SlashingCategoryListResponse {
+  category: string;
+  slashing_parameters: SlashingCategoryListResponseSlashingParameters;
+}
+
+export interface SlashingCategoryListResponseSlashingParameters {
+  /**
+   * The asset ids (CAIP-19) of the vaults the service accepts as security and can slash.
+   * Empty, indicates that the vaults of every asset can be slashed. Max 10 asset ids.
+   */
+  asset_ids?: string[];
+  /**
+   * The address to which the slashed funds will be sent after the slashing is finalized.
+   * None, indicates that the slashed funds will be burned.
+   */
+  destination?: null | string;
+  /**
+   * The weighted destinations the slashed funds are split between after the slashing is
+   * finalized. If not empty, `destination` must be `None`, and the `bips` of the destinations
+   * must add up to 10_000 (100%). Max 10 destinations.
+   */
+  destinations?: DestinationElement[];
+  /**
+   * The maximum percentage of the operator's total stake that can be slashed. The value is
+   * represented in bips (basis points), where 100 bips = 1%. And the value must be between 0
+   * and 10_000 (inclusive).
+   */
+  max_slashing_bips: number;
+  /**
+   * The minimum amount of time (in seconds) that the slashing can be delayed before it is
+   * executed and finalized. Setting this value to a duration less than the queued withdrawal
+   * delay is recommended. To prevent restaker's early withdrawal of their assets from the
+   * vault due to the impending slash, defeating the purpose of shared security.
+   */
+  resolution_window: number;
+}
+
+export interface DestinationElement {
+  /**
+   * The share of the slashed funds in bips (basis points), where 100 bips = 1%. Must be
+   * greater than zero.
+   */
+  bips: number;
+  recipient: PurpleSlashingRecipientClass | SlashingRecipientEnum;
+}
+
+/**
+ * The slashed funds are sent to the address.
+ */
+export interface PurpleSlashingRecipientClass {
+  address: string;
+}
+
+export interface OperatorManagerListResponse {
+  manager: string;
+  permissions: ManagerPermission[];
+}
+
+/**
+ * Metadata of a service or operator. The latest metadata is stored on-chain (with history)
+ * and also emitted as `MetadataUpdated` events. When updating the metadata, fields that are
+ * `None` are not updated.
+ */
+export interface MetadataResponse {
+  name?: null | string;
+  uri?: null | string;
+}
+
+/**
+ * Progress of the sunset of a retired service or operator, see
+ * [`ExecuteMsg::DeregisterAsService`](crate::msg::ExecuteMsg::DeregisterAsService) and
+ * [`ExecuteMsg::DeregisterAsOperator`](crate::msg::ExecuteMsg::DeregisterAsOperator).
+ */
+export interface SunsetResponse {
+  /**
+   * Whether all relationships have been walked and deregistered
+   */
+  completed: boolean;
+  /**
+   * The counterparty of the last walked relationship, relationships are walked in ascending
+   * order of the counterparty address.
+   */
+  last?: null | string;
+}
+
+export interface PendingCommissionResponse {
+  /**
+   * The proposed commission (in bips).
+   */
+  commission_bips: number;
+  /**
+   * The timestamp (in seconds) at which the proposed commission can be activated.
+   */
+  effective_timestamp: number;
+}
+
+export interface PendingSlashingParametersResponse {
+  /**
+   * The timestamp (in seconds) at which the proposed slashing parameters can be activated.
+   */
+  effective_timestamp: number;
+  /**
+   * The proposed slashing parameters.
+   */
+  slashing_parameters: PendingSlashingParametersResponseSlashingParameters;
+}
+
+/**
+ * The proposed slashing parameters.
+ */
+export interface PendingSlashingParametersResponseSlashingParameters {
+  /**
+   * The asset ids (CAIP-19) of the vaults the service accepts as security and can slash.
+   * Empty, indicates that the vaults of every asset can be slashed. Max 10 asset ids.
+   */
+  asset_ids?: string[];
+  /**
+   * The address to which the slashed funds will be sent after the slashing is finalized.
+   * None, indicates that the slashed funds will be burned.
+   */
+  destination?: null | string;
+  /**
+   * The weighted destinations the slashed funds are split between after the slashing is
+   * finalized. If not empty, `destination` must be `None`, and the `bips` of the destinations
+   * must add up to 10_000 (100%). Max 10 destinations.
+   */
+  destinations?: SlashingParametersWeightedDestination[];
+  /**
+   * The maximum percentage of the operator's total stake that can be slashed. The value is
+   * represented in bips (basis points), where 100 bips = 1%. And the value must be between 0
+   * and 10_000 (inclusive).
+   */
+  max_slashing_bips: number;
+  /**
+   * The minimum amount of time (in seconds) that the slashing can be delayed before it is
+   * executed and finalized. Setting this value to a duration less than the queued withdrawal
+   * delay is recommended. To prevent restaker's early withdrawal of their assets from the
+   * vault due to the impending slash, defeating the purpose of shared security.
+   */
+  resolution_window: number;
+}
+
+export interface SlashingParametersWeightedDestination {
+  /**
+   * The share of the slashed funds in bips (basis points), where 100 bips = 1%. Must be
+   * greater than zero.
+   */
+  bips: number;
+  recipient: FluffySlashingRecipientClass | SlashingRecipientEnum;
+}
+
+/**
+ * The slashed funds are sent to the address.
+ */
+export interface FluffySlashingRecipientClass {
+  address: string;
+}
+
+/**
+ * A registration status change at `timestamp` (in seconds), the new status is effective
+ * from `timestamp + 1` in timestamp queries.
+ */
+export interface RegistrationHistoryResponse {
+  status: number;
+  timestamp: number;
+}
+
 export interface SlashingParametersResponse {
+  /**
+   * The asset ids (CAIP-19) of the vaults the service accepts as security and can slash.
+   * Empty, indicates that the vaults of every asset can be slashed. Max 10 asset ids.
+   */
+  asset_ids?: string[];
+  /**
+   * The address to which the slashed funds will be sent after the slashing is finalized.
+   * None, indicates that the slashed funds will be burned.
+   */
+  destination?: null | string;
+  /**
+   * The weighted destinations the slashed funds are split between after the slashing is
+   * finalized. If not empty, `destination` must be `None`, and the `bips` of the destinations
+   * must add up to 10_000 (100%). Max 10 destinations.
+   */
+  destinations?: SlashingParametersResponseWeightedDestination[];
+  /**
+   * The maximum percentage of the operator's total stake that can be slashed. The value is
+   * represented in bips (basis points), where 100 bips = 1%. And the value must be between 0
+   * and 10_000 (inclusive).
+   */
+  max_slashing_bips: number;
+  /**
+   * The minimum amount of time (in seconds) that the slashing can be delayed before it is
+   * executed and finalized. Setting this value to a duration less than the queued withdrawal
+   * delay is recommended. To prevent restaker's early withdrawal of their assets from the
+   * vault due to the impending slash, defeating the purpose of shared security.
+   */
+  resolution_window: number;
+}
+
+export interface SlashingParametersResponseWeightedDestination {
+  /**
+   * The share of the slashed funds in bips (basis points), where 100 bips = 1%. Must be
+   * greater than zero.
+   */
+  bips: number;
+  recipient: TentacledSlashingRecipientClass | SlashingRecipientEnum;
+}
+
+/**
+ * The slashed funds are sent to the address.
+ */
+export interface TentacledSlashingRecipientClass {
+  address: string;
+}
+
+/**
+ * A slashing opt-in change at `timestamp` (in seconds), `opted_in` is `false` if the opt-in
+ * was reset.
+ */
+export interface SlashingOptInHistoryResponse {
+  opted_in: boolean;
+  timestamp: number;
+}
+
+/**
+ * A slashing parameters change at `timestamp` (in seconds), `slashing_parameters` is `None`
+ * if slashing was disabled.
+ */
+export interface SlashingParametersHistoryResponse {
+  slashing_parameters?: SlashingParametersHistoryResponseSlashingParameters | null;
+  timestamp: number;
+}
+
+export interface SlashingParametersHistoryResponseSlashingParameters {
+  /**
+   * The asset ids (CAIP-19) of the vaults the service accepts as security and can slash.
+   * Empty, indicates that the vaults of every asset can be slashed. Max 10 asset ids.
+   */
+  asset_ids?: string[];
   /**
    * The address to which the slashed funds will be sent after the slashing is finalized.
    * None, indicates that the slashed funds will be burned.
    */
   destination?: null | string;
+  /**
+   * The weighted destinations the slashed funds are split between after the slashing is
+   * finalized. If not empty, `destination` must be `None`, and the `bips` of the destinations
+   * must add up to 10_000 (100%). Max 10 destinations.
+   */
+  destinations?: PurpleWeightedDestination[];
   /**
    * The maximum percentage of the operator's total stake that can be slashed. The value is
    * represented in bips (basis points), where 100 bips = 1%. And the value must be between 0
@@ -163,3 +1228,19 @@ export interface SlashingParametersResponse {
    */
   resolution_window: number;
 }
+
+export interface PurpleWeightedDestination {
+  /**
+   * The share of the slashed funds in bips (basis points), where 100 bips = 1%. Must be
+   * greater than zero.
+   */
+  bips: number;
+  recipient: StickySlashingRecipientClass | SlashingRecipientEnum;
+}
+
+/**
+ * The slashed funds are sent to the address.
+ */
+export interface StickySlashingRecipientClass {
+  address: string;
+}
@@ -2,6 +2,121 @@
 // DO NOT MODIFY IT BY HAND.
 
 /**
+ * A thin wrapper around u64 that is using strings for JSON encoding/decoding, such that the
+ * full u64 range can be used for clients that convert JSON numbers to floats, like
+ * JavaScript and jq.
+ *
+ * # Examples
+ *
+ * Use `from` to create instances of this and `u64` to get the value out:
+ *
+ * ``` # use cosmwasm_std::Uint64; let a = Uint64::from(42u64); assert_eq!(a.u64(), 42);
+ *
+ * let b = Uint64::from(70u32); assert_eq!(b.u64(), 70); ```
+ *
+ * The timestamp at which the slashing condition occurred.
+ *
+ * A point in time in nanosecond precision.
+ *
+ * This type can represent times from 1970-01-01T00:00:00Z to 2554-07-21T23:34:33Z.
+ *
+ * ## Examples
+ *
+ * ``` # use cosmwasm_std::Timestamp; let ts = Timestamp::from_nanos(1_000_000_202);
+ * assert_eq!(ts.nanos(), 1_000_000_202); assert_eq!(ts.seconds(), 1);
+ * assert_eq!(ts.subsec_nanos(), 202);
+ *
+ * let ts = ts.plus_seconds(2); assert_eq!(ts.nanos(), 3_000_000_202);
+ * assert_eq!(ts.seconds(), 3); assert_eq!(ts.subsec_nanos(), 202); ```
+ *
+ * SlashingRequestId stores the id in hexbinary. It's a 32-byte hash of the slashing
+ * request
+ *
+ * This is a wrapper around Vec<u8> to add hex de/serialization with serde. It also adds
+ * some helper methods to help encode inline.
+ *
+ * This is similar to `cosmwasm_std::Binary` but uses hex. See also
+ * <https://github.com/CosmWasm/cosmwasm/blob/main/docs/MESSAGE_TYPES.md>.
+ *
+ * A human readable address.
+ *
+ * In Cosmos, this is typically bech32 encoded. But for multi-chain smart contracts no
+ * assumptions should be made other than being UTF-8 encoded and of reasonable length.
+ *
+ * This type represents a validated address. It can be created in the following ways 1. Use
+ * `Addr::unchecked(input)` 2. Use `let checked: Addr = deps.api.addr_validate(input)?` 3.
+ * Use `let checked: Addr = deps.api.addr_humanize(canonical_addr)?` 4. Deserialize from
+ * JSON. This must only be done from JSON that was validated before such as a contract's
+ * state. `Addr` must not be used in messages sent by the user because this would result in
+ * unvalidated instances.
+ *
+ * This type is immutable. If you really need to mutate it (Really? Are you sure?), create a
+ * mutable copy using `let mut mutable = Addr::to_string()` and operate on that `String`
+ * instance.
+ *
+ * The response to the `AllocationDelay` query. Not exported. This is just a wrapper around
+ * `Uint64`, so that the schema can be generated.
+ *
+ * A thin wrapper around u128 that is using strings for JSON encoding/decoding, such that
+ * the full u128 range can be used for clients that convert JSON numbers to floats, like
+ * JavaScript and jq.
+ *
+ * # Examples
+ *
+ * Use `from` to create instances of this and `u128` to get the value out:
+ *
+ * ``` # use cosmwasm_std::Uint128; let a = Uint128::from(123u128); assert_eq!(a.u128(),
+ * 123);
+ *
+ * let b = Uint128::from(42u64); assert_eq!(b.u128(), 42);
+ *
+ * let c = Uint128::from(70u32); assert_eq!(c.u128(), 70); ```
+ *
+ * The timestamp after which the request is no longer valid. This will be `request_time` +
+ * `resolution_window` + `SLASHING_REQUEST_EXPIRY_WINDOW`
+ *
+ * The timestamp when the request resolution window will end and becomes eligible for
+ * locking. This will be `request_time` + `resolution_window`.
+ *
+ * The timestamp when the request was submitted.
+ *
+ * The service that initiated the slashing request.
+ *
+ * The response to the `WithdrawalLockPeriod` query. Not exported. This is just a wrapper
+ * around `Uint64`, so that the schema can be generated.
+ *
+ * The total assets under management.
+ *
+ * A fixed-point decimal value with 18 fractional digits, i.e.
+ * Decimal(1_000_000_000_000_000_000) == 1.0
+ *
+ * The greatest possible value that can be represented is
+ * 340282366920938463463.374607431768211455 (which is (2^128 - 1) / 10^18)
+ *
+ * The assets allocated to services, `total_assets * allocated_bips / 10,000` rounded down.
+ *
+ * The assets free to allocate, `total_assets - allocated_assets`.
+ *
+ * Total assets in the vault
+ */
+type AllocationDelayResponse = string;
+
+/**
+ * The response to the `IsSlashPending` query. Not exported. This is just a wrapper around
+ * `bool`, so that the schema can be generated.
+ *
+ * The response to the `IsValidating` query. Not exported. This is just a wrapper around
+ * `bool`, so that the schema can be generated.
+ *
+ * The response to the `IsWhitelisted` query. Not exported. This is just a wrapper around
+ * `bool`, so that the schema can be generated.
+ */
+type IsSlashPendingResponse = boolean;
+
+/**
+ * The response to the `IsSlashPending` query. Not exported. This is just a wrapper around
+ * `bool`, so that the schema can be generated.
+ *
  * The response to the `IsValidating` query. Not exported. This is just a wrapper around
  * `bool`, so that the schema can be generated.
  *
@@ -11,6 +126,9 @@
 type IsValidatingResponse = boolean;
 
 /**
+ * The response to the `IsSlashPending` query. Not exported. This is just a wrapper around
+ * `bool`, so that the schema can be generated.
+ *
  * The response to the `IsValidating` query. Not exported. This is just a wrapper around
  * `bool`, so that the schema can be generated.
  *
@@ -19,6 +137,15 @@ type IsValidatingResponse = boolean;
  */
 type IsWhitelistedResponse = boolean;
 
+/**
+ * The response to the `MaxVaultsPerOperator` query. Not exported. This is just a wrapper
+ * around `u32`, so that the schema can be generated.
+ *
+ * The response to the `OperatorVaultCount` query. Not exported. This is just a wrapper
+ * around `u32`, so that the schema can be generated.
+ */
+type MaxVaultsPerOperatorResponse = number;
+
 /**
  * A thin wrapper around u64 that is using strings for JSON encoding/decoding, such that the
  * full u64 range can be used for clients that convert JSON numbers to floats, like
@@ -72,6 +199,9 @@ type IsWhitelistedResponse = boolean;
  * mutable copy using `let mut mutable = Addr::to_string()` and operate on that `String`
  * instance.
  *
+ * The response to the `AllocationDelay` query. Not exported. This is just a wrapper around
+ * `Uint64`, so that the schema can be generated.
+ *
  * A thin wrapper around u128 that is using strings for JSON encoding/decoding, such that
  * the full u128 range can be used for clients that convert JSON numbers to floats, like
  * JavaScript and jq.
@@ -99,9 +229,32 @@ type IsWhitelistedResponse = boolean;
  *
  * The response to the `WithdrawalLockPeriod` query. Not exported. This is just a wrapper
  * around `Uint64`, so that the schema can be generated.
+ *
+ * The total assets under management.
+ *
+ * A fixed-point decimal value with 18 fractional digits, i.e.
+ * Decimal(1_000_000_000_000_000_000) == 1.0
+ *
+ * The greatest possible value that can be represented is
+ * 340282366920938463463.374607431768211455 (which is (2^128 - 1) / 10^18)
+ *
+ * The assets allocated to services, `total_assets * allocated_bips / 10,000` rounded down.
+ *
+ * The assets free to allocate, `total_assets - allocated_assets`.
+ *
+ * Total assets in the vault
  */
 type WithdrawalLockPeriodResponse = string;
 
+/**
+ * The response to the `MaxVaultsPerOperator` query. Not exported. This is just a wrapper
+ * around `u32`, so that the schema can be generated.
+ *
+ * The response to the `OperatorVaultCount` query. Not exported. This is just a wrapper
+ * around `u32`, so that the schema can be generated.
+ */
+type OperatorVaultCountResponse = number;
+
 export interface InstantiateMsg {
   guardrail: string;
   owner: string;
@@ -116,6 +269,45 @@ export interface InstantiateMsg {
  * ExecuteMsg SetWithdrawalLockPeriod the lock period for withdrawal. Only the `owner` can
  * call this message.
  *
+ * ExecuteMsg SetMinWithdrawalLockPeriod the minimum lock period for withdrawal. The default
+ * and per-operator withdrawal lock periods cannot be set below it, effective withdrawal
+ * lock periods below it are raised to it. Only the `owner` can call this message.
+ *
+ * ExecuteMsg SetOperatorWithdrawalLockPeriod the lock period for withdrawal of the vaults
+ * of the operator (info.sender), overriding the default withdrawal lock period. Set to
+ * `None` to remove the override. An increase is effective immediately, a decrease is
+ * effective after the current withdrawal lock period of the operator. Only a registered
+ * operator can call this message, once the `owner` has set the minimum withdrawal lock
+ * period.
+ *
+ * ExecuteMsg SetMaxVaultsPerOperator the max number of (whitelisted) vaults an operator can
+ * have. Whitelisting a new vault of an operator at the max fails. This bounds the number of
+ * vaults iterated over when locking a slashing request, so it cannot exceed 80 (the lock
+ * slashing gas limit, less its fixed cost, over the estimated gas per vault). Only the
+ * `owner` can call this message.
+ *
+ * ExecuteMsg SetOracle the oracle contract (BVS Oracle) used to value the assets under
+ * management in USD. Set to `None` to remove the oracle. Only the `owner` can call this
+ * message.
+ *
+ * ExecuteMsg SetAllocationDelay the delay (in seconds) before an allocation increase
+ * becomes slashable. Only the `owner` can call this message.
+ *
+ * ExecuteMsg SetAllocation allocates `bips` of the stake of the `vault` to the `service`,
+ * the slashable magnitude of the vault for the service. The allocations of a vault
+ * (including pending deallocations) cannot exceed 10,000 bips (100%).
+ *
+ * An allocation increase becomes slashable after the allocation delay, and requires an
+ * active registration between the operator and the service. A deallocation becomes
+ * effective after the withdrawal lock period of the operator, until then the stake is still
+ * slashable at the current allocation. Only one allocation change can be pending at a time
+ * per (vault, service).
+ *
+ * The first allocation of an operator becomes effective after the longer of the allocation
+ * delay and the withdrawal lock period of the operator. Once it is effective, a service can
+ * only slash the stake allocated to it (at the incident timestamp). Only the operator of
+ * the vault (info.sender) can call this message.
+ *
  * ExecuteMsg TransferOwnership See [`bvs_library::ownership::transfer_ownership`] for more
  * information on this field
  *
@@ -130,8 +322,12 @@ export interface InstantiateMsg {
  * The slashing amount (in bips) must not exceed the max_slashing_bips set by the service -
  * The operator must have opted in to slashing at the specified timestamp - The timestamp
  * must be within the allowable slashing window (not too old or in the future) - The service
- * must not have another active slashing request against the same operator - The reason
- * provided in metadata must not exceed the maximum allowed length
+ * must not have another active slashing request against the same operator - The previous
+ * slashing request of the service against the operator, if canceled or expired, must have
+ * ended at least [`SLASHING_REQUEST_COOLDOWN`](crate::state::SLASHING_REQUEST_COOLDOWN) ago
+ * - The reason provided in metadata must not exceed the maximum allowed length - The
+ * selected vaults (if provided) must belong to the operator and their assets must be
+ * accepted by the service
  *
  * When successful, this creates a slashing request with an expiry time based on the
  * resolution_window parameter and returns a unique slashing request ID.
@@ -147,21 +343,76 @@ export interface InstantiateMsg {
  * The service (slash initiator) should cancel the slashing process if the operator has
  * resolved the issue. The definition of “resolved” is up to the service to define.
  *
+ * ExecuteMsg ExpireSlashing moves a pending slashing request past its `request_expiry` to
+ * the `Expired` status, clearing it as the pending slashing request of the service and
+ * operator.
+ *
+ * Anyone can call this message, so a stale slashing request doesn't linger as pending until
+ * the service files a new one.
+ *
  * ExecuteMsg FinalizeSlashing moves the slashed collateral from the router to the
  * destination specified in the slashing parameters that were agreed upon by the service and
  * operator.
  *
  * This is the final step in the slashing process and should only be called after the
  * request has been locked, and the guardrail proposal has been voted on and passed.
+ *
+ * ExecuteMsg SweepSlashed transfers the idle slashed collateral of the slashing request
+ * (held by the router after it was finalized without a destination) to the `recipient`. The
+ * collateral stranded before the router tracked it is held under the all-zeros slashing
+ * request id. Only the `owner` or the `guardrail` can call this message.
+ *
+ * ExecuteMsg RedistributeSlashed splits the idle slashed collateral of the slashing request
+ * (held by the router after it was finalized without a destination) between the weighted
+ * `destinations`. The `bips` of the destinations must add up to 10_000 (100%), each share
+ * is rounded down and the rounding dust goes to the first destination. Only the `owner` or
+ * the `guardrail` can call this message.
  */
 export interface ExecuteMsg {
   set_vault?: SetVault;
   set_withdrawal_lock_period?: string;
+  set_min_withdrawal_lock_period?: string;
+  set_operator_withdrawal_lock_period?: null | string;
+  set_max_vaults_per_operator?: number;
+  set_oracle?: SetOracle;
+  set_allocation_delay?: string;
+  set_allocation?: SetAllocation;
   transfer_ownership?: TransferOwnership;
   request_slashing?: RequestSlashingClass;
   lock_slashing?: string;
   cancel_slashing?: string;
+  expire_slashing?: string;
   finalize_slashing?: string;
+  sweep_slashed?: SweepSlashed;
+  redistribute_slashed?: RedistributeSlashed;
+}
+
+export interface RedistributeSlashed {
+  destinations: WeightedDestination[];
+  slashing_request_id: string;
+}
+
+export interface WeightedDestination {
+  /**
+   * The share of the slashed funds in bips (basis points), where 100 bips = 1%. Must be
+   * greater than zero.
+   */
+  bips: number;
+  recipient: SlashingRecipientClass | SlashingRecipientEnum;
+}
+
+/**
+ * The slashed funds are sent to the address.
+ */
+export interface SlashingRecipientClass {
+  address: string;
+}
+
+/**
+ * The slashed funds are burned.
+ */
+export enum SlashingRecipientEnum {
+  Burn = "burn",
 }
 
 export interface RequestSlashingClass {
@@ -171,6 +422,13 @@ export interface RequestSlashingClass {
    * have opted in.
    */
   bips: number;
+  /**
+   * The named slashing category (registered by the service in the registry) of the slashing
+   * condition. If provided, the slashing parameters and operator opt-in of the category at
+   * the timestamp are enforced, else the service default slashing parameters and opt-in are
+   * enforced.
+   */
+  category?: null | string;
   /**
    * Additional contextual information about the slashing request.
    */
@@ -184,6 +442,13 @@ export interface RequestSlashingClass {
    * The timestamp at which the slashing condition occurred.
    */
   timestamp: string;
+  /**
+   * The vaults of the operator to slash, if provided, else every vault of the operator. Each
+   * vault must belong to the operator and its asset must be accepted by the service (see the
+   * `asset_ids` of the slashing parameters at the timestamp). Vaults of assets not accepted
+   * by the service are never slashed.
+   */
+  vaults?: string[] | null;
 }
 
 /**
@@ -197,11 +462,26 @@ export interface RequestSlashingMetadata {
   reason: string;
 }
 
+export interface SetAllocation {
+  bips: number;
+  service: string;
+  vault: string;
+}
+
+export interface SetOracle {
+  oracle?: null | string;
+}
+
 export interface SetVault {
   vault: string;
   whitelisted: boolean;
 }
 
+export interface SweepSlashed {
+  recipient: string;
+  slashing_request_id: string;
+}
+
 export interface TransferOwnership {
   new_owner: string;
 }
@@ -213,23 +493,103 @@ export interface TransferOwnership {
  * QueryMsg IsValidating: returns true if the operator is validating services. See BVS
  * Registry for more information.
  *
+ * QueryMsg IsSlashPending: returns true if a slash that would slash the `vault` is pending,
+ * a slashing request against the vault's operator that is not locked, canceled or expired
+ * yet, that selects the vault (if it selects vaults), accepts the vault's asset and has
+ * stake of the vault allocated to its service at the incident timestamp. The vault freezes
+ * redemptions while a slash is pending.
+ *
  * QueryMsg ListVaults: returns a list of vaults. You can provide `limit` and `start_after`
  * to paginate the results. The max `limit` is 100.
  *
  * QueryMsg ListVaultsByOperator: returns a list of vaults managed by given operator. You
  * can provide `limit` and `start_after` to paginate the results. The max `limit` is 100.
  *
- * QueryMsg WithdrawalLockPeriod: returns the withdrawal lock period.
+ * QueryMsg WithdrawalLockPeriod: returns the default withdrawal lock period, for operators
+ * without an override.
+ *
+ * QueryMsg MinWithdrawalLockPeriod: returns the minimum withdrawal lock period.
+ *
+ * QueryMsg OperatorWithdrawalLockPeriod: returns the effective withdrawal lock period of
+ * the operator. Called by the vaults when queuing withdrawals, The period in effect at the
+ * incident is the max slashable delay of slashing requests against the operator.
+ *
+ * QueryMsg MaxVaultsPerOperator: returns the max number of vaults an operator can have.
+ *
+ * QueryMsg OperatorVaultCount: returns the number of (whitelisted) vaults of the operator,
+ * counted towards the max vaults per operator.
+ *
+ * QueryMsg Oracle: returns the oracle contract, `None` if not set.
+ *
+ * QueryMsg OperatorAum: returns the assets under management of the operator, the
+ * `total_assets` of its whitelisted vaults aggregated per asset. If `slashing_request_id`
+ * is provided, returns the assets under management of the operator at the moment the
+ * slashing request was locked instead. If the oracle is set, each current asset is valued
+ * in USD at the current price.
+ *
+ * QueryMsg VaultAum: returns the assets under management of the vault. If
+ * `slashing_request_id` is provided, returns the assets under management of the vault at
+ * the moment the slashing request was locked instead. If the oracle is set, the current
+ * asset is valued in USD at the current price.
+ *
+ * QueryMsg AllocationDelay: returns the delay (in seconds) before an allocation increase
+ * becomes slashable.
+ *
+ * QueryMsg Allocation: returns the current allocation of the `vault` to the `service`, with
+ * the pending allocation change if any.
+ *
+ * QueryMsg VaultAllocations: returns the allocations of the `vault` to services, with the
+ * stake allocated (including pending deallocations) and free to allocate.
+ *
+ * QueryMsg ListSlashingRequests: returns a list of slashing requests (of any status,
+ * including history), filtered by `service`, `operator` and `status` if provided. Ordered
+ * from the latest to the earliest requested (by `request_time`, then by id). A `status`
+ * filter combined with `service` or `operator` is applied while iterating over their
+ * requests, it can read the whole history of the operator (or service) to fill a page. You
+ * can provide `limit` and `start_after` to paginate the results. The max `limit` is 100.
+ *
+ * QueryMsg IdleSlashedBalances: returns the idle slashed collateral held by the router (of
+ * slashing requests finalized without a destination), per asset.
+ *
+ * QueryMsg IdleSlashed: returns the idle slashed collateral held by the router for the
+ * slashing request, per asset.
  */
 export interface QueryMsg {
   is_whitelisted?: IsWhitelisted;
   is_validating?: IsValidating;
+  is_slash_pending?: IsSlashPending;
   list_vaults?: ListVaults;
   list_vaults_by_operator?: ListVaultsByOperator;
   withdrawal_lock_period?: WithdrawalLockPeriod;
+  min_withdrawal_lock_period?: MinWithdrawalLockPeriod;
+  operator_withdrawal_lock_period?: OperatorWithdrawalLockPeriod;
+  max_vaults_per_operator?: MaxVaultsPerOperator;
+  operator_vault_count?: OperatorVaultCount;
+  oracle?: Oracle;
+  operator_aum?: OperatorAum;
+  vault_aum?: VaultAum;
+  allocation_delay?: AllocationDelay;
+  allocation?: AllocationClass;
+  vault_allocations?: VaultAllocations;
   slashing_request_id?: SlashingRequestID;
   slashing_request?: string;
   slashing_locked?: SlashingLocked;
+  list_slashing_requests?: ListSlashingRequests;
+  idle_slashed_balances?: IdleSlashedBalances;
+  idle_slashed?: string;
+}
+
+export interface AllocationClass {
+  service: string;
+  vault: string;
+}
+
+export interface AllocationDelay {}
+
+export interface IdleSlashedBalances {}
+
+export interface IsSlashPending {
+  vault: string;
 }
 
 export interface IsValidating {
@@ -240,6 +600,33 @@ export interface IsWhitelisted {
   vault: string;
 }
 
+export interface ListSlashingRequests {
+  limit?: number | null;
+  operator?: null | string;
+  service?: null | string;
+  start_after?: null | string;
+  status?: SlashingRequestStatus | null;
+}
+
+/**
+ * The slashing request is pending and has not been processed yet.
+ *
+ * The slashing request has been executed and funds are locked.
+ *
+ * The slashing request has been finalized.
+ *
+ * The slashing request has been canceled.
+ *
+ * The slashing request has expired without being locked.
+ */
+export enum SlashingRequestStatus {
+  Canceled = "canceled",
+  Expired = "expired",
+  Finalized = "finalized",
+  Locked = "locked",
+  Pending = "pending",
+}
+
 export interface ListVaults {
   limit?: number | null;
   start_after?: null | string;
@@ -251,6 +638,25 @@ export interface ListVaultsByOperator {
   start_after?: null | string;
 }
 
+export interface MaxVaultsPerOperator {}
+
+export interface MinWithdrawalLockPeriod {}
+
+export interface OperatorAum {
+  operator: string;
+  slashing_request_id?: null | string;
+}
+
+export interface OperatorVaultCount {
+  operator: string;
+}
+
+export interface OperatorWithdrawalLockPeriod {
+  operator: string;
+}
+
+export interface Oracle {}
+
 export interface SlashingLocked {
   slashing_request_id: string;
 }
@@ -260,8 +666,133 @@ export interface SlashingRequestID {
   service: string;
 }
 
+export interface VaultAllocations {
+  vault: string;
+}
+
+export interface VaultAum {
+  slashing_request_id?: null | string;
+  vault: string;
+}
+
 export interface WithdrawalLockPeriod {}
 
+export interface AllocationResponse {
+  /**
+   * The allocated magnitude (bips of the vault's stake) slashable by the service.
+   */
+  bips: number;
+  /**
+   * The allocation change pending until its `effective_time`.
+   */
+  pending?: PendingAllocation | null;
+}
+
+export interface PendingAllocation {
+  bips: number;
+  effective_time: string;
+}
+
+/**
+ * The response to the `IdleSlashedBalances` and `IdleSlashed` queries. One item per asset,
+ * ordered by `asset_id`.
+ */
+export interface IdleSlashedResponse {
+  amount: string;
+  /**
+   * Asset identifier, using the CAIP-19 format.
+   */
+  asset_id: string;
+}
+
+/**
+ * The response to the `ListSlashingRequests` query. For pagination, the `start_after` field
+ * is the last `id` from the previous page.
+ */
+export interface SlashingRequestListResponse {
+  id: string;
+  slashing_request: SlashingRequest;
+}
+
+export interface SlashingRequest {
+  /**
+   * The core slashing request data including operator, bips, timestamp, and metadata.
+   */
+  request: RequestSlashingPayload;
+  /**
+   * The timestamp after which the request is no longer valid. This will be `request_time` +
+   * `resolution_window` + `SLASHING_REQUEST_EXPIRY_WINDOW`
+   */
+  request_expiry: string;
+  /**
+   * The timestamp when the request resolution window will end and becomes eligible for
+   * locking. This will be `request_time` + `resolution_window`.
+   */
+  request_resolution: string;
+  /**
+   * The timestamp when the request was submitted.
+   */
+  request_time: string;
+  /**
+   * The service that initiated the slashing request.
+   */
+  service: string;
+  /**
+   * The status of the slashing request.
+   */
+  status: number;
+}
+
+/**
+ * The core slashing request data including operator, bips, timestamp, and metadata.
+ */
+export interface RequestSlashingPayload {
+  /**
+   * The percentage of tokens to slash in basis points (1/100th of a percent). Max bips to
+   * slash is set by the service slashing parameters at the timestamp and the operator must
+   * have opted in.
+   */
+  bips: number;
+  /**
+   * The named slashing category (registered by the service in the registry) of the slashing
+   * condition. If provided, the slashing parameters and operator opt-in of the category at
+   * the timestamp are enforced, else the service default slashing parameters and opt-in are
+   * enforced.
+   */
+  category?: null | string;
+  /**
+   * Additional contextual information about the slashing request.
+   */
+  metadata: SlashingMetadata;
+  /**
+   * The operator address to slash. (service, operator) must have active registration at the
+   * timestamp.
+   */
+  operator: string;
+  /**
+   * The timestamp at which the slashing condition occurred.
+   */
+  timestamp: string;
+  /**
+   * The vaults of the operator to slash, if provided, else every vault of the operator. Each
+   * vault must belong to the operator and its asset must be accepted by the service (see the
+   * `asset_ids` of the slashing parameters at the timestamp). Vaults of assets not accepted
+   * by the service are never slashed.
+   */
+  vaults?: string[] | null;
+}
+
+/**
+ * Additional contextual information about the slashing request.
+ */
+export interface SlashingMetadata {
+  /**
+   * The reason for the slashing request. Must contain human-readable string. Max length of
+   * 250 characters, empty string is allowed but not recommended.
+   */
+  reason: string;
+}
+
 /**
  * The response to the `ListVaults` query. For pagination, the `start_after` field is the
  * last `vault` from the previous page.
@@ -271,6 +802,28 @@ export interface VaultListResponse {
   whitelisted: boolean;
 }
 
+/**
+ * The assets under management of a single asset.
+ *
+ * The response to the `OperatorAum` query. One item per asset, ordered by `asset_id`.
+ */
+export interface OperatorAumResponse {
+  /**
+   * Asset identifier, using the CAIP-19 format.
+   */
+  asset_id: string;
+  /**
+   * The total assets under management.
+   */
+  total_assets: string;
+  /**
+   * The USD value of the `total_assets`, at the current price of the oracle. `None` if the
+   * oracle is not set, has no (valid) price for the asset, or for assets under management at
+   * a slashing request.
+   */
+  usd_value?: null | string;
+}
+
 export interface SlashingLockedResponse {
   amount: string;
   vault: string;
@@ -315,6 +868,13 @@ export interface RequestClass {
    * have opted in.
    */
   bips: number;
+  /**
+   * The named slashing category (registered by the service in the registry) of the slashing
+   * condition. If provided, the slashing parameters and operator opt-in of the category at
+   * the timestamp are enforced, else the service default slashing parameters and opt-in are
+   * enforced.
+   */
+  category?: null | string;
   /**
    * Additional contextual information about the slashing request.
    */
@@ -328,6 +888,13 @@ export interface RequestClass {
    * The timestamp at which the slashing condition occurred.
    */
   timestamp: string;
+  /**
+   * The vaults of the operator to slash, if provided, else every vault of the operator. Each
+   * vault must belong to the operator and its asset must be accepted by the service (see the
+   * `asset_ids` of the slashing parameters at the timestamp). Vaults of assets not accepted
+   * by the service are never slashed.
+   */
+  vaults?: string[] | null;
 }
 
 /**
@@ -340,3 +907,78 @@ export interface RequestMetadata {
    */
   reason: string;
 }
+
+/**
+ * The response to the `VaultAllocations` query.
+ */
+export interface VaultAllocationsResponse {
+  /**
+   * The assets allocated to services, `total_assets * allocated_bips / 10,000` rounded down.
+   */
+  allocated_assets: string;
+  /**
+   * The bips of the vault's stake allocated to services, including pending deallocations.
+   */
+  allocated_bips: number;
+  /**
+   * The allocations of the vault, ordered by `service`.
+   */
+  allocations: VaultAllocation[];
+  /**
+   * The timestamp the allocations of the vault's operator are effective from, `None` if the
+   * operator never allocated. Before it, every service of the operator can slash the full
+   * stake of the vault.
+   */
+  effective_time?: null | string;
+  /**
+   * The assets free to allocate, `total_assets - allocated_assets`.
+   */
+  free_assets: string;
+  /**
+   * Total assets in the vault
+   */
+  total_assets: string;
+}
+
+export interface VaultAllocation {
+  allocation: Allocation;
+  service: string;
+}
+
+export interface Allocation {
+  /**
+   * The allocated magnitude (bips of the vault's stake) slashable by the service.
+   */
+  bips: number;
+  /**
+   * The allocation change pending until its `effective_time`.
+   */
+  pending?: AllocationPendingAllocation | null;
+}
+
+export interface AllocationPendingAllocation {
+  bips: number;
+  effective_time: string;
+}
+
+/**
+ * The response to the `VaultAum` query.
+ *
+ * The assets under management of a single asset.
+ */
+export interface VaultAumResponse {
+  /**
+   * Asset identifier, using the CAIP-19 format.
+   */
+  asset_id: string;
+  /**
+   * The total assets under management.
+   */
+  total_assets: string;
+  /**
+   * The USD value of the `total_assets`, at the current price of the oracle. `None` if the
+   * oracle is not set, has no (valid) price for the asset, or for assets under management at
+   * a slashing request.
+   */
+  usd_value?: null | string;
+}